@@ -19,6 +19,10 @@ const SUPPORTED_LANGUAGES = [
   'vue',
   'svelte',
   'rust',
+  // Indexed only with `tarus.experimentalTextScan`
+  'astro',
+  'html',
+  'mdx',
 ] as const;
 
 let client: LanguageClient;
@@ -56,6 +60,17 @@ const activate = (context: ExtensionContext) => {
     documentSelector: SUPPORTED_LANGUAGES.map((lang) => {
       return { scheme: 'file', language: lang };
     }),
+    // Report settings changes, and on-disk changes (e.g. git branch switches) for files not
+    // open in the editor
+    synchronize: {
+      configurationSection: 'tarus',
+      fileEvents: [
+        vscode.workspace.createFileSystemWatcher(
+          '**/*.{rs,ts,tsx,js,jsx,vue,svelte,astro,html,mdx}'
+        ),
+        vscode.workspace.createFileSystemWatcher('**/capabilities/*.json'),
+      ],
+    },
   };
 
   client = new LanguageClient(
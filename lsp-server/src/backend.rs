@@ -0,0 +1,1287 @@
+//! Language server backend: the `LanguageServer` implementation wiring requests to the
+//! capability handlers and keeping the project index in sync with the workspace
+
+use dashmap::DashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::OnceCell;
+use tower_lsp_server::jsonrpc::Result;
+use tower_lsp_server::lsp_types::LSPAny;
+use tower_lsp_server::lsp_types::{
+    CodeActionParams, CodeActionResponse, CodeLens, CodeLensParams, CompletionParams,
+    CompletionResponse, ConfigurationItem, ConfigurationParams, Diagnostic,
+    DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+    DocumentChanges, DocumentSymbolParams, DocumentSymbolResponse, ExecuteCommandParams,
+    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams, InitializeParams,
+    InitializeResult, InitializedParams, InlayHint, InlayHintParams, Location, MessageType, OneOf,
+    PrepareRenameResponse, ReferenceParams, RenameParams, ServerCapabilities, SymbolInformation,
+    TextDocumentPositionParams, Uri, WorkspaceEdit, WorkspaceSymbol, WorkspaceSymbolParams,
+};
+use tower_lsp_server::{Client, ClientSocket, LanguageServer, LspService, UriExt};
+
+use crate::capabilities::diagnostics::{DiagnosticsStatus, StatusNotification};
+use crate::capabilities::{self, build_server_capabilities, diagnostics};
+use crate::indexer::{
+    CodeLensMode, DiagnosticsScope, EventNamingConvention, IndexKey, IndexingMode, ProjectIndex,
+};
+use crate::scanner::{self, is_tauri_project, ScanLimitHit, ScanLimits};
+use crate::syntax::FrontendPatterns;
+use crate::utils::uri_to_path;
+use crate::{config_reader, constants, file_processor};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+/// The Tarus language server
+#[derive(Debug)]
+pub struct Backend {
+    client: Client,
+    workspace_root: OnceCell<PathBuf>,
+    project_index: Arc<ProjectIndex>,
+    is_developer_mode_active: Arc<AtomicBool>,
+    debounce_tasks: Arc<DashMap<PathBuf, tokio::task::JoinHandle<()>>>,
+    /// Whether the client accepts nested `DocumentSymbol` responses
+    hierarchical_document_symbols: Arc<AtomicBool>,
+    /// Cache of open document contents for completion and other features.
+    /// Entries are removed on `did_close`, so the keys are the set of open files.
+    document_cache: Arc<DashMap<PathBuf, String>>,
+    /// Last syntax tree of each open Rust/TS/JS document, with pending edits applied.
+    /// An entry always matches the `document_cache` text, so it can seed an incremental parse.
+    syntax_trees: Arc<DashMap<PathBuf, tree_sitter::Tree>>,
+    /// Every diagnostics publish goes through here
+    diagnostics: DiagnosticsScheduler,
+}
+
+/// Batches `textDocument/publishDiagnostics` notifications.
+///
+/// Scheduled files are published once no new file was scheduled for
+/// [`constants::DIAGNOSTICS_QUIET_MS`], or at the latest
+/// [`constants::DIAGNOSTICS_MAX_DELAY_MS`] after the first one. A file scheduled several
+/// times in between is published once, with diagnostics computed at publish time, so bulk
+/// indexing does not flood the client while a single edit still shows up promptly.
+#[derive(Debug, Clone)]
+struct DiagnosticsScheduler {
+    client: Client,
+    project_index: Arc<ProjectIndex>,
+    /// The backend's `document_cache`, whose keys are the open files
+    open_files: Arc<DashMap<PathBuf, String>>,
+    /// Files to publish, each with whether to clear its diagnostics when it is out of scope
+    pending: Arc<parking_lot::Mutex<BTreeMap<PathBuf, bool>>>,
+    wake: Arc<Notify>,
+    worker_started: Arc<AtomicBool>,
+    status: Arc<parking_lot::Mutex<StatusTracker>>,
+}
+
+/// Diagnostics counts of each published file, summed up for `tarus/status`
+#[derive(Debug, Default)]
+struct StatusTracker {
+    /// Files with at least one error or warning published
+    files: HashMap<PathBuf, DiagnosticsStatus>,
+    /// Totals of the last `tarus/status` notification
+    sent: Option<DiagnosticsStatus>,
+}
+
+impl StatusTracker {
+    fn record(&mut self, path: &Path, diagnostics: &[Diagnostic]) {
+        let status = DiagnosticsStatus::from_diagnostics(diagnostics);
+        if status.is_empty() {
+            self.files.remove(path);
+        } else {
+            self.files.insert(path.to_path_buf(), status);
+        }
+    }
+
+    /// Workspace totals, if they changed since the last call
+    fn take_changed(&mut self) -> Option<DiagnosticsStatus> {
+        let mut total = DiagnosticsStatus::default();
+        for status in self.files.values() {
+            total.merge(status);
+        }
+
+        if self.sent.as_ref() == Some(&total) {
+            return None;
+        }
+        self.sent = Some(total.clone());
+        Some(total)
+    }
+}
+
+impl DiagnosticsScheduler {
+    fn new(
+        client: Client,
+        project_index: Arc<ProjectIndex>,
+        open_files: Arc<DashMap<PathBuf, String>>,
+    ) -> Self {
+        Self {
+            client,
+            project_index,
+            open_files,
+            pending: Arc::default(),
+            wake: Arc::new(Notify::new()),
+            worker_started: Arc::new(AtomicBool::new(false)),
+            status: Arc::default(),
+        }
+    }
+
+    /// Queue `paths` for the next publish
+    fn schedule(&self, paths: impl IntoIterator<Item = PathBuf>) {
+        self.queue(paths, false);
+    }
+
+    /// Queue `paths` for the next publish after the diagnostics scope changed: the files the
+    /// new scope leaves out get an empty list, clearing what was published under the old one
+    fn schedule_rescoped(&self, paths: impl IntoIterator<Item = PathBuf>) {
+        self.queue(paths, true);
+    }
+
+    fn queue(&self, paths: impl IntoIterator<Item = PathBuf>, clear_out_of_scope: bool) {
+        {
+            let mut pending = self.pending.lock();
+            for path in paths {
+                *pending.entry(path).or_default() |= clear_out_of_scope;
+            }
+        }
+        self.wake.notify_one();
+
+        // Started on first use: the service is built before the runtime serves it
+        if !self.worker_started.swap(true, Ordering::Relaxed) {
+            let scheduler = self.clone();
+            tokio::spawn(async move { scheduler.run().await });
+        }
+    }
+
+    async fn run(&self) {
+        let quiet = Duration::from_millis(constants::DIAGNOSTICS_QUIET_MS);
+        let max_delay = Duration::from_millis(constants::DIAGNOSTICS_MAX_DELAY_MS);
+
+        loop {
+            self.wake.notified().await;
+
+            let deadline = tokio::time::Instant::now() + max_delay;
+            while tokio::time::timeout_at(
+                deadline.min(tokio::time::Instant::now() + quiet),
+                self.wake.notified(),
+            )
+            .await
+            .is_ok()
+            {}
+
+            let paths = std::mem::take(&mut *self.pending.lock());
+            for (path, clear_out_of_scope) in paths {
+                self.publish(&path, clear_out_of_scope).await;
+            }
+            self.send_status().await;
+        }
+    }
+
+    /// Publish no diagnostics for `path`, e.g. a file closed in `openFiles` scope
+    async fn clear(&self, uri: Uri, path: &Path) {
+        self.status.lock().record(path, &[]);
+        self.client.publish_diagnostics(uri, Vec::new(), None).await;
+        self.send_status().await;
+    }
+
+    /// Send `tarus/status` when enabled and the totals changed since the last one.
+    ///
+    /// Called once per publish batch, so it is throttled like the publishes themselves.
+    async fn send_status(&self) {
+        if !self.project_index.status_notification() {
+            return;
+        }
+        let changed = self.status.lock().take_changed();
+        if let Some(status) = changed {
+            self.client
+                .send_notification::<StatusNotification>(status)
+                .await;
+        }
+    }
+
+    /// Publish the current diagnostics of `path`; a file that is no longer indexed gets an
+    /// empty list whatever the scope, so its old diagnostics are cleared. A file out of scope
+    /// is skipped, or cleared with `clear_out_of_scope`.
+    async fn publish(&self, path: &PathBuf, clear_out_of_scope: bool) {
+        // Published under the path the client opened or the scan found, not the resolved one
+        let Some(uri) = Uri::from_file_path(self.project_index.original_path(path)) else {
+            return;
+        };
+        // A file without keys may still have hints (runtime-built names, syntax errors)
+        let diagnostics = if !self.project_index.is_indexed(path) {
+            Vec::new()
+        } else {
+            match diagnostics::compute_scoped_file_diagnostics(
+                path,
+                &self.project_index,
+                &self.open_files,
+            ) {
+                Some(diagnostics) => diagnostics,
+                None if clear_out_of_scope => Vec::new(),
+                None => return,
+            }
+        };
+        self.status.lock().record(path, &diagnostics);
+        self.client
+            .publish_diagnostics(uri, diagnostics, None)
+            .await;
+    }
+}
+
+impl Backend {
+    /// Helper: Checks if the server is fully initialized (workspace root set)
+    fn is_ready(&self) -> bool {
+        self.workspace_root.get().is_some()
+    }
+
+    fn on_change(&self, path: &Path) {
+        if !self.is_ready() {
+            return;
+        }
+
+        let is_dev_mode = self.is_developer_mode_active.load(Ordering::Relaxed);
+        let before = is_dev_mode.then(|| self.project_index.file_snapshot(path));
+
+        match file_processor::process_file_index(path, &self.project_index) {
+            Ok(true) => {
+                let report = self.project_index.file_report(path);
+                self.log_dev_info(&report);
+
+                if let Some(before) = before {
+                    let delta = before.diff(&self.project_index.file_snapshot(path));
+                    if !delta.is_empty() {
+                        self.log_dev_info(&format!("🔀 Key delta:\n{delta}"));
+                    }
+                }
+            }
+            Ok(false) => {}
+            Err(e) => self.log_dev_info(&e.to_string()),
+        }
+    }
+
+    /// Log a developer-mode message without waiting for the client.
+    ///
+    /// The send runs on its own task, so a slow or disconnected client can never hold up
+    /// the request being logged. Messages may therefore arrive out of order, which is fine
+    /// for these diagnostic traces.
+    fn log_dev_info(&self, message: &str) {
+        if self.is_developer_mode_active.load(Ordering::Relaxed) {
+            let client = self.client.clone();
+            let message = message.to_string();
+            tokio::spawn(async move {
+                client.log_message(MessageType::INFO, message).await;
+            });
+        }
+    }
+
+    /// Log a developer-mode result: "✅ Found N <label>" or "⚠️ No <label> found".
+    fn log_dev_result(&self, count: Option<usize>, label: &str) {
+        match count {
+            Some(n) => self.log_dev_info(&format!("✅ Found {n} {label}")),
+            None => self.log_dev_info(&format!("⚠️ No {label} found")),
+        }
+    }
+
+    /// Log the value a setting was initialized to.
+    async fn log_setting(&self, label: &str, value: impl std::fmt::Display) {
+        self.client
+            .log_message(
+                MessageType::INFO,
+                &format!("{label} initialized to: {value}"),
+            )
+            .await;
+    }
+
+    /// Load the `constants::CONFIG_SECTIONS` settings from VS Code configuration.
+    async fn load_config(&self) {
+        let request = ConfigurationParams {
+            items: constants::CONFIG_SECTIONS
+                .iter()
+                .map(|section| ConfigurationItem {
+                    scope_uri: None,
+                    section: Some((*section).to_string()),
+                })
+                .collect(),
+        };
+
+        let Ok(response) = self.client.configuration(request.items).await else {
+            return;
+        };
+
+        let mut iter = response.into_iter();
+        self.apply_core_settings(&mut iter).await;
+        self.apply_feature_settings(&mut iter).await;
+    }
+
+    /// Apply the settings from `tarus.developerMode` through `tarus.frontend.patterns`,
+    /// in `constants::CONFIG_SECTIONS` order.
+    async fn apply_core_settings(&self, iter: &mut impl Iterator<Item = serde_json::Value>) {
+        if let Some(settings) = iter.next() {
+            if let Some(is_enabled) = settings.as_bool() {
+                self.is_developer_mode_active
+                    .store(is_enabled, Ordering::Relaxed);
+                self.project_index.set_developer_mode(is_enabled);
+                self.log_setting("Developer Mode", is_enabled).await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(limit) = settings.as_u64() {
+                self.project_index
+                    .set_reference_limit(usize::try_from(limit).unwrap_or(3));
+                self.log_setting("Reference Limit", limit).await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(scope) = settings.as_str().and_then(DiagnosticsScope::from_setting) {
+                self.project_index.set_diagnostics_scope(scope);
+                self.log_setting("Diagnostics Scope", format!("{scope:?}"))
+                    .await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(convention) = settings
+                .as_str()
+                .and_then(EventNamingConvention::from_setting)
+            {
+                self.project_index.set_event_naming(convention);
+                self.log_setting("Event Naming Convention", format!("{convention:?}"))
+                    .await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(max) = settings.as_u64() {
+                self.project_index
+                    .set_code_action_candidates(usize::try_from(max).unwrap_or(1).max(1));
+                self.log_setting("Code Action Candidates", max).await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if settings.is_array() {
+                let patterns = FrontendPatterns::from_setting(&settings);
+                let custom = patterns.custom().count();
+                self.project_index.set_frontend_patterns(patterns);
+                self.log_setting("Frontend Patterns", format!("{custom} custom"))
+                    .await;
+            }
+        }
+    }
+
+    /// Apply the settings from `tarus.experimentalTextScan` onwards, in
+    /// `constants::CONFIG_SECTIONS` order.
+    async fn apply_feature_settings(&self, iter: &mut impl Iterator<Item = serde_json::Value>) {
+        if let Some(settings) = iter.next() {
+            if let Some(is_enabled) = settings.as_bool() {
+                self.project_index.set_experimental_text_scan(is_enabled);
+                self.log_setting("Experimental Text Scan", is_enabled).await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(is_enabled) = settings.as_bool() {
+                self.project_index.set_code_lens_enabled(is_enabled);
+                self.log_setting("Code Lens", is_enabled).await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(mode) = settings.as_str().and_then(CodeLensMode::from_setting) {
+                self.project_index.set_code_lens_mode(mode);
+                self.log_setting("Code Lens Mode", format!("{mode:?}"))
+                    .await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(is_enabled) = settings.as_bool() {
+                self.project_index.set_propagate_diagnostics(is_enabled);
+                self.log_setting("Diagnostics Propagation", is_enabled)
+                    .await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(events) = settings.as_array() {
+                let events: Vec<String> = events
+                    .iter()
+                    .filter_map(|v| v.as_str().map(str::to_string))
+                    .collect();
+                self.log_setting("Framework Events", events.len()).await;
+                self.project_index.set_framework_events(events);
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(prefix) = settings.as_str() {
+                self.log_setting("Command Prefix", prefix).await;
+                self.project_index.set_command_prefix(prefix.to_string());
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(max) = settings.as_u64() {
+                self.project_index.set_hover_max_references(
+                    usize::try_from(max).unwrap_or(constants::DEFAULT_HOVER_MAX_REFERENCES),
+                );
+                self.log_setting("Hover Max References", max).await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(mode) = settings.as_str().and_then(IndexingMode::from_setting) {
+                self.project_index.set_indexing_mode(mode);
+                self.log_setting("Indexing Mode", format!("{mode:?}")).await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(is_enabled) = settings.as_bool() {
+                self.project_index.set_require_await(is_enabled);
+                self.log_setting("Require Await", is_enabled).await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(paths) = settings.as_array() {
+                let paths: Vec<String> = paths
+                    .iter()
+                    .filter_map(|v| v.as_str().map(str::to_string))
+                    .collect();
+                self.log_setting("Extra Scan Paths", paths.len()).await;
+                self.project_index.set_scan_extra_paths(paths);
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(dir) = settings.as_str() {
+                let dir = dir.trim();
+                self.log_setting("Command Directory", dir).await;
+                self.project_index
+                    .set_command_dir((!dir.is_empty()).then(|| dir.to_string()));
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(max) = settings.as_u64() {
+                self.project_index
+                    .set_scan_max_files(usize::try_from(max).unwrap_or(usize::MAX).max(1));
+                self.log_setting("Scan Max Files", max).await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(is_enabled) = settings.as_bool() {
+                self.project_index.set_status_notification(is_enabled);
+                self.log_setting("Status Notification", is_enabled).await;
+            }
+        }
+
+        if let Some(settings) = iter.next() {
+            if let Some(is_enabled) = settings.as_bool() {
+                self.project_index.set_exclude_plugin_commands(is_enabled);
+                self.log_setting("Exclude Plugin Commands", is_enabled)
+                    .await;
+            }
+        }
+    }
+
+    /// Discover type generator configurations and register their output paths, along with the
+    /// path aliases imports of invoke wrappers are resolved through.
+    async fn load_generators(&self, root: &std::path::Path) {
+        let root_for_generators = root.to_path_buf();
+        let (generators, path_aliases) = tokio::task::spawn_blocking(move || {
+            (
+                config_reader::discover_generators(&root_for_generators),
+                scanner::PathAliasResolver::from_workspace(&root_for_generators),
+            )
+        })
+        .await
+        .unwrap_or_default();
+        self.project_index.set_path_aliases(path_aliases);
+
+        if generators.is_empty() {
+            self.client
+                .log_message(
+                    MessageType::INFO,
+                    "TARUS: No type generator configurations found. Using content-based detection as fallback.",
+                )
+                .await;
+        } else {
+            for g in &generators {
+                self.client
+                    .log_message(
+                        MessageType::INFO,
+                        &format!(
+                            "TARUS: Detected {:?} generator → {}",
+                            g.kind,
+                            g.output_path.display()
+                        ),
+                    )
+                    .await;
+            }
+        }
+
+        self.project_index.set_generator_bindings(generators);
+    }
+
+    /// Re-read settings and generator configurations, then rebuild the index from scratch.
+    async fn reload_config(&self) {
+        let Some(root) = self.workspace_root.get() else {
+            return;
+        };
+
+        self.load_config().await;
+        self.load_generators(root).await;
+        self.project_index.clear();
+
+        // Open documents are indexed from their buffers, which may differ from disk
+        for doc in self.document_cache.iter() {
+            file_processor::process_file_content(doc.key(), doc.value(), &self.project_index);
+        }
+
+        self.spawn_indexing(root.clone());
+    }
+
+    /// Spawn background task that scans workspace files, indexes them, and publishes diagnostics.
+    fn spawn_indexing(&self, root: PathBuf) {
+        let project_index = self.project_index.clone();
+        let client = self.client.clone();
+        let is_dev_mode = self.is_developer_mode_active.clone();
+        let scheduler = self.diagnostics.clone();
+        let open_files = self.document_cache.clone();
+
+        tokio::spawn(async move {
+            client
+                .log_message(MessageType::INFO, "🚀 Starting background indexing...")
+                .await;
+
+            let (extra_paths, missing) =
+                scanner::resolve_extra_paths(&root, &project_index.scan_extra_paths());
+            for entry in missing {
+                client
+                    .log_message(
+                        MessageType::WARNING,
+                        &format!("TARUS: Ignoring tarus.scan.extraPaths entry '{entry}': path does not exist"),
+                    )
+                    .await;
+            }
+
+            let limits = ScanLimits {
+                max_files: project_index.scan_max_files(),
+                ..ScanLimits::default()
+            };
+            let scan = tokio::task::spawn_blocking(move || {
+                scanner::scan_workspace_files_with_limits(&root, &extra_paths, limits)
+            })
+            .await
+            .unwrap_or_default();
+
+            if let Some(limit) = scan.limit_hit {
+                let reason = match limit {
+                    ScanLimitHit::MaxFiles(max) => {
+                        format!("reached tarus.scan.maxFiles ({max} files)")
+                    }
+                    ScanLimitHit::TimeBudget(budget) => {
+                        format!("ran out of time after {}s", budget.as_secs())
+                    }
+                };
+                client
+                    .log_message(
+                        MessageType::WARNING,
+                        format!(
+                            "TARUS: Workspace scan stopped early: {reason}. Indexing the {} file(s) found so far. Open the Tauri project folder instead of a parent directory, or raise tarus.scan.maxFiles.",
+                            scan.files.len()
+                        ),
+                    )
+                    .await;
+            }
+
+            let files = scan.files;
+
+            for path in files {
+                // Open documents are indexed from their buffers, not from disk
+                if open_files.contains_key(&project_index.index_path(&path))
+                    || file_processor::is_deferred_file(&path, &project_index)
+                {
+                    continue;
+                }
+                // Failures are recorded on the index and listed in the summary logged below
+                let _ = file_processor::process_file_index(&path, &project_index);
+            }
+
+            let pruned = project_index.gc();
+            if pruned > 0 {
+                client
+                    .log_message(
+                        MessageType::INFO,
+                        format!("🧹 Pruned {pruned} stale parse error(s)"),
+                    )
+                    .await;
+            }
+
+            scheduler.schedule(project_index.get_indexed_paths());
+
+            client
+                .log_message(MessageType::INFO, project_index.technical_report())
+                .await;
+            if is_dev_mode.load(Ordering::Relaxed) {
+                client
+                    .log_message(MessageType::INFO, project_index.full_technical_report())
+                    .await;
+            }
+
+            client
+                .log_message(MessageType::INFO, "🏁 Indexing complete".to_string())
+                .await;
+        });
+    }
+}
+
+impl LanguageServer for Backend {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let hierarchical_symbols = params
+            .capabilities
+            .text_document
+            .as_ref()
+            .and_then(|td| td.document_symbol.as_ref())
+            .and_then(|ds| ds.hierarchical_document_symbol_support)
+            .unwrap_or(false);
+        self.hierarchical_document_symbols
+            .store(hierarchical_symbols, Ordering::Relaxed);
+
+        let root_path = params
+            .workspace_folders
+            .as_ref()
+            .and_then(|folders| folders.first())
+            .and_then(|folder| folder.uri.to_file_path())
+            .map(|path_cow| path_cow.to_path_buf())
+            .or_else(|| {
+                #[allow(deprecated)]
+                params
+                    .root_uri
+                    .and_then(|uri| uri.to_file_path().map(|path_cow| path_cow.to_path_buf()))
+            });
+
+        let mut is_tauri = false;
+
+        if let Some(root) = root_path {
+            if is_tauri_project(&root) {
+                is_tauri = true;
+                let _ = self.workspace_root.set(root.clone());
+
+                self.client
+                    .log_message(
+                        MessageType::INFO,
+                        "✅ Tauri project detected. Tree-sitter parser ready.",
+                    )
+                    .await;
+            }
+        }
+
+        if !is_tauri {
+            return Ok(InitializeResult {
+                capabilities: ServerCapabilities::default(),
+                server_info: None,
+            });
+        }
+
+        Ok(InitializeResult {
+            capabilities: build_server_capabilities(),
+            ..Default::default()
+        })
+    }
+
+    async fn initialized(&self, _: InitializedParams) {
+        if !self.is_ready() {
+            return;
+        }
+
+        self.load_config().await;
+
+        let Some(root) = self.workspace_root.get() else {
+            return;
+        };
+
+        self.load_generators(root).await;
+        self.spawn_indexing(root.clone());
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        self.log_dev_info(&format!(
+            "➡️ Request: Definition at {:?} line: {}, char: {}",
+            uri, position.line, position.character
+        ));
+
+        let result = capabilities::definition::handle_goto_definition(params, &self.project_index);
+
+        let count = result.as_ref().and_then(|r| {
+            if let GotoDefinitionResponse::Link(links) = r {
+                Some(links.len())
+            } else {
+                None
+            }
+        });
+        self.log_dev_result(count, "definition links");
+
+        Ok(result)
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+
+        self.log_dev_info(&format!(
+            "➡️ Request: References at {:?} line: {}, char: {}",
+            uri, position.line, position.character
+        ));
+
+        let result = capabilities::references::handle_references(params, &self.project_index);
+
+        self.log_dev_result(result.as_ref().map(Vec::len), "references");
+
+        Ok(result)
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let position = params.position;
+
+        self.log_dev_info(&format!(
+            "➡️ Request: PrepareRename at {:?} line: {}, char: {}",
+            params.text_document.uri, position.line, position.character
+        ));
+
+        Ok(capabilities::rename::handle_prepare_rename(
+            &params,
+            &self.project_index,
+        ))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+
+        self.log_dev_info(&format!(
+            "➡️ Request: Rename to {:?} at {:?} line: {}, char: {}",
+            params.new_name, uri, position.line, position.character
+        ));
+
+        let result = capabilities::rename::handle_rename(&params, &self.project_index);
+
+        self.log_dev_result(
+            result
+                .as_ref()
+                .and_then(|edit| match &edit.document_changes {
+                    Some(DocumentChanges::Edits(edits)) => Some(edits.len()),
+                    _ => None,
+                }),
+            "files to rename in",
+        );
+
+        Ok(result)
+    }
+
+    async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        let uri = &params.text_document.uri;
+
+        self.log_dev_info(&format!("➡️ Request: CodeLens for {uri:?}"));
+
+        let result = capabilities::code_lens::handle_code_lens(params, &self.project_index);
+
+        self.log_dev_result(result.as_ref().map(Vec::len), "code lenses");
+
+        Ok(result)
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        self.log_dev_info(&format!(
+            "➡️ Request: Hover at {:?} line: {}, char: {}",
+            uri, position.line, position.character
+        ));
+
+        let result = capabilities::hover::handle_hover(params, &self.project_index);
+
+        self.log_dev_result(result.as_ref().map(|_| 1), "hover tooltip");
+
+        Ok(result)
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let uri = &params.text_document.uri;
+
+        self.log_dev_info(&format!("➡️ Request: InlayHint for {uri:?}"));
+
+        let result = capabilities::inlay_hints::handle_inlay_hint(&params, &self.project_index);
+
+        self.log_dev_result(result.as_ref().map(Vec::len), "inlay hints");
+
+        Ok(result)
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = &params.text_document.uri;
+        let position = params.range.start;
+
+        self.log_dev_info(&format!(
+            "➡️ Request: CodeAction at {:?} line: {}, char: {}",
+            uri, position.line, position.character
+        ));
+
+        let workspace_root = self.workspace_root.get().cloned();
+        let result = capabilities::code_actions::handle_code_action(
+            &params,
+            &self.project_index,
+            workspace_root.as_ref(),
+        );
+
+        self.log_dev_result(result.as_ref().map(Vec::len), "code actions");
+
+        Ok(result)
+    }
+
+    async fn execute_command(&self, params: ExecuteCommandParams) -> Result<Option<LSPAny>> {
+        self.log_dev_info(&format!("➡️ Request: ExecuteCommand '{}'", params.command));
+
+        // Reloading needs the client and workspace root, so it is handled here
+        if params.command == constants::CMD_RELOAD_CONFIG {
+            self.reload_config().await;
+            return Ok(None);
+        }
+
+        let result = capabilities::execute_command::handle_execute_command(
+            &params,
+            &self.project_index,
+            self.workspace_root.get().map(PathBuf::as_path),
+        );
+
+        let count = match &result {
+            Some(serde_json::Value::Array(items)) if items.is_empty() => None,
+            Some(serde_json::Value::Array(items)) => Some(items.len()),
+            Some(_) => Some(1),
+            None => None,
+        };
+        self.log_dev_result(count, "command results");
+
+        Ok(result)
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let uri = &params.text_document.uri;
+
+        self.log_dev_info(&format!("➡️ Request: DocumentSymbol for {uri:?}"));
+
+        let result = capabilities::symbols::handle_document_symbol(
+            params,
+            &self.project_index,
+            self.hierarchical_document_symbols.load(Ordering::Relaxed),
+        );
+
+        self.log_dev_result(result.as_ref().map(document_symbol_len), "document symbols");
+
+        Ok(result)
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<OneOf<Vec<SymbolInformation>, Vec<WorkspaceSymbol>>>> {
+        self.log_dev_info(&format!(
+            "➡️ Request: WorkspaceSymbol query: '{}'",
+            params.query
+        ));
+
+        // tower-lsp drops this future on `$/cancelRequest` and replies with a cancelled
+        // error; the guard then stops the search still running on the blocking pool
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let _guard = CancelOnDrop(Arc::clone(&cancelled));
+
+        let project_index = Arc::clone(&self.project_index);
+        let search_cancelled = Arc::clone(&cancelled);
+        let result = tokio::task::spawn_blocking(move || {
+            capabilities::symbols::handle_workspace_symbol(
+                &params,
+                &project_index,
+                &search_cancelled,
+            )
+        })
+        .await
+        .unwrap_or_default();
+
+        self.log_dev_result(result.as_ref().map(one_of_len), "workspace symbols");
+
+        Ok(result)
+    }
+
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        self.log_dev_info("➡️ Request: Completion");
+
+        let result = capabilities::completion::handle_completion(
+            &params,
+            &self.project_index,
+            &self.document_cache,
+        );
+
+        self.log_dev_result(
+            result.as_ref().map(completion_response_len),
+            "completion items",
+        );
+
+        Ok(result)
+    }
+
+    // =============================================================================
+    // Text Document Synchronization
+    // =============================================================================
+    // NOTE: These handlers are kept inline (not extracted to a module) because
+    // they require complex orchestration with Backend's state (client,
+    // debounce_tasks, developer_mode) and cross-file diagnostic propagation.
+    // Extracting them would duplicate Backend's responsibilities.
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        if !self.is_ready() {
+            return;
+        }
+
+        let path = self
+            .project_index
+            .register_path(&uri_to_path(&params.text_document.uri));
+        let content = params.text_document.text.clone();
+
+        // Cache document content for completion
+        self.document_cache.insert(path.clone(), content.clone());
+        let old_keys = self.project_index.get_file_keys(&path);
+
+        let (processed, tree) = file_processor::process_file_content_incremental(
+            &path,
+            &content,
+            None,
+            &self.project_index,
+        );
+        if let Some(tree) = tree {
+            self.syntax_trees.insert(path.clone(), tree);
+        }
+        if processed {
+            let report = self.project_index.file_report(&path);
+            self.log_dev_info(&report);
+        }
+
+        // In lazy mode the file may only now be indexed, which affects its commands' definitions
+        self.diagnostics.schedule(diagnostics::affected_files(
+            &path,
+            &old_keys,
+            &self.project_index,
+        ));
+    }
+
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        if !self.is_ready() {
+            return;
+        }
+
+        let path = self
+            .project_index
+            .register_path(&uri_to_path(&params.text_document.uri));
+        if !params.content_changes.is_empty() {
+            // Without a didOpen the buffer is unknown; ranged edits then apply to the file on disk
+            let cached = self.document_cache.get(&path).map(|text| text.clone());
+            let is_open = cached.is_some();
+            let base = cached.or_else(|| std::fs::read_to_string(&path).ok());
+            let needs_base = params.content_changes[0].range.is_some();
+            let Some(base) = base.or_else(|| (!needs_base).then(String::new)) else {
+                self.client
+                    .log_message(
+                        MessageType::WARNING,
+                        format!(
+                            "TARUS: Ignoring edits to {}: the document is not open and cannot be read",
+                            path.display()
+                        ),
+                    )
+                    .await;
+                return;
+            };
+
+            // With TextDocumentSyncKind::INCREMENTAL, changes are ranged edits applied in order.
+            // The tree entry is locked while the text is updated, so both stay in sync.
+            let content = {
+                let mut tree_entry = self.syntax_trees.entry(path.clone());
+                let mut text = base;
+                let mut tree = match &mut tree_entry {
+                    dashmap::Entry::Occupied(e) => Some(e.get().clone()),
+                    dashmap::Entry::Vacant(_) => None,
+                };
+                for change in &params.content_changes {
+                    file_processor::apply_content_change(&mut text, &mut tree, change);
+                }
+                match (tree_entry, tree) {
+                    (dashmap::Entry::Occupied(mut e), Some(tree)) => {
+                        e.insert(tree);
+                    }
+                    (dashmap::Entry::Occupied(e), None) => {
+                        e.remove();
+                    }
+                    (dashmap::Entry::Vacant(_), _) => {}
+                }
+
+                // Cache document content immediately for completion (before debounce). A
+                // document that was never opened is indexed from the edited text but stays
+                // closed, so disk changes still reach it.
+                if is_open {
+                    self.document_cache.insert(path.clone(), text.clone());
+                }
+                text
+            };
+
+            // Cancel existing debounce task for this file
+            if let Some((_key, old_task)) = self.debounce_tasks.remove(&path) {
+                old_task.abort();
+            }
+
+            // Spawn new debounced task
+            let project_index = self.project_index.clone();
+            let client = self.client.clone();
+            let path_clone = path.clone();
+            let is_dev_mode = self.is_developer_mode_active.clone();
+            let syntax_trees = self.syntax_trees.clone();
+            let scheduler = self.diagnostics.clone();
+
+            let task = tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(constants::DEBOUNCE_MS)).await;
+                process_debounced_change(
+                    &path_clone,
+                    &content,
+                    &project_index,
+                    &client,
+                    &is_dev_mode,
+                    &syntax_trees,
+                    &scheduler,
+                )
+                .await;
+            });
+
+            self.debounce_tasks.insert(path, task);
+        }
+    }
+
+    async fn did_save(&self, params: DidSaveTextDocumentParams) {
+        if !self.is_ready() {
+            return;
+        }
+
+        let path = self
+            .project_index
+            .register_path(&uri_to_path(&params.text_document.uri));
+        let old_keys = self.project_index.get_file_keys(&path);
+        self.on_change(&path);
+
+        self.diagnostics.schedule(diagnostics::affected_files(
+            &path,
+            &old_keys,
+            &self.project_index,
+        ));
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        if !self.is_ready() {
+            return;
+        }
+
+        let path = self
+            .project_index
+            .register_path(&uri_to_path(&params.text_document.uri));
+        self.document_cache.remove(&path);
+        self.syntax_trees.remove(&path);
+
+        // Closed files are out of scope in `openFiles` mode — clear their diagnostics
+        if self.project_index.diagnostics_scope() == DiagnosticsScope::OpenFiles {
+            self.diagnostics
+                .clear(params.text_document.uri, &path)
+                .await;
+        }
+    }
+
+    async fn did_change_configuration(&self, _: DidChangeConfigurationParams) {
+        if !self.is_ready() {
+            return;
+        }
+
+        let scope = self.project_index.diagnostics_scope();
+        self.load_config().await;
+
+        // Republish every file under the new scope; those it leaves out are cleared
+        if self.project_index.diagnostics_scope() != scope {
+            self.diagnostics
+                .schedule_rescoped(self.project_index.get_indexed_paths());
+        }
+
+        // Code lens settings apply without re-indexing; have the client re-request lenses
+        let _ = self.client.code_lens_refresh().await;
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        if !self.is_ready() {
+            return;
+        }
+
+        // Open documents are indexed from their buffers, which take precedence over disk
+        let events: Vec<_> = params
+            .changes
+            .into_iter()
+            .map(|event| {
+                let path = self.project_index.index_path(&uri_to_path(&event.uri));
+                (path, event.typ)
+            })
+            .filter(|(path, _)| !self.document_cache.contains_key(path))
+            .collect();
+        if events.is_empty() {
+            return;
+        }
+
+        let old_keys: Vec<(PathBuf, Vec<IndexKey>)> = events
+            .iter()
+            .map(|(path, _)| (path.clone(), self.project_index.get_file_keys(path)))
+            .collect();
+        self.log_dev_info(&format!("➡️ Watched files changed: {}", old_keys.len()));
+
+        file_processor::process_watched_files(events, &self.project_index);
+
+        // Removed files are published too, which clears their diagnostics
+        for (path, keys) in &old_keys {
+            self.diagnostics
+                .schedule(diagnostics::affected_files(path, keys, &self.project_index));
+        }
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Sets the flag when dropped, i.e. when the request future owning it is cancelled
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+fn document_symbol_len(response: &DocumentSymbolResponse) -> usize {
+    match response {
+        DocumentSymbolResponse::Flat(syms) => syms.len(),
+        DocumentSymbolResponse::Nested(syms) => syms.len(),
+    }
+}
+
+fn one_of_len(response: &OneOf<Vec<SymbolInformation>, Vec<WorkspaceSymbol>>) -> usize {
+    match response {
+        OneOf::Left(syms) => syms.len(),
+        OneOf::Right(syms) => syms.len(),
+    }
+}
+
+fn completion_response_len(response: &CompletionResponse) -> usize {
+    match response {
+        CompletionResponse::Array(items) => items.len(),
+        CompletionResponse::List(list) => list.items.len(),
+    }
+}
+
+/// Process a file change after debounce: parse, compute affected keys,
+/// and publish diagnostics for all impacted files.
+async fn process_debounced_change(
+    path: &std::path::Path,
+    content: &str,
+    project_index: &Arc<ProjectIndex>,
+    client: &Client,
+    is_dev_mode: &Arc<AtomicBool>,
+    syntax_trees: &DashMap<PathBuf, tree_sitter::Tree>,
+    scheduler: &DiagnosticsScheduler,
+) {
+    let open_files = &*scheduler.open_files;
+
+    // Get OLD keys before processing (will be removed)
+    let old_keys: Vec<IndexKey> = project_index.get_file_keys(path);
+
+    // The cached tree only seeds the parse if no edit arrived after `content` was captured
+    let old_tree = syntax_trees
+        .get(path)
+        .and_then(|tree| is_current_text(open_files, path, content).then(|| tree.clone()));
+    let (processed, new_tree) = file_processor::process_file_content_incremental(
+        path,
+        content,
+        old_tree.as_ref(),
+        project_index,
+    );
+    if let Some(tree) = new_tree {
+        let entry = syntax_trees.entry(path.to_path_buf());
+        if is_current_text(open_files, path, content) {
+            entry.insert(tree);
+        }
+    }
+    if !processed {
+        return;
+    }
+
+    // Log parse errors in developer mode (check AFTER processing)
+    if is_dev_mode.load(Ordering::Relaxed) {
+        if let Some(error) = project_index.get_parse_error(path) {
+            client
+                .log_message(MessageType::ERROR, error.to_string())
+                .await;
+        }
+    }
+
+    // Publish diagnostics for the file and every file sharing its old or new keys
+    scheduler.schedule(diagnostics::affected_files(path, &old_keys, project_index));
+}
+
+/// Whether `content` is still the latest cached text of the open document at `path`
+fn is_current_text(open_files: &DashMap<PathBuf, String>, path: &Path, content: &str) -> bool {
+    open_files
+        .get(path)
+        .is_some_and(|text| text.as_str() == content)
+}
+
+/// Build the language service and the socket it talks to the client through; serve both
+/// with [`tower_lsp_server::Server`] over stdio or any other byte stream.
+#[must_use]
+pub fn build_service() -> (LspService<Backend>, ClientSocket) {
+    LspService::new(|client| {
+        let project_index = Arc::new(ProjectIndex::new());
+        let document_cache = Arc::new(DashMap::new());
+        let diagnostics = DiagnosticsScheduler::new(
+            client.clone(),
+            project_index.clone(),
+            document_cache.clone(),
+        );
+
+        Backend {
+            client,
+            workspace_root: OnceCell::new(),
+            project_index,
+            is_developer_mode_active: Arc::new(AtomicBool::new(false)),
+            debounce_tasks: Arc::new(DashMap::new()),
+            hierarchical_document_symbols: Arc::new(AtomicBool::new(false)),
+            document_cache,
+            syntax_trees: Arc::new(DashMap::new()),
+            diagnostics,
+        }
+    })
+}
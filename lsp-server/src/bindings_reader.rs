@@ -76,6 +76,7 @@ pub fn parse_specta_bindings(content: &str, source_path: &Path) -> Vec<CommandSc
             return_type,
             source_path: source_path.to_path_buf(),
             generator: GeneratorKind::Specta,
+            rename_all: None,
         });
     }
 
@@ -103,7 +104,11 @@ fn extract_params_from_node(params_node: tree_sitter::Node<'_>, content: &str) -
                 .to_string();
 
             if !name.is_empty() && !ts_type.is_empty() {
-                params.push(ParamSchema { name, ts_type });
+                params.push(ParamSchema {
+                    name,
+                    ts_type,
+                    optional: child.kind() == "optional_parameter",
+                });
             }
         }
     }
@@ -154,9 +159,10 @@ fn unwrap_return_type_node(node: tree_sitter::Node<'_>, content: &str) -> String
 
 /// Parse a TypeScript bindings file and return a map of `TypeName -> definition`.
 ///
-/// Handles both:
-/// - `export type Name = ...;` (type aliases)
+/// Handles:
+/// - `export type Name = ...;` (type aliases; object types are normalized like interfaces)
 /// - `export interface Name { ... }` (interfaces)
+/// - `export enum Name { ... }` (enums, stored as a string-literal union of their members)
 ///
 /// This is a unified parser used by Specta, ts-rs, and tauri-typegen.
 #[must_use]
@@ -186,7 +192,14 @@ fn parse_type_aliases_and_interfaces(
 
         while let Some(m) = matches.next() {
             let name = capture_text(m, name_idx, bytes).to_string();
-            let def = capture_text(m, value_idx, bytes).to_string();
+            // Object types are normalized like interface bodies, so `type X = { ... }` and
+            // `interface X { ... }` store the same definition whatever their layout
+            let def = find_capture(m, value_idx)
+                .map(|cap| cap.node)
+                .filter(|node| node.kind() == "object_type")
+                .map(|node| extract_interface_fields(node, content))
+                .filter(|def| !def.is_empty())
+                .unwrap_or_else(|| capture_text(m, value_idx, bytes).to_string());
 
             if !name.is_empty() && !def.is_empty() {
                 aliases.insert(name, def);
@@ -218,9 +231,127 @@ fn parse_type_aliases_and_interfaces(
         }
     }
 
+    if let Ok(query) = Query::new(&ts_lang, include_str!("queries/bindings_enums.scm")) {
+        let name_idx = query.capture_index_for_name("enum_name");
+        let body_idx = query.capture_index_for_name("enum_body");
+
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, tree.root_node(), bytes);
+
+        while let Some(m) = matches.next() {
+            let name = capture_text(m, name_idx, bytes).to_string();
+            let Some(body) = find_capture(m, body_idx).map(|cap| cap.node) else {
+                continue;
+            };
+
+            let members = extract_enum_members(body, bytes);
+            if !name.is_empty() && !members.is_empty() {
+                let def = members
+                    .iter()
+                    .map(|m| format!("\"{m}\""))
+                    .collect::<Vec<_>>()
+                    .join(" | ");
+                aliases.insert(name, def);
+            }
+        }
+    }
+
     aliases
 }
 
+/// Extract the serialized names of enum members.
+///
+/// String initializers (`Active = "active"`) win; otherwise the member name is used,
+/// matching how serde serializes a unit variant.
+fn extract_enum_members(body: tree_sitter::Node<'_>, bytes: &[u8]) -> Vec<String> {
+    let mut members = Vec::new();
+    let mut cursor = body.walk();
+
+    for child in body.named_children(&mut cursor) {
+        let member = match child.kind() {
+            "property_identifier" => child.utf8_text(bytes).ok().map(str::to_string),
+            "enum_assignment" => {
+                let value = child
+                    .child_by_field_name("value")
+                    .filter(|v| v.kind() == "string")
+                    .and_then(|v| v.utf8_text(bytes).ok())
+                    .map(|v| v.trim_matches(['"', '\'']).to_string());
+                value.or_else(|| {
+                    child
+                        .child_by_field_name("name")
+                        .and_then(|n| n.utf8_text(bytes).ok())
+                        .map(str::to_string)
+                })
+            }
+            _ => None,
+        };
+        members.extend(member);
+    }
+
+    members
+}
+
+/// Parse the variants of a string-literal union such as `"active" | "inactive"`.
+///
+/// Returns `None` unless every member of the union is a string literal.
+#[must_use]
+pub fn parse_string_union_variants(def: &str) -> Option<Vec<String>> {
+    fn collect(node: tree_sitter::Node<'_>, bytes: &[u8], out: &mut Vec<String>) -> Option<()> {
+        match node.kind() {
+            "union_type" => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    collect(child, bytes, out)?;
+                }
+                Some(())
+            }
+            "literal_type" => {
+                let literal = node.named_child(0).filter(|n| n.kind() == "string")?;
+                let text = literal.utf8_text(bytes).ok()?;
+                out.push(text.trim_matches(['"', '\'']).to_string());
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    let source = format!("type __Tarus = {def};");
+    let tree = parse_ts(&source)?;
+    let value = tree
+        .root_node()
+        .named_child(0)?
+        .child_by_field_name("value")?;
+
+    let mut variants = Vec::new();
+    collect(value, source.as_bytes(), &mut variants)?;
+    (!variants.is_empty()).then_some(variants)
+}
+
+/// Parse the fields of an inline object type definition such as `{ id: number; name: string }`.
+///
+/// Returns `None` when `def` is not an object type (e.g. a union or a primitive alias).
+#[must_use]
+pub fn parse_object_type_fields(def: &str) -> Option<Vec<(String, String)>> {
+    let source = format!("type __Tarus = {def};");
+    let tree = parse_ts(&source)?;
+
+    let value = tree
+        .root_node()
+        .named_child(0)?
+        .child_by_field_name("value")
+        .filter(|n| n.kind() == "object_type")?;
+
+    Some(
+        extract_named_fields(value, source.as_bytes())
+            .into_iter()
+            .filter_map(|field| {
+                let (name, type_text) = field.split_once(": ")?;
+                Some((name.to_string(), type_text.to_string()))
+            })
+            .collect(),
+    )
+}
+
 /// Extract `property_signature` children from `node` as `name: type` pairs.
 ///
 /// Skips index signatures and any child that isn't a `property_signature`.
@@ -251,7 +382,8 @@ fn extract_named_fields(node: tree_sitter::Node<'_>, bytes: &[u8]) -> Vec<String
     fields
 }
 
-/// Extract fields from an `interface_body` node into a compact inline object string.
+/// Extract fields from an `interface_body` or `object_type` node into a compact inline
+/// object string.
 fn extract_interface_fields(body_node: tree_sitter::Node<'_>, content: &str) -> String {
     let fields = extract_named_fields(body_node, content.as_bytes());
     if fields.is_empty() {
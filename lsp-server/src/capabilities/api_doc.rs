@@ -0,0 +1,204 @@
+//! API documentation report
+//!
+//! Renders the indexed Tauri API surface (commands and events) as Markdown, grouped by the
+//! backend file that defines or emits each entry. Returned by `tarus.generateApiDoc` so the
+//! extension can write it to `docs/tauri-api.md`.
+
+use crate::indexer::{CommandSummary, LocationInfo, ProjectIndex};
+use crate::syntax::{Behavior, EntityType};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Group heading for commands invoked from the frontend but not defined in Rust
+const UNDEFINED_COMMANDS_GROUP: &str = "Not defined in Rust";
+/// Group heading for events that are never emitted from Rust
+const FRONTEND_EVENTS_GROUP: &str = "Frontend only";
+
+/// Generate the Markdown API report.
+///
+/// Paths are shown relative to `workspace_root` when given. Groups, entries and locations
+/// are sorted so the output is reproducible.
+#[must_use]
+pub fn generate_api_doc(project_index: &ProjectIndex, workspace_root: Option<&Path>) -> String {
+    let display = |path: &Path| {
+        workspace_root
+            .and_then(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path)
+            .display()
+            .to_string()
+            .replace('\\', "/")
+    };
+
+    let mut md = String::from("# Tauri API\n");
+
+    md.push_str("\n## Commands\n");
+    let commands = group_commands(project_index, &display);
+    if commands.is_empty() {
+        md.push_str("\n_No commands found._\n");
+    }
+    for (group, summaries) in &commands {
+        let _ = writeln!(md, "\n### `{group}`");
+        for summary in summaries {
+            let _ = writeln!(md, "\n#### `{}`", summary.name);
+            let _ = writeln!(md, "\n```ts\n{}\n```", command_signature(summary));
+            let locations = sorted_locations(project_index, EntityType::Command, &summary.name);
+            push_location_list(
+                &mut md,
+                "Called from",
+                &locations,
+                |b| matches!(b, Behavior::Call | Behavior::SpectaCall),
+                &display,
+            );
+        }
+    }
+
+    md.push_str("\n## Events\n");
+    let events = group_events(project_index, &display);
+    if events.is_empty() {
+        md.push_str("\n_No events found._\n");
+    }
+    for (group, names) in &events {
+        let _ = writeln!(md, "\n### `{group}`");
+        for (name, locations) in names {
+            let _ = writeln!(md, "\n#### `{name}`");
+            if let Some(schema) = project_index.get_event_schema(name) {
+                let _ = writeln!(md, "\nPayload: `{}`", schema.payload_type);
+            }
+            push_location_list(
+                &mut md,
+                "Emitted from",
+                locations,
+                |b| b == Behavior::Emit,
+                &display,
+            );
+            push_location_list(
+                &mut md,
+                "Listened for in",
+                locations,
+                |b| b == Behavior::Listen,
+                &display,
+            );
+        }
+    }
+
+    md
+}
+
+/// Command summaries grouped by the Rust file defining them, sorted by name within a group
+fn group_commands(
+    project_index: &ProjectIndex,
+    display: &dyn Fn(&Path) -> String,
+) -> BTreeMap<String, Vec<CommandSummary>> {
+    let mut groups: BTreeMap<String, Vec<CommandSummary>> = BTreeMap::new();
+
+    for summary in project_index.commands_summary() {
+        let group = summary
+            .definition_path
+            .as_deref()
+            .filter(|p| p.extension().is_some_and(|e| e == "rs"))
+            .map_or_else(|| UNDEFINED_COMMANDS_GROUP.to_string(), display);
+        groups.entry(group).or_default().push(summary);
+    }
+
+    groups
+}
+
+/// Events grouped by the first Rust file emitting them, falling back to their definition
+/// (e.g. a `tauri-specta` event struct), then by name.
+fn group_events(
+    project_index: &ProjectIndex,
+    display: &dyn Fn(&Path) -> String,
+) -> BTreeMap<String, BTreeMap<String, Vec<LocationInfo>>> {
+    let mut groups: BTreeMap<String, BTreeMap<String, Vec<LocationInfo>>> = BTreeMap::new();
+
+    for (name, _) in project_index.get_all_names(EntityType::Event).iter() {
+        let locations = sorted_locations(project_index, EntityType::Event, name);
+
+        let backend_file = |behavior: Behavior| {
+            locations
+                .iter()
+                .find(|l| l.behavior == behavior && l.path.extension().is_some_and(|e| e == "rs"))
+                .map(|l| l.path.clone())
+        };
+        let group = backend_file(Behavior::Emit)
+            .or_else(|| backend_file(Behavior::Definition))
+            .map_or_else(|| FRONTEND_EVENTS_GROUP.to_string(), |p| display(&p));
+
+        groups
+            .entry(group)
+            .or_default()
+            .insert(name.clone(), locations);
+    }
+
+    groups
+}
+
+/// Locations of `name` ordered by path and position
+fn sorted_locations(
+    project_index: &ProjectIndex,
+    entity: EntityType,
+    name: &str,
+) -> Vec<LocationInfo> {
+    let mut locations = project_index.get_locations(entity, name);
+    locations.sort_by(|a, b| {
+        (&a.path, a.range.start.line, a.range.start.character).cmp(&(
+            &b.path,
+            b.range.start.line,
+            b.range.start.character,
+        ))
+    });
+    locations
+}
+
+/// Frontend-facing call signature, e.g. `invoke<User>("get_user", { id: number })`.
+///
+/// Schema types are already mapped to TypeScript (see `rust_type_to_ts`).
+fn command_signature(summary: &CommandSummary) -> String {
+    let name = &summary.name;
+    let Some(return_type) = &summary.return_type else {
+        return format!("invoke(\"{name}\")");
+    };
+
+    let args = if summary.params.is_empty() {
+        String::new()
+    } else {
+        let fields: Vec<String> = summary
+            .params
+            .iter()
+            .map(|p| {
+                let marker = if p.optional { "?" } else { "" };
+                format!("{}{marker}: {}", p.name, p.ts_type)
+            })
+            .collect();
+        format!(", {{ {} }}", fields.join(", "))
+    };
+
+    format!("invoke<{return_type}>(\"{name}\"{args})")
+}
+
+/// Write a bulleted `path:line` list of the locations matching `behavior`
+fn push_location_list(
+    md: &mut String,
+    label: &str,
+    locations: &[LocationInfo],
+    behavior: impl Fn(Behavior) -> bool,
+    display: &dyn Fn(&Path) -> String,
+) {
+    let matching: Vec<&LocationInfo> = locations.iter().filter(|l| behavior(l.behavior)).collect();
+
+    if matching.is_empty() {
+        let _ = writeln!(md, "\n{label}: _none_");
+        return;
+    }
+
+    let _ = writeln!(md, "\n{label}:\n");
+    for loc in matching {
+        let _ = writeln!(
+            md,
+            "- `{}:{}`",
+            display(&loc.path),
+            loc.range.start.line + 1
+        );
+    }
+}
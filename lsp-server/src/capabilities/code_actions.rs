@@ -1,8 +1,10 @@
 //! Code Actions capability - generate Rust command templates
 
-use crate::indexer::{GeneratorKind, LocationInfo, ProjectIndex};
+use crate::indexer::{CommandSchema, GeneratorKind, LocationInfo, ProjectIndex};
 use crate::scanner::find_src_tauri_dir;
 use crate::syntax::{Behavior, EntityType};
+use crate::utils::{snake_to_camel, uri_to_path};
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 use tower_lsp_server::lsp_types::{
     CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
@@ -10,6 +12,13 @@ use tower_lsp_server::lsp_types::{
     TextDocumentEdit, TextEdit, Uri, WorkspaceEdit,
 };
 use tower_lsp_server::UriExt;
+use walkdir::WalkDir;
+
+/// Module the generated frontend calls import `invoke` from
+const TAURI_CORE_MODULE: &str = "@tauri-apps/api/core";
+
+/// Frontend files a generated invoke call can be appended to
+const INVOKE_TARGET_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx"];
 
 const COMMAND_TEMPLATE: &str =
     "\n#[tauri::command]\nfn {name}() -> Result<String, String> {\n    Ok(\"Not implemented\".to_string())\n}\n";
@@ -22,14 +31,25 @@ pub struct RustFileCandidate {
     pub insertion_line: usize,
 }
 
+/// Frontend file candidate for a generated invoke call
+#[derive(Debug, Clone)]
+struct FrontendFileCandidate {
+    path: PathBuf,
+    priority: u8,
+    /// End of the file, where the call is appended
+    end: Position,
+    /// Text between the existing content and the call
+    separator: &'static str,
+    imports_invoke: bool,
+}
+
 /// Handle code action request (pure function)
 pub fn handle_code_action(
     params: &CodeActionParams,
     project_index: &ProjectIndex,
     workspace_root: Option<&PathBuf>,
 ) -> Option<CodeActionResponse> {
-    let path_cow = params.text_document.uri.to_file_path()?;
-    let path = path_cow.to_path_buf();
+    let path = uri_to_path(&params.text_document.uri);
     let (key, loc) = project_index.get_key_at_position(&path, params.range.start)?;
 
     match key.entity {
@@ -46,8 +66,176 @@ fn handle_event_action(
     project_index: &ProjectIndex,
     params: &CodeActionParams,
 ) -> Option<CodeActionResponse> {
-    let action = make_event_payload_action(event_name, loc, project_index, params)?;
-    Some(vec![CodeActionOrCommand::CodeAction(action)])
+    let actions: Vec<_> = [
+        make_event_typo_action(event_name, loc, project_index, params),
+        make_event_naming_action(event_name, loc, project_index, params),
+        make_event_payload_action(event_name, loc, project_index, params),
+        make_listen_toggle_action(loc, params),
+    ]
+    .into_iter()
+    .flatten()
+    .map(CodeActionOrCommand::CodeAction)
+    .collect();
+
+    (!actions.is_empty()).then_some(actions)
+}
+
+/// Build a quick fix renaming an event that breaks `tarus.events.namingConvention` everywhere.
+fn make_event_naming_action(
+    event_name: &str,
+    loc: &LocationInfo,
+    project_index: &ProjectIndex,
+    params: &CodeActionParams,
+) -> Option<CodeAction> {
+    if !matches!(loc.behavior, Behavior::Emit | Behavior::Listen) || loc.codegen_origin.is_some() {
+        return None;
+    }
+
+    let expected = project_index.event_naming().suggest(event_name)?;
+
+    Some(CodeAction {
+        title: format!("Rename event to '{expected}' everywhere"),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(params.context.diagnostics.clone()),
+        edit: Some(event_rename_edit(event_name, &expected, project_index)?),
+        ..Default::default()
+    })
+}
+
+/// Build a workspace edit renaming every emit/listen occurrence of an event name.
+///
+/// Definitions and generated bindings are left alone: their ranges do not cover a
+/// string literal, and generated files are overwritten by their generator anyway.
+pub(crate) fn event_rename_edit(
+    event_name: &str,
+    new_name: &str,
+    project_index: &ProjectIndex,
+) -> Option<WorkspaceEdit> {
+    let mut by_file: std::collections::BTreeMap<PathBuf, Vec<Range>> =
+        std::collections::BTreeMap::new();
+    for loc in project_index.get_locations(EntityType::Event, event_name) {
+        if matches!(loc.behavior, Behavior::Emit | Behavior::Listen) && loc.codegen_origin.is_none()
+        {
+            by_file.entry(loc.path).or_default().push(loc.range);
+        }
+    }
+
+    let document_edits: Vec<_> = by_file
+        .into_iter()
+        .filter_map(|(path, mut ranges)| {
+            ranges.sort_by_key(|r| (r.start.line, r.start.character));
+            ranges.dedup();
+            Some(TextDocumentEdit {
+                text_document: OptionalVersionedTextDocumentIdentifier {
+                    uri: Uri::from_file_path(&path)?,
+                    version: None,
+                },
+                edits: ranges
+                    .into_iter()
+                    .map(|range| {
+                        OneOf::Left(TextEdit {
+                            range,
+                            new_text: new_name.to_string(),
+                        })
+                    })
+                    .collect(),
+            })
+        })
+        .collect();
+
+    (!document_edits.is_empty()).then(|| WorkspaceEdit {
+        document_changes: Some(DocumentChanges::Edits(document_edits)),
+        ..Default::default()
+    })
+}
+
+/// Build a refactor that swaps a frontend `listen()` call for `once()` or back.
+///
+/// Uses the counterpart's local alias when it is already imported; otherwise the callee is
+/// renamed to the bare API name and the name is added to the callee's named import.
+fn make_listen_toggle_action(loc: &LocationInfo, params: &CodeActionParams) -> Option<CodeAction> {
+    let toggle = loc.listen_toggle.as_ref()?;
+    let replacement = toggle
+        .target_local
+        .clone()
+        .unwrap_or_else(|| toggle.target_api.clone());
+
+    let mut edits = vec![OneOf::Left(TextEdit {
+        range: toggle.callee_range,
+        new_text: replacement,
+    })];
+    if let Some(pos) = toggle.import_insert {
+        edits.push(OneOf::Left(TextEdit {
+            range: Range {
+                start: pos,
+                end: pos,
+            },
+            new_text: format!(", {}", toggle.target_api),
+        }));
+    }
+
+    let workspace_edit = WorkspaceEdit {
+        document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+            text_document: OptionalVersionedTextDocumentIdentifier {
+                uri: params.text_document.uri.clone(),
+                version: None,
+            },
+            edits,
+        }])),
+        ..Default::default()
+    };
+
+    Some(CodeAction {
+        title: format!("Convert to {}()", toggle.target_api),
+        kind: Some(CodeActionKind::REFACTOR_REWRITE),
+        edit: Some(workspace_edit),
+        ..Default::default()
+    })
+}
+
+/// Build a quickfix that replaces a misspelled listened-for event name with a close emitted one.
+fn make_event_typo_action(
+    event_name: &str,
+    loc: &LocationInfo,
+    project_index: &ProjectIndex,
+    params: &CodeActionParams,
+) -> Option<CodeAction> {
+    if !matches!(loc.behavior, Behavior::Listen) {
+        return None;
+    }
+
+    let key = crate::indexer::IndexKey {
+        entity: EntityType::Event,
+        name: event_name.to_string(),
+    };
+    if project_index.get_diagnostic_info(&key).has_emitters() {
+        return None;
+    }
+
+    let suggestion = super::diagnostics::suggest_event_name(event_name, project_index)?;
+
+    let workspace_edit = WorkspaceEdit {
+        document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+            text_document: OptionalVersionedTextDocumentIdentifier {
+                uri: params.text_document.uri.clone(),
+                version: None,
+            },
+            edits: vec![OneOf::Left(TextEdit {
+                range: loc.range,
+                new_text: suggestion.clone(),
+            })],
+        }])),
+        ..Default::default()
+    };
+
+    Some(CodeAction {
+        title: format!("Change event name to '{suggestion}'"),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(params.context.diagnostics.clone()),
+        edit: Some(workspace_edit),
+        is_preferred: Some(true),
+        ..Default::default()
+    })
 }
 
 fn handle_command_action(
@@ -62,27 +250,39 @@ fn handle_command_action(
         return Some(vec![CodeActionOrCommand::CodeAction(action)]);
     }
 
-    // Generate Rust command stub if command not yet defined
     let info = project_index.get_diagnostic_info(key);
+    if loc.behavior == Behavior::Definition {
+        // The inverse of the stub below: call a command nothing invokes yet
+        if info.has_calls() {
+            return None;
+        }
+        return make_invoke_call_actions(&key.name, project_index, params, workspace_root?);
+    }
+
+    // Generate Rust command stub if command not yet defined
     if info.has_definition() {
         return None;
     }
 
     let root = workspace_root?;
-    let candidates = tokio::task::block_in_place(|| find_rust_file_candidates(root));
+    let command_dir = project_index.command_dir().map(|dir| root.join(dir));
+    let candidates =
+        tokio::task::block_in_place(|| find_rust_file_candidates(root, command_dir.as_deref()));
     if candidates.is_empty() {
         return None;
     }
 
     let mut actions = Vec::new();
-    for candidate in rank_and_limit(candidates) {
+    let max = project_index.code_action_candidates();
+    for candidate in rank_and_limit(candidates, max, |c| (c.priority, &c.path)) {
         let file_name = candidate
             .path
             .file_name()
             .and_then(|n| n.to_str())
             .unwrap_or("unknown");
 
-        let new_text = COMMAND_TEMPLATE.replace("{name}", &key.name);
+        let new_text = generate_command_stub(&key.name, loc.return_type.as_deref(), project_index);
+        let data = serde_json::json!({ "generatedText": new_text });
         let Some(target_uri) = Uri::from_file_path(&candidate.path) else {
             continue;
         };
@@ -116,6 +316,8 @@ fn handle_command_action(
             kind: Some(CodeActionKind::QUICKFIX),
             diagnostics: Some(params.context.diagnostics.clone()),
             edit: Some(workspace_edit),
+            // Lets the client preview the insertion (see `tarus.previewGeneratedType`)
+            data: Some(data),
             ..Default::default()
         }));
     }
@@ -123,24 +325,223 @@ fn handle_command_action(
     Some(actions)
 }
 
-fn find_rust_file_candidates(workspace_root: &Path) -> Vec<RustFileCandidate> {
-    let Some(src_tauri_dir) = find_src_tauri_dir(workspace_root) else {
-        return Vec::new();
+/// Build one "Generate frontend invoke call" action per ranked frontend file.
+///
+/// The call is appended to the file as an exported async function, and `invoke` is
+/// imported when the file does not import it yet.
+fn make_invoke_call_actions(
+    command_name: &str,
+    project_index: &ProjectIndex,
+    params: &CodeActionParams,
+    root: &Path,
+) -> Option<CodeActionResponse> {
+    let schema = project_index.get_schema(command_name)?;
+    let candidates =
+        tokio::task::block_in_place(|| find_frontend_file_candidates(root, project_index));
+
+    let max = project_index.code_action_candidates();
+    let actions: Vec<_> = rank_and_limit(candidates, max, |c| (c.priority, &c.path))
+        .into_iter()
+        .filter_map(|candidate| {
+            let file_name = candidate.path.file_name()?.to_str()?.to_string();
+            let typed = candidate
+                .path
+                .extension()
+                .is_some_and(|ext| ext == "ts" || ext == "tsx");
+            let new_text = generate_invoke_call(&schema, typed);
+            let data = serde_json::json!({ "generatedText": new_text });
+
+            let mut edits = Vec::new();
+            if !candidate.imports_invoke {
+                edits.push(OneOf::Left(TextEdit {
+                    range: Range::default(),
+                    new_text: format!("import {{ invoke }} from \"{TAURI_CORE_MODULE}\";\n"),
+                }));
+            }
+            edits.push(OneOf::Left(TextEdit {
+                range: Range {
+                    start: candidate.end,
+                    end: candidate.end,
+                },
+                new_text: candidate.separator.to_string() + &new_text,
+            }));
+
+            let workspace_edit = WorkspaceEdit {
+                document_changes: Some(DocumentChanges::Edits(vec![TextDocumentEdit {
+                    text_document: OptionalVersionedTextDocumentIdentifier {
+                        uri: Uri::from_file_path(&candidate.path)?,
+                        version: None,
+                    },
+                    edits,
+                }])),
+                ..Default::default()
+            };
+
+            Some(CodeActionOrCommand::CodeAction(CodeAction {
+                title: format!("Generate frontend invoke call for '{command_name}' in {file_name}"),
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(params.context.diagnostics.clone()),
+                edit: Some(workspace_edit),
+                data: Some(data),
+                ..Default::default()
+            }))
+        })
+        .collect();
+
+    (!actions.is_empty()).then_some(actions)
+}
+
+/// Frontend function calling a command through `invoke`.
+///
+/// Parameters use the argument names the frontend passes (camelCase unless the command
+/// says otherwise) and, when `typed`, the TypeScript types and return type of the command
+/// schema:
+///
+/// ```ts
+/// export async function getUser(userId: number): Promise<User> {
+///   return invoke<User>("get_user", { userId });
+/// }
+/// ```
+#[must_use]
+pub fn generate_invoke_call(schema: &CommandSchema, typed: bool) -> String {
+    let arg_names: Vec<String> = schema
+        .params
+        .iter()
+        .map(|param| schema.frontend_param_name(param))
+        .collect();
+
+    let signature = arg_names
+        .iter()
+        .zip(&schema.params)
+        .map(|(name, param)| {
+            if typed {
+                format!("{name}: {}", param.ts_type)
+            } else {
+                name.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let args = if arg_names.is_empty() {
+        String::new()
+    } else {
+        format!(", {{ {} }}", arg_names.join(", "))
+    };
+    let (return_annotation, type_arg) = if typed {
+        (
+            format!(": Promise<{}>", schema.return_type),
+            format!("<{}>", schema.return_type),
+        )
+    } else {
+        (String::new(), String::new())
     };
 
-    let src_dir = src_tauri_dir.join("src");
-    if !src_dir.exists() {
-        return Vec::new();
-    }
+    format!(
+        "export async function {}({signature}){return_annotation} {{\n  return invoke{type_arg}(\"{}\"{args});\n}}\n",
+        snake_to_camel(&schema.command_name),
+        schema.command_name,
+    )
+}
 
-    let mut candidates = Vec::new();
-    let Ok(entries) = std::fs::read_dir(&src_dir) else {
-        return Vec::new();
+/// Rust source for a new command stub.
+///
+/// When the call site names its result type (`invoke<Status>(...)`) and that type is a
+/// string-literal union or TS enum with no Rust counterpart yet, the matching Rust enum is
+/// generated alongside the command, which returns its first variant.
+#[must_use]
+pub fn generate_command_stub(
+    command_name: &str,
+    return_type: Option<&str>,
+    project_index: &ProjectIndex,
+) -> String {
+    let enum_stub = return_type.and_then(|type_name| {
+        if project_index.get_struct_schema(type_name).is_some() {
+            return None;
+        }
+        let def = project_index.type_aliases.get(type_name)?;
+        let variants = crate::bindings_reader::parse_string_union_variants(def.value())?;
+        Some((type_name, variants))
+    });
+
+    let Some((type_name, variants)) = enum_stub else {
+        return COMMAND_TEMPLATE.replace("{name}", command_name);
     };
 
-    for entry in entries.flatten() {
-        let path = entry.path();
-        if !path.is_file() || path.extension().and_then(|s| s.to_str()) != Some("rs") {
+    let first_variant = enum_variant_ident(&variants[0]);
+    format!(
+        "\n{}\n#[tauri::command]\nfn {command_name}() -> Result<{type_name}, String> {{\n    Ok({type_name}::{first_variant})\n}}\n",
+        rust_enum_template(type_name, &variants)
+    )
+}
+
+/// Generate a serde-compatible Rust enum for the given serialized variant names.
+///
+/// Variants are converted to `PascalCase`; a `#[serde(rename = "...")]` is added whenever
+/// the identifier differs from the serialized name.
+#[must_use]
+pub fn rust_enum_template(name: &str, variants: &[String]) -> String {
+    let mut out = format!(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]\npub enum {name} {{\n"
+    );
+
+    for variant in variants {
+        let ident = enum_variant_ident(variant);
+        if ident != *variant {
+            let _ = writeln!(out, "    #[serde(rename = \"{variant}\")]");
+        }
+        let _ = writeln!(out, "    {ident},");
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// `in-progress` / `in_progress` / `inProgress` → `InProgress`
+fn enum_variant_ident(variant: &str) -> String {
+    variant
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect()
+}
+
+/// Rust files under `src-tauri/src` (subdirectories included) that can take a new command
+///
+/// Files inside `command_dir` (`tarus.commandDir`) rank above all others; the directory is
+/// scanned even when it lies outside `src-tauri/src`.
+fn find_rust_file_candidates(
+    workspace_root: &Path,
+    command_dir: Option<&Path>,
+) -> Vec<RustFileCandidate> {
+    let src_dir = find_src_tauri_dir(workspace_root)
+        .map(|dir| dir.join("src"))
+        .filter(|dir| dir.exists());
+    let command_dir = command_dir.filter(|dir| dir.is_dir());
+
+    let mut dirs: Vec<&Path> = src_dir.iter().map(PathBuf::as_path).collect();
+    if let Some(dir) = command_dir {
+        if !dirs.iter().any(|src| dir.starts_with(src)) {
+            dirs.push(dir);
+        }
+    }
+
+    let mut candidates = Vec::new();
+    let mut entry_shims = Vec::new();
+
+    let files = dirs.into_iter().flat_map(|dir| {
+        WalkDir::new(dir)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|e| e.file_type().is_file())
+            .map(walkdir::DirEntry::into_path)
+    });
+    for path in files {
+        if path.extension().and_then(|s| s.to_str()) != Some("rs") {
             continue;
         }
 
@@ -149,9 +550,20 @@ fn find_rust_file_candidates(workspace_root: &Path) -> Vec<RustFileCandidate> {
         };
 
         let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-        let priority = calculate_file_priority(file_name, &content);
+        let mut priority = calculate_file_priority(file_name, &content);
+        if command_dir.is_some_and(|dir| path.starts_with(dir)) {
+            priority = priority.saturating_add(crate::constants::PRIORITY_COMMAND_DIR_BONUS);
+        }
         let insertion_line = find_insertion_line(&content);
 
+        // Tauri v2's `main.rs` only calls `<crate>_lib::run()`; commands belong in `lib.rs`
+        if file_name == "main.rs"
+            && !content.contains("invoke_handler(")
+            && !content.contains("#[tauri::command]")
+        {
+            entry_shims.push(path.clone());
+        }
+
         candidates.push(RustFileCandidate {
             path,
             priority,
@@ -159,9 +571,66 @@ fn find_rust_file_candidates(workspace_root: &Path) -> Vec<RustFileCandidate> {
         });
     }
 
+    if candidates.iter().any(|c| c.path.ends_with("lib.rs")) {
+        candidates.retain(|c| !entry_shims.contains(&c.path));
+    }
+
     candidates
 }
 
+/// Frontend script files that can take a generated invoke call
+///
+/// Only files that already import from `@tauri-apps/api/core` or carry a well-known API
+/// module name are offered; generated bindings and declaration files are skipped.
+fn find_frontend_file_candidates(
+    workspace_root: &Path,
+    project_index: &ProjectIndex,
+) -> Vec<FrontendFileCandidate> {
+    crate::scanner::scan_workspace_files(workspace_root, &[])
+        .into_iter()
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| INVOKE_TARGET_EXTENSIONS.contains(&ext))
+                && !path.to_string_lossy().ends_with(".d.ts")
+                && project_index.get_generator_for_file(path).is_none()
+        })
+        .filter_map(|path| {
+            let content = std::fs::read_to_string(&path).ok()?;
+            let stem = path.file_stem().and_then(|n| n.to_str()).unwrap_or("");
+            let imports_invoke = content.contains(TAURI_CORE_MODULE) && content.contains("invoke");
+            let priority = calculate_frontend_file_priority(stem, imports_invoke)?;
+
+            let separator = if content.is_empty() || content.ends_with('\n') {
+                "\n"
+            } else {
+                "\n\n"
+            };
+            Some(FrontendFileCandidate {
+                priority,
+                end: crate::position::byte_offset_to_position(&content, content.len()),
+                separator,
+                imports_invoke,
+                path,
+            })
+        })
+        .collect()
+}
+
+fn calculate_frontend_file_priority(file_stem: &str, imports_invoke: bool) -> Option<u8> {
+    use crate::constants::{
+        PRIORITY_FRONTEND_API_FILE, PRIORITY_FRONTEND_COMMAND_FILE, PRIORITY_FRONTEND_INVOKES,
+    };
+
+    let well_known = matches!(file_stem, "api" | "commands" | "tauri" | "ipc");
+    match (imports_invoke, well_known) {
+        (true, true) => Some(PRIORITY_FRONTEND_API_FILE),
+        (true, false) => Some(PRIORITY_FRONTEND_INVOKES),
+        (false, true) => Some(PRIORITY_FRONTEND_COMMAND_FILE),
+        (false, false) => None,
+    }
+}
+
 fn calculate_file_priority(file_name: &str, content: &str) -> u8 {
     use crate::constants::{
         PRIORITY_COMMAND_FILE, PRIORITY_DEFAULT, PRIORITY_HAS_COMMAND_ATTR,
@@ -211,9 +680,27 @@ fn find_insertion_line(content: &str) -> usize {
     0
 }
 
-fn rank_and_limit(mut candidates: Vec<RustFileCandidate>) -> Vec<RustFileCandidate> {
-    candidates.sort_by_key(|b| std::cmp::Reverse(b.priority));
-    candidates.into_iter().take(5).collect()
+/// Best candidates first (ties broken by path), without files that resolve to the same
+/// target (e.g. a symlinked module), capped at `max`.
+///
+/// `rank` returns a candidate's priority and path.
+fn rank_and_limit<T>(
+    mut candidates: Vec<T>,
+    max: usize,
+    rank: impl Fn(&T) -> (u8, &PathBuf),
+) -> Vec<T> {
+    candidates.sort_by(|a, b| {
+        let ((a_priority, a_path), (b_priority, b_path)) = (rank(a), rank(b));
+        b_priority.cmp(&a_priority).then_with(|| a_path.cmp(b_path))
+    });
+
+    let mut seen = std::collections::HashSet::new();
+    candidates.retain(|c| {
+        let path = rank(c).1;
+        seen.insert(std::fs::canonicalize(path).unwrap_or(path.clone()))
+    });
+    candidates.truncate(max);
+    candidates
 }
 
 /// Build a quickfix code action that inserts or replaces a type annotation (`<T>`).
@@ -1,20 +1,24 @@
 //! Code Lens capability - shows reference counts above symbols
 
-use crate::indexer::ProjectIndex;
+use crate::indexer::{CodeLensMode, ProjectIndex};
+use crate::utils::uri_to_path;
 use serde_json::json;
 use std::path::PathBuf;
 use tower_lsp_server::lsp_types::{CodeLens, CodeLensParams, Uri};
 use tower_lsp_server::UriExt;
 
-/// Handle code lens request (pure function)
+/// Handle code lens request (pure function); `None` when code lenses are turned off
 pub fn handle_code_lens(
     params: CodeLensParams,
     project_index: &ProjectIndex,
 ) -> Option<Vec<CodeLens>> {
+    if project_index.code_lens_mode() == CodeLensMode::Off {
+        return None;
+    }
+
     let uri = params.text_document.uri;
 
-    let path_cow = uri.to_file_path()?;
-    let path: PathBuf = path_cow.to_path_buf();
+    let path: PathBuf = uri_to_path(&uri);
     let lens_data = project_index.get_lens_data(&path);
 
     if lens_data.is_empty() {
@@ -1,29 +1,18 @@
 //! Completion capability - autocomplete commands and events
 
 use crate::indexer::ProjectIndex;
-use crate::syntax::EntityType;
+use crate::syntax::{is_plugin_command, EntityType, RUST_EVENT_METHODS};
+use crate::utils::uri_to_path;
 use dashmap::DashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tower_lsp_server::lsp_types::{
-    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse,
+    CompletionItem, CompletionItemKind, CompletionItemLabelDetails, CompletionParams,
+    CompletionResponse, Documentation, MarkupContent, MarkupKind,
 };
-use tower_lsp_server::UriExt;
-
-const COMPLETION_TRIGGERS: &[&str] = &[
-    "invoke",
-    "emit",
-    "emitTo",
-    "listen",
-    "once",
-    "emit_to",
-    "emit_str",
-    "emit_str_to",
-    "emit_filter",
-    "emit_str_filter",
-    "listen_any",
-    "once_any",
-];
+
+/// Frontend functions that trigger completion; Rust methods come from `RUST_EVENT_METHODS`
+const COMPLETION_TRIGGERS: &[&str] = &["invoke", "emit", "emitTo", "listen", "once"];
 
 /// Handle completion request (pure function)
 pub fn handle_completion(
@@ -32,8 +21,7 @@ pub fn handle_completion(
     document_cache: &Arc<DashMap<PathBuf, String>>,
 ) -> Option<CompletionResponse> {
     let uri = &params.text_document_position.text_document.uri;
-    let path_cow = uri.to_file_path()?;
-    let path: PathBuf = path_cow.to_path_buf();
+    let path: PathBuf = project_index.index_path(&uri_to_path(uri));
 
     // Try to get content from cache first, fallback to reading from disk
     let content = document_cache
@@ -50,28 +38,48 @@ pub fn handle_completion(
 
     let line = lines[line_idx];
     let col = params.text_document_position.position.character as usize;
-    let byte_index = crate::utils::lsp_character_to_byte_index(line, col);
+    let byte_index = crate::position::lsp_character_to_byte_index(line, col);
     let prefix = &line[..byte_index];
 
     // Check if in completion context
     // Support both direct calls: invoke("...") and generic calls: invoke<Type>("...")
-    let in_context = COMPLETION_TRIGGERS.iter().any(|name| {
-        if let Some(pos) = prefix.rfind(name) {
-            let rest = &prefix[pos + name.len()..];
-            rest.starts_with('(') || rest.starts_with('<')
-        } else {
-            false
-        }
-    });
+    let rust_triggers = RUST_EVENT_METHODS.iter().map(|m| m.method);
+    let in_context = COMPLETION_TRIGGERS
+        .iter()
+        .copied()
+        .chain(rust_triggers)
+        .any(|name| {
+            if let Some(pos) = prefix.rfind(name) {
+                let rest = &prefix[pos + name.len()..];
+                rest.starts_with('(') || rest.starts_with('<')
+            } else {
+                false
+            }
+        });
 
     if !in_context {
         return None;
     }
 
+    // Inside the generic of `invoke<|>("cmd")`: suggest the command's resolved return type
+    if let Some(item) = complete_invoke_generic(prefix, &line[byte_index..], project_index) {
+        return Some(CompletionResponse::Array(vec![item]));
+    }
+
     let mut items = Vec::new();
+    let exclude_plugins = project_index.exclude_plugin_commands();
 
     // Add commands
     for (name, def_loc) in project_index.get_all_names(EntityType::Command).iter() {
+        if exclude_plugins
+            && (is_plugin_command(name)
+                || def_loc
+                    .as_ref()
+                    .is_some_and(|l| is_in_node_modules(&l.path)))
+        {
+            continue;
+        }
+
         let detail = def_loc.as_ref().map(|l| {
             let filename = l
                 .path
@@ -81,10 +89,31 @@ pub fn handle_completion(
             format!("Command defined in {filename}")
         });
 
+        let documentation = def_loc.as_ref().and_then(|l| l.doc.clone()).map(|doc| {
+            Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: doc,
+            })
+        });
+
+        // Argument object of the call, optional parameters marked with `?`
+        let label_details = project_index
+            .get_schema(name)
+            .filter(|schema| !schema.params.is_empty())
+            .map(|schema| CompletionItemLabelDetails {
+                detail: Some(format!(
+                    " {{ {} }}",
+                    schema.frontend_arg_fields().join(", ")
+                )),
+                description: None,
+            });
+
         items.push(CompletionItem {
             label: name.clone(),
+            label_details,
             kind: Some(CompletionItemKind::FUNCTION),
             detail,
+            documentation,
             ..Default::default()
         });
     }
@@ -105,3 +134,46 @@ pub fn handle_completion(
 
     Some(CompletionResponse::Array(items))
 }
+
+/// Whether `path` lies in a `node_modules` directory, i.e. belongs to a package
+fn is_in_node_modules(path: &Path) -> bool {
+    path.components().any(|c| c.as_os_str() == "node_modules")
+}
+
+/// Complete the type argument of `invoke<|>("cmd")` with the command's return type.
+///
+/// Schemas store the success type already unwrapped (`Result<User, String>` → `User`),
+/// which is what the `invoke` promise resolves to.
+fn complete_invoke_generic(
+    prefix: &str,
+    suffix: &str,
+    project_index: &ProjectIndex,
+) -> Option<CompletionItem> {
+    let pos = prefix.rfind("invoke")?;
+    let rest = prefix[pos + "invoke".len()..].strip_prefix('<')?;
+    if rest.contains(['>', '(']) {
+        return None;
+    }
+
+    let command_name = first_string_argument(suffix)?;
+    let schema = project_index.get_schema(command_name)?;
+
+    Some(CompletionItem {
+        label: schema.return_type.clone(),
+        kind: Some(CompletionItemKind::TYPE_PARAMETER),
+        detail: Some(format!("Return type of '{command_name}'")),
+        ..Default::default()
+    })
+}
+
+/// Text of the first string literal after the opening parenthesis: `>("get_user")` → `get_user`.
+fn first_string_argument(suffix: &str) -> Option<&str> {
+    let args = &suffix[suffix.find('(')? + 1..];
+    let args = args.trim_start();
+    let quote = args
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '"' | '\'' | '`'))?;
+    let body = &args[1..];
+    Some(&body[..body.find(quote)?])
+}
@@ -4,6 +4,7 @@
 
 use crate::indexer::{LocationInfo, ProjectIndex};
 use crate::syntax::Behavior;
+use crate::utils::uri_to_path;
 use std::path::PathBuf;
 use tower_lsp_server::lsp_types::{
     GotoDefinitionParams, GotoDefinitionResponse, LocationLink, Uri,
@@ -18,8 +19,7 @@ pub fn handle_goto_definition(
     let uri = params.text_document_position_params.text_document.uri;
     let position = params.text_document_position_params.position;
 
-    let path_cow = uri.to_file_path()?;
-    let path: PathBuf = path_cow.to_path_buf();
+    let path: PathBuf = uri_to_path(&uri);
 
     if let Some((key, origin_loc)) = project_index.get_key_at_position(&path, position) {
         let all_refs = project_index.get_locations(key.entity, &key.name);
@@ -8,13 +8,81 @@
 //!    tauri-typegen) has been indexed. Uses `CommandSchema` sourced from those generators;
 //!    `GeneratorKind::RustSource` schemas are intentionally excluded from type checking.
 
-use crate::indexer::{DiagnosticInfo, GeneratorKind, IndexKey, LocationInfo, ProjectIndex};
+use crate::indexer::{
+    DiagnosticInfo, DiagnosticsScope, GeneratorKind, IndexKey, LocationInfo, ProjectIndex,
+};
 use crate::syntax::Behavior;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::path::PathBuf;
-use tower_lsp_server::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Range};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use tower_lsp_server::lsp_types::notification::Notification;
+use tower_lsp_server::lsp_types::{
+    CodeDescription, Diagnostic, DiagnosticSeverity, NumberOrString, Range,
+};
+
+/// Errors and warnings among published diagnostics, the params of `tarus/status`
+///
+/// ```json
+/// { "errors": 0, "warnings": 3,
+///   "byCode": { "tarus/command-undefined": 1, "tarus/event-no-listeners": 2 } }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsStatus {
+    pub errors: usize,
+    pub warnings: usize,
+    /// Errors and warnings per diagnostic code
+    pub by_code: BTreeMap<String, usize>,
+}
+
+impl DiagnosticsStatus {
+    /// Counts of one file's diagnostics
+    #[must_use]
+    pub fn from_diagnostics(diagnostics: &[Diagnostic]) -> Self {
+        let mut status = Self::default();
+        for diagnostic in diagnostics {
+            match diagnostic.severity {
+                Some(DiagnosticSeverity::ERROR) => status.errors += 1,
+                Some(DiagnosticSeverity::WARNING) => status.warnings += 1,
+                _ => continue,
+            }
+            if let Some(NumberOrString::String(code)) = &diagnostic.code {
+                *status.by_code.entry(code.clone()).or_default() += 1;
+            }
+        }
+        status
+    }
+
+    /// Add the counts of `other`
+    pub fn merge(&mut self, other: &Self) {
+        self.errors += other.errors;
+        self.warnings += other.warnings;
+        for (code, count) in &other.by_code {
+            *self.by_code.entry(code.clone()).or_default() += count;
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.errors == 0 && self.warnings == 0
+    }
+}
+
+/// `tarus/status` server notification: diagnostics totals across the published files
+/// (`tarus.diagnostics.statusNotification`)
+#[derive(Debug)]
+pub enum StatusNotification {}
+
+impl Notification for StatusNotification {
+    type Params = DiagnosticsStatus;
+    const METHOD: &'static str = crate::constants::STATUS_NOTIFICATION;
+}
 
 /// Create a diagnostic with `tarus` source and optional code/data.
+///
+/// A coded diagnostic also links to the documentation of its rule.
 fn tarus_diagnostic(
     range: Range,
     severity: DiagnosticSeverity,
@@ -27,12 +95,22 @@ fn tarus_diagnostic(
         severity: Some(severity),
         source: Some("tarus".to_string()),
         code: code.map(|c| NumberOrString::String(c.to_string())),
+        code_description: code.and_then(code_description),
         message,
         data,
         ..Default::default()
     }
 }
 
+/// Link to the documentation of the rule behind diagnostic `code` (`tarus/<rule>`)
+fn code_description(code: &str) -> Option<CodeDescription> {
+    let rule = code.strip_prefix("tarus/")?;
+    let href = format!("{}#{rule}", crate::constants::DIAGNOSTICS_DOCS_URL);
+    Some(CodeDescription {
+        href: href.parse().ok()?,
+    })
+}
+
 /// Build JSON data payload for type-annotation code actions (shared by return-type and event-payload).
 fn make_type_action_data(loc: &LocationInfo, expected: &str) -> serde_json::Value {
     let mut data = json!({ "expected": expected });
@@ -88,6 +166,47 @@ fn check_type_annotation(
     }
 }
 
+/// Compute diagnostics for a file only if it falls within the configured diagnostics scope.
+///
+/// In `OpenFiles` mode, files that are not in `open_files` are skipped entirely and `None`
+/// is returned, so nothing should be published for them.
+pub fn compute_scoped_file_diagnostics(
+    path: &PathBuf,
+    project_index: &ProjectIndex,
+    open_files: &DashMap<PathBuf, String>,
+) -> Option<Vec<Diagnostic>> {
+    if project_index.diagnostics_scope() == DiagnosticsScope::OpenFiles
+        && !open_files.contains_key(&project_index.index_path(path))
+    {
+        return None;
+    }
+
+    Some(compute_file_diagnostics(path, project_index))
+}
+
+/// Files whose diagnostics may change after `path` was re-indexed, sorted by path.
+///
+/// `old_keys` are the keys the file had before the change. Besides `path` itself, this
+/// includes every file referencing an old or new key — e.g. the frontend callers of a
+/// command that was just defined — unless `tarus.diagnostics.propagate` is off.
+#[must_use]
+pub fn affected_files(
+    path: &Path,
+    old_keys: &[IndexKey],
+    project_index: &ProjectIndex,
+) -> Vec<PathBuf> {
+    let mut files = BTreeSet::from([path.to_path_buf()]);
+
+    if project_index.propagate_diagnostics() {
+        let new_keys = project_index.get_file_keys(path);
+        for key in old_keys.iter().chain(&new_keys) {
+            files.extend(project_index.files_referencing(key.entity, &key.name));
+        }
+    }
+
+    files.into_iter().collect()
+}
+
 /// Compute diagnostics for a specific file
 pub fn compute_file_diagnostics(path: &PathBuf, project_index: &ProjectIndex) -> Vec<Diagnostic> {
     if project_index.get_parse_error(path).is_some() {
@@ -102,6 +221,33 @@ pub fn compute_file_diagnostics(path: &PathBuf, project_index: &ProjectIndex) ->
     let has_bindings = project_index.has_bindings_files();
     let mut diagnostics = Vec::new();
 
+    // Developer mode: point at where tree-sitter had to recover, as findings past it may be lost
+    if project_index.developer_mode() {
+        if let Some(range) = project_index.get_syntax_error(path) {
+            diagnostics.push(tarus_diagnostic(
+                range,
+                DiagnosticSeverity::INFORMATION,
+                "Syntax error: commands and events after this point may not be indexed".to_string(),
+                Some(crate::constants::DIAG_SYNTAX_ERROR),
+                None,
+            ));
+        }
+    }
+
+    for dynamic in project_index.get_dynamic_names(path) {
+        let what = match dynamic.entity {
+            crate::syntax::EntityType::Command => "Command",
+            crate::syntax::EntityType::Event => "Event",
+        };
+        diagnostics.push(tarus_diagnostic(
+            dynamic.range,
+            DiagnosticSeverity::HINT,
+            format!("{what} name is built at runtime, so this call is not linked or checked"),
+            Some(crate::constants::DIAG_DYNAMIC_NAME),
+            None,
+        ));
+    }
+
     for key in &keys {
         let info = project_index.get_diagnostic_info(key);
         let locations = project_index.get_locations(key.entity, &key.name);
@@ -115,12 +261,34 @@ pub fn compute_file_diagnostics(path: &PathBuf, project_index: &ProjectIndex) ->
             .iter()
             .find(|l| matches!(l.behavior, Behavior::Emit))
             .map(|l| l.range);
+        let expected_name = match key.entity {
+            crate::syntax::EntityType::Event => project_index.event_naming().suggest(&key.name),
+            crate::syntax::EntityType::Command => None,
+        };
 
         for loc in &local_locations {
+            if let Some(expected) = &expected_name {
+                check_event_naming(loc, key, expected, project_index, &mut diagnostics);
+            }
+
+            if loc.behavior == Behavior::Definition {
+                check_param_name_collisions(loc, key, project_index, &mut diagnostics);
+                check_command_registration(loc, key, project_index, &mut diagnostics);
+            }
+
+            if first_call == Some(loc.range) && info.has_definition() {
+                check_command_permission(loc, key, project_index, &mut diagnostics);
+            }
+
+            if loc.unawaited && project_index.require_await() {
+                check_invoke_awaited(loc, key, &mut diagnostics);
+            }
+
             compute_structural_diagnostics(
                 loc,
                 key,
                 &info,
+                project_index,
                 first_call,
                 first_emit,
                 &mut diagnostics,
@@ -139,20 +307,28 @@ fn compute_structural_diagnostics(
     loc: &LocationInfo,
     key: &IndexKey,
     info: &DiagnosticInfo,
+    project_index: &ProjectIndex,
     first_call: Option<tower_lsp_server::lsp_types::Range>,
     first_emit: Option<tower_lsp_server::lsp_types::Range>,
     diagnostics: &mut Vec<Diagnostic>,
 ) {
     let msg = match loc.behavior {
         Behavior::Definition => {
-            let (entity_label, usage_label, is_unused) = match key.entity {
-                crate::syntax::EntityType::Command => {
-                    ("Command", "invoked in frontend", !info.has_calls())
-                }
+            let (entity_label, usage_label, is_unused, unused_code) = match key.entity {
+                // Commands compiled only for tests are not part of the frontend API
+                crate::syntax::EntityType::Command => (
+                    "Command",
+                    "invoked in frontend",
+                    !info.has_calls()
+                        && !loc.test_only
+                        && project_index.is_relevant_command(&key.name),
+                    crate::constants::DIAG_COMMAND_UNUSED,
+                ),
                 crate::syntax::EntityType::Event => (
                     "Event",
                     "emitted or listened for",
                     !info.has_emitters() && !info.has_listeners(),
+                    crate::constants::DIAG_EVENT_UNUSED,
                 ),
             };
             if is_unused {
@@ -162,30 +338,51 @@ fn compute_structural_diagnostics(
                         "{entity_label} '{}' is defined but never {usage_label}",
                         key.name
                     ),
+                    unused_code,
                 ))
             } else {
                 None
             }
         }
         Behavior::Call | Behavior::SpectaCall if !info.has_definition() => {
-            if first_call == Some(loc.range) {
+            if first_call == Some(loc.range) && project_index.is_relevant_command(&key.name) {
                 Some((
                     DiagnosticSeverity::WARNING,
                     format!("Command '{}' is not defined in Rust backend", key.name),
+                    crate::constants::DIAG_COMMAND_UNDEFINED,
                 ))
             } else {
                 None
             }
         }
-        Behavior::Listen if !info.has_emitters() => Some((
-            DiagnosticSeverity::WARNING,
-            format!("Event '{}' is listened for but never emitted", key.name),
-        )),
+        Behavior::Listen
+            if !info.has_emitters() && !project_index.is_framework_event(&key.name) =>
+        {
+            if let Some(suggestion) = suggest_event_name(&key.name, project_index) {
+                diagnostics.push(tarus_diagnostic(
+                    loc.range,
+                    DiagnosticSeverity::WARNING,
+                    format!(
+                        "Event '{}' is listened for but never emitted, did you mean '{suggestion}'?",
+                        key.name
+                    ),
+                    Some(crate::constants::DIAG_EVENT_NAME_TYPO),
+                    Some(json!({ "suggestion": suggestion })),
+                ));
+                return;
+            }
+            Some((
+                DiagnosticSeverity::WARNING,
+                format!("Event '{}' is listened for but never emitted", key.name),
+                crate::constants::DIAG_EVENT_NOT_EMITTED,
+            ))
+        }
         Behavior::Emit if !info.has_listeners() => {
             if first_emit == Some(loc.range) {
                 Some((
                     DiagnosticSeverity::WARNING,
                     format!("Event '{}' is emitted but no listeners found", key.name),
+                    crate::constants::DIAG_EVENT_NO_LISTENERS,
                 ))
             } else {
                 None
@@ -194,9 +391,199 @@ fn compute_structural_diagnostics(
         _ => None,
     };
 
-    if let Some((severity, message)) = msg {
-        diagnostics.push(tarus_diagnostic(loc.range, severity, message, None, None));
+    if let Some((severity, message, code)) = msg {
+        diagnostics.push(tarus_diagnostic(
+            loc.range,
+            severity,
+            message,
+            Some(code),
+            None,
+        ));
+    }
+}
+
+/// Warn on an emit/listen whose event name breaks `tarus.events.namingConvention`.
+fn check_event_naming(
+    loc: &LocationInfo,
+    key: &IndexKey,
+    expected: &str,
+    project_index: &ProjectIndex,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if !matches!(loc.behavior, Behavior::Emit | Behavior::Listen) || loc.codegen_origin.is_some() {
+        return;
     }
+
+    diagnostics.push(tarus_diagnostic(
+        loc.range,
+        DiagnosticSeverity::WARNING,
+        format!(
+            "Event '{}' does not follow the {} naming convention (expected '{expected}')",
+            key.name,
+            project_index.event_naming().label()
+        ),
+        Some(crate::constants::DIAG_EVENT_NAMING_CONVENTION),
+        Some(json!({ "expected": expected })),
+    ));
+}
+
+/// Warn on a Rust command definition whose parameters map to the same frontend argument
+/// key, e.g. `user_name` and `userName` both become `userName`.
+fn check_param_name_collisions(
+    loc: &LocationInfo,
+    key: &IndexKey,
+    project_index: &ProjectIndex,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if key.entity != crate::syntax::EntityType::Command {
+        return;
+    }
+    let Some(schema) = project_index.get_schema(&key.name) else {
+        return;
+    };
+    if schema.generator != GeneratorKind::RustSource || schema.source_path != loc.path {
+        return;
+    }
+
+    let mut by_frontend_name: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for param in &schema.params {
+        by_frontend_name
+            .entry(schema.frontend_param_name(param))
+            .or_default()
+            .push(&param.name);
+    }
+
+    for (frontend_name, params) in by_frontend_name {
+        if params.len() < 2 {
+            continue;
+        }
+        let names = params
+            .iter()
+            .map(|p| format!("'{p}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        diagnostics.push(tarus_diagnostic(
+            loc.range,
+            DiagnosticSeverity::WARNING,
+            format!(
+                "Command '{}' parameters {names} map to the same frontend argument '{frontend_name}'",
+                key.name
+            ),
+            Some(crate::constants::DIAG_PARAM_NAME_COLLISION),
+            None,
+        ));
+    }
+}
+
+/// Warn on a Rust command definition missing from `generate_handler![...]`, which makes
+/// every `invoke` of it fail at runtime.
+///
+/// Only checked once some file registers commands, so projects that build their handler
+/// another way get no false positives.
+fn check_command_registration(
+    loc: &LocationInfo,
+    key: &IndexKey,
+    project_index: &ProjectIndex,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    // Commands declared in the frontend (`"kind": "define"` patterns) have no handler
+    if key.entity != crate::syntax::EntityType::Command
+        || !loc.path.extension().is_some_and(|ext| ext == "rs")
+        || loc.test_only
+        || !project_index.is_relevant_command(&key.name)
+        || !project_index.has_command_registrations()
+        || project_index.is_command_registered(&key.name)
+    {
+        return;
+    }
+
+    diagnostics.push(tarus_diagnostic(
+        loc.range,
+        DiagnosticSeverity::WARNING,
+        format!(
+            "Command '{}' is not registered in generate_handler![]",
+            key.name
+        ),
+        Some(crate::constants::DIAG_COMMAND_NOT_REGISTERED),
+        None,
+    ));
+}
+
+/// Warn on the first call in a file to a command no capability file permits.
+///
+/// Only reported once the app declares command permissions; see
+/// [`ProjectIndex::is_command_permitted`].
+fn check_command_permission(
+    loc: &LocationInfo,
+    key: &IndexKey,
+    project_index: &ProjectIndex,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if !project_index.is_relevant_command(&key.name)
+        || project_index.is_command_permitted(&key.name)
+    {
+        return;
+    }
+
+    diagnostics.push(tarus_diagnostic(
+        loc.range,
+        DiagnosticSeverity::WARNING,
+        format!(
+            "Command '{}' is not permitted by any capability (missing 'allow-{}')",
+            key.name,
+            key.name.replace('_', "-")
+        ),
+        Some(crate::constants::DIAG_COMMAND_NOT_PERMITTED),
+        None,
+    ));
+}
+
+/// Warn on an `invoke(...)` used as a bare statement (`tarus.diagnostics.requireAwait`).
+///
+/// Its Promise is dropped, so the result is lost and a rejection goes unhandled.
+fn check_invoke_awaited(loc: &LocationInfo, key: &IndexKey, diagnostics: &mut Vec<Diagnostic>) {
+    diagnostics.push(tarus_diagnostic(
+        loc.range,
+        DiagnosticSeverity::WARNING,
+        format!(
+            "Result of invoke('{}') is not awaited; use `await`, `.then()`, or `void` to discard it",
+            key.name
+        ),
+        Some(crate::constants::DIAG_INVOKE_NOT_AWAITED),
+        None,
+    ));
+}
+
+/// Find an emitted event whose name is a likely typo of `name`.
+///
+/// The allowed edit distance scales with the name length (none below 5 chars, 1 below 8,
+/// otherwise 2) so that short, legitimately distinct names like `open`/`close` are left alone.
+/// Ties are broken alphabetically to keep the suggestion stable.
+pub(crate) fn suggest_event_name(name: &str, project_index: &ProjectIndex) -> Option<String> {
+    let max_distance = match name.chars().count() {
+        0..5 => return None,
+        5..8 => 1,
+        _ => 2,
+    };
+
+    project_index
+        .get_all_names(crate::syntax::EntityType::Event)
+        .iter()
+        .map(|(candidate, _)| candidate)
+        .filter(|candidate| candidate.as_str() != name)
+        .filter_map(|candidate| {
+            let distance = crate::utils::edit_distance(name, candidate);
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .filter(|(_, candidate)| {
+            let key = IndexKey {
+                entity: crate::syntax::EntityType::Event,
+                name: (*candidate).clone(),
+            };
+            project_index.get_diagnostic_info(&key).has_emitters()
+        })
+        .min()
+        .map(|(_, candidate)| candidate.clone())
 }
 
 fn compute_type_diagnostics(
@@ -205,6 +592,10 @@ fn compute_type_diagnostics(
     project_index: &ProjectIndex,
     diagnostics: &mut Vec<Diagnostic>,
 ) {
+    // Calls through a local wrapper carry no arguments or generics of their own
+    if loc.wrapper_name.is_some() {
+        return;
+    }
     if let Some(d) = check_param_keys(loc, &key.name, project_index) {
         diagnostics.push(d);
     }
@@ -214,6 +605,9 @@ fn compute_type_diagnostics(
     if let Some(d) = check_return_type(loc, &key.name, project_index) {
         diagnostics.push(d);
     }
+    if let Some(d) = check_invoke_generic_type(loc, project_index) {
+        diagnostics.push(d);
+    }
     if let Some(d) = check_event_payload_type(loc, &key.name, project_index) {
         diagnostics.push(d);
     }
@@ -224,13 +618,14 @@ fn compute_type_diagnostics(
 ///
 /// Only activates when:
 /// - The location is a `Call` (i.e. `invoke("name", { key: val, ... })`)
-/// - The call has `call_param_keys` recorded by the parser
+/// - The call has `call_param_keys` or an unresolved `call_arg_variable` recorded by the parser
 /// - A `CommandSchema` exists for this command sourced from a binding generator
 ///   (`Specta`, `TsRs`, or `Typegen`) — **not** `RustSource`
 ///
 /// Reports:
 /// - `WARNING` for missing required parameters
 /// - `WARNING` for unexpected (extra) parameters
+/// - `INFO` when the arguments are a variable that couldn't be resolved
 fn check_param_keys(
     loc: &LocationInfo,
     command_name: &str,
@@ -241,7 +636,9 @@ fn check_param_keys(
         return None;
     }
 
-    let call_keys = loc.call_param_keys.as_ref()?;
+    if loc.call_param_keys.is_none() && loc.call_arg_variable.is_none() {
+        return None;
+    }
 
     // Get schema — must come from a bindings generator, not from Rust source analysis
     let schema = project_index.get_schema(command_name)?;
@@ -249,43 +646,60 @@ fn check_param_keys(
         return None;
     }
 
-    // BTreeSet gives deterministic ordering for diagnostic messages
-    let expected: std::collections::BTreeSet<&str> =
-        schema.params.iter().map(|p| p.name.as_str()).collect();
-    let actual: std::collections::BTreeSet<&str> = call_keys.iter().map(String::as_str).collect();
+    // Arguments passed as a variable whose shape couldn't be resolved in the file
+    if let Some(variable) = &loc.call_arg_variable {
+        return Some(tarus_diagnostic(
+            loc.range,
+            DiagnosticSeverity::INFORMATION,
+            format!(
+                "invoke('{command_name}') argument '{variable}' passed as variable, parameters not verified"
+            ),
+            Some(crate::constants::DIAG_ARGS_NOT_VERIFIED),
+            None,
+        ));
+    }
 
-    // Missing required params (present in schema, absent in call)
-    let missing: Vec<&str> = expected.difference(&actual).copied().collect();
+    let call_keys = loc.call_param_keys.as_ref()?;
+
+    let expected: Vec<(&str, Option<&str>)> = schema
+        .params
+        .iter()
+        .map(|p| (p.name.as_str(), None))
+        .collect();
+    let actual: Vec<(&str, Option<&str>)> = call_keys.iter().map(|k| (k.as_str(), None)).collect();
 
-    if !missing.is_empty() {
+    let mut diff = diff_fields(&expected, &actual, project_index);
+    diff.missing
+        .retain(|name| !schema.params.iter().any(|p| p.optional && p.name == *name));
+
+    // Missing required params (present in schema, absent in call)
+    if !diff.missing.is_empty() {
         return Some(tarus_diagnostic(
             loc.range,
             DiagnosticSeverity::WARNING,
             format!(
                 "invoke('{}') is missing required argument{}: {}",
                 command_name,
-                if missing.len() == 1 { "" } else { "s" },
-                missing.join(", ")
+                if diff.missing.len() == 1 { "" } else { "s" },
+                diff.missing.join(", ")
             ),
-            None,
+            Some(crate::constants::DIAG_MISSING_ARGUMENT),
             None,
         ));
     }
 
     // Unexpected extra params (present in call, absent from schema)
-    let extra: Vec<&str> = actual.difference(&expected).copied().collect();
-
-    if !extra.is_empty() {
+    if !diff.extra.is_empty() {
         return Some(tarus_diagnostic(
             loc.range,
             DiagnosticSeverity::WARNING,
             format!(
                 "invoke('{}') has unexpected argument{}: {}",
                 command_name,
-                if extra.len() == 1 { "" } else { "s" },
-                extra.join(", ")
+                if diff.extra.len() == 1 { "" } else { "s" },
+                diff.extra.join(", ")
             ),
-            None,
+            Some(crate::constants::DIAG_UNEXPECTED_ARGUMENT),
             None,
         ));
     }
@@ -293,6 +707,66 @@ fn check_param_keys(
     None
 }
 
+/// Differences between an expected and an actual set of named fields.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Fields present in the expected set but absent from the actual set
+    pub missing: Vec<String>,
+    /// Fields present in the actual set but absent from the expected set
+    pub extra: Vec<String>,
+    /// Fields present in both with incompatible types: `(name, expected, actual)`
+    pub mismatched: Vec<(String, String, String)>,
+}
+
+impl FieldDiff {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.mismatched.is_empty()
+    }
+}
+
+/// Compare two sets of `(name, type)` fields.
+///
+/// Types are only compared when both sides carry one (`None` means "name only"),
+/// using [`types_match`]. Results are sorted by field name for deterministic output.
+#[must_use]
+pub fn diff_fields(
+    expected: &[(&str, Option<&str>)],
+    actual: &[(&str, Option<&str>)],
+    project_index: &ProjectIndex,
+) -> FieldDiff {
+    // BTreeMap gives deterministic ordering for diagnostic messages
+    let expected: BTreeMap<&str, Option<&str>> = expected.iter().copied().collect();
+    let actual: BTreeMap<&str, Option<&str>> = actual.iter().copied().collect();
+
+    let mut diff = FieldDiff::default();
+
+    for (name, expected_type) in &expected {
+        match actual.get(name) {
+            None => diff.missing.push((*name).to_string()),
+            Some(actual_type) => {
+                if let (Some(e), Some(a)) = (expected_type, actual_type) {
+                    if !types_match(a, e, project_index) {
+                        diff.mismatched.push((
+                            (*name).to_string(),
+                            (*e).to_string(),
+                            (*a).to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    diff.extra = actual
+        .keys()
+        .filter(|name| !expected.contains_key(*name))
+        .map(|name| (*name).to_string())
+        .collect();
+
+    diff
+}
+
 /// Validate the argument count of a `commands.methodName(...)` (`SpectaCall`) against the
 /// expected parameter count in the `CommandSchema`.
 ///
@@ -386,6 +860,188 @@ fn check_return_type(
     )
 }
 
+/// Warn when the `invoke<T>()` type argument names a type that exists nowhere: not a TS
+/// built-in, not a generated binding type or Rust struct, and neither declared nor imported
+/// in the calling file.
+fn check_invoke_generic_type(
+    loc: &LocationInfo,
+    project_index: &ProjectIndex,
+) -> Option<Diagnostic> {
+    if !matches!(loc.behavior, Behavior::Call) {
+        return None;
+    }
+
+    let ts_type = loc.return_type.as_deref()?;
+    let unknown = referenced_type_names(ts_type).into_iter().find(|name| {
+        !TS_BUILTIN_TYPES.contains(name)
+            && !project_index.type_aliases.contains_key(*name)
+            && !project_index.struct_schemas.contains_key(*name)
+            && !project_index.file_has_type(&loc.path, name)
+            && !project_index.has_ambient_type(name)
+    })?;
+
+    Some(tarus_diagnostic(
+        loc.type_arg_range.unwrap_or(loc.range),
+        DiagnosticSeverity::WARNING,
+        format!("Unknown type '{unknown}' in invoke generic"),
+        Some(crate::constants::DIAG_UNKNOWN_INVOKE_TYPE),
+        None,
+    ))
+}
+
+/// TypeScript primitives, standard library and DOM global types accepted in `invoke<T>()`
+const TS_BUILTIN_TYPES: &[&str] = &[
+    "string",
+    "number",
+    "boolean",
+    "bigint",
+    "symbol",
+    "object",
+    "any",
+    "unknown",
+    "never",
+    "void",
+    "null",
+    "undefined",
+    "true",
+    "false",
+    "keyof",
+    "readonly",
+    "Array",
+    "ReadonlyArray",
+    "Record",
+    "Partial",
+    "Required",
+    "Readonly",
+    "Pick",
+    "Omit",
+    "Exclude",
+    "Extract",
+    "NonNullable",
+    "Awaited",
+    "Promise",
+    "Map",
+    "Set",
+    "Date",
+    "Error",
+    "Uint8Array",
+    "ArrayBuffer",
+    // Utility types
+    "ReturnType",
+    "Parameters",
+    "ConstructorParameters",
+    "InstanceType",
+    "ThisType",
+    "ThisParameterType",
+    "OmitThisParameter",
+    "NoInfer",
+    "Uppercase",
+    "Lowercase",
+    "Capitalize",
+    "Uncapitalize",
+    // Standard library
+    "Object",
+    "String",
+    "Number",
+    "Boolean",
+    "Symbol",
+    "BigInt",
+    "Function",
+    "RegExp",
+    "PropertyKey",
+    "PromiseLike",
+    "ArrayLike",
+    "Iterable",
+    "Iterator",
+    "IterableIterator",
+    "AsyncIterable",
+    "AsyncIterator",
+    "ReadonlyMap",
+    "ReadonlySet",
+    "WeakMap",
+    "WeakSet",
+    "WeakRef",
+    "DataView",
+    "SharedArrayBuffer",
+    "ArrayBufferLike",
+    "ArrayBufferView",
+    "Int8Array",
+    "Int16Array",
+    "Int32Array",
+    "Uint8ClampedArray",
+    "Uint16Array",
+    "Uint32Array",
+    "Float32Array",
+    "Float64Array",
+    "BigInt64Array",
+    "BigUint64Array",
+    // DOM
+    "Blob",
+    "File",
+    "FileList",
+    "FormData",
+    "URL",
+    "URLSearchParams",
+    "Headers",
+    "Request",
+    "Response",
+    "ReadableStream",
+    "ImageData",
+    "ImageBitmap",
+];
+
+/// Type names referenced by a TS type expression, skipping string literals, object literal
+/// keys and members of qualified names (`api.User` yields `api`). Types using `typeof`
+/// refer to values and yield nothing.
+fn referenced_type_names(ts_type: &str) -> Vec<&str> {
+    if ts_type.contains("typeof") {
+        return Vec::new();
+    }
+
+    let is_ident_start = |c: char| c.is_ascii_alphabetic() || c == '_' || c == '$';
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+
+    let mut names = Vec::new();
+    let mut chars = ts_type.char_indices().peekable();
+    let mut prev = ' ';
+
+    while let Some((start, c)) = chars.next() {
+        if matches!(c, '"' | '\'' | '`') {
+            // Skip the string literal
+            for (_, next) in chars.by_ref() {
+                if next == c {
+                    break;
+                }
+            }
+            prev = c;
+            continue;
+        }
+
+        if !is_ident_start(c) || is_ident(prev) {
+            prev = c;
+            continue;
+        }
+
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, next)) = chars.peek() {
+            if !is_ident(next) {
+                break;
+            }
+            end = i + next.len_utf8();
+            chars.next();
+        }
+
+        let rest = ts_type[end..].trim_start();
+        let is_key = rest.starts_with(':') || rest.starts_with("?:");
+        if prev != '.' && !is_key {
+            names.push(&ts_type[start..end]);
+        }
+        prev = 'a';
+    }
+
+    names
+}
+
 /// Validate the payload type of an `emit()` / `listen()` / `once()` call against the `EventSchema`.
 ///
 /// Two cases:
@@ -400,9 +1056,10 @@ fn check_event_payload_type(
         return None;
     }
 
-    // Rust files don't use generic type parameters on emit/listen/once — skip
+    // Rust files don't use generic type parameters on emit/listen/once; only listeners
+    // that deserialize a typed payload can be checked
     if loc.path.extension().is_some_and(|ext| ext == "rs") {
-        return None;
+        return check_rust_listen_payload_type(loc, event_name, project_index);
     }
 
     // Typed codegen APIs (e.g. specta events.X.listen) already provide type safety — skip
@@ -445,6 +1102,39 @@ fn check_event_payload_type(
     )
 }
 
+/// Validate the payload type deserialized by a Rust `listen`/`once` handler against the
+/// event's schema.
+///
+/// Reports a `WARNING` only when the payload type was statically determined; untyped
+/// handlers produce no diagnostic.
+fn check_rust_listen_payload_type(
+    loc: &LocationInfo,
+    event_name: &str,
+    project_index: &ProjectIndex,
+) -> Option<Diagnostic> {
+    if !matches!(loc.behavior, Behavior::Listen) {
+        return None;
+    }
+
+    let payload_type = loc.payload_type.as_deref()?;
+    let schema = project_index.get_event_schema(event_name)?;
+    let expected = &schema.payload_type;
+
+    if expected == "void" || types_match(payload_type, expected, project_index) {
+        return None;
+    }
+
+    Some(tarus_diagnostic(
+        loc.range,
+        DiagnosticSeverity::WARNING,
+        format!(
+            "listen('{event_name}') payload type mismatch: handler reads '{payload_type}', expected '{expected}'"
+        ),
+        Some(crate::constants::DIAG_EVENT_PAYLOAD_MISMATCH),
+        None,
+    ))
+}
+
 /// Check if a TypeScript type string is "known" (either a primitive or a registered alias).
 /// Recursively handles array notations and unions.
 pub(crate) fn is_known_type(ts_type: &str, project_index: &ProjectIndex) -> bool {
@@ -571,4 +1261,19 @@ mod tests {
         assert!(!is_known_type("UnknownType", &project_index));
         assert!(!is_known_type("string | UnknownType", &project_index));
     }
+
+    #[test]
+    fn test_referenced_type_names() {
+        assert_eq!(referenced_type_names("UserDto[]"), ["UserDto"]);
+        assert_eq!(
+            referenced_type_names("Record<string, Array<User | null>>"),
+            ["Record", "string", "Array", "User", "null"]
+        );
+        assert_eq!(
+            referenced_type_names("{ id: number; tag?: 'a' | \"b\" }"),
+            ["number"]
+        );
+        assert_eq!(referenced_type_names("api.User"), ["api"]);
+        assert!(referenced_type_names("typeof defaults").is_empty());
+    }
 }
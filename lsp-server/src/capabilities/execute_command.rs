@@ -0,0 +1,194 @@
+//! Execute command capability
+//!
+//! Dispatches `workspace/executeCommand` requests for server-side commands.
+
+use crate::capabilities::api_doc::generate_api_doc;
+use crate::capabilities::code_actions::generate_command_stub;
+use crate::capabilities::diagnostics::compute_file_diagnostics;
+use crate::capabilities::type_sync::check_type_sync;
+use crate::constants::{
+    CMD_CHECK_TYPE_SYNC, CMD_DIAGNOSTICS_JSON, CMD_GENERATE_API_DOC, CMD_GOTO_COMMAND,
+    CMD_ORPHANED_LISTENERS, CMD_PREVIEW_GENERATED_TYPE, CMD_RELOAD_CONFIG,
+};
+use crate::indexer::{IndexKey, ProjectIndex};
+use crate::syntax::{Behavior, EntityType};
+use std::path::Path;
+use tower_lsp_server::lsp_types::{DiagnosticSeverity, ExecuteCommandParams, Location, Uri};
+use tower_lsp_server::UriExt;
+
+/// Commands handled by the server, advertised in `execute_command_provider`
+pub const SERVER_COMMANDS: &[&str] = &[
+    CMD_CHECK_TYPE_SYNC,
+    CMD_RELOAD_CONFIG,
+    CMD_GENERATE_API_DOC,
+    CMD_PREVIEW_GENERATED_TYPE,
+    CMD_GOTO_COMMAND,
+    CMD_ORPHANED_LISTENERS,
+    CMD_DIAGNOSTICS_JSON,
+];
+
+/// Handle execute command request
+///
+/// `tarus.reloadConfig` needs the LSP client and is handled by the backend directly.
+#[must_use]
+pub fn handle_execute_command(
+    params: &ExecuteCommandParams,
+    project_index: &ProjectIndex,
+    workspace_root: Option<&Path>,
+) -> Option<serde_json::Value> {
+    match params.command.as_str() {
+        CMD_CHECK_TYPE_SYNC => serde_json::to_value(check_type_sync(project_index)).ok(),
+        CMD_GENERATE_API_DOC => Some(serde_json::Value::String(generate_api_doc(
+            project_index,
+            workspace_root,
+        ))),
+        CMD_PREVIEW_GENERATED_TYPE => preview_generated_type(params, project_index),
+        CMD_GOTO_COMMAND => goto_command(params, project_index),
+        CMD_ORPHANED_LISTENERS => Some(orphaned_listeners(project_index)),
+        CMD_DIAGNOSTICS_JSON => Some(diagnostics_json(project_index)),
+        _ => None,
+    }
+}
+
+/// `tarus.previewGeneratedType [command, returnType?]`: the stub "Create Rust command" inserts.
+///
+/// Without an explicit return type, the first call site's `invoke<T>()` type argument is used,
+/// matching what the code action generates at that call.
+fn preview_generated_type(
+    params: &ExecuteCommandParams,
+    project_index: &ProjectIndex,
+) -> Option<serde_json::Value> {
+    let command_name = params.arguments.first()?.as_str()?;
+    let return_type = params
+        .arguments
+        .get(1)
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .or_else(|| {
+            project_index
+                .get_locations(EntityType::Command, command_name)
+                .into_iter()
+                .filter(|l| l.behavior == Behavior::Call)
+                .find_map(|l| l.return_type)
+        });
+
+    Some(serde_json::Value::String(generate_command_stub(
+        command_name,
+        return_type.as_deref(),
+        project_index,
+    )))
+}
+
+/// `tarus.gotoCommand [command]`: the `Location[]` of the command's definitions.
+///
+/// Several locations are returned when the name is defined more than once (e.g. behind
+/// `#[cfg]`); an unknown command yields an empty array.
+fn goto_command(
+    params: &ExecuteCommandParams,
+    project_index: &ProjectIndex,
+) -> Option<serde_json::Value> {
+    let command_name = params.arguments.first()?.as_str()?;
+    let locations: Vec<Location> = project_index
+        .get_locations(EntityType::Command, command_name)
+        .into_iter()
+        .filter(|l| l.behavior == Behavior::Definition)
+        .filter_map(|l| {
+            Some(Location {
+                uri: Uri::from_file_path(&l.path)?,
+                range: l.range,
+            })
+        })
+        .collect();
+
+    serde_json::to_value(locations).ok()
+}
+
+/// `tarus.orphanedListeners`: every listen location of an event nothing emits.
+///
+/// Returns `[{ event, uri, range }]` sorted by event name, then file and position. Events
+/// emitted by the framework (`tauri://focus`, plugin events) are skipped.
+fn orphaned_listeners(project_index: &ProjectIndex) -> serde_json::Value {
+    let mut names: Vec<String> = project_index
+        .get_all_names(EntityType::Event)
+        .iter()
+        .map(|(name, _)| name.clone())
+        .filter(|name| !project_index.is_framework_event(name))
+        .collect();
+    names.sort();
+    names.dedup();
+
+    let mut listeners = Vec::new();
+    for name in names {
+        let key = IndexKey {
+            entity: EntityType::Event,
+            name,
+        };
+        if project_index.get_diagnostic_info(&key).has_emitters() {
+            continue;
+        }
+
+        let mut locations: Vec<_> = project_index
+            .get_locations(EntityType::Event, &key.name)
+            .into_iter()
+            .filter(|l| l.behavior == Behavior::Listen)
+            .collect();
+        locations.sort_by(|a, b| {
+            (&a.path, a.range.start.line, a.range.start.character).cmp(&(
+                &b.path,
+                b.range.start.line,
+                b.range.start.character,
+            ))
+        });
+
+        for loc in locations {
+            let Some(uri) = Uri::from_file_path(&loc.path) else {
+                continue;
+            };
+            listeners.push(serde_json::json!({
+                "event": key.name,
+                "uri": uri.as_str(),
+                "range": loc.range,
+            }));
+        }
+    }
+
+    serde_json::Value::Array(listeners)
+}
+
+/// `tarus.diagnosticsJson`: the diagnostics of every indexed file, for CLI and CI wrappers.
+///
+/// Returns `[{ file, line, column, severity, message, source }]` sorted by file, then
+/// position. `line` and `column` are 1-based, as problem matchers expect. Each file is
+/// computed once with the same cross-file checks as the published diagnostics, regardless
+/// of `tarus.diagnostics.scope`.
+fn diagnostics_json(project_index: &ProjectIndex) -> serde_json::Value {
+    let mut paths = project_index.get_indexed_paths();
+    paths.sort();
+    paths.dedup();
+
+    let mut entries = Vec::new();
+    for path in paths {
+        let mut diagnostics = compute_file_diagnostics(&path, project_index);
+        diagnostics.sort_by(|a, b| (a.range.start, &a.message).cmp(&(b.range.start, &b.message)));
+        diagnostics.dedup_by(|a, b| a.range == b.range && a.message == b.message);
+
+        for diagnostic in diagnostics {
+            let severity = match diagnostic.severity {
+                Some(DiagnosticSeverity::ERROR) => "error",
+                Some(DiagnosticSeverity::INFORMATION) => "info",
+                Some(DiagnosticSeverity::HINT) => "hint",
+                _ => "warning",
+            };
+            entries.push(serde_json::json!({
+                "file": path.to_string_lossy(),
+                "line": diagnostic.range.start.line + 1,
+                "column": diagnostic.range.start.character + 1,
+                "severity": severity,
+                "message": diagnostic.message,
+                "source": diagnostic.source.unwrap_or_default(),
+            }));
+        }
+    }
+
+    serde_json::Value::Array(entries)
+}
@@ -1,26 +1,34 @@
 //! Hover capability - shows detailed tooltip with usage statistics
 
-use crate::indexer::{LocationInfo, ProjectIndex};
+use crate::indexer::{definition_in, CommandSchema, LocationInfo, ProjectIndex};
 use crate::syntax::{Behavior, EntityType};
+use crate::utils::uri_to_path;
 use std::fmt::Write as _;
-use tower_lsp_server::lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind};
+use tower_lsp_server::lsp_types::{
+    Hover, HoverContents, HoverParams, MarkupContent, MarkupKind, Uri,
+};
 use tower_lsp_server::UriExt;
 
+/// Max emitter/listener links rendered in an event hover
+const MAX_COUNTERPART_LINKS: usize = 3;
+
 /// Handle hover request (pure function)
 pub fn handle_hover(params: HoverParams, project_index: &ProjectIndex) -> Option<Hover> {
     let uri = params.text_document_position_params.text_document.uri;
     let position = params.text_document_position_params.position;
 
-    let path_cow = uri.to_file_path()?;
-    let path = &path_cow;
+    let path_buf = uri_to_path(&uri);
+    let path = &path_buf;
 
     if let Some((key, origin_loc)) = project_index.get_key_at_position(path, position) {
-        let locations = project_index.get_locations(key.entity, &key.name);
-
-        if locations.is_empty() {
+        let view = project_index.get_locations_iter(key.entity, &key.name);
+        if view.is_empty() {
             return None;
         }
 
+        let locations: Vec<&LocationInfo> = view.iter().collect();
+        let max_references = project_index.hover_max_references();
+
         let mut md_text = String::new();
         let icon = match key.entity {
             EntityType::Command => "⚙️",
@@ -29,21 +37,43 @@ pub fn handle_hover(params: HoverParams, project_index: &ProjectIndex) -> Option
 
         let _ = write!(md_text, "### {} {:?}: `{}`\n\n", icon, key.entity, key.name);
 
-        // Command return type
+        // Command doc comment, cfg gate and return type
         if key.entity == EntityType::Command {
+            let definition = definition_in(key.entity, locations.iter().copied());
+            if let Some(doc) = definition.and_then(|l| l.doc.as_deref()) {
+                let _ = writeln!(md_text, "{doc}\n");
+            }
+            if let Some(cfg) = definition.and_then(|l| l.cfg.as_deref()) {
+                let _ = writeln!(md_text, "{}\n", cfg_label(cfg));
+            }
             if let Some(schema) = project_index.get_schema(&key.name) {
+                if !schema.params.is_empty() {
+                    let _ = writeln!(md_text, "**Arguments:** `{}`\n", arguments_object(&schema));
+                }
                 let _ = writeln!(md_text, "**Returns:** `{}`\n", schema.return_type);
             }
+            if let Some(error_type) = definition.and_then(|l| l.error_type.as_deref()) {
+                let _ = writeln!(md_text, "**Rejects with:** `{error_type}`\n");
+            }
         }
 
         // Definitions Section
-        push_definitions_section(&mut md_text, key.entity, &locations);
+        push_definitions_section(&mut md_text, key.entity, &locations, max_references);
+
+        // Clickable links to the other side of the event
+        if key.entity == EntityType::Event {
+            push_event_counterpart_links(&mut md_text, origin_loc.behavior, &locations);
+        }
 
         // Reference count breakdown
         push_reference_summary(&mut md_text, key.entity, &locations);
 
         // Sample references
-        push_sample_references(&mut md_text, key.entity, &locations);
+        push_sample_references(&mut md_text, key.entity, &locations, max_references);
+
+        // Release the index read lock before computing diagnostics
+        drop(locations);
+        drop(view);
 
         // Add warnings/tips
         push_diagnostic_tips(&mut md_text, key.entity, &key.name, project_index);
@@ -60,17 +90,69 @@ pub fn handle_hover(params: HoverParams, project_index: &ProjectIndex) -> Option
     None
 }
 
-fn file_icon(path: &std::path::Path) -> &'static str {
-    if path.extension().is_some_and(|e| e == "rs") {
-        "🦀"
-    } else {
-        "⚡️"
+/// Icon and framework name of a referencing file.
+///
+/// Angular files are recognized by the Angular CLI naming convention (`*.component.ts`,
+/// `*.service.ts`, ...), since hover only sees indexed locations, not file contents.
+fn file_icon_and_name(path: &std::path::Path) -> (&'static str, &'static str) {
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    let is_angular = [".component.ts", ".service.ts", ".directive.ts", ".pipe.ts"]
+        .iter()
+        .any(|suffix| file_name.ends_with(suffix));
+
+    match path.extension().and_then(|e| e.to_str()).unwrap_or("") {
+        "rs" => ("🦀", "Rust"),
+        "vue" => ("💚", "Vue"),
+        "svelte" => ("🧡", "Svelte"),
+        "ts" if is_angular => ("🅰️", "Angular"),
+        "tsx" | "jsx" => ("⚛️", "React"),
+        "ts" => ("⚡️", "TypeScript"),
+        "js" => ("⚡️", "JavaScript"),
+        _ => ("⚡️", "Frontend"),
+    }
+}
+
+/// The arguments object `invoke` takes for a command: `{ userId: number, note?: string | null }`
+fn arguments_object(schema: &CommandSchema) -> String {
+    let fields: Vec<String> = schema
+        .params
+        .iter()
+        .map(|param| {
+            let optional = if param.optional { "?" } else { "" };
+            format!(
+                "{}{optional}: {}",
+                schema.frontend_param_name(param),
+                param.ts_type
+            )
+        })
+        .collect();
+    format!("{{ {} }}", fields.join(", "))
+}
+
+/// Hover line naming the feature of a `feature = "..."` gate, or the raw `cfg(...)` predicate
+fn cfg_label(cfg: &str) -> String {
+    let feature = cfg
+        .strip_prefix("feature")
+        .map(str::trim_start)
+        .and_then(|rest| rest.strip_prefix('='))
+        .map(|rest| rest.trim().trim_matches('"'));
+
+    match feature {
+        Some(name) if !name.contains('"') => format!("**Available under feature:** `{name}`"),
+        _ => format!("**Available under:** `cfg({cfg})`"),
     }
 }
 
-fn push_definitions_section(md_text: &mut String, entity: EntityType, locations: &[LocationInfo]) {
+/// Lists at most `max` definitions (listeners for events), then a count of the rest
+fn push_definitions_section(
+    md_text: &mut String,
+    entity: EntityType,
+    locations: &[&LocationInfo],
+    max: usize,
+) {
     let definitions: Vec<&LocationInfo> = locations
         .iter()
+        .copied()
         .filter(|l| match entity {
             EntityType::Command => l.behavior == Behavior::Definition,
             EntityType::Event => l.behavior == Behavior::Listen,
@@ -80,23 +162,75 @@ fn push_definitions_section(md_text: &mut String, entity: EntityType, locations:
     if !definitions.is_empty() {
         md_text.push_str("**Definition:**\n");
 
-        for def in &definitions {
+        for def in definitions.iter().take(max) {
             let filename = def.path.file_name().and_then(|n| n.to_str()).unwrap_or("?");
 
             let _ = writeln!(
                 md_text,
                 "- {} `{}:{}`",
-                file_icon(&def.path),
+                file_icon_and_name(&def.path).0,
                 filename,
                 def.range.start.line + 1
             );
         }
+        push_more_line(md_text, definitions.len(), max);
 
         md_text.push('\n');
     }
 }
 
-fn push_reference_summary(md_text: &mut String, entity: EntityType, locations: &[LocationInfo]) {
+/// Link the emitters when hovering a listener, and the listeners when hovering an emit.
+///
+/// Links are `file://` URIs with an `#L<line>` fragment, which the editor opens directly
+/// from hover markdown; without a counterpart a plain-text note is shown instead.
+fn push_event_counterpart_links(
+    md_text: &mut String,
+    origin: Behavior,
+    locations: &[&LocationInfo],
+) {
+    let (label, target) = if origin == Behavior::Emit {
+        ("Go to listener", Behavior::Listen)
+    } else {
+        ("Go to emitter", Behavior::Emit)
+    };
+
+    let targets: Vec<&LocationInfo> = locations
+        .iter()
+        .copied()
+        .filter(|l| l.behavior == target)
+        .collect();
+
+    if targets.is_empty() {
+        let _ = writeln!(md_text, "**{label}:** _none found_\n");
+        return;
+    }
+
+    let links: Vec<String> = targets
+        .iter()
+        .take(MAX_COUNTERPART_LINKS)
+        .map(|loc| location_link(loc))
+        .collect();
+    let more = targets.len().saturating_sub(MAX_COUNTERPART_LINKS);
+
+    let _ = write!(md_text, "**{label}:** {}", links.join(", "));
+    if more > 0 {
+        let _ = write!(md_text, " *(+{more} more)*");
+    }
+    md_text.push_str("\n\n");
+}
+
+/// Markdown link `[file.rs:12](file:///…/file.rs#L12)`, or plain code if no URI can be built
+fn location_link(loc: &LocationInfo) -> String {
+    let filename = loc.path.file_name().and_then(|n| n.to_str()).unwrap_or("?");
+    let line = loc.range.start.line + 1;
+
+    match Uri::from_file_path(&loc.path) {
+        Some(uri) => format!("[{filename}:{line}]({}#L{line})", uri.as_str()),
+        None => format!("`{filename}:{line}`"),
+    }
+}
+
+fn push_reference_summary(md_text: &mut String, entity: EntityType, locations: &[&LocationInfo]) {
     let total_refs = locations.len();
     let _ = writeln!(md_text, "**References ({total_refs} total)**");
 
@@ -113,9 +247,8 @@ fn push_reference_summary(md_text: &mut String, entity: EntityType, locations: &
         if definitions_count > 0 {
             let _ = writeln!(md_text, "- 🦀 {definitions_count} definition(s)");
         }
-        if calls_count > 0 {
-            let _ = writeln!(md_text, "- ⚡ {calls_count} call(s)");
-        }
+        // Listed even when zero, so an unused command reads as such
+        let _ = writeln!(md_text, "- ⚡ {calls_count} call(s)");
     } else {
         let emits_count = locations
             .iter()
@@ -137,9 +270,16 @@ fn push_reference_summary(md_text: &mut String, entity: EntityType, locations: &
     md_text.push('\n');
 }
 
-fn push_sample_references(md_text: &mut String, entity: EntityType, locations: &[LocationInfo]) {
+/// Lists at most `max` calls/emits, then a count of the rest
+fn push_sample_references(
+    md_text: &mut String,
+    entity: EntityType,
+    locations: &[&LocationInfo],
+    max: usize,
+) {
     let references: Vec<&LocationInfo> = locations
         .iter()
+        .copied()
         .filter(|l| match entity {
             EntityType::Command => l.behavior != Behavior::Definition,
             EntityType::Event => l.behavior != Behavior::Listen,
@@ -148,12 +288,7 @@ fn push_sample_references(md_text: &mut String, entity: EntityType, locations: &
 
     if !references.is_empty() {
         md_text.push_str("**Sample References:**\n");
-        for (i, rf) in references.iter().enumerate() {
-            if i >= 5 {
-                let _ = writeln!(md_text, "- *...and {} more*", references.len() - 5);
-                break;
-            }
-
+        for rf in references.iter().take(max) {
             let filename = rf.path.file_name().and_then(|n| n.to_str()).unwrap_or("?");
             let mut behavior_badge = format!("{:?}", rf.behavior).to_uppercase();
 
@@ -161,20 +296,26 @@ fn push_sample_references(md_text: &mut String, entity: EntityType, locations: &
                 let _ = write!(behavior_badge, " ({count} ARGS)");
             }
 
+            let (icon, framework) = file_icon_and_name(&rf.path);
             let _ = writeln!(
                 md_text,
-                "- {} `[{}] {}:{}`",
-                file_icon(&rf.path),
-                behavior_badge,
-                filename,
+                "- {icon} `[{behavior_badge}] {filename}:{}` {framework}",
                 rf.range.start.line + 1
             );
         }
+        push_more_line(md_text, references.len(), max);
 
         md_text.push('\n');
     }
 }
 
+/// `- *...and N more*` when `total` entries exceed the `shown` cap
+fn push_more_line(md_text: &mut String, total: usize, shown: usize) {
+    if total > shown {
+        let _ = writeln!(md_text, "- *...and {} more*", total - shown);
+    }
+}
+
 fn push_diagnostic_tips(
     md_text: &mut String,
     entity: EntityType,
@@ -191,7 +332,10 @@ fn push_diagnostic_tips(
         md_text.push_str("⚠️ *No backend implementation found*\n");
     } else if entity == EntityType::Command && !info.has_calls() {
         md_text.push_str("💡 *Defined but never called in frontend*\n");
-    } else if entity == EntityType::Event && !info.has_emitters() {
+    } else if entity == EntityType::Event
+        && !info.has_emitters()
+        && !project_index.is_framework_event(name)
+    {
         md_text.push_str("💡 *Event listened for but never emitted*\n");
     } else if entity == EntityType::Event && !info.has_listeners() {
         md_text.push_str("💡 *Event emitted but no listeners found*\n");
@@ -0,0 +1,73 @@
+//! Inlay Hints capability - shows event payload types at frontend emit sites
+
+use crate::indexer::ProjectIndex;
+use crate::syntax::{Behavior, EntityType};
+use crate::utils::uri_to_path;
+use std::path::PathBuf;
+use tower_lsp_server::lsp_types::{
+    InlayHint, InlayHintKind, InlayHintLabel, InlayHintParams, Position, Range,
+};
+
+/// Handle inlay hint request (pure function)
+///
+/// Each `emit("event", payload)` in the requested range gets a `: Payload` hint after the
+/// event name when the event's payload type is known. Emits that already carry a type
+/// argument or go through a typed codegen API are skipped.
+pub fn handle_inlay_hint(
+    params: &InlayHintParams,
+    project_index: &ProjectIndex,
+) -> Option<Vec<InlayHint>> {
+    let path: PathBuf = project_index.index_path(&uri_to_path(&params.text_document.uri));
+    if path.extension().is_some_and(|ext| ext == "rs") {
+        return None;
+    }
+
+    let mut keys = project_index.get_file_keys(&path);
+    keys.retain(|key| key.entity == EntityType::Event);
+    keys.sort();
+    keys.dedup();
+
+    let mut hints = Vec::new();
+    for key in keys {
+        let Some(payload_type) = project_index.event_payload_type(&key.name) else {
+            continue;
+        };
+
+        let locations = project_index.get_locations_iter(key.entity, &key.name);
+        for loc in locations.iter().filter(|loc| {
+            loc.path == path
+                && loc.behavior == Behavior::Emit
+                && loc.return_type.is_none()
+                && loc.codegen_origin.is_none()
+                && in_range(loc.range, params.range)
+        }) {
+            // The event name range excludes its quotes; the hint goes past the closing one
+            let position = Position {
+                line: loc.range.end.line,
+                character: loc.range.end.character + 1,
+            };
+            hints.push(InlayHint {
+                position,
+                label: InlayHintLabel::String(format!(": {payload_type}")),
+                kind: Some(InlayHintKind::TYPE),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: Some(true),
+                data: None,
+            });
+        }
+    }
+
+    if hints.is_empty() {
+        return None;
+    }
+
+    hints.sort_by_key(|hint| (hint.position.line, hint.position.character));
+    Some(hints)
+}
+
+/// Whether `inner` overlaps the requested `range`
+fn in_range(inner: Range, range: Range) -> bool {
+    inner.start <= range.end && inner.end >= range.start
+}
@@ -3,27 +3,33 @@
 //! Handles Shift+F12 to find all references
 
 use crate::indexer::ProjectIndex;
+use crate::syntax::Behavior;
+use crate::utils::uri_to_path;
 use std::path::PathBuf;
 use tower_lsp_server::lsp_types::{Location, ReferenceParams, Uri};
 use tower_lsp_server::UriExt;
 
 /// Handle find references request (pure function)
+///
+/// Rust definitions are only included when the client asks for the declaration
+/// (`context.includeDeclaration`).
 pub fn handle_references(
     params: ReferenceParams,
     project_index: &ProjectIndex,
 ) -> Option<Vec<Location>> {
     let uri = params.text_document_position.text_document.uri;
     let position = params.text_document_position.position;
+    let include_declaration = params.context.include_declaration;
 
-    let path_cow = uri.to_file_path()?;
-    let path: PathBuf = path_cow.to_path_buf();
+    let path: PathBuf = uri_to_path(&uri);
 
     // Find the key under the cursor
     if let Some((key, _)) = project_index.get_key_at_position(&path, position) {
-        let refs = project_index.get_locations(key.entity, &key.name);
+        let refs = project_index.get_locations_iter(key.entity, &key.name);
 
         let locations: Vec<Location> = refs
             .iter()
+            .filter(|r| include_declaration || r.behavior != Behavior::Definition)
             .filter_map(|r| {
                 let uri = Uri::from_file_path(&r.path)?;
                 Some(Location {
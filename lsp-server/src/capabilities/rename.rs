@@ -0,0 +1,67 @@
+//! Rename capability - renames an event across its emit and listen sites
+
+use crate::capabilities::code_actions::event_rename_edit;
+use crate::indexer::{IndexKey, LocationInfo, ProjectIndex};
+use crate::syntax::{Behavior, EntityType};
+use crate::utils::uri_to_path;
+use tower_lsp_server::lsp_types::{
+    Position, PrepareRenameResponse, RenameParams, TextDocumentPositionParams, Uri, WorkspaceEdit,
+};
+
+/// Handle prepare rename request (pure function)
+///
+/// Only events written as a string literal at the cursor can be renamed; the response is
+/// the literal's content range with the current name as placeholder.
+pub fn handle_prepare_rename(
+    params: &TextDocumentPositionParams,
+    project_index: &ProjectIndex,
+) -> Option<PrepareRenameResponse> {
+    let (key, loc) = renamable_event_at(&params.text_document.uri, params.position, project_index)?;
+
+    Some(PrepareRenameResponse::RangeWithPlaceholder {
+        range: loc.range,
+        placeholder: key.name,
+    })
+}
+
+/// Handle rename request (pure function)
+///
+/// Rewrites every emit/listen string literal of the event, in Rust and frontend files alike.
+/// Event names are matched exactly, so `file-saved` and `file_saved` are separate events
+/// and renaming one leaves the other untouched.
+pub fn handle_rename(params: &RenameParams, project_index: &ProjectIndex) -> Option<WorkspaceEdit> {
+    let position = &params.text_document_position;
+    let (key, _) = renamable_event_at(
+        &position.text_document.uri,
+        position.position,
+        project_index,
+    )?;
+
+    if !is_valid_event_name(&params.new_name) || params.new_name == key.name {
+        return None;
+    }
+
+    event_rename_edit(&key.name, &params.new_name, project_index)
+}
+
+fn renamable_event_at(
+    uri: &Uri,
+    position: Position,
+    project_index: &ProjectIndex,
+) -> Option<(IndexKey, LocationInfo)> {
+    let path = uri_to_path(uri);
+    let (key, loc) = project_index.get_key_at_position(&path, position)?;
+
+    let renamable = key.entity == EntityType::Event
+        && matches!(loc.behavior, Behavior::Emit | Behavior::Listen)
+        && loc.codegen_origin.is_none();
+    renamable.then_some((key, loc))
+}
+
+/// Whether `name` can be written into any string literal as-is
+fn is_valid_event_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name
+            .chars()
+            .any(|ch| matches!(ch, '"' | '\'' | '`' | '\\') || ch.is_control())
+}
@@ -1,21 +1,31 @@
 //! Document and Workspace Symbol capabilities
 
 use crate::indexer::ProjectIndex;
+use crate::utils::uri_to_path;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
 use tower_lsp_server::lsp_types::{
     DocumentSymbolParams, DocumentSymbolResponse, OneOf, SymbolInformation, WorkspaceSymbolParams,
 };
-use tower_lsp_server::UriExt;
 
 /// Handle document symbol request (pure function)
+///
+/// Returns nested symbols when the client supports them (`hierarchical`),
+/// flat `SymbolInformation` otherwise.
 pub fn handle_document_symbol(
     params: DocumentSymbolParams,
     project_index: &ProjectIndex,
+    hierarchical: bool,
 ) -> Option<DocumentSymbolResponse> {
     let uri = params.text_document.uri;
 
-    let path_cow = uri.to_file_path()?;
-    let path: PathBuf = path_cow.to_path_buf();
+    let path: PathBuf = uri_to_path(&uri);
+
+    if hierarchical {
+        let symbols = project_index.get_document_symbols_hierarchical(&path);
+        return (!symbols.is_empty()).then_some(DocumentSymbolResponse::Nested(symbols));
+    }
+
     let symbols = project_index.get_document_symbols(&path);
 
     if symbols.is_empty() {
@@ -26,11 +36,14 @@ pub fn handle_document_symbol(
 }
 
 /// Handle workspace symbol request (pure function)
+///
+/// Returns `None` early once `cancelled` is set (`$/cancelRequest`).
 pub fn handle_workspace_symbol(
     params: &WorkspaceSymbolParams,
     project_index: &ProjectIndex,
+    cancelled: &AtomicBool,
 ) -> Option<OneOf<Vec<SymbolInformation>, Vec<tower_lsp_server::lsp_types::WorkspaceSymbol>>> {
-    let symbols = project_index.search_workspace_symbols(&params.query);
+    let symbols = project_index.search_workspace_symbols(&params.query, cancelled)?;
 
     if symbols.is_empty() {
         return None;
@@ -0,0 +1,163 @@
+//! Type sync check
+//!
+//! Compares the Rust structs used by commands (as parameters or return values)
+//! against the TypeScript types emitted by the bindings generator and reports
+//! every type whose fields have drifted apart.
+
+use crate::bindings_reader::parse_object_type_fields;
+use crate::capabilities::diagnostics::diff_fields;
+use crate::indexer::{ProjectIndex, StructSchema};
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+/// Sync status of a single Rust struct against its generated TypeScript type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TypeSyncStatus {
+    #[serde(rename = "in sync")]
+    InSync,
+    #[serde(rename = "interface absent")]
+    InterfaceAbsent,
+    #[serde(rename = "out of sync")]
+    OutOfSync,
+}
+
+/// Result of checking one Rust struct
+#[derive(Debug, Clone, Serialize)]
+pub struct TypeSyncEntry {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub status: TypeSyncStatus,
+    pub differences: Vec<String>,
+}
+
+/// Summary returned by the `tarus.checkTypeSync` command
+#[derive(Debug, Clone, Serialize)]
+pub struct TypeSyncReport {
+    pub checked: usize,
+    #[serde(rename = "outOfSync")]
+    pub out_of_sync: Vec<TypeSyncEntry>,
+}
+
+/// Check every struct reachable from command parameters and return types.
+///
+/// Structs referenced by fields of other checked structs are included as well,
+/// so nested payloads are compared too.
+#[must_use]
+pub fn check_type_sync(project_index: &ProjectIndex) -> TypeSyncReport {
+    let mut pending: Vec<String> = project_index
+        .command_schemas
+        .iter()
+        .flat_map(|entry| {
+            let schema = entry.value();
+            schema
+                .params
+                .iter()
+                .flat_map(|p| type_identifiers(&p.ts_type))
+                .chain(type_identifiers(&schema.return_type))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let mut visited = BTreeSet::new();
+
+    while let Some(name) = pending.pop() {
+        if visited.contains(&name) {
+            continue;
+        }
+        let Some(schema) = project_index.get_struct_schema(&name) else {
+            continue;
+        };
+        pending.extend(
+            schema
+                .fields
+                .iter()
+                .flat_map(|f| type_identifiers(&f.ts_type)),
+        );
+        visited.insert(name);
+    }
+
+    // Structs of the same name in different files are each compared with the one TS type
+    let entries: Vec<TypeSyncEntry> = visited
+        .iter()
+        .flat_map(|name| project_index.get_struct_schemas(name))
+        .map(|schema| check_struct(&schema, project_index))
+        .collect();
+
+    TypeSyncReport {
+        checked: entries.len(),
+        out_of_sync: entries
+            .into_iter()
+            .filter(|e| e.status != TypeSyncStatus::InSync)
+            .collect(),
+    }
+}
+
+/// Compare a single Rust struct with the generated TypeScript type of the same name.
+fn check_struct(schema: &StructSchema, project_index: &ProjectIndex) -> TypeSyncEntry {
+    let entry = |status, differences| TypeSyncEntry {
+        type_name: schema.struct_name.clone(),
+        status,
+        differences,
+    };
+
+    let Some(definition) = project_index
+        .type_aliases
+        .get(&schema.struct_name)
+        .map(|v| v.value().clone())
+    else {
+        return entry(TypeSyncStatus::InterfaceAbsent, Vec::new());
+    };
+
+    let Some(ts_fields) = parse_object_type_fields(&definition) else {
+        return entry(
+            TypeSyncStatus::OutOfSync,
+            vec![format!("TypeScript type is not an object: {definition}")],
+        );
+    };
+
+    let (fields, unresolved) = project_index.serialized_struct_fields(schema);
+    let expected: Vec<(&str, Option<&str>)> = fields
+        .iter()
+        .map(|f| (f.name.as_str(), Some(f.ts_type.as_str())))
+        .collect();
+    let actual: Vec<(&str, Option<&str>)> = ts_fields
+        .iter()
+        .map(|(name, ts_type)| (name.as_str(), Some(ts_type.as_str())))
+        .collect();
+
+    let mut diff = diff_fields(&expected, &actual, project_index);
+    // TypeScript fields may come from a flattened struct that is not indexed
+    if !unresolved.is_empty() {
+        diff.extra.clear();
+    }
+    if diff.is_empty() {
+        return entry(TypeSyncStatus::InSync, Vec::new());
+    }
+
+    let differences = diff
+        .missing
+        .iter()
+        .map(|name| format!("field '{name}' is missing in TypeScript"))
+        .chain(
+            diff.extra
+                .iter()
+                .map(|name| format!("field '{name}' does not exist in Rust")),
+        )
+        .chain(diff.mismatched.iter().map(|(name, expected, actual)| {
+            format!("field '{name}': Rust has '{expected}', TypeScript has '{actual}'")
+        }))
+        .collect();
+
+    entry(TypeSyncStatus::OutOfSync, differences)
+}
+
+/// Split a TypeScript type string into the identifiers it mentions.
+///
+/// `Array<User> | null` → `["Array", "User", "null"]`
+fn type_identifiers(ts_type: &str) -> Vec<String> {
+    ts_type
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
@@ -43,6 +43,117 @@ pub fn discover_generators(workspace_root: &Path) -> Vec<DiscoveredGenerator> {
     results
 }
 
+/// Discover the `src` directories of every member crate of the Cargo workspace that
+/// contains the Tauri app.
+///
+/// The workspace manifest is the nearest `Cargo.toml` with a `[workspace]` table, starting
+/// at the `src-tauri` directory and walking up (the same lookup Cargo uses), so it may sit
+/// outside the editor's workspace root. Members support exact paths and trailing `/*` globs;
+/// `exclude` entries are honored.
+#[must_use]
+pub fn discover_workspace_member_src_dirs(workspace_root: &Path) -> Vec<PathBuf> {
+    let Some(src_tauri_dir) = crate::scanner::find_src_tauri_dir(workspace_root) else {
+        return Vec::new();
+    };
+
+    let Some((manifest_dir, workspace)) = src_tauri_dir.ancestors().find_map(|dir| {
+        let content = std::fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+        let manifest: toml::Value = content.parse().ok()?;
+        let workspace = manifest.get("workspace")?.as_table()?.clone();
+        Some((dir.to_path_buf(), workspace))
+    }) else {
+        return Vec::new();
+    };
+
+    let string_list = |key: &str| -> Vec<String> {
+        workspace
+            .get(key)
+            .and_then(toml::Value::as_array)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(toml::Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let excluded: Vec<PathBuf> = string_list("exclude")
+        .iter()
+        .map(|e| normalize_path(&manifest_dir.join(e)))
+        .collect();
+
+    let mut member_dirs = Vec::new();
+    for member in string_list("members") {
+        if let Some(parent) = member.strip_suffix("/*") {
+            let Ok(entries) = std::fs::read_dir(manifest_dir.join(parent)) else {
+                continue;
+            };
+            member_dirs.extend(entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()));
+        } else {
+            member_dirs.push(manifest_dir.join(member));
+        }
+    }
+
+    let mut src_dirs: Vec<PathBuf> = member_dirs
+        .into_iter()
+        .map(|dir| normalize_path(&dir))
+        .filter(|dir| !excluded.contains(dir) && dir.join("Cargo.toml").is_file())
+        .map(|dir| dir.join("src"))
+        .filter(|src| src.is_dir())
+        .collect();
+    src_dirs.sort();
+    src_dirs.dedup();
+    src_dirs
+}
+
+/// How many `..` levels a path dependency may climb above `src-tauri`
+const MAX_PATH_DEPENDENCY_LEVELS: usize = 3;
+
+/// Discover the `src` directories of the crates the Tauri app depends on by path
+/// (`core = { path = "../core" }` in `src-tauri/Cargo.toml`).
+///
+/// Only relative paths climbing at most [`MAX_PATH_DEPENDENCY_LEVELS`] directories are
+/// followed, so a dependency on some unrelated checkout elsewhere on disk is not scanned.
+/// Both `[dependencies]` and `[build-dependencies]` are read.
+#[must_use]
+pub fn discover_path_dependency_src_dirs(workspace_root: &Path) -> Vec<PathBuf> {
+    let Some(src_tauri_dir) = crate::scanner::find_src_tauri_dir(workspace_root) else {
+        return Vec::new();
+    };
+
+    let Some(manifest) = std::fs::read_to_string(src_tauri_dir.join("Cargo.toml"))
+        .ok()
+        .and_then(|content| content.parse::<toml::Value>().ok())
+    else {
+        return Vec::new();
+    };
+
+    let mut src_dirs: Vec<PathBuf> = ["dependencies", "build-dependencies"]
+        .iter()
+        .filter_map(|section| manifest.get(section)?.as_table())
+        .flat_map(|deps| deps.values())
+        .filter_map(|dep| dep.get("path")?.as_str())
+        .map(Path::new)
+        .filter(|path| {
+            path.is_relative()
+                && path
+                    .components()
+                    .filter(|c| matches!(c, Component::ParentDir))
+                    .count()
+                    <= MAX_PATH_DEPENDENCY_LEVELS
+        })
+        .map(|path| normalize_path(&src_tauri_dir.join(path)))
+        .filter(|dir| dir.join("Cargo.toml").is_file())
+        .map(|dir| dir.join("src"))
+        .filter(|src| src.is_dir())
+        .collect();
+    src_dirs.sort();
+    src_dirs.dedup();
+    src_dirs
+}
+
 // ────────────────────────────────────────────────────────────────────────────
 // Individual generator discovery
 // ────────────────────────────────────────────────────────────────────────────
@@ -1,18 +1,65 @@
 //! Shared constants used across the LSP server.
 
 /// File extensions the server can parse and index.
-pub const SUPPORTED_EXTENSIONS: &[&str] = &["rs", "ts", "tsx", "js", "jsx", "vue", "svelte"];
+pub const SUPPORTED_EXTENSIONS: &[&str] =
+    &["rs", "ts", "tsx", "js", "jsx", "vue", "svelte", "astro"];
+
+/// File extensions without a tree-sitter grammar, indexed by plain-text scanning when
+/// `tarus.experimentalTextScan` is enabled.
+pub const TEXT_SCAN_EXTENSIONS: &[&str] = &["html", "mdx"];
 
 // ---------------------------------------------------------------------------
 // Diagnostic codes — used in `diagnostics.rs` for publishing and in
 // `code_actions.rs` for matching.
 // ---------------------------------------------------------------------------
 
+pub const DIAG_COMMAND_UNDEFINED: &str = "tarus/command-undefined";
+pub const DIAG_COMMAND_UNUSED: &str = "tarus/command-unused";
+pub const DIAG_EVENT_UNUSED: &str = "tarus/event-unused";
+pub const DIAG_EVENT_NOT_EMITTED: &str = "tarus/event-not-emitted";
+pub const DIAG_EVENT_NO_LISTENERS: &str = "tarus/event-no-listeners";
+pub const DIAG_MISSING_ARGUMENT: &str = "tarus/missing-argument";
+pub const DIAG_UNEXPECTED_ARGUMENT: &str = "tarus/unexpected-argument";
+pub const DIAG_ARGS_NOT_VERIFIED: &str = "tarus/args-not-verified";
 pub const DIAG_ARG_COUNT_MISMATCH: &str = "tarus/arg-count-mismatch";
 pub const DIAG_RETURN_TYPE_MISSING: &str = "tarus/return-type-missing";
 pub const DIAG_RETURN_TYPE_MISMATCH: &str = "tarus/return-type-mismatch";
 pub const DIAG_EVENT_PAYLOAD_MISSING: &str = "tarus/event-payload-missing";
 pub const DIAG_EVENT_PAYLOAD_MISMATCH: &str = "tarus/event-payload-mismatch";
+pub const DIAG_EVENT_NAME_TYPO: &str = "tarus/event-name-typo";
+pub const DIAG_EVENT_NAMING_CONVENTION: &str = "tarus/event-naming-convention";
+pub const DIAG_UNKNOWN_INVOKE_TYPE: &str = "tarus/unknown-invoke-type";
+pub const DIAG_PARAM_NAME_COLLISION: &str = "tarus/param-name-collision";
+pub const DIAG_COMMAND_NOT_REGISTERED: &str = "tarus/command-not-registered";
+pub const DIAG_COMMAND_NOT_PERMITTED: &str = "tarus/command-not-permitted";
+pub const DIAG_SYNTAX_ERROR: &str = "tarus/syntax-error";
+pub const DIAG_INVOKE_NOT_AWAITED: &str = "tarus/invoke-not-awaited";
+pub const DIAG_DYNAMIC_NAME: &str = "tarus/dynamic-name";
+
+/// Server notification with diagnostics totals (`tarus.diagnostics.statusNotification`)
+pub const STATUS_NOTIFICATION: &str = "tarus/status";
+
+/// Documentation of the diagnostic rules; each code's rule name is a heading anchor in it
+pub const DIAGNOSTICS_DOCS_URL: &str = "https://github.com/mvoof/tarus/blob/main/DIAGNOSTICS.md";
+
+// ---------------------------------------------------------------------------
+// Server-side commands handled via `workspace/executeCommand`
+// ---------------------------------------------------------------------------
+
+/// Compare Rust command structs against generated TypeScript types
+pub const CMD_CHECK_TYPE_SYNC: &str = "tarus.checkTypeSync";
+/// Re-read settings and generator configs, then rebuild the index
+pub const CMD_RELOAD_CONFIG: &str = "tarus.reloadConfig";
+/// Render the indexed commands and events as a Markdown API report
+pub const CMD_GENERATE_API_DOC: &str = "tarus.generateApiDoc";
+/// Return the Rust source a "Create Rust command" action would insert, without applying it
+pub const CMD_PREVIEW_GENERATED_TYPE: &str = "tarus.previewGeneratedType";
+/// Return the definition `Location[]` of a command given by name
+pub const CMD_GOTO_COMMAND: &str = "tarus.gotoCommand";
+/// List the listeners of events that are never emitted (excluding framework events)
+pub const CMD_ORPHANED_LISTENERS: &str = "tarus.orphanedListeners";
+/// Return the diagnostics of every indexed file as a flat, problem-matcher friendly array
+pub const CMD_DIAGNOSTICS_JSON: &str = "tarus.diagnosticsJson";
 
 // ---------------------------------------------------------------------------
 // File priority scores for code-action candidate ranking.
@@ -33,6 +80,45 @@ pub const PRIORITY_COMMAND_FILE: u8 = 70;
 pub const PRIORITY_MOD_RS: u8 = 65;
 /// Any other Rust file
 pub const PRIORITY_DEFAULT: u8 = 50;
+/// Added to files inside `tarus.commandDir`, ranking them above all others
+pub const PRIORITY_COMMAND_DIR_BONUS: u8 = 100;
+
+// Frontend files for a generated invoke call ("Generate frontend invoke call").
+
+/// Well-known API module (`api.ts`, `commands.ts`, ...) that already imports `invoke`
+pub const PRIORITY_FRONTEND_API_FILE: u8 = 90;
+/// File that already imports `invoke`
+pub const PRIORITY_FRONTEND_INVOKES: u8 = 80;
+/// Well-known API module name without an `invoke` import yet
+pub const PRIORITY_FRONTEND_COMMAND_FILE: u8 = 70;
+
+// ---------------------------------------------------------------------------
+// Configuration
+// ---------------------------------------------------------------------------
+
+/// Settings requested from the client, in the order `load_config` reads them
+pub const CONFIG_SECTIONS: &[&str] = &[
+    "tarus.developerMode",
+    "tarus.referenceLimit",
+    "tarus.diagnostics.scope",
+    "tarus.events.namingConvention",
+    "tarus.codeActions.maxCandidates",
+    "tarus.frontend.patterns",
+    "tarus.experimentalTextScan",
+    "tarus.codeLens.enabled",
+    "tarus.codeLens.mode",
+    "tarus.diagnostics.propagate",
+    "tarus.events.frameworkEvents",
+    "tarus.commandPrefix",
+    "tarus.hover.maxReferences",
+    "tarus.indexing.mode",
+    "tarus.diagnostics.requireAwait",
+    "tarus.scan.extraPaths",
+    "tarus.commandDir",
+    "tarus.scan.maxFiles",
+    "tarus.diagnostics.statusNotification",
+    "tarus.completion.excludePluginCommands",
+];
 
 // ---------------------------------------------------------------------------
 // Timing & limits
@@ -60,5 +146,24 @@ pub const TYPEGEN_LISTEN_FN: &str = "listen";
 /// Debounce delay (ms) before re-processing a file after edits.
 pub const DEBOUNCE_MS: u64 = 300;
 
+/// Quiet period (ms) without new requests after which scheduled diagnostics are published.
+pub const DIAGNOSTICS_QUIET_MS: u64 = 30;
+
+/// Longest delay (ms) a scheduled diagnostics publish waits under a constant stream of
+/// requests (bulk indexing, many watched-file events).
+pub const DIAGNOSTICS_MAX_DELAY_MS: u64 = 500;
+
 /// Default maximum number of references shown for a command/event.
 pub const DEFAULT_REFERENCE_LIMIT: usize = 3;
+
+/// Default maximum number of definitions and sample references listed in a hover.
+pub const DEFAULT_HOVER_MAX_REFERENCES: usize = 5;
+
+/// Default maximum number of target files offered by "Create Rust command" actions.
+pub const DEFAULT_CODE_ACTION_CANDIDATES: usize = 3;
+
+/// Default maximum number of files collected by the workspace scan (`tarus.scan.maxFiles`).
+pub const DEFAULT_SCAN_MAX_FILES: usize = 50_000;
+
+/// Time budget (ms) of the workspace scan; it stops and indexes what it found when spent.
+pub const SCAN_TIME_BUDGET_MS: u64 = 30_000;
@@ -0,0 +1,63 @@
+//! Crate-level error type
+//!
+//! Failures that can reach library callers: unreadable files, malformed configuration and
+//! tree-sitter parse failures. Index state lives behind `parking_lot` locks and `DashMap`,
+//! neither of which can be poisoned, so locking never fails.
+
+use crate::syntax::ParseError;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Errors returned by the indexing and configuration APIs
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// A file could not be read from disk
+    Io {
+        path: PathBuf,
+        source: Arc<std::io::Error>,
+    },
+    /// A configuration file (e.g. `tsconfig.json`) is malformed
+    Config { path: PathBuf, message: String },
+    /// A source file could not be parsed
+    Parse { path: PathBuf, source: ParseError },
+}
+
+impl Error {
+    /// Wrap an I/O error for `path`
+    #[must_use]
+    pub fn io(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        Error::Io {
+            path: path.into(),
+            source: Arc::new(source),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, source } => {
+                write!(f, "Failed to read {}: {source}", path.display())
+            }
+            Error::Config { path, message } => {
+                write!(f, "Invalid config {}: {message}", path.display())
+            }
+            Error::Parse { path, source } => {
+                write!(f, "Failed to parse {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source.as_ref()),
+            Error::Config { .. } => None,
+            Error::Parse { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Result type for the crate's fallible APIs
+pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -1,9 +1,24 @@
 //! File processing utilities
 
 use crate::bindings_reader;
-use crate::indexer::{GeneratorKind, ProjectIndex};
+use crate::error::{Error, Result};
+use crate::indexer::{
+    FileChange, FileIndex, Finding, GeneratorKind, IndexKey, IndexingMode, ProjectIndex,
+    StructSchema,
+};
+use crate::permissions;
+use crate::position::{byte_offset_to_point, lsp_character_to_byte_index, position_to_byte_offset};
+use crate::syntax::{Behavior, EntityType};
 use crate::tree_parser;
+use crate::ts_tree_utils::first_error_item_start;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use tower_lsp_server::lsp_types::{
+    FileChangeType, Position, Range, TextDocumentContentChangeEvent,
+};
+use tree_sitter::{InputEdit, Tree};
 
 /// Check if file extension is supported
 #[must_use]
@@ -13,67 +28,572 @@ pub fn is_supported_file(path: &Path) -> bool {
         .is_some_and(|ext| crate::constants::SUPPORTED_EXTENSIONS.contains(&ext))
 }
 
+/// Check if file has no tree-sitter grammar but can be indexed by plain-text scanning
+#[must_use]
+pub fn is_text_scan_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| crate::constants::TEXT_SCAN_EXTENSIONS.contains(&ext))
+}
+
+/// Check if file is indexed with the current settings
+fn is_indexed_file(path: &Path, project_index: &ProjectIndex) -> bool {
+    is_supported_file(path)
+        || permissions::is_capability_file(path)
+        || (project_index.experimental_text_scan() && is_text_scan_file(path))
+}
+
+/// Whether indexing `path` from disk waits until it is opened: in lazy mode, for frontend
+/// files not indexed yet. Rust sources, generated bindings and capability files hold the
+/// definitions, schemas and permissions other files resolve against, so they are always
+/// indexed.
+#[must_use]
+pub fn is_deferred_file(path: &Path, project_index: &ProjectIndex) -> bool {
+    project_index.indexing_mode() == IndexingMode::Lazy
+        && !path.extension().is_some_and(|ext| ext == "rs")
+        && !permissions::is_capability_file(path)
+        && project_index.get_generator_for_file(path).is_none()
+        && !project_index.is_indexed(path)
+}
+
 /// Process file content from editor buffer.
 ///
-/// Returns `true` if the file was successfully routed and processed.
-///
-/// # Panics
-///
-/// Panics if the `generator_bindings` lock is poisoned (only if another thread panicked
-/// while holding the write lock).
+/// Returns `true` if the file was successfully routed and processed. Parse failures are
+/// recorded on the index (see [`ProjectIndex::get_parse_error`]).
 pub fn process_file_content(path: &Path, content: &str, project_index: &ProjectIndex) -> bool {
-    if !is_supported_file(path) {
-        return false;
+    process_file_content_incremental(path, content, None, project_index).0
+}
+
+/// Process file content from editor buffer, reusing the previous syntax tree.
+///
+/// `old_tree` must have every edit since it was parsed applied (see [`apply_content_change`]).
+/// Returns whether the file was processed, plus the new syntax tree for Rust and
+/// TypeScript/JavaScript files so the caller can cache it for the next edit.
+pub fn process_file_content_incremental(
+    path: &Path,
+    content: &str,
+    old_tree: Option<&Tree>,
+    project_index: &ProjectIndex,
+) -> (bool, Option<Tree>) {
+    if !is_indexed_file(path, project_index) {
+        return (false, None);
+    }
+
+    // Buffer content may differ from disk; the next read of the file must parse it again
+    project_index.forget_content_hash(path);
+
+    if permissions::is_capability_file(path) {
+        return match permissions::parse_capability(path, content) {
+            Ok(command_permissions) => {
+                project_index.add_file(FileIndex {
+                    path: path.to_path_buf(),
+                    command_permissions: Some(command_permissions),
+                    ..FileIndex::default()
+                });
+                (true, None)
+            }
+            Err(e) => {
+                // A half-written capability must not keep granting its old permissions
+                project_index.remove_file(path);
+                project_index.set_parse_error(path, e);
+                (false, None)
+            }
+        };
+    }
+
+    if is_text_scan_file(path) {
+        let findings = tree_parser::scan_text(content, &project_index.frontend_patterns());
+        project_index.add_file(FileIndex {
+            path: path.to_path_buf(),
+            findings,
+            ..FileIndex::default()
+        });
+
+        return (true, None);
     }
 
     // Check if this is a generated bindings file via config-based discovery.
     if let Some(kind) = project_index.get_generator_for_file(path) {
         process_bindings_file(path, content, kind, project_index);
 
-        return true;
+        return (true, None);
     }
 
     if path.extension().is_some_and(|s| s == "rs") {
-        match tree_parser::parse_rust_full(content, path) {
-            Ok(rust_index) => {
-                let path_buf = path.to_path_buf();
-
-                project_index.remove_schemas_for_file(&path_buf);
-                project_index.remove_event_schemas_for_file(&path_buf);
-
-                project_index.add_file(rust_index.file_index);
-
-                for schema in rust_index.command_schemas {
-                    add_command_schema_if_higher_priority(schema, project_index);
+        match tree_parser::parse_rust_full(content, path, old_tree) {
+            Ok(mut rust_index) => {
+                // A transient syntax error can hide items from tree-sitter's error recovery;
+                // from the damaged item on, keep the previous findings (and schemas) until the
+                // file parses without losing any of them
+                if rust_index.has_syntax_errors
+                    && loses_indexed_keys(&rust_index.file_index, project_index)
+                {
+                    let previous = project_index.get_indexed_source(path);
+                    if let Some((previous, error_start)) =
+                        previous.zip(first_error_item_start(&rust_index.tree, content))
+                    {
+                        let lines = LineMap::new(&previous, content);
+                        keep_previous_from(error_start, &lines, &mut rust_index, project_index);
+                    }
                 }
 
-                for schema in rust_index.event_schemas {
-                    add_event_schema_if_higher_priority(schema, project_index);
-                }
+                let (file_index, tree) = replace_rust_schemas(path, rust_index, project_index);
+                project_index.add_file(file_index);
+                project_index.set_indexed_source(path, content);
 
-                true
+                (true, Some(tree))
             }
 
             Err(e) => {
-                project_index.set_parse_error(path.to_path_buf(), format!("{e:?}"));
-                false
+                project_index.set_parse_error(
+                    path,
+                    Error::Parse {
+                        path: path.to_path_buf(),
+                        source: e,
+                    },
+                );
+                (false, None)
             }
         }
     } else {
-        match tree_parser::parse(path, content) {
-            Ok(file_index) => {
+        match tree_parser::parse(path, content, old_tree, &project_index.frontend_patterns()) {
+            Ok((file_index, tree)) => {
                 project_index.add_file(file_index);
 
-                true
+                (true, tree)
             }
 
             Err(e) => {
-                project_index.set_parse_error(path.to_path_buf(), format!("{e:?}"));
+                project_index.set_parse_error(
+                    path,
+                    Error::Parse {
+                        path: path.to_path_buf(),
+                        source: e,
+                    },
+                );
+
+                (false, None)
+            }
+        }
+    }
+}
+
+/// Swap in a parsed Rust file's schemas, returning its file index and syntax tree.
+fn replace_rust_schemas(
+    path: &Path,
+    rust_index: tree_parser::RustFileIndex,
+    project_index: &ProjectIndex,
+) -> (FileIndex, Tree) {
+    remove_rust_schemas(path, project_index);
+
+    for schema in rust_index.command_schemas {
+        add_command_schema_if_higher_priority(schema, project_index);
+    }
+
+    for schema in rust_index.event_schemas {
+        add_event_schema_if_higher_priority(schema, project_index);
+    }
+
+    for schema in rust_index.struct_schemas {
+        project_index.add_struct_schema(schema);
+    }
+
+    for alias in rust_index.type_aliases {
+        project_index.add_rust_type_alias(alias);
+    }
+
+    (rust_index.file_index, rust_index.tree)
+}
+
+/// Drop the command, event and struct schemas and the type aliases extracted from a Rust file.
+fn remove_rust_schemas(path: &Path, project_index: &ProjectIndex) {
+    let path_buf = path.to_path_buf();
+
+    project_index.remove_schemas_for_file(&path_buf);
+    project_index.remove_event_schemas_for_file(&path_buf);
+    project_index.remove_struct_schemas_for_file(&path_buf);
+    project_index.remove_rust_type_aliases_for_file(&path_buf);
+}
+
+/// Apply `workspace/didChangeWatchedFiles` events from disk as one index batch.
+///
+/// Created and changed files are read and parsed unless their content is unchanged,
+/// deleted files are dropped along with their schemas, and the file index is updated once
+/// through [`ProjectIndex::apply_batch`]. Read and parse failures are recorded per file.
+pub fn process_watched_files(events: Vec<(PathBuf, FileChangeType)>, project_index: &ProjectIndex) {
+    let mut batch = Vec::new();
+    let mut batch_errors = Vec::new();
+    let mut hashes = Vec::new();
+
+    for (path, change) in events {
+        if !is_indexed_file(&path, project_index) || is_deferred_file(&path, project_index) {
+            continue;
+        }
+
+        if change == FileChangeType::DELETED {
+            remove_rust_schemas(&path, project_index);
+            project_index.remove_type_aliases_for_file(&path);
+            batch.push(FileChange::Remove(path));
+            continue;
+        }
+
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                // Keep the error: the removal is applied with the batch, after this
+                let error = Error::io(&path, e);
+                batch.push(FileChange::Remove(path.clone()));
+                batch_errors.push((path, error));
+                continue;
+            }
+        };
+
+        // Branch switches touch many files whose content didn't change
+        let hash = content_hash(&content);
+        if project_index.has_content_hash(&path, hash) {
+            continue;
+        }
 
-                false
+        if let Some(kind) = project_index.get_generator_for_file(&path) {
+            process_bindings_file(&path, &content, kind, project_index);
+            project_index.set_content_hash(&path, hash);
+            continue;
+        }
+
+        let parsed = if path.extension().is_some_and(|s| s == "rs") {
+            tree_parser::parse_rust_full(&content, &path, None)
+                .map(|rust_index| replace_rust_schemas(&path, rust_index, project_index).0)
+        } else {
+            tree_parser::parse(&path, &content, None, &project_index.frontend_patterns())
+                .map(|(file_index, _)| file_index)
+        };
+
+        match parsed {
+            Ok(file_index) => {
+                batch.push(FileChange::Upsert(file_index));
+                hashes.push((path, hash));
             }
+            Err(source) => project_index.set_parse_error(
+                &path,
+                Error::Parse {
+                    path: path.clone(),
+                    source,
+                },
+            ),
         }
     }
+
+    project_index.apply_batch(batch);
+    for (path, error) in batch_errors {
+        project_index.set_parse_error(&path, error);
+    }
+    for (path, hash) in hashes {
+        project_index.set_content_hash(&path, hash);
+    }
+}
+
+/// Apply one `didChange` content change to a document's text and its cached syntax tree.
+///
+/// Ranged changes are spliced into `text` and mirrored on `tree` via `Tree::edit`, so the
+/// next parse can be incremental. A change without a range replaces the whole document and
+/// drops the tree, forcing a full parse.
+pub fn apply_content_change(
+    text: &mut String,
+    tree: &mut Option<Tree>,
+    change: &TextDocumentContentChangeEvent,
+) {
+    let Some(range) = change.range else {
+        text.clone_from(&change.text);
+        *tree = None;
+        return;
+    };
+
+    let start_byte = position_to_byte_offset(text, range.start);
+    let old_end_byte = position_to_byte_offset(text, range.end).max(start_byte);
+    let start_position = byte_offset_to_point(text, start_byte);
+    let old_end_position = byte_offset_to_point(text, old_end_byte);
+
+    text.replace_range(start_byte..old_end_byte, &change.text);
+
+    if let Some(tree) = tree {
+        let new_end_byte = start_byte + change.text.len();
+        tree.edit(&InputEdit {
+            start_byte,
+            old_end_byte,
+            new_end_byte,
+            start_position,
+            old_end_position,
+            new_end_position: byte_offset_to_point(text, new_end_byte),
+        });
+    }
+}
+
+/// Check whether replacing a file's entry with `file_index` would drop any indexed key.
+fn loses_indexed_keys(file_index: &FileIndex, project_index: &ProjectIndex) -> bool {
+    let new_keys: HashSet<IndexKey> = file_index
+        .findings
+        .iter()
+        .map(|f| IndexKey {
+            entity: f.entity,
+            name: f.key.clone(),
+        })
+        .collect();
+
+    project_index
+        .get_file_keys(&file_index.path)
+        .iter()
+        .any(|key| !new_keys.contains(key))
+}
+
+/// Replace what `rust_index` found at or after `error_start` with the file's previous
+/// findings and schemas there, moved to the lines the edit left them on.
+///
+/// Previous entries on edited lines are dropped rather than kept at stale coordinates.
+fn keep_previous_from(
+    error_start: Position,
+    lines: &LineMap,
+    rust_index: &mut tree_parser::RustFileIndex,
+    project_index: &ProjectIndex,
+) {
+    let path = rust_index.file_index.path.clone();
+    let findings = &mut rust_index.file_index.findings;
+    findings.retain(|f| f.range.start < error_start);
+    let previous: Vec<Finding> = project_index
+        .get_file_findings(&path)
+        .into_iter()
+        .filter_map(|f| lines.finding(f))
+        .filter(|f| f.range.start >= error_start)
+        .collect();
+
+    // Command and event schemas have no position; they go with their definition or emit
+    let defined = finding_names(findings, EntityType::Command, Behavior::Definition);
+    let emitted = finding_names(findings, EntityType::Event, Behavior::Emit);
+    rust_index
+        .command_schemas
+        .retain(|s| defined.contains(&s.command_name));
+    rust_index
+        .event_schemas
+        .retain(|s| emitted.contains(&s.event_name));
+    rust_index
+        .struct_schemas
+        .retain(|s| s.range.start < error_start);
+    rust_index
+        .type_aliases
+        .retain(|a| a.range.start < error_start);
+
+    let kept_defined = finding_names(&previous, EntityType::Command, Behavior::Definition);
+    let kept_emitted = finding_names(&previous, EntityType::Event, Behavior::Emit);
+    rust_index.command_schemas.extend(
+        project_index
+            .get_schemas_for_file(&path)
+            .into_iter()
+            .filter(|s| {
+                kept_defined.contains(&s.command_name) && !defined.contains(&s.command_name)
+            }),
+    );
+    rust_index.event_schemas.extend(
+        project_index
+            .get_event_schemas_for_file(&path)
+            .into_iter()
+            .filter(|s| kept_emitted.contains(&s.event_name) && !emitted.contains(&s.event_name)),
+    );
+    rust_index.struct_schemas.extend(
+        project_index
+            .get_struct_schemas_for_file(&path)
+            .into_iter()
+            .filter_map(|s| lines.struct_schema(s))
+            .filter(|s| s.range.start >= error_start),
+    );
+    rust_index.type_aliases.extend(
+        project_index
+            .get_rust_type_aliases_for_file(&path)
+            .into_iter()
+            .filter_map(|mut a| {
+                a.range = lines.range(a.range)?;
+                Some(a)
+            })
+            .filter(|a| a.range.start >= error_start),
+    );
+
+    findings.extend(previous);
+}
+
+/// Names of the findings with this entity and behavior.
+fn finding_names(findings: &[Finding], entity: EntityType, behavior: Behavior) -> HashSet<String> {
+    findings
+        .iter()
+        .filter(|f| f.entity == entity && f.behavior == behavior)
+        .map(|f| f.key.clone())
+        .collect()
+}
+
+/// Cap on the lines compared by [`LineMap::new`] (old × new changed lines); past it, only the
+/// unchanged lines around the edit are matched.
+const MAX_LINE_DIFF_CELLS: usize = 1 << 20;
+
+/// Where each line of a file's previous text is in its edited text.
+///
+/// Unchanged lines are matched by a line diff; the lines of an edit are paired in order. A
+/// position moves to its paired line only if the text before it there is the same, and a range
+/// only if it also covers the same text.
+struct LineMap<'a> {
+    old_lines: Vec<&'a str>,
+    new_lines: Vec<&'a str>,
+    /// Paired new line of each old line; `None` for lines the edit removed
+    lines: Vec<Option<usize>>,
+}
+
+impl<'a> LineMap<'a> {
+    fn new(old: &'a str, new: &'a str) -> Self {
+        let old_lines: Vec<&str> = old.split('\n').collect();
+        let new_lines: Vec<&str> = new.split('\n').collect();
+
+        let prefix = old_lines
+            .iter()
+            .zip(&new_lines)
+            .take_while(|(a, b)| a == b)
+            .count();
+        let suffix = old_lines[prefix..]
+            .iter()
+            .rev()
+            .zip(new_lines[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let mut matched: Vec<(usize, usize)> = (0..prefix).map(|i| (i, i)).collect();
+        let old_changed = &old_lines[prefix..old_lines.len() - suffix];
+        let new_changed = &new_lines[prefix..new_lines.len() - suffix];
+        if old_changed.len() * new_changed.len() <= MAX_LINE_DIFF_CELLS {
+            matched.extend(
+                common_lines(old_changed, new_changed)
+                    .into_iter()
+                    .map(|(o, n)| (prefix + o, prefix + n)),
+            );
+        }
+        matched.extend(
+            (1..=suffix)
+                .rev()
+                .map(|i| (old_lines.len() - i, new_lines.len() - i)),
+        );
+
+        let mut lines = vec![None; old_lines.len()];
+        let (mut old_next, mut new_next) = (0, 0);
+        for (old_line, new_line) in matched {
+            for (o, n) in (old_next..old_line).zip(new_next..new_line) {
+                lines[o] = Some(n);
+            }
+            lines[old_line] = Some(new_line);
+            (old_next, new_next) = (old_line + 1, new_line + 1);
+        }
+        for (o, n) in (old_next..old_lines.len()).zip(new_next..new_lines.len()) {
+            lines[o] = Some(n);
+        }
+
+        Self {
+            old_lines,
+            new_lines,
+            lines,
+        }
+    }
+
+    fn position(&self, position: Position) -> Option<Position> {
+        let line = (*self.lines.get(position.line as usize)?)?;
+        let moved = Position::new(u32::try_from(line).ok()?, position.character);
+
+        let line_start = |p: Position| Range::new(Position::new(p.line, 0), p);
+        let before = range_text(&self.old_lines, line_start(position))?;
+        (Some(before) == range_text(&self.new_lines, line_start(moved))).then_some(moved)
+    }
+
+    fn range(&self, range: Range) -> Option<Range> {
+        let start = self.position(range.start)?;
+        let end_line = (*self.lines.get(range.end.line as usize)?)?;
+        let moved = Range::new(
+            start,
+            Position::new(u32::try_from(end_line).ok()?, range.end.character),
+        );
+
+        let text = range_text(&self.old_lines, range)?;
+        (Some(text) == range_text(&self.new_lines, moved)).then_some(moved)
+    }
+
+    fn finding(&self, mut finding: Finding) -> Option<Finding> {
+        finding.range = self.range(finding.range)?;
+        finding.call_name_end = map_some(finding.call_name_end, |p| self.position(p))?;
+        finding.type_arg_range = map_some(finding.type_arg_range, |r| self.range(r))?;
+        finding.statement_start = map_some(finding.statement_start, |p| self.position(p))?;
+        if let Some(toggle) = &mut finding.listen_toggle {
+            toggle.callee_range = self.range(toggle.callee_range)?;
+            toggle.import_insert = map_some(toggle.import_insert, |p| self.position(p))?;
+        }
+        Some(finding)
+    }
+
+    fn struct_schema(&self, mut schema: StructSchema) -> Option<StructSchema> {
+        schema.range = self.range(schema.range)?;
+        schema.name_range = self.range(schema.name_range)?;
+        schema.field_ranges = schema
+            .field_ranges
+            .iter()
+            .map(|r| self.range(*r))
+            .collect::<Option<_>>()?;
+        Some(schema)
+    }
+}
+
+/// Text of `lines` covered by `range`, one slice per line.
+fn range_text<'a>(lines: &[&'a str], range: Range) -> Option<Vec<&'a str>> {
+    let lines = lines.get(range.start.line as usize..=range.end.line as usize)?;
+    let (first, last) = (lines.first()?, lines.last()?);
+    let start = lsp_character_to_byte_index(first, range.start.character as usize);
+    let end = lsp_character_to_byte_index(last, range.end.character as usize);
+
+    if lines.len() == 1 {
+        return Some(vec![first.get(start..end)?]);
+    }
+    let mut text = vec![first.get(start..)?];
+    text.extend(&lines[1..lines.len() - 1]);
+    text.push(last.get(..end)?);
+    Some(text)
+}
+
+/// Map an optional value, failing only when a present value can't be mapped.
+fn map_some<T>(value: Option<T>, map: impl FnOnce(T) -> Option<T>) -> Option<Option<T>> {
+    match value {
+        Some(value) => map(value).map(Some),
+        None => Some(None),
+    }
+}
+
+/// Index pairs of a longest common subsequence of `old` and `new` lines.
+fn common_lines(old: &[&str], new: &[&str]) -> Vec<(usize, usize)> {
+    // lengths[i * width + j]: length of the common subsequence of old[i..] and new[j..]
+    let width = new.len() + 1;
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
 }
 
 /// Add a command schema only if no higher-priority (non-RustSource) schema already exists.
@@ -153,26 +673,59 @@ fn process_bindings_file(
     }
 }
 
-/// Process file from disk
-pub fn process_file_index(path: PathBuf, project_index: &ProjectIndex) -> bool {
-    if !is_supported_file(&path) {
-        return false;
+/// Process file from disk.
+///
+/// Content identical to what the file was last indexed from is not parsed again.
+/// Returns `Ok(false)` for unsupported files or content that was not indexed.
+///
+/// # Errors
+///
+/// Returns [`Error::Io`] if the file cannot be read (its stale entries are dropped), or
+/// [`Error::Parse`] if it cannot be parsed. Either error is also recorded on the index for
+/// diagnostics.
+pub fn process_file_index(path: &Path, project_index: &ProjectIndex) -> Result<bool> {
+    if !is_indexed_file(path, project_index) {
+        return Ok(false);
     }
 
-    match std::fs::read_to_string(&path) {
-        Ok(content) => process_file_content(&path, &content, project_index),
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
         Err(e) => {
-            project_index.set_parse_error(path, format!("Failed to read file: {e}"));
-            false
+            let error = Error::io(path, e);
+            project_index.remove_file(path);
+            project_index.set_parse_error(path, error.clone());
+            return Err(error);
         }
+    };
+
+    let hash = content_hash(&content);
+    if project_index.has_content_hash(path, hash) {
+        return Ok(true);
+    }
+
+    if process_file_content(path, &content, project_index) {
+        project_index.set_content_hash(path, hash);
+        return Ok(true);
+    }
+
+    match project_index.get_parse_error(path) {
+        Some(error) => Err(error),
+        None => Ok(false),
     }
 }
 
+/// Hash of a file's content, compared to skip re-indexing files that didn't change
+fn content_hash(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::indexer::{DiscoveredGenerator, GeneratorKind, ProjectIndex};
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
 
     fn load_fixture(relative_path: &str) -> String {
         let fixtures_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
@@ -203,4 +756,117 @@ mod tests {
             "TaskState alias should be in index"
         );
     }
+
+    #[test]
+    fn test_transient_rust_syntax_error_keeps_previous_findings() {
+        use crate::syntax::EntityType;
+
+        let index = ProjectIndex::new();
+        let path = PathBuf::from("commands.rs");
+        let line = |name: &str| {
+            index
+                .get_locations(EntityType::Command, name)
+                .iter()
+                .map(|loc| loc.range.start.line)
+                .collect::<Vec<_>>()
+        };
+
+        let valid = "struct User {\n    name: String,\n}\n\n#[tauri::command]\nfn hello() {}\n\n#[tauri::command]\nfn greet() {}\n\n#[tauri::command]\nfn farewell() {}\n\nstruct Settings {\n    theme: String,\n}\n";
+        assert!(process_file_content(&path, valid, &index));
+        assert_eq!(line("farewell"), vec![11]);
+
+        // Mid-edit: a line was added at the top, `User` and `hello` were renamed above the
+        // damage, and an unclosed block in `greet` makes error recovery drop the items after it
+        let broken = "use serde::Serialize;\nstruct Account {\n    name: String,\n}\n\n#[tauri::command]\nfn hi() {}\n\n#[tauri::command]\nfn greet() {\n    if x {\n}\n\n#[tauri::command]\nfn farewell() {}\n\nstruct Settings {\n    theme: String,\n}\n";
+        process_file_content(&path, broken, &index);
+
+        assert_eq!(
+            line("hi"),
+            vec![6],
+            "edits before the syntax error should be indexed"
+        );
+        assert!(
+            line("hello").is_empty(),
+            "hello was renamed before the syntax error"
+        );
+        assert_eq!(
+            line("greet"),
+            vec![9],
+            "greet should survive a transient syntax error, moved down with the added line"
+        );
+        assert_eq!(
+            line("farewell"),
+            vec![14],
+            "farewell should survive a transient syntax error, moved below the added lines"
+        );
+        assert!(
+            index.get_struct_schema("User").is_none(),
+            "User was renamed before the syntax error"
+        );
+        assert!(index.get_struct_schema("Account").is_some());
+        assert_eq!(
+            index
+                .get_struct_schema("Settings")
+                .map(|s| (s.range.start.line, s.field_ranges[0].start.line)),
+            Some((16, 17)),
+            "a struct after the syntax error keeps its schema, moved with the file"
+        );
+
+        // Once fixed, the file is re-indexed normally
+        let fixed = "#[tauri::command]\nfn greet() {}\n";
+        assert!(process_file_content(&path, fixed, &index));
+        assert!(line("farewell").is_empty());
+        assert!(index.get_struct_schema("Settings").is_none());
+    }
+
+    #[test]
+    fn test_process_file_index_skips_unchanged_content() {
+        use crate::syntax::{EntityType, FrontendPatterns};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.ts");
+        std::fs::write(&path, "track(\"saved\");\n").unwrap();
+
+        let index = ProjectIndex::new();
+        assert!(process_file_index(&path, &index).unwrap());
+
+        // The new pattern only shows up in the index once the file is parsed again
+        index.set_frontend_patterns(FrontendPatterns::from_setting(&serde_json::json!([
+            { "function": "track", "kind": "emit" }
+        ])));
+        assert!(process_file_index(&path, &index).unwrap());
+        assert!(
+            index.get_locations(EntityType::Event, "saved").is_empty(),
+            "Identical content should not be parsed again"
+        );
+
+        std::fs::write(&path, "track(\"saved\");\n// edited\n").unwrap();
+        assert!(process_file_index(&path, &index).unwrap());
+        assert_eq!(index.get_locations(EntityType::Event, "saved").len(), 1);
+
+        // Removing the file forgets its hash
+        index.remove_file(&path);
+        assert!(process_file_index(&path, &index).unwrap());
+        assert_eq!(index.get_locations(EntityType::Event, "saved").len(), 1);
+    }
+
+    #[test]
+    fn test_process_file_index_reports_unreadable_file() {
+        use crate::error::Error;
+
+        let index = ProjectIndex::new();
+        let path = PathBuf::from("/nonexistent/tarus/commands.rs");
+
+        let result = process_file_index(&path, &index);
+
+        assert!(matches!(&result, Err(Error::Io { path: p, .. }) if *p == path));
+        assert!(
+            matches!(index.get_parse_error(&path), Some(Error::Io { .. })),
+            "The error is recorded for diagnostics"
+        );
+        assert!(matches!(
+            process_file_index(Path::new("notes.txt"), &index),
+            Ok(false)
+        ));
+    }
 }
@@ -3,7 +3,7 @@
 use crate::syntax::{Behavior, EntityType};
 
 use super::types::{DiagnosticInfo, IndexKey, LocationInfo, NameLocation};
-use super::ProjectIndex;
+use super::{definition_in, ProjectIndex};
 use std::sync::Arc;
 
 impl ProjectIndex {
@@ -29,11 +29,7 @@ impl ProjectIndex {
                 .iter()
                 .filter(|e| e.key().entity == entity)
                 .map(|e| {
-                    let definition = e
-                        .value()
-                        .iter()
-                        .find(|l| l.behavior == Behavior::Definition)
-                        .cloned();
+                    let definition = definition_in(entity, e.value().iter()).cloned();
                     (e.key().name.clone(), definition)
                 })
                 .collect(),
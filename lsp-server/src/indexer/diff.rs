@@ -0,0 +1,113 @@
+//! Key-level differences between two index states
+
+use crate::syntax::Behavior;
+use std::cmp::Ordering;
+use std::path::Path;
+
+use super::types::{IndexDiff, IndexKey, LocationInfo};
+use super::ProjectIndex;
+
+/// What a location looks like to the user; the other fields derive from the same source text
+type LocationSignature<'a> = (&'a Path, (u32, u32), (u32, u32), Behavior);
+
+impl ProjectIndex {
+    /// Keys added, removed and changed going from `self` (older) to `other` (newer).
+    ///
+    /// Both key sets are sorted and merged in one pass; locations are only compared for
+    /// keys present in both.
+    #[must_use]
+    pub fn diff(&self, other: &ProjectIndex) -> IndexDiff {
+        let old_keys = self.sorted_keys();
+        let new_keys = other.sorted_keys();
+        let mut diff = IndexDiff::default();
+
+        let (mut i, mut j) = (0, 0);
+        while i < old_keys.len() || j < new_keys.len() {
+            let order = match (old_keys.get(i), new_keys.get(j)) {
+                (Some(old), Some(new)) => old.cmp(new),
+                (Some(_), None) => Ordering::Less,
+                (None, _) => Ordering::Greater,
+            };
+
+            match order {
+                Ordering::Less => {
+                    diff.removed.push(old_keys[i].clone());
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    diff.added.push(new_keys[j].clone());
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    let key = &old_keys[i];
+                    if !self.same_locations(other, key) {
+                        diff.changed.push(key.clone());
+                    }
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        diff
+    }
+
+    /// A new index holding only the locations of `path`, to [`Self::diff`] a file's entries
+    /// before and after it is re-indexed.
+    #[must_use]
+    pub fn file_snapshot(&self, path: &Path) -> ProjectIndex {
+        let snapshot = ProjectIndex::new();
+        let canonical = self.index_path(path);
+
+        let Some(keys) = self.file_map.get(&canonical) else {
+            return snapshot;
+        };
+
+        for key in keys.value() {
+            if let Some(locs) = self.map.get(key) {
+                let file_locs: Vec<LocationInfo> = locs
+                    .iter()
+                    .filter(|l| l.path == canonical)
+                    .cloned()
+                    .collect();
+                snapshot.map.insert(key.clone(), file_locs);
+            }
+        }
+        snapshot.file_map.insert(canonical, keys.value().clone());
+
+        snapshot
+    }
+
+    fn sorted_keys(&self) -> Vec<IndexKey> {
+        let mut keys: Vec<IndexKey> = self.map.iter().map(|e| e.key().clone()).collect();
+        keys.sort_unstable();
+        keys
+    }
+
+    /// Whether `key` has the same locations in both indices, in any order
+    fn same_locations(&self, other: &ProjectIndex, key: &IndexKey) -> bool {
+        let (Some(old), Some(new)) = (self.map.get(key), other.map.get(key)) else {
+            return false;
+        };
+        if old.len() != new.len() {
+            return false;
+        }
+
+        let mut old_sigs: Vec<LocationSignature<'_>> = old.iter().map(signature).collect();
+        let mut new_sigs: Vec<LocationSignature<'_>> = new.iter().map(signature).collect();
+        old_sigs.sort_unstable();
+        new_sigs.sort_unstable();
+        old_sigs == new_sigs
+    }
+}
+
+fn signature(loc: &LocationInfo) -> LocationSignature<'_> {
+    let start = loc.range.start;
+    let end = loc.range.end;
+    (
+        &loc.path,
+        (start.line, start.character),
+        (end.line, end.character),
+        loc.behavior,
+    )
+}
@@ -1,18 +1,31 @@
 //! `CodeLens` data preparation
 
-use std::collections::{HashMap, HashSet};
-use std::path::{Path, PathBuf};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
 use std::sync::atomic::Ordering;
 use tower_lsp_server::lsp_types::Range;
 
-use super::types::{IndexKey, LocationInfo};
+use super::types::{CodeLensMode, IndexKey, LocationInfo};
 use super::ProjectIndex;
+use crate::syntax::Behavior;
 
 impl ProjectIndex {
     /// Preparing data for `CodeLens`
+    ///
+    /// In [`CodeLensMode::DefinitionsOnly`] only definition locations get lenses.
+    ///
+    /// The lenses of a key are the same at each of its occurrences in the file, so they are
+    /// built once per key and copied to every occurrence but the last, which takes them.
     pub fn get_lens_data(&self, path: &Path) -> Vec<(Range, String, Vec<LocationInfo>)> {
+        let canonical = self.index_path(path);
+        let path = canonical.as_path();
         let mut result = Vec::new();
+        let definitions_only = self.code_lens_mode() == CodeLensMode::DefinitionsOnly;
+        let is_current_rust = is_rust_file(path);
+        let limit = self.reference_limit.load(Ordering::Relaxed);
 
+        // Held across the per-key views below, which is the guard order of
+        // [`ProjectIndex::get_locations_iter`]
         let Some(keys) = self.file_map.get(&path.to_path_buf()) else {
             return result;
         };
@@ -24,73 +37,71 @@ impl ProjectIndex {
                 continue;
             }
 
-            let Some(all_locations) = self.map.get(key) else {
-                continue;
-            };
+            let all_locations = self.get_locations_iter(key.entity, &key.name);
 
-            let current_file_locations: Vec<&LocationInfo> =
-                all_locations.iter().filter(|l| l.path == path).collect();
-
-            let targets: Vec<LocationInfo> = all_locations
+            let anchors: Vec<Range> = all_locations
                 .iter()
-                .filter(|l| l.path != path)
-                .cloned()
+                .filter(|l| {
+                    l.path == path && (!definitions_only || l.behavior == Behavior::Definition)
+                })
+                .map(lens_anchor)
                 .collect();
-
-            if targets.is_empty() {
+            let Some((last_anchor, other_anchors)) = anchors.split_last() else {
                 continue;
-            }
-
-            let is_current_rust = path.extension().and_then(|s| s.to_str()) == Some("rs");
-            let limit = self.reference_limit.load(Ordering::Relaxed);
+            };
 
-            let mut rust_targets = Vec::new();
-            let mut frontend_targets = Vec::new();
+            // Lenses only point at other files; keys referenced solely from this one get none
+            let (rust_targets, frontend_targets): (Vec<&LocationInfo>, Vec<&LocationInfo>) =
+                all_locations
+                    .iter()
+                    .filter(|l| l.path != path)
+                    .partition(|t| is_rust_file(&t.path));
 
-            for t in &targets {
-                if t.path.extension().and_then(|s| s.to_str()) == Some("rs") {
-                    rust_targets.push(t.clone());
-                } else {
-                    frontend_targets.push(t.clone());
-                }
+            let mut lenses = Vec::new();
+            if !is_current_rust {
+                push_file_lenses(&mut lenses, &rust_targets, limit, "rust refs");
             }
-
-            for my_loc in current_file_locations {
-                if is_current_rust {
-                    push_file_lenses(
-                        &mut result,
-                        my_loc.range,
-                        frontend_targets.clone(),
-                        limit,
-                        "references",
-                    );
-                } else {
-                    push_file_lenses(
-                        &mut result,
-                        my_loc.range,
-                        rust_targets.clone(),
-                        limit,
-                        "rust refs",
-                    );
-                    push_file_lenses(
-                        &mut result,
-                        my_loc.range,
-                        frontend_targets.clone(),
-                        limit,
-                        "references",
-                    );
-                }
+            push_file_lenses(&mut lenses, &frontend_targets, limit, "references");
+
+            for anchor in other_anchors {
+                result.extend(
+                    lenses
+                        .iter()
+                        .map(|(title, targets)| (*anchor, title.clone(), targets.clone())),
+                );
             }
+            result.extend(
+                lenses
+                    .into_iter()
+                    .map(|(title, targets)| (*last_anchor, title, targets)),
+            );
         }
 
         result
     }
 }
 
+fn is_rust_file(path: &Path) -> bool {
+    path.extension().and_then(|s| s.to_str()) == Some("rs")
+}
+
+/// Lens range for a location: starts at the enclosing statement when known, so a
+/// multi-line `invoke(...)` gets its lens above the statement rather than mid-expression.
+fn lens_anchor(loc: &LocationInfo) -> Range {
+    match loc.statement_start {
+        Some(start) => Range {
+            start,
+            end: loc.range.end,
+        },
+        None => loc.range,
+    }
+}
+
+/// Lenses (title, targets) for `targets`: one per file, or a single summary lens when they
+/// span more files than `limit`
 fn push_file_lenses(
-    result: &mut Vec<(Range, String, Vec<LocationInfo>)>,
-    range: Range,
-    targets: Vec<LocationInfo>,
+    lenses: &mut Vec<(String, Vec<LocationInfo>)>,
+    targets: &[&LocationInfo],
     limit: usize,
     summary_label: &str,
 ) {
@@ -98,29 +109,30 @@ fn push_file_lenses(
         return;
     }
 
-    let mut files_map: HashMap<PathBuf, Vec<LocationInfo>> = HashMap::new();
-    for t in &targets {
-        files_map.entry(t.path.clone()).or_default().push(t.clone());
+    let mut by_file: BTreeMap<&Path, Vec<&LocationInfo>> = BTreeMap::new();
+    for &target in targets {
+        by_file
+            .entry(target.path.as_path())
+            .or_default()
+            .push(target);
     }
 
-    if files_map.len() <= limit {
-        let mut sorted_files: Vec<_> = files_map.into_iter().collect();
-        sorted_files.sort_by(|a, b| a.0.cmp(&b.0));
-
-        for (fpath, locs) in sorted_files {
+    if by_file.len() <= limit {
+        for (fpath, locs) in by_file {
             let fname = fpath
                 .file_name()
                 .and_then(|s| s.to_str())
-                .unwrap_or("unknown")
-                .to_string();
+                .unwrap_or("unknown");
 
-            result.push((range, format!("Go to {fname}"), locs));
+            lenses.push((
+                format!("Go to {fname}"),
+                locs.into_iter().cloned().collect(),
+            ));
         }
     } else {
-        result.push((
-            range,
+        lenses.push((
             format!("{} {}", targets.len(), summary_label),
-            targets,
+            targets.iter().map(|t| (*t).clone()).collect(),
         ));
     }
 }
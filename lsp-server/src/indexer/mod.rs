@@ -3,27 +3,39 @@
 //! ## Submodules
 //! - `types` — core data types (`Finding`, `IndexKey`, `LocationInfo`, schemas, `DiagnosticInfo`)
 //! - `generators` — generator discovery and bindings detection
-//! - `schemas` — command/event schema and type alias CRUD
+//! - `schemas` — command/event/struct schema and type alias CRUD, Rust alias expansion
 //! - `symbols` — document and workspace symbol search
 //! - `lens` — `CodeLens` data preparation
 //! - `reports` — debug reports and introspection
 //! - `cache` — name and diagnostic info caching
+//! - `diff` — key-level differences between two index states
+//! - `summary` — per-command aggregation (`CommandSummary`) for docs and UIs
+//! - `wrappers` — cross-file invoke wrapper resolution
 
 mod cache;
+mod diff;
 mod generators;
 mod lens;
 mod reports;
 mod schemas;
+mod summary;
 mod symbols;
 pub mod types;
+mod wrappers;
 
 pub use types::*;
 
-use crate::syntax::EntityType;
+use crate::error::Error;
+use crate::permissions::CommandPermissions;
+use crate::scanner::PathAliasResolver;
+use crate::syntax::{Behavior, EntityType, FrontendPatterns};
+use dashmap::mapref::one::Ref;
 use dashmap::DashMap;
 use parking_lot::RwLock;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use tower_lsp_server::lsp_types::{Range, SymbolInformation};
 
 #[derive(Debug)]
 pub struct ProjectIndex {
@@ -34,24 +46,101 @@ pub struct ProjectIndex {
     pub(crate) event_names_cache: RwLock<NameCache>,
     // Cache for diagnostic info (avoids re-iterating locations)
     pub(crate) diagnostic_info_cache: DashMap<IndexKey, DiagnosticInfo>,
+    // Cache for document symbols by file (outline views request them on every change)
+    pub(crate) document_symbols_cache: DashMap<PathBuf, Vec<SymbolInformation>>,
+    // Cross-file invoke wrappers: (exporting file, export name ("getUser", "api.getUser")) -> command
+    pub(crate) wrapper_commands: DashMap<(PathBuf, String), String>,
+    // Reverse index: source_path -> wrappers exported there (for stale removal)
+    pub(crate) wrapper_exports: DashMap<PathBuf, Vec<(String, String)>>,
+    // Calls to imported functions per file, resolved through `wrapper_commands`
+    pub(crate) imported_calls: DashMap<PathBuf, Vec<ImportedCall>>,
+    // Reverse index: command -> (exporting file, location) of each resolved imported call
+    pub(crate) wrapper_calls: DashMap<String, Vec<(PathBuf, LocationInfo)>>,
+    // Frontend types declared or imported per file (for `invoke<T>` validation)
+    pub(crate) file_type_names: DashMap<PathBuf, Vec<String>>,
+    // Commands listed in `generate_handler!` per Rust file
+    pub(crate) registered_commands: DashMap<PathBuf, Vec<String>>,
+    // App command permissions per capability file
+    pub(crate) command_permissions: DashMap<PathBuf, CommandPermissions>,
+    // Range of the first syntax error per frontend file
+    pub(crate) syntax_errors: DashMap<PathBuf, Range>,
+    // Template-literal names built at runtime, per frontend file
+    pub(crate) dynamic_names: DashMap<PathBuf, Vec<DynamicName>>,
     // Parse errors by file path
-    pub(crate) parse_errors: DashMap<PathBuf, String>,
+    pub(crate) parse_errors: DashMap<PathBuf, Error>,
+    // Hash of the content each file was last indexed from, to skip unchanged files
+    pub(crate) content_hashes: DashMap<PathBuf, u64>,
+    // Buffer text each Rust file was last indexed from, to carry its findings across an edit
+    pub(crate) indexed_sources: DashMap<PathBuf, Arc<str>>,
+    // Path a file was opened or scanned under -> its canonical index path, resolved once
+    pub(crate) index_paths: DashMap<PathBuf, PathBuf>,
+    // Reverse of `index_paths` where they differ: canonical path -> path the client knows it by
+    pub(crate) original_paths: DashMap<PathBuf, PathBuf>,
     // Configuration: Max number of individual file links to show in CodeLens before summarizing
     pub(crate) reference_limit: AtomicUsize,
+    // Configuration: Max number of definitions and sample references listed in a hover
+    pub(crate) hover_max_references: AtomicUsize,
+    // Configuration: Max number of target files offered by "Create Rust command" actions
+    pub(crate) code_action_candidates: AtomicUsize,
+    // Configuration: Which files receive diagnostics
+    pub(crate) diagnostics_scope: RwLock<DiagnosticsScope>,
+    // Configuration: Republish diagnostics of other files referencing a changed file's keys
+    pub(crate) propagate_diagnostics: AtomicBool,
+    // Configuration: Report frontend invoke calls whose Promise is dropped
+    pub(crate) require_await: AtomicBool,
+    // Configuration: Send `tarus/status` diagnostics totals after each publish batch
+    pub(crate) status_notification: AtomicBool,
+    // Configuration: Leave plugin commands out of command completion
+    pub(crate) exclude_plugin_commands: AtomicBool,
+    // Configuration: Event names emitted by the framework beyond the built-in set
+    pub(crate) framework_events: RwLock<Vec<String>>,
+    // Configuration: Name prefix of the commands diagnostics are reported for
+    pub(crate) command_prefix: RwLock<String>,
+    // Configuration: Required casing for event names
+    pub(crate) event_naming: RwLock<EventNamingConvention>,
+    // Configuration: Frontend functions whose arguments name commands and events
+    pub(crate) frontend_patterns: RwLock<FrontendPatterns>,
+    // Configuration: Index files without a grammar (.html, .mdx) by plain-text scanning
+    pub(crate) experimental_text_scan: AtomicBool,
+    // Configuration: Whether code lenses are shown at all
+    pub(crate) code_lens_enabled: AtomicBool,
+    // Configuration: Which locations get code lenses
+    pub(crate) code_lens_mode: RwLock<CodeLensMode>,
+    // Configuration: Developer mode, which adds syntax error diagnostics
+    pub(crate) developer_mode: AtomicBool,
+    // Configuration: Whether frontend files are indexed on startup or when opened
+    pub(crate) indexing_mode: RwLock<IndexingMode>,
+    // Configuration: Paths scanned in addition to the workspace, as configured
+    pub(crate) scan_extra_paths: RwLock<Vec<String>>,
+    // Configuration: Preferred directory for new commands, as configured
+    pub(crate) command_dir: RwLock<Option<String>>,
+    // Configuration: Maximum number of files collected by the workspace scan
+    pub(crate) scan_max_files: AtomicUsize,
     // Schema storage: command_name -> CommandSchema
     pub(crate) command_schemas: DashMap<String, CommandSchema>,
     // Reverse index: source_path -> list of command names (for stale removal)
     pub(crate) generated_file_paths: DashMap<PathBuf, Vec<String>>,
     // Type alias storage: alias_name -> type definition string
     pub(crate) type_aliases: DashMap<String, String>,
-    // Reverse index: source_path -> list of alias names (for stale removal)
-    pub(crate) generated_alias_paths: DashMap<PathBuf, Vec<String>>,
+    // Reverse index: source_path -> (alias name, definition) declared there, to pick the
+    // canonical definition among files and for stale removal
+    pub(crate) generated_alias_paths: DashMap<PathBuf, Vec<(String, String)>>,
     // Event schema storage: event_name -> EventSchema
     pub(crate) event_schemas: DashMap<String, EventSchema>,
     // Reverse index: source_path -> list of event names (for stale removal)
     pub(crate) generated_event_paths: DashMap<PathBuf, Vec<String>>,
+    // Rust struct storage: struct_name -> StructSchema per declaring file, ordered by path
+    pub(crate) struct_schemas: DashMap<String, Vec<StructSchema>>,
+    // Reverse index: source_path -> list of struct names (for stale removal)
+    pub(crate) struct_schema_paths: DashMap<PathBuf, Vec<String>>,
+    // Rust type alias storage: alias_name -> RustTypeAlias per declaring file, ordered by path
+    pub(crate) rust_type_aliases: DashMap<String, Vec<RustTypeAlias>>,
+    // Reverse index: source_path -> list of Rust alias names (for stale removal)
+    pub(crate) rust_type_alias_paths: DashMap<PathBuf, Vec<String>>,
     // Generators discovered from project configuration files
     pub(crate) generator_bindings: RwLock<Vec<DiscoveredGenerator>>,
+    // `tsconfig.json`/`jsconfig.json` path aliases, to resolve the modules wrappers are imported from
+    pub(crate) path_aliases: RwLock<Option<PathAliasResolver>>,
 }
 
 impl Default for ProjectIndex {
@@ -62,15 +151,55 @@ impl Default for ProjectIndex {
             command_names_cache: RwLock::new(None),
             event_names_cache: RwLock::new(None),
             diagnostic_info_cache: DashMap::new(),
+            document_symbols_cache: DashMap::new(),
+            wrapper_commands: DashMap::new(),
+            wrapper_exports: DashMap::new(),
+            imported_calls: DashMap::new(),
+            wrapper_calls: DashMap::new(),
+            file_type_names: DashMap::new(),
+            registered_commands: DashMap::new(),
+            command_permissions: DashMap::new(),
+            syntax_errors: DashMap::new(),
+            dynamic_names: DashMap::new(),
             parse_errors: DashMap::new(),
+            content_hashes: DashMap::new(),
+            indexed_sources: DashMap::new(),
+            index_paths: DashMap::new(),
+            original_paths: DashMap::new(),
             reference_limit: AtomicUsize::new(crate::constants::DEFAULT_REFERENCE_LIMIT),
+            hover_max_references: AtomicUsize::new(crate::constants::DEFAULT_HOVER_MAX_REFERENCES),
+            code_action_candidates: AtomicUsize::new(
+                crate::constants::DEFAULT_CODE_ACTION_CANDIDATES,
+            ),
+            diagnostics_scope: RwLock::new(DiagnosticsScope::default()),
+            propagate_diagnostics: AtomicBool::new(true),
+            require_await: AtomicBool::new(false),
+            status_notification: AtomicBool::new(false),
+            exclude_plugin_commands: AtomicBool::new(false),
+            framework_events: RwLock::new(Vec::new()),
+            command_prefix: RwLock::new(String::new()),
+            event_naming: RwLock::new(EventNamingConvention::default()),
+            frontend_patterns: RwLock::new(FrontendPatterns::default()),
+            experimental_text_scan: AtomicBool::new(false),
+            code_lens_enabled: AtomicBool::new(true),
+            code_lens_mode: RwLock::new(CodeLensMode::default()),
+            developer_mode: AtomicBool::new(false),
+            indexing_mode: RwLock::new(IndexingMode::default()),
+            scan_extra_paths: RwLock::new(Vec::new()),
+            command_dir: RwLock::new(None),
+            scan_max_files: AtomicUsize::new(crate::constants::DEFAULT_SCAN_MAX_FILES),
             command_schemas: DashMap::new(),
             generated_file_paths: DashMap::new(),
             type_aliases: DashMap::new(),
             generated_alias_paths: DashMap::new(),
             event_schemas: DashMap::new(),
             generated_event_paths: DashMap::new(),
+            struct_schemas: DashMap::new(),
+            struct_schema_paths: DashMap::new(),
+            rust_type_aliases: DashMap::new(),
+            rust_type_alias_paths: DashMap::new(),
             generator_bindings: RwLock::new(Vec::new()),
+            path_aliases: RwLock::new(None),
         }
     }
 }
@@ -93,32 +222,96 @@ impl ProjectIndex {
         path: &Path,
         position: tower_lsp_server::lsp_types::Position,
     ) -> Option<(IndexKey, LocationInfo)> {
-        let keys_in_file = self.file_map.get(path)?;
-
-        for key in keys_in_file.value() {
-            if let Some(locations) = self.map.get(key) {
-                for loc in locations.value() {
-                    if loc.path == path && crate::utils::is_position_in_range(position, loc.range) {
-                        return Some((key.clone(), loc.clone()));
+        let canonical = self.index_path(path);
+        let path = canonical.as_path();
+
+        if let Some(keys_in_file) = self.file_map.get(path) {
+            for key in keys_in_file.value() {
+                if let Some(locations) = self.map.get(key) {
+                    for loc in locations.value() {
+                        if loc.path == path
+                            && crate::utils::is_position_in_range(position, loc.range)
+                        {
+                            return Some((key.clone(), loc.clone()));
+                        }
                     }
                 }
             }
         }
 
-        None
+        self.wrapper_call_at(path, position)
     }
 
     /// Appends (or overwrites) the parsing results of a single file
-    ///
-    /// # Panics
-    ///
-    /// Panics if the cache lock is poisoned (only occurs if another thread panicked while holding the lock)
     pub fn add_file(&self, file_index: FileIndex) {
+        self.insert_file(file_index);
+        self.invalidate_caches();
+    }
+
+    /// Deletes all entries associated with a specific file.
+    pub fn remove_file(&self, path: &Path) {
+        let dropped = self.drop_file(path);
+        self.forget_path(path);
+        if dropped {
+            self.invalidate_caches();
+        }
+    }
+
+    /// Apply many file additions and removals in order, invalidating the name caches once.
+    ///
+    /// The resulting index is the same as calling [`Self::add_file`] / [`Self::remove_file`]
+    /// for each change, without the per-file cache churn (e.g. on a git branch switch).
+    pub fn apply_batch(&self, changes: Vec<FileChange>) {
+        let mut stale = false;
+        for change in changes {
+            stale |= match change {
+                FileChange::Upsert(file_index) => {
+                    self.insert_file(file_index);
+                    true
+                }
+                FileChange::Remove(path) => {
+                    let dropped = self.drop_file(&path);
+                    self.forget_path(&path);
+                    dropped
+                }
+            };
+        }
+
+        if stale {
+            self.invalidate_caches();
+        }
+    }
+
+    /// Replace a file's entries without invalidating the name caches
+    fn insert_file(&self, file_index: FileIndex) {
+        // A symlinked and a real path to the same file share one entry
+        let path_ref = self.register_path(&file_index.path);
+
         // Clear old data about this file so that there are no duplicates
-        self.remove_file(&file_index.path);
+        self.drop_file(&path_ref);
 
         let mut keys_in_this_file = std::collections::HashSet::new();
-        let path_ref = file_index.path;
+
+        self.add_wrapper_links(&path_ref, file_index.wrappers);
+        if !file_index.type_names.is_empty() {
+            self.file_type_names
+                .insert(path_ref.clone(), file_index.type_names);
+        }
+        if !file_index.registered_commands.is_empty() {
+            self.registered_commands
+                .insert(path_ref.clone(), file_index.registered_commands);
+        }
+        if let Some(permissions) = file_index.command_permissions {
+            self.command_permissions
+                .insert(path_ref.clone(), permissions);
+        }
+        if let Some(range) = file_index.syntax_error {
+            self.syntax_errors.insert(path_ref.clone(), range);
+        }
+        if !file_index.dynamic_names.is_empty() {
+            self.dynamic_names
+                .insert(path_ref.clone(), file_index.dynamic_names);
+        }
 
         for finding in file_index.findings {
             let key = IndexKey {
@@ -126,63 +319,240 @@ impl ProjectIndex {
                 name: finding.key.clone(),
             };
 
-            let info = LocationInfo::from((&path_ref, finding));
+            let info = LocationInfo::from((path_ref.as_path(), finding));
 
             self.map.entry(key.clone()).or_default().push(info);
 
             keys_in_this_file.insert(key);
         }
 
-        let keys_vec: Vec<_> = keys_in_this_file.iter().cloned().collect();
-        self.file_map.insert(path_ref, keys_vec);
-
-        self.invalidate_caches();
-
         for key in &keys_in_this_file {
             self.diagnostic_info_cache.remove(key);
         }
+
+        let keys_vec: Vec<_> = keys_in_this_file.into_iter().collect();
+        self.file_map.insert(path_ref, keys_vec);
     }
 
-    /// Deletes all entries associated with a specific file.
-    pub fn remove_file(&self, path: &Path) {
-        if let Some((_, keys)) = self.file_map.remove(path) {
-            for key in keys {
-                self.map.entry(key.clone()).and_modify(|locs| {
-                    locs.retain(|loc| loc.path != path);
-                });
-
-                if self.map.get(&key).is_some_and(|locs| locs.is_empty()) {
-                    self.map.remove(&key);
-                }
+    /// Remove a file's entries without invalidating the name caches; returns whether
+    /// the file was indexed.
+    fn drop_file(&self, path: &Path) -> bool {
+        let canonical = self.index_path(path);
+        let path = canonical.as_path();
+
+        // Also remove parse errors, local types and wrapper links for this file
+        self.parse_errors.remove(path);
+        self.content_hashes.remove(path);
+        self.indexed_sources.remove(path);
+        self.file_type_names.remove(path);
+        self.registered_commands.remove(path);
+        self.command_permissions.remove(path);
+        self.syntax_errors.remove(path);
+        self.dynamic_names.remove(path);
+        self.remove_wrapper_links(path);
+
+        // Symbols are cached while holding the file's `file_map` entry, so dropping them
+        // after the entry is gone cannot race with a lookup caching the old symbols
+        let removed = self.file_map.remove(path);
+        self.document_symbols_cache.remove(path);
+        let Some((_, keys)) = removed else {
+            return false;
+        };
 
-                self.diagnostic_info_cache.remove(&key);
+        for key in keys {
+            self.map.entry(key.clone()).and_modify(|locs| {
+                locs.retain(|loc| loc.path != path);
+            });
+
+            if self.map.get(&key).is_some_and(|locs| locs.is_empty()) {
+                self.map.remove(&key);
             }
 
-            self.invalidate_caches();
+            self.diagnostic_info_cache.remove(&key);
         }
 
-        // Also remove parse errors for this file
-        self.parse_errors.remove(path);
+        true
     }
 
-    /// Store a parse error for a file
-    pub fn set_parse_error(&self, path: PathBuf, error: String) {
-        self.parse_errors.insert(path, error);
+    /// Drop every indexed file, schema and type alias.
+    ///
+    /// Settings (reference limit, diagnostics scope, event naming), generator bindings and
+    /// resolved file paths are kept, so the index can be rebuilt after a configuration reload.
+    pub fn clear(&self) {
+        self.map.clear();
+        self.file_map.clear();
+        self.diagnostic_info_cache.clear();
+        self.document_symbols_cache.clear();
+        self.parse_errors.clear();
+        self.content_hashes.clear();
+        self.indexed_sources.clear();
+        self.file_type_names.clear();
+        self.registered_commands.clear();
+        self.command_permissions.clear();
+        self.syntax_errors.clear();
+        self.dynamic_names.clear();
+        self.wrapper_commands.clear();
+        self.wrapper_exports.clear();
+        self.imported_calls.clear();
+        self.wrapper_calls.clear();
+        self.command_schemas.clear();
+        self.generated_file_paths.clear();
+        self.type_aliases.clear();
+        self.generated_alias_paths.clear();
+        self.event_schemas.clear();
+        self.generated_event_paths.clear();
+        self.struct_schemas.clear();
+        self.struct_schema_paths.clear();
+        self.rust_type_aliases.clear();
+        self.rust_type_alias_paths.clear();
+        self.invalidate_caches();
     }
 
-    /// Get parse error for a file (if any)
-    pub fn get_parse_error(&self, path: &Path) -> Option<String> {
-        self.parse_errors.get(path).map(|e| e.value().clone())
+    /// Drop state left behind by files deleted without a [`Self::remove_file`] call.
+    ///
+    /// Prunes parse errors of files that are neither indexed nor on disk; an error for a file
+    /// that still exists is kept until the file parses again. Returns the number of pruned
+    /// entries.
+    pub fn gc(&self) -> usize {
+        let before = self.parse_errors.len();
+        self.parse_errors
+            .retain(|path, _| self.file_map.contains_key(path) || path.exists());
+
+        before - self.parse_errors.len()
+    }
+
+    /// Store the error that prevented a file from being indexed
+    pub fn set_parse_error(&self, path: &Path, error: Error) {
+        self.parse_errors.insert(self.register_path(path), error);
+    }
+
+    /// Get the error that prevented a file from being indexed (if any)
+    pub fn get_parse_error(&self, path: &Path) -> Option<Error> {
+        self.parse_errors
+            .get(&self.index_path(path))
+            .map(|e| e.value().clone())
+    }
+
+    /// Record the hash of the content `path` was just indexed from
+    pub fn set_content_hash(&self, path: &Path, hash: u64) {
+        self.content_hashes.insert(self.index_path(path), hash);
+    }
+
+    /// Forget the content hash of `path`, so its next re-index parses it again
+    pub fn forget_content_hash(&self, path: &Path) {
+        self.content_hashes.remove(&self.index_path(path));
+    }
+
+    /// Whether `path` was last indexed from content with this hash
+    pub fn has_content_hash(&self, path: &Path, hash: u64) -> bool {
+        self.content_hashes
+            .get(&self.index_path(path))
+            .is_some_and(|h| *h == hash)
+    }
+
+    /// Record the buffer text a Rust file was just indexed from
+    pub fn set_indexed_source(&self, path: &Path, content: &str) {
+        self.indexed_sources
+            .insert(self.index_path(path), Arc::from(content));
+    }
+
+    /// Buffer text a Rust file's findings were extracted from; `None` when it was indexed
+    /// from disk
+    pub fn get_indexed_source(&self, path: &Path) -> Option<Arc<str>> {
+        self.indexed_sources
+            .get(&self.index_path(path))
+            .map(|source| Arc::clone(source.value()))
+    }
+
+    /// Range of the first syntax error in an indexed file that still parsed (if any)
+    pub fn get_syntax_error(&self, path: &Path) -> Option<Range> {
+        self.syntax_errors
+            .get(&self.index_path(path))
+            .map(|r| *r.value())
+    }
+
+    /// Calls in an indexed frontend file whose name is built at runtime
+    pub fn get_dynamic_names(&self, path: &Path) -> Vec<DynamicName> {
+        self.dynamic_names
+            .get(&self.index_path(path))
+            .map(|names| names.value().clone())
+            .unwrap_or_default()
     }
 
     /// Retrieves all locations associated with a specific entity
+    ///
+    /// Command locations include calls through invoke wrappers imported from other files.
+    /// Prefer [`Self::get_locations_iter`] when the locations are only read.
     pub fn get_locations(&self, entity: EntityType, name: &str) -> Vec<LocationInfo> {
+        self.get_locations_iter(entity, name)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// The definition location of an entity
+    ///
+    /// Commands resolve to their `#[tauri::command]` function. Events resolve to a Rust
+    /// definition when there is one, otherwise to their first listener, the same side hover
+    /// lists under **Definition**.
+    pub fn get_definition(&self, entity: EntityType, name: &str) -> Option<LocationInfo> {
         let key = IndexKey {
             entity,
             name: name.to_string(),
         };
+        let locations = self.map.get(&key)?;
+        definition_in(entity, locations.iter()).cloned()
+    }
 
-        self.map.get(&key).map(|v| v.clone()).unwrap_or_default()
+    /// Borrowed view of the locations of an entity, without cloning the indexed vector
+    ///
+    /// The view holds a read lock on the key's shard of `map`: drop it before anything that
+    /// may write to the index, or the write waits on the view forever.
+    ///
+    /// Guards are taken in one order: a `file_map` entry, then `map` shards, then the maps
+    /// read under a view (wrapper calls, schemas). Code lens holds the file's `file_map` entry
+    /// around its views and hover reads schemas through one, but nothing locks `file_map`
+    /// while holding a view, or `map` while holding one of those maps, so two readers can't
+    /// wait on each other through a writer queued between them.
+    pub fn get_locations_iter(&self, entity: EntityType, name: &str) -> Locations<'_> {
+        let key = IndexKey {
+            entity,
+            name: name.to_string(),
+        };
+
+        Locations {
+            indexed: self.map.get(&key),
+            wrapper_calls: if entity == EntityType::Command {
+                self.wrapper_call_locations(name)
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    /// Distinct files containing any location (definition, call, emit, listen) for the key,
+    /// sorted by path
+    pub fn files_referencing(&self, entity: EntityType, name: &str) -> Vec<PathBuf> {
+        let key = IndexKey {
+            entity,
+            name: name.to_string(),
+        };
+
+        let mut paths = self
+            .map
+            .get(&key)
+            .map(|locs| distinct_paths(locs.iter()))
+            .unwrap_or_default();
+        if entity == EntityType::Command {
+            paths.extend(
+                self.wrapper_call_locations(name)
+                    .into_iter()
+                    .map(|loc| loc.path),
+            );
+            paths.sort();
+            paths.dedup();
+        }
+        paths
     }
 
     /// Set the reference limit for `CodeLens` display
@@ -190,21 +560,381 @@ impl ProjectIndex {
         self.reference_limit.store(limit, Ordering::Relaxed);
     }
 
+    /// Set the max number of definitions and sample references listed in a hover
+    pub fn set_hover_max_references(&self, max: usize) {
+        self.hover_max_references.store(max, Ordering::Relaxed);
+    }
+
+    /// Get the max number of definitions and sample references listed in a hover
+    pub fn hover_max_references(&self) -> usize {
+        self.hover_max_references.load(Ordering::Relaxed)
+    }
+
+    /// Set the max number of target files offered by "Create Rust command" actions
+    pub fn set_code_action_candidates(&self, max: usize) {
+        self.code_action_candidates.store(max, Ordering::Relaxed);
+    }
+
+    /// Get the max number of target files offered by "Create Rust command" actions
+    pub fn code_action_candidates(&self) -> usize {
+        self.code_action_candidates.load(Ordering::Relaxed)
+    }
+
+    /// Set which files receive diagnostics
+    pub fn set_diagnostics_scope(&self, scope: DiagnosticsScope) {
+        *self.diagnostics_scope.write() = scope;
+    }
+
+    /// Get which files receive diagnostics
+    pub fn diagnostics_scope(&self) -> DiagnosticsScope {
+        *self.diagnostics_scope.read()
+    }
+
+    /// Set whether developer mode is on
+    pub fn set_developer_mode(&self, enabled: bool) {
+        self.developer_mode.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether developer mode is on
+    pub fn developer_mode(&self) -> bool {
+        self.developer_mode.load(Ordering::Relaxed)
+    }
+
+    /// Set whether a file change republishes diagnostics of the files referencing its keys
+    pub fn set_propagate_diagnostics(&self, enabled: bool) {
+        self.propagate_diagnostics.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether a file change republishes diagnostics of the files referencing its keys
+    pub fn propagate_diagnostics(&self) -> bool {
+        self.propagate_diagnostics.load(Ordering::Relaxed)
+    }
+
+    /// Set whether invoke calls used as bare statements are reported as not awaited
+    pub fn set_require_await(&self, enabled: bool) {
+        self.require_await.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether invoke calls used as bare statements are reported as not awaited
+    pub fn require_await(&self) -> bool {
+        self.require_await.load(Ordering::Relaxed)
+    }
+
+    /// Set whether diagnostics totals are sent to the client as `tarus/status`
+    pub fn set_status_notification(&self, enabled: bool) {
+        self.status_notification.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether diagnostics totals are sent to the client as `tarus/status`
+    pub fn status_notification(&self) -> bool {
+        self.status_notification.load(Ordering::Relaxed)
+    }
+
+    /// Set whether command completion leaves out plugin commands
+    pub fn set_exclude_plugin_commands(&self, enabled: bool) {
+        self.exclude_plugin_commands
+            .store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether command completion leaves out plugin commands
+    pub fn exclude_plugin_commands(&self) -> bool {
+        self.exclude_plugin_commands.load(Ordering::Relaxed)
+    }
+
+    /// Set the event names emitted by the framework beyond the built-in set
+    pub fn set_framework_events(&self, events: Vec<String>) {
+        *self.framework_events.write() = events;
+    }
+
+    /// Whether the framework emits the event `name` (see [`crate::syntax::is_framework_event`])
+    pub fn is_framework_event(&self, name: &str) -> bool {
+        crate::syntax::is_framework_event(name, &self.framework_events.read())
+    }
+
+    /// Set the name prefix of the commands diagnostics are reported for (empty for all)
+    pub fn set_command_prefix(&self, prefix: String) {
+        *self.command_prefix.write() = prefix;
+    }
+
+    /// Whether command `name` matches `tarus.commandPrefix`, i.e. whether unused/undefined
+    /// diagnostics apply to it
+    pub fn is_relevant_command(&self, name: &str) -> bool {
+        name.starts_with(self.command_prefix.read().as_str())
+    }
+
+    /// Set the required casing for event names
+    pub fn set_event_naming(&self, convention: EventNamingConvention) {
+        *self.event_naming.write() = convention;
+    }
+
+    /// Get the required casing for event names
+    pub fn event_naming(&self) -> EventNamingConvention {
+        *self.event_naming.read()
+    }
+
+    /// Set the frontend call patterns used when parsing (takes effect on the next parse)
+    pub fn set_frontend_patterns(&self, patterns: FrontendPatterns) {
+        *self.frontend_patterns.write() = patterns;
+    }
+
+    /// Get the frontend call patterns used when parsing
+    pub fn frontend_patterns(&self) -> FrontendPatterns {
+        self.frontend_patterns.read().clone()
+    }
+
+    /// Enable plain-text scanning of files without a tree-sitter grammar
+    pub fn set_experimental_text_scan(&self, enabled: bool) {
+        self.experimental_text_scan
+            .store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether files without a tree-sitter grammar are indexed by plain-text scanning
+    pub fn experimental_text_scan(&self) -> bool {
+        self.experimental_text_scan.load(Ordering::Relaxed)
+    }
+
+    /// Show or hide all code lenses
+    pub fn set_code_lens_enabled(&self, enabled: bool) {
+        self.code_lens_enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Set which locations get code lenses
+    pub fn set_code_lens_mode(&self, mode: CodeLensMode) {
+        *self.code_lens_mode.write() = mode;
+    }
+
+    /// Effective code lens mode: `Off` when code lenses are disabled
+    pub fn code_lens_mode(&self) -> CodeLensMode {
+        if self.code_lens_enabled.load(Ordering::Relaxed) {
+            *self.code_lens_mode.read()
+        } else {
+            CodeLensMode::Off
+        }
+    }
+
+    /// Set whether frontend files are indexed on startup or when opened
+    pub fn set_indexing_mode(&self, mode: IndexingMode) {
+        *self.indexing_mode.write() = mode;
+    }
+
+    /// Get whether frontend files are indexed on startup or when opened
+    pub fn indexing_mode(&self) -> IndexingMode {
+        *self.indexing_mode.read()
+    }
+
+    /// Set the paths scanned in addition to the workspace (`tarus.scan.extraPaths`)
+    pub fn set_scan_extra_paths(&self, paths: Vec<String>) {
+        *self.scan_extra_paths.write() = paths;
+    }
+
+    /// Get the paths scanned in addition to the workspace, as configured
+    pub fn scan_extra_paths(&self) -> Vec<String> {
+        self.scan_extra_paths.read().clone()
+    }
+
+    /// Set the directory whose files the "Create Rust command" quick fix offers first
+    /// (`tarus.commandDir`), absolute or relative to the workspace folder
+    pub fn set_command_dir(&self, dir: Option<String>) {
+        *self.command_dir.write() = dir;
+    }
+
+    /// Get the preferred directory for new commands, as configured
+    pub fn command_dir(&self) -> Option<String> {
+        self.command_dir.read().clone()
+    }
+
+    /// Set the maximum number of files collected by the workspace scan (`tarus.scan.maxFiles`)
+    pub fn set_scan_max_files(&self, max: usize) {
+        self.scan_max_files.store(max, Ordering::Relaxed);
+    }
+
+    /// Get the maximum number of files collected by the workspace scan
+    pub fn scan_max_files(&self) -> usize {
+        self.scan_max_files.load(Ordering::Relaxed)
+    }
+
+    /// Resolve the path a file is opened or scanned under to its index path.
+    ///
+    /// Symlinks and `..` are resolved on the first call for a path only; later calls, and
+    /// lookups through [`Self::index_path`], use the recorded result.
+    pub fn register_path(&self, path: &Path) -> PathBuf {
+        if let Some(canonical) = self.index_paths.get(path) {
+            return canonical.clone();
+        }
+
+        let canonical = crate::utils::canonical_path(path);
+        if canonical != path {
+            self.original_paths
+                .insert(canonical.clone(), path.to_path_buf());
+        }
+        self.index_paths
+            .insert(path.to_path_buf(), canonical.clone());
+        canonical
+    }
+
+    /// Index path of a registered path, without touching the filesystem; other paths are
+    /// returned unchanged
+    pub fn index_path(&self, path: &Path) -> PathBuf {
+        self.index_paths
+            .get(path)
+            .map_or_else(|| path.to_path_buf(), |canonical| canonical.clone())
+    }
+
+    /// Path the client knows an indexed file by, to publish results back under the URI it
+    /// was opened or scanned with
+    pub fn original_path(&self, path: &Path) -> PathBuf {
+        self.original_paths
+            .get(path)
+            .map_or_else(|| path.to_path_buf(), |original| original.clone())
+    }
+
+    /// Forget how a removed file's path resolved, so a file created there later is resolved
+    /// again
+    fn forget_path(&self, path: &Path) {
+        if let Some((_, canonical)) = self.index_paths.remove(path) {
+            self.original_paths
+                .remove_if(&canonical, |_, original| original == path);
+        }
+    }
+
+    /// Whether a file has an entry in the index (possibly without any findings)
+    pub fn is_indexed(&self, path: &Path) -> bool {
+        self.file_map.contains_key(&self.index_path(path))
+    }
+
+    /// Whether a frontend file declares or imports the type `name`
+    pub fn file_has_type(&self, path: &Path, name: &str) -> bool {
+        self.file_type_names
+            .get(&self.index_path(path))
+            .is_some_and(|names| names.iter().any(|n| n == name))
+    }
+
+    /// Whether a declaration file (`.d.ts`) declares or imports the type `name`, which may
+    /// then be an ambient type visible in every file
+    pub fn has_ambient_type(&self, name: &str) -> bool {
+        self.file_type_names.iter().any(|entry| {
+            is_declaration_file(entry.key()) && entry.value().iter().any(|n| n == name)
+        })
+    }
+
+    /// Whether any indexed file registers commands with `generate_handler!`
+    pub fn has_command_registrations(&self) -> bool {
+        !self.registered_commands.is_empty()
+    }
+
+    /// Whether command `name` is listed in some `generate_handler!`
+    pub fn is_command_registered(&self, name: &str) -> bool {
+        self.registered_commands
+            .iter()
+            .any(|entry| entry.value().iter().any(|n| n == name))
+    }
+
+    /// Whether the capability files permit invoking command `name`
+    ///
+    /// Only checked once some capability grants an `allow-<command>` permission: before that
+    /// the app declares no command permissions and Tauri allows every command. App
+    /// permission sets are not expanded, so a capability listing one permits any command.
+    pub fn is_command_permitted(&self, name: &str) -> bool {
+        let mut checked = false;
+        for entry in &self.command_permissions {
+            let permissions = entry.value();
+            if permissions.has_permission_sets || permissions.allowed.iter().any(|c| c == name) {
+                return true;
+            }
+            checked |= !permissions.allowed.is_empty();
+        }
+        !checked
+    }
+
     /// Get keys associated with a file path
     pub fn get_file_keys(&self, path: &Path) -> Vec<IndexKey> {
         self.file_map
-            .get(path)
+            .get(&self.index_path(path))
             .map(|keys| keys.value().clone())
             .unwrap_or_default()
     }
 
+    /// Rebuild the findings indexed for a file from its stored locations
+    pub fn get_file_findings(&self, path: &Path) -> Vec<Finding> {
+        let canonical = self.index_path(path);
+        let path = canonical.as_path();
+        let mut findings = Vec::new();
+        for key in self.get_file_keys(path) {
+            let Some(locations) = self.map.get(&key) else {
+                continue;
+            };
+            findings.extend(
+                locations
+                    .iter()
+                    .filter(|loc| loc.path == path)
+                    .map(|loc| Finding::from((key.clone(), loc.clone()))),
+            );
+        }
+        findings
+    }
+
     /// Get all indexed file paths (for iterating over the entire index)
     pub fn get_indexed_paths(&self) -> Vec<PathBuf> {
         self.file_map.iter().map(|e| e.key().clone()).collect()
     }
+}
+
+/// Whether `path` is a TypeScript declaration file (`types.d.ts`)
+fn is_declaration_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.to_lowercase().ends_with(".d.ts"))
+}
+
+/// Locations of one key, borrowed from the index (see [`ProjectIndex::get_locations_iter`])
+pub struct Locations<'a> {
+    indexed: Option<Ref<'a, IndexKey, Vec<LocationInfo>>>,
+    wrapper_calls: Vec<LocationInfo>,
+}
+
+impl Locations<'_> {
+    /// Indexed locations followed by calls through imported invoke wrappers
+    pub fn iter(&self) -> impl Iterator<Item = &LocationInfo> {
+        self.indexed
+            .iter()
+            .flat_map(|locs| locs.iter())
+            .chain(&self.wrapper_calls)
+    }
 
-    /// Get all locations for a given index key
-    pub fn get_locations_for_key(&self, key: &IndexKey) -> Vec<LocationInfo> {
-        self.map.get(key).map(|v| v.clone()).unwrap_or_default()
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.indexed.as_ref().map_or(0, |locs| locs.len()) + self.wrapper_calls.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 }
+
+/// The definition among `locations`, see [`ProjectIndex::get_definition`]
+pub(crate) fn definition_in<'a>(
+    entity: EntityType,
+    mut locations: impl Iterator<Item = &'a LocationInfo> + Clone,
+) -> Option<&'a LocationInfo> {
+    let definition = locations
+        .clone()
+        .find(|l| l.behavior == Behavior::Definition);
+    match entity {
+        EntityType::Command => definition,
+        EntityType::Event => {
+            definition.or_else(|| locations.find(|l| l.behavior == Behavior::Listen))
+        }
+    }
+}
+
+/// Distinct, sorted file paths of a set of locations
+pub(crate) fn distinct_paths<'a>(
+    locations: impl Iterator<Item = &'a LocationInfo>,
+) -> Vec<PathBuf> {
+    locations
+        .map(|loc| loc.path.clone())
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
@@ -2,15 +2,31 @@
 
 use super::types::IndexKey;
 use super::ProjectIndex;
+use crate::syntax::{Behavior, EntityType};
 use std::collections::HashMap;
 use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 
 use super::types::LocationInfo;
 
+/// Number of most-referenced commands and events listed in the summary report
+const REPORT_TOP_REFERENCED: usize = 5;
+
+/// Per-entity totals gathered for the summary report
+#[derive(Default)]
+struct EntitySummary {
+    names: usize,
+    definitions: usize,
+    references: usize,
+    /// `(name, reference count)` of names with at least one reference
+    referenced: Vec<(String, usize)>,
+}
+
 impl ProjectIndex {
     /// Generates a report only for a specific file (delta update)
     pub fn file_report(&self, path: &Path) -> String {
+        let canonical = self.index_path(path);
+        let path = canonical.as_path();
         let filename = path
             .file_name()
             .and_then(|s| s.to_str())
@@ -20,7 +36,7 @@ impl ProjectIndex {
 
         let _ = writeln!(report_message, "\n📝 === UPDATE REPORT: {filename} ===");
 
-        let Some(keys) = self.file_map.get(&path.to_path_buf()) else {
+        let Some(keys) = self.file_map.get(path) else {
             return format!("📝 File update: {filename:?} (No Tarus keys found)");
         };
 
@@ -57,8 +73,102 @@ impl ProjectIndex {
         report_message
     }
 
-    /// Creates a readable report of the index contents
+    /// Creates a short summary of the index: totals per entity type, the most-referenced
+    /// commands and events, orphan counts and the files that could not be indexed
     pub fn technical_report(&self) -> String {
+        let mut report = String::from("\n📊 === INDEX SUMMARY ===\n");
+        let _ = writeln!(
+            report,
+            "Files: {} indexed, {} failed",
+            self.file_map.len(),
+            self.parse_errors.len()
+        );
+
+        let mut commands = EntitySummary::default();
+        let mut events = EntitySummary::default();
+        let (mut never_invoked, mut undefined) = (0usize, 0usize);
+        let (mut never_emitted, mut never_listened) = (0usize, 0usize);
+
+        // Keys are collected first so no shard lock is held while reading locations
+        let keys: Vec<IndexKey> = self.map.iter().map(|e| e.key().clone()).collect();
+        for key in &keys {
+            let (definitions, references) = {
+                let locations = self.get_locations_iter(key.entity, &key.name);
+                let definitions = locations
+                    .iter()
+                    .filter(|l| l.behavior == Behavior::Definition)
+                    .count();
+                (definitions, locations.len() - definitions)
+            };
+
+            let info = self.get_diagnostic_info(key);
+            let summary = match key.entity {
+                EntityType::Command => {
+                    never_invoked += usize::from(info.has_definition() && !info.has_calls());
+                    undefined += usize::from(!info.has_definition() && info.has_calls());
+                    &mut commands
+                }
+                EntityType::Event => {
+                    never_emitted += usize::from(info.has_listeners() && !info.has_emitters());
+                    never_listened += usize::from(info.has_emitters() && !info.has_listeners());
+                    &mut events
+                }
+            };
+
+            summary.names += 1;
+            summary.definitions += definitions;
+            summary.references += references;
+            if references > 0 {
+                summary.referenced.push((key.name.clone(), references));
+            }
+        }
+
+        for (label, summary) in [("Commands", &mut commands), ("Events", &mut events)] {
+            let _ = writeln!(
+                report,
+                "{label}: {} ({} definitions, {} references)",
+                summary.names, summary.definitions, summary.references
+            );
+
+            summary
+                .referenced
+                .sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            if !summary.referenced.is_empty() {
+                let top: Vec<String> = summary
+                    .referenced
+                    .iter()
+                    .take(REPORT_TOP_REFERENCED)
+                    .map(|(name, count)| format!("{name} ({count})"))
+                    .collect();
+                let _ = writeln!(report, "  most referenced: {}", top.join(", "));
+            }
+        }
+
+        let _ = writeln!(
+            report,
+            "Orphans: {never_invoked} commands never invoked, {undefined} commands not defined, \
+             {never_emitted} events never emitted, {never_listened} events never listened for"
+        );
+
+        if !self.parse_errors.is_empty() {
+            let mut errors: Vec<String> = self
+                .parse_errors
+                .iter()
+                .map(|e| e.value().to_string())
+                .collect();
+            errors.sort();
+
+            report.push_str("Failed files:\n");
+            for error in errors {
+                let _ = writeln!(report, "  - {error}");
+            }
+        }
+
+        report
+    }
+
+    /// Creates a full dump of every key, location and file entry (developer mode)
+    pub fn full_technical_report(&self) -> String {
         let mut report_message = String::from("\n💾 === TECHNICAL INDEX DUMP ===\n");
 
         if self.map.is_empty() {
@@ -1,7 +1,11 @@
-//! Command schema, type alias, and event schema CRUD operations
+//! Command schema, type alias, event schema, and struct schema CRUD operations
 
-use super::types::{CommandSchema, EventSchema};
+use super::types::{
+    CommandSchema, EventSchema, GeneratorKind, ParamSchema, RustTypeAlias, StructSchema,
+};
 use super::ProjectIndex;
+use crate::rust_type_extractor::{expand_type_alias, rust_type_base_name, rust_type_to_ts};
+use crate::syntax::{Behavior, EntityType};
 use std::path::{Path, PathBuf};
 
 impl ProjectIndex {
@@ -25,25 +29,83 @@ impl ProjectIndex {
         }
     }
 
+    /// Command schemas stored for a specific file, as extracted (aliases unexpanded)
+    pub fn get_schemas_for_file(&self, path: &Path) -> Vec<CommandSchema> {
+        let Some(names) = self.generated_file_paths.get(path) else {
+            return Vec::new();
+        };
+        names
+            .iter()
+            .filter_map(|name| self.command_schemas.get(name))
+            .filter(|schema| schema.source_path == path)
+            .map(|schema| schema.clone())
+            .collect()
+    }
+
     /// Retrieve a command schema by command name
+    ///
+    /// Types of a schema extracted from Rust source that name an indexed Rust `type` alias
+    /// are expanded one level, so `ApiResult<User>` reads as `User`.
     pub fn get_schema(&self, name: &str) -> Option<CommandSchema> {
-        self.command_schemas.get(name).map(|v| v.clone())
+        let mut schema = self.command_schemas.get(name).map(|v| v.clone())?;
+        if schema.generator == GeneratorKind::RustSource && !self.rust_type_aliases.is_empty() {
+            // Unknown Rust types pass through `rust_type_to_ts` verbatim, so an alias use
+            // is still Rust syntax here
+            if let Some(expanded) = self.expand_rust_type_alias(&schema.return_type) {
+                schema.return_type = rust_type_to_ts(&expanded);
+            }
+            for param in &mut schema.params {
+                if let Some(expanded) = self.expand_rust_type_alias(&param.ts_type) {
+                    param.ts_type = rust_type_to_ts(&expanded);
+                }
+            }
+        }
+        Some(schema)
     }
 
     /// Store a type alias (name -> definition string)
+    ///
+    /// When several files declare the same name, the canonical definition wins no matter
+    /// which file was processed last: a `.d.ts` file first, then the first path.
     pub fn add_type_alias(&self, name: String, def: String, path: PathBuf) {
-        self.type_aliases.insert(name.clone(), def);
-        self.generated_alias_paths
-            .entry(path)
-            .or_default()
-            .push(name);
+        {
+            let mut aliases = self.generated_alias_paths.entry(path).or_default();
+            match aliases.iter_mut().find(|(n, _)| *n == name) {
+                Some(existing) => existing.1 = def,
+                None => aliases.push((name.clone(), def)),
+            }
+        }
+        self.refresh_type_alias(&name);
     }
 
-    /// Remove all type aliases associated with a specific file
+    /// Remove all type aliases associated with a specific file; names declared in another
+    /// file fall back to that file's definition
     pub fn remove_type_aliases_for_file(&self, path: &Path) {
-        if let Some((_, names)) = self.generated_alias_paths.remove(&path.to_path_buf()) {
-            for name in names {
-                self.type_aliases.remove(&name);
+        if let Some((_, aliases)) = self.generated_alias_paths.remove(&path.to_path_buf()) {
+            for (name, _) in aliases {
+                self.refresh_type_alias(&name);
+            }
+        }
+    }
+
+    /// Recompute the stored definition of `name` from the files declaring it
+    fn refresh_type_alias(&self, name: &str) {
+        let canonical = self
+            .generated_alias_paths
+            .iter()
+            .filter_map(|entry| {
+                let (_, def) = entry.value().iter().find(|(n, _)| n == name)?;
+                Some((alias_source_rank(entry.key()), def.clone()))
+            })
+            .min_by(|a, b| a.0.cmp(&b.0))
+            .map(|(_, def)| def);
+
+        match canonical {
+            Some(def) => {
+                self.type_aliases.insert(name.to_string(), def);
+            }
+            None => {
+                self.type_aliases.remove(name);
             }
         }
     }
@@ -68,10 +130,224 @@ impl ProjectIndex {
         }
     }
 
+    /// Event schemas stored for a specific file
+    pub fn get_event_schemas_for_file(&self, path: &Path) -> Vec<EventSchema> {
+        let Some(names) = self.generated_event_paths.get(path) else {
+            return Vec::new();
+        };
+        names
+            .iter()
+            .filter_map(|name| self.event_schemas.get(name))
+            .filter(|schema| schema.source_path == path)
+            .map(|schema| schema.clone())
+            .collect()
+    }
+
     /// Retrieve an event schema by event name
     pub fn get_event_schema(&self, name: &str) -> Option<EventSchema> {
         self.event_schemas.get(name).map(|v| v.clone())
     }
+
+    /// Payload type of an event: from its schema, or else from the first listener that
+    /// declares one (`listen<T>()` in the frontend, `payload::<T>()` in Rust).
+    ///
+    /// `None` when nothing declares a type or the payload is `void`/`null`.
+    pub fn event_payload_type(&self, name: &str) -> Option<String> {
+        let declared = match self.get_event_schema(name) {
+            Some(schema) => Some(schema.payload_type),
+            None => self
+                .get_locations_iter(EntityType::Event, name)
+                .iter()
+                .filter(|loc| loc.behavior == Behavior::Listen)
+                .find_map(|loc| {
+                    if loc.path.extension().is_some_and(|ext| ext == "rs") {
+                        loc.payload_type.clone()
+                    } else {
+                        loc.return_type.clone().filter(|t| t != "any")
+                    }
+                }),
+        };
+
+        declared.filter(|t| t != "void" && t != "null")
+    }
+
+    /// Store a Rust struct layout (replaces any existing struct with the same name in the
+    /// same file)
+    ///
+    /// Structs of the same name declared in different files are all kept, ordered by path.
+    pub fn add_struct_schema(&self, schema: StructSchema) {
+        let path = schema.source_path.clone();
+        let name = schema.struct_name.clone();
+        {
+            let mut schemas = self.struct_schemas.entry(name.clone()).or_default();
+            match schemas.iter_mut().find(|s| s.source_path == path) {
+                Some(existing) => *existing = schema,
+                None => {
+                    let at = schemas.partition_point(|s| s.source_path < path);
+                    schemas.insert(at, schema);
+                }
+            }
+        }
+        let mut names = self.struct_schema_paths.entry(path).or_default();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    /// Remove all struct layouts associated with a specific file; structs of the same name
+    /// in other files are kept
+    pub fn remove_struct_schemas_for_file(&self, path: &Path) {
+        if let Some((_, names)) = self.struct_schema_paths.remove(&path.to_path_buf()) {
+            for name in names {
+                self.struct_schemas.remove_if_mut(&name, |_, schemas| {
+                    schemas.retain(|s| s.source_path != path);
+                    schemas.is_empty()
+                });
+            }
+        }
+    }
+
+    /// Struct layouts declared in a specific file
+    pub fn get_struct_schemas_for_file(&self, path: &Path) -> Vec<StructSchema> {
+        let Some(names) = self.struct_schema_paths.get(path) else {
+            return Vec::new();
+        };
+        names
+            .iter()
+            .filter_map(|name| self.struct_schemas.get(name))
+            .filter_map(|schemas| schemas.iter().find(|s| s.source_path == path).cloned())
+            .collect()
+    }
+
+    /// Retrieve a Rust struct layout by struct name (the first by path when several files
+    /// declare it)
+    pub fn get_struct_schema(&self, name: &str) -> Option<StructSchema> {
+        self.struct_schemas
+            .get(name)
+            .and_then(|v| v.first().cloned())
+    }
+
+    /// Retrieve every Rust struct layout with the given name, ordered by path
+    pub fn get_struct_schemas(&self, name: &str) -> Vec<StructSchema> {
+        self.struct_schemas
+            .get(name)
+            .map(|v| v.clone())
+            .unwrap_or_default()
+    }
+
+    /// Store a Rust `type` alias (replaces any existing alias with the same name in the
+    /// same file)
+    ///
+    /// Aliases of the same name declared in different files are all kept, ordered by path.
+    pub fn add_rust_type_alias(&self, alias: RustTypeAlias) {
+        let path = alias.source_path.clone();
+        let name = alias.name.clone();
+        {
+            let mut aliases = self.rust_type_aliases.entry(name.clone()).or_default();
+            match aliases.iter_mut().find(|a| a.source_path == path) {
+                Some(existing) => *existing = alias,
+                None => {
+                    let at = aliases.partition_point(|a| a.source_path < path);
+                    aliases.insert(at, alias);
+                }
+            }
+        }
+        let mut names = self.rust_type_alias_paths.entry(path).or_default();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    /// Remove all Rust `type` aliases associated with a specific file; aliases of the same
+    /// name in other files are kept
+    pub fn remove_rust_type_aliases_for_file(&self, path: &Path) {
+        if let Some((_, names)) = self.rust_type_alias_paths.remove(&path.to_path_buf()) {
+            for name in names {
+                self.rust_type_aliases.remove_if_mut(&name, |_, aliases| {
+                    aliases.retain(|a| a.source_path != path);
+                    aliases.is_empty()
+                });
+            }
+        }
+    }
+
+    /// Rust `type` aliases declared in a specific file
+    pub fn get_rust_type_aliases_for_file(&self, path: &Path) -> Vec<RustTypeAlias> {
+        let Some(names) = self.rust_type_alias_paths.get(path) else {
+            return Vec::new();
+        };
+        names
+            .iter()
+            .filter_map(|name| self.rust_type_aliases.get(name))
+            .filter_map(|aliases| aliases.iter().find(|a| a.source_path == path).cloned())
+            .collect()
+    }
+
+    /// Expand `rust_type` by one level if it names an indexed Rust `type` alias
+    /// (`ApiResult<User>` → `Result<User, AppError>`)
+    ///
+    /// When several files declare the alias, the first by path is used.
+    pub fn expand_rust_type_alias(&self, rust_type: &str) -> Option<String> {
+        let aliases = self.rust_type_aliases.get(rust_type_base_name(rust_type))?;
+        expand_type_alias(rust_type, aliases.first()?)
+    }
+
+    /// Fields of `schema` as serde serializes them: `#[serde(rename)]` and `rename_all` are
+    /// applied to field names, and each `#[serde(flatten)]` field is replaced by the fields of
+    /// its struct, recursively.
+    ///
+    /// Also returns the flattened types that are not indexed, whose fields are unknown.
+    pub fn serialized_struct_fields(
+        &self,
+        schema: &StructSchema,
+    ) -> (Vec<ParamSchema>, Vec<String>) {
+        let mut fields = Vec::new();
+        let mut unresolved = Vec::new();
+        let mut visited = vec![schema.struct_name.clone()];
+        self.collect_serialized_fields(schema, &mut visited, &mut fields, &mut unresolved);
+        (fields, unresolved)
+    }
+
+    fn collect_serialized_fields(
+        &self,
+        schema: &StructSchema,
+        visited: &mut Vec<String>,
+        fields: &mut Vec<ParamSchema>,
+        unresolved: &mut Vec<String>,
+    ) {
+        for field in &schema.fields {
+            if !schema.flattened.contains(&field.name) {
+                fields.push(ParamSchema {
+                    name: schema.serialized_name(field),
+                    ..field.clone()
+                });
+                continue;
+            }
+
+            // `Option<Base>` maps to `Base | null`; the flattened fields are those of `Base`
+            let type_name = field.ts_type.split('|').next().unwrap_or_default().trim();
+            if visited.iter().any(|v| v == type_name) {
+                continue;
+            }
+            match self.get_struct_schema(type_name) {
+                Some(inner) => {
+                    visited.push(type_name.to_string());
+                    self.collect_serialized_fields(&inner, visited, fields, unresolved);
+                }
+                None => unresolved.push(type_name.to_string()),
+            }
+        }
+    }
+}
+
+/// Order of precedence between files declaring the same type: declaration files
+/// (`.d.ts`) first, then by path
+fn alias_source_rank(path: &Path) -> (bool, PathBuf) {
+    let is_declaration = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.ends_with(".d.ts"));
+    (!is_declaration, path.to_path_buf())
 }
 
 #[cfg(test)]
@@ -102,4 +378,115 @@ mod tests {
             "Alias should be removed"
         );
     }
+
+    #[test]
+    fn test_type_alias_conflict_prefers_declaration_file() {
+        let local = PathBuf::from("src/bindings/User.ts");
+        let declaration = PathBuf::from("src/types/api.d.ts");
+
+        for order in [[&local, &declaration], [&declaration, &local]] {
+            let index = ProjectIndex::new();
+            for path in order {
+                let def = if *path == declaration {
+                    "{ id: string }"
+                } else {
+                    "{ id: number }"
+                };
+                index.add_type_alias("User".to_string(), def.to_string(), path.clone());
+            }
+
+            assert_eq!(
+                index.type_aliases.get("User").map(|v| v.clone()),
+                Some("{ id: string }".to_string())
+            );
+
+            index.remove_type_aliases_for_file(&declaration);
+            assert_eq!(
+                index.type_aliases.get("User").map(|v| v.clone()),
+                Some("{ id: number }".to_string()),
+                "Removing the canonical file falls back to the remaining one"
+            );
+        }
+    }
+
+    fn struct_schema(name: &str, path: &str) -> StructSchema {
+        StructSchema {
+            struct_name: name.to_string(),
+            fields: Vec::new(),
+            source_path: PathBuf::from(path),
+            range: tower_lsp_server::lsp_types::Range::default(),
+            name_range: tower_lsp_server::lsp_types::Range::default(),
+            field_ranges: Vec::new(),
+            flattened: Vec::new(),
+            rename_all: None,
+            renamed: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_struct_schemas_same_name_in_two_files() {
+        let index = ProjectIndex::new();
+        index.add_struct_schema(struct_schema("User", "src/models.rs"));
+        index.add_struct_schema(struct_schema("User", "src/admin.rs"));
+
+        let paths: Vec<_> = index
+            .get_struct_schemas("User")
+            .into_iter()
+            .map(|s| s.source_path)
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                PathBuf::from("src/admin.rs"),
+                PathBuf::from("src/models.rs")
+            ]
+        );
+
+        index.remove_struct_schemas_for_file(Path::new("src/admin.rs"));
+        assert_eq!(
+            index.get_struct_schema("User").map(|s| s.source_path),
+            Some(PathBuf::from("src/models.rs")),
+            "Removing one file keeps the struct declared in the other"
+        );
+
+        index.remove_struct_schemas_for_file(Path::new("src/models.rs"));
+        assert!(!index.struct_schemas.contains_key("User"));
+    }
+
+    fn rust_type_alias(name: &str, target: &str, path: &str) -> RustTypeAlias {
+        RustTypeAlias {
+            name: name.to_string(),
+            generics: vec!["T".to_string()],
+            target: target.to_string(),
+            source_path: PathBuf::from(path),
+            range: tower_lsp_server::lsp_types::Range::default(),
+        }
+    }
+
+    #[test]
+    fn test_rust_type_aliases_same_name_in_two_files() {
+        let index = ProjectIndex::new();
+        index.add_rust_type_alias(rust_type_alias(
+            "ApiResult",
+            "Result<T, ApiError>",
+            "src/api.rs",
+        ));
+        index.add_rust_type_alias(rust_type_alias(
+            "ApiResult",
+            "Result<T, DbError>",
+            "src/db.rs",
+        ));
+
+        assert_eq!(
+            index.expand_rust_type_alias("ApiResult<User>"),
+            Some("Result<User, ApiError>".to_string())
+        );
+
+        index.remove_rust_type_aliases_for_file(Path::new("src/api.rs"));
+        assert_eq!(
+            index.expand_rust_type_alias("ApiResult<User>"),
+            Some("Result<User, DbError>".to_string()),
+            "Removing one file keeps the alias declared in the other"
+        );
+    }
 }
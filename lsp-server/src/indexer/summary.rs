@@ -0,0 +1,60 @@
+//! Aggregated per-command views for docs and UIs
+
+use super::types::{CommandSummary, ParamSchema};
+use super::ProjectIndex;
+use crate::syntax::{Behavior, EntityType};
+
+impl ProjectIndex {
+    /// Every indexed command with its signature, definition file and call count, sorted by
+    /// name.
+    ///
+    /// Parameter names are the ones the frontend passes (see
+    /// [`CommandSchema::frontend_param_name`](super::CommandSchema::frontend_param_name)).
+    /// Params and return type are empty when no schema is known for the command. The
+    /// definition path prefers a Rust definition over a frontend-declared one.
+    #[must_use]
+    pub fn commands_summary(&self) -> Vec<CommandSummary> {
+        let mut summaries: Vec<CommandSummary> = self
+            .map
+            .iter()
+            .filter(|e| e.key().entity == EntityType::Command)
+            .map(|e| {
+                let locations = e.value();
+                let definition_path = locations
+                    .iter()
+                    .filter(|l| l.behavior == Behavior::Definition)
+                    .min_by_key(|l| (!l.path.extension().is_some_and(|ext| ext == "rs"), &l.path))
+                    .map(|l| l.path.clone());
+                let call_count = locations
+                    .iter()
+                    .filter(|l| matches!(l.behavior, Behavior::Call | Behavior::SpectaCall))
+                    .count();
+
+                CommandSummary {
+                    name: e.key().name.clone(),
+                    params: Vec::new(),
+                    return_type: None,
+                    definition_path,
+                    call_count,
+                }
+            })
+            .collect();
+
+        for summary in &mut summaries {
+            if let Some(schema) = self.get_schema(&summary.name) {
+                summary.params = schema
+                    .params
+                    .iter()
+                    .map(|p| ParamSchema {
+                        name: schema.frontend_param_name(p),
+                        ..p.clone()
+                    })
+                    .collect();
+                summary.return_type = Some(schema.return_type);
+            }
+        }
+
+        summaries.sort_by(|a, b| a.name.cmp(&b.name));
+        summaries
+    }
+}
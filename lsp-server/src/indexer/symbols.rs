@@ -2,7 +2,8 @@
 
 use crate::syntax::{Behavior, EntityType};
 use std::path::Path;
-use tower_lsp_server::lsp_types::{Location, SymbolInformation, SymbolKind, Uri};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tower_lsp_server::lsp_types::{DocumentSymbol, Location, SymbolInformation, SymbolKind, Uri};
 use tower_lsp_server::UriExt;
 
 use super::types::{IndexKey, LocationInfo};
@@ -41,7 +42,16 @@ fn make_symbol_info(key: &IndexKey, loc: &LocationInfo, uri: Uri) -> SymbolInfor
 
 impl ProjectIndex {
     /// Get document symbols for outline view
+    ///
+    /// Symbols only depend on the file's own entries, so they are cached per file until it
+    /// is re-indexed or removed.
     pub fn get_document_symbols(&self, path: &Path) -> Vec<SymbolInformation> {
+        let canonical = self.index_path(path);
+        let path = canonical.as_path();
+        if let Some(cached) = self.document_symbols_cache.get(path) {
+            return cached.clone();
+        }
+
         let mut symbols = Vec::new();
 
         let Some(keys) = self.file_map.get(&path.to_path_buf()) else {
@@ -61,30 +71,173 @@ impl ProjectIndex {
         }
 
         symbols.sort_by_key(|s| s.location.range.start.line);
+        self.document_symbols_cache
+            .insert(path.to_path_buf(), symbols.clone());
+        symbols
+    }
+
+    /// Get nested document symbols for outline view
+    ///
+    /// Commands and events are leaves; Rust structs contain their fields as children.
+    #[allow(deprecated)]
+    pub fn get_document_symbols_hierarchical(&self, path: &Path) -> Vec<DocumentSymbol> {
+        let mut symbols: Vec<DocumentSymbol> = self
+            .get_document_symbols(path)
+            .into_iter()
+            .map(|info| DocumentSymbol {
+                name: info.name,
+                detail: info.container_name,
+                kind: info.kind,
+                tags: None,
+                deprecated: None,
+                range: info.location.range,
+                selection_range: info.location.range,
+                children: None,
+            })
+            .collect();
+
+        let struct_names = self
+            .struct_schema_paths
+            .get(path)
+            .map(|names| names.value().clone())
+            .unwrap_or_default();
+
+        for schema in struct_names.iter().flat_map(|n| self.get_struct_schemas(n)) {
+            if schema.source_path != path {
+                continue;
+            }
+
+            let fields = schema
+                .fields
+                .iter()
+                .zip(&schema.field_ranges)
+                .map(|(field, range)| DocumentSymbol {
+                    name: field.name.clone(),
+                    detail: Some(field.ts_type.clone()),
+                    kind: SymbolKind::FIELD,
+                    tags: None,
+                    deprecated: None,
+                    range: *range,
+                    selection_range: *range,
+                    children: None,
+                })
+                .collect();
+
+            symbols.push(DocumentSymbol {
+                name: schema.struct_name,
+                detail: None,
+                kind: SymbolKind::STRUCT,
+                tags: None,
+                deprecated: None,
+                range: schema.range,
+                selection_range: schema.name_range,
+                children: Some(fields),
+            });
+        }
+
+        symbols.sort_by_key(|s| s.range.start);
         symbols
     }
 
     /// Search workspace symbols by query (Ctrl+T)
-    pub fn search_workspace_symbols(&self, query: &str) -> Vec<SymbolInformation> {
-        let mut symbols = Vec::new();
+    ///
+    /// Names match when the query is a case-insensitive subsequence of them; results are
+    /// ranked by [`fuzzy_score`] (exact, then prefix matches first) before the first 100
+    /// are kept.
+    ///
+    /// `cancelled` is checked once per key; returns `None` as soon as it is set, so a
+    /// superseded search stops scanning the index.
+    pub fn search_workspace_symbols(
+        &self,
+        query: &str,
+        cancelled: &AtomicBool,
+    ) -> Option<Vec<SymbolInformation>> {
+        let mut ranked = Vec::new();
         let query_lower = query.to_lowercase();
 
         for entry in &self.map {
+            if cancelled.load(Ordering::Relaxed) {
+                return None;
+            }
+
             let key = entry.key();
 
-            if !query.is_empty() && !key.name.to_lowercase().contains(&query_lower) {
+            let Some(score) = fuzzy_score(&query_lower, &key.name) else {
                 continue;
-            }
+            };
 
             for loc in entry.value() {
                 let Some(uri) = Uri::from_file_path(&loc.path) else {
                     continue;
                 };
-                symbols.push(make_symbol_info(key, loc, uri));
+                ranked.push((score, make_symbol_info(key, loc, uri)));
             }
         }
 
-        symbols.truncate(100);
-        symbols
+        // Highest score first; ties by name so the order does not depend on map iteration
+        ranked.sort_by(|(a_score, a), (b_score, b)| {
+            b_score.cmp(a_score).then_with(|| a.name.cmp(&b.name))
+        });
+        ranked.truncate(100);
+        Some(ranked.into_iter().map(|(_, symbol)| symbol).collect())
     }
 }
+
+/// Score of `name` for the lowercase `query`, or `None` when the query is not a
+/// subsequence of the name (case-insensitive).
+///
+/// Exact matches score highest, then prefix matches; other matches earn points per
+/// character, with bonuses for runs of consecutive characters and for characters that
+/// start a word (`get_user` beats `arguments_user` for `gu`).
+fn fuzzy_score(query: &str, name: &str) -> Option<u32> {
+    const EXACT: u32 = 10_000;
+    const PREFIX: u32 = 5_000;
+    const CONSECUTIVE: u32 = 5;
+    const WORD_START: u32 = 8;
+
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let name_lower = name.to_lowercase();
+    if name_lower == query {
+        return Some(EXACT);
+    }
+
+    let chars: Vec<char> = name.chars().collect();
+    let mut score = if name_lower.starts_with(query) {
+        PREFIX
+    } else {
+        0
+    };
+    let mut previous: Option<usize> = None;
+    let mut start = 0;
+
+    for q in query.chars() {
+        let index = (start..chars.len()).find(|&i| chars[i].to_lowercase().eq(q.to_lowercase()))?;
+
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == index) {
+            score += CONSECUTIVE;
+        }
+        if is_word_start(&chars, index) {
+            score += WORD_START;
+        }
+
+        previous = Some(index);
+        start = index + 1;
+    }
+
+    Some(score)
+}
+
+/// Whether `chars[index]` starts a word: the first character, one after a separator
+/// (`_`, `-`, `:`, `.`, `/`) or an uppercase letter after a lowercase one.
+fn is_word_start(chars: &[char], index: usize) -> bool {
+    let Some(before) = index.checked_sub(1).map(|i| chars[i]) else {
+        return true;
+    };
+
+    matches!(before, '_' | '-' | ':' | '.' | '/')
+        || (before.is_lowercase() && chars[index].is_uppercase())
+}
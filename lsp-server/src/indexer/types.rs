@@ -1,7 +1,8 @@
 //! Core type definitions for the project index
 
+use crate::permissions::CommandPermissions;
 use crate::syntax::{Behavior, EntityType};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tower_lsp_server::lsp_types::{Position, Range};
 
 /// Which tool generated the binding file (or the source itself)
@@ -28,6 +29,8 @@ pub struct DiscoveredGenerator {
 pub struct ParamSchema {
     pub name: String,
     pub ts_type: String,
+    /// The frontend may omit the argument: an `Option<T>` in Rust, `name?:` in bindings
+    pub optional: bool,
 }
 
 /// Type signature of a Tauri event payload, extracted from bindings or Rust source
@@ -47,6 +50,264 @@ pub struct CommandSchema {
     pub return_type: String,
     pub source_path: PathBuf,
     pub generator: GeneratorKind,
+    /// `rename_all` of `#[tauri::command(...)]` (Rust source only)
+    pub rename_all: Option<String>,
+}
+
+impl CommandSchema {
+    /// Argument key the frontend passes for `param`.
+    ///
+    /// Bindings already use frontend names; Rust parameter names are converted to camelCase
+    /// unless the command declares `rename_all = "snake_case"`.
+    #[must_use]
+    pub fn frontend_param_name(&self, param: &ParamSchema) -> String {
+        if self.generator != GeneratorKind::RustSource
+            || self.rename_all.as_deref() == Some("snake_case")
+        {
+            return param.name.clone();
+        }
+
+        crate::utils::snake_to_camel(&param.name)
+    }
+
+    /// Frontend argument fields, e.g. `["name: string", "nickname?: string | null"]`
+    #[must_use]
+    pub fn frontend_arg_fields(&self) -> Vec<String> {
+        self.params
+            .iter()
+            .map(|p| {
+                let marker = if p.optional { "?" } else { "" };
+                format!("{}{marker}: {}", self.frontend_param_name(p), p.ts_type)
+            })
+            .collect()
+    }
+}
+
+/// One command as seen across the index (see `ProjectIndex::commands_summary`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandSummary {
+    pub name: String,
+    /// Parameters under their frontend argument names
+    pub params: Vec<ParamSchema>,
+    /// TypeScript return type, when a schema is known
+    pub return_type: Option<String>,
+    pub definition_path: Option<PathBuf>,
+    /// Number of `invoke` and typed bindings calls
+    pub call_count: usize,
+}
+
+/// Which files receive diagnostics (`tarus.diagnostics.scope`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiagnosticsScope {
+    /// Only files currently open in the editor
+    #[default]
+    OpenFiles,
+    /// Every indexed file in the workspace
+    Workspace,
+}
+
+impl DiagnosticsScope {
+    /// Parse the setting value (`"openFiles"` or `"workspace"`)
+    #[must_use]
+    pub fn from_setting(value: &str) -> Option<Self> {
+        match value {
+            "openFiles" => Some(Self::OpenFiles),
+            "workspace" => Some(Self::Workspace),
+            _ => None,
+        }
+    }
+}
+
+/// Which locations get code lenses (`tarus.codeLens.mode`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CodeLensMode {
+    /// Definitions and every call site
+    #[default]
+    All,
+    /// Only `Behavior::Definition` locations (Rust commands and events)
+    DefinitionsOnly,
+    Off,
+}
+
+impl CodeLensMode {
+    /// Parse the setting value (`"all"`, `"definitionsOnly"` or `"off"`)
+    #[must_use]
+    pub fn from_setting(value: &str) -> Option<Self> {
+        match value {
+            "all" => Some(Self::All),
+            "definitionsOnly" => Some(Self::DefinitionsOnly),
+            "off" => Some(Self::Off),
+            _ => None,
+        }
+    }
+}
+
+/// When frontend files are indexed (`tarus.indexing.mode`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndexingMode {
+    /// Every workspace file is indexed on startup
+    #[default]
+    Eager,
+    /// Startup indexes Rust sources and generated bindings only; frontend files are indexed
+    /// when opened, so references and code lenses miss calls in files not opened yet
+    Lazy,
+}
+
+impl IndexingMode {
+    /// Parse the setting value (`"eager"` or `"lazy"`)
+    #[must_use]
+    pub fn from_setting(value: &str) -> Option<Self> {
+        match value {
+            "eager" => Some(Self::Eager),
+            "lazy" => Some(Self::Lazy),
+            _ => None,
+        }
+    }
+}
+
+/// Required casing for event names (`tarus.events.namingConvention`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventNamingConvention {
+    #[default]
+    Off,
+    Kebab,
+    Snake,
+    Camel,
+}
+
+impl EventNamingConvention {
+    /// Parse the setting value (`"off"`, `"kebab"`, `"snake"` or `"camel"`)
+    #[must_use]
+    pub fn from_setting(value: &str) -> Option<Self> {
+        match value {
+            "off" => Some(Self::Off),
+            "kebab" => Some(Self::Kebab),
+            "snake" => Some(Self::Snake),
+            "camel" => Some(Self::Camel),
+            _ => None,
+        }
+    }
+
+    /// Human-readable name used in diagnostics
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Kebab => "kebab-case",
+            Self::Snake => "snake_case",
+            Self::Camel => "camelCase",
+        }
+    }
+
+    /// The conforming spelling of `name`, or `None` if it already conforms (or checking is off).
+    ///
+    /// `:` and `/` are treated as namespace separators and kept as-is
+    /// (`app:userUpdated` → `app:user-updated`).
+    #[must_use]
+    pub fn suggest(self, name: &str) -> Option<String> {
+        if self == Self::Off {
+            return None;
+        }
+
+        let mut converted = String::with_capacity(name.len());
+        let mut segment = String::new();
+        for ch in name.chars().chain(std::iter::once('\0')) {
+            if matches!(ch, ':' | '/' | '\0') {
+                converted.push_str(&self.convert_segment(&segment));
+                if ch != '\0' {
+                    converted.push(ch);
+                }
+                segment.clear();
+            } else {
+                segment.push(ch);
+            }
+        }
+
+        (converted != name).then_some(converted)
+    }
+
+    fn convert_segment(self, segment: &str) -> String {
+        let words = crate::utils::split_words(segment);
+        match self {
+            Self::Off => segment.to_string(),
+            Self::Kebab => words.join("-"),
+            Self::Snake => words.join("_"),
+            Self::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    let mut chars = word.chars();
+                    match chars.next() {
+                        Some(first) if i > 0 => {
+                            first.to_ascii_uppercase().to_string() + chars.as_str()
+                        }
+                        _ => word.clone(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Field layout of a Rust struct, with field types mapped to TypeScript
+#[derive(Debug, Clone)]
+pub struct StructSchema {
+    pub struct_name: String,
+    pub fields: Vec<ParamSchema>,
+    pub source_path: PathBuf,
+    /// Range of the whole `struct` item
+    pub range: Range,
+    /// Range of the struct name
+    pub name_range: Range,
+    /// Range of each field declaration, parallel to `fields`
+    pub field_ranges: Vec<Range>,
+    /// Names of the `#[serde(flatten)]` fields, whose type's fields are serialized inline
+    pub flattened: Vec<String>,
+    /// `rename_all` of the struct's `#[serde(...)]` attribute (`"camelCase"`)
+    pub rename_all: Option<String>,
+    /// Field name and serialized name of each `#[serde(rename = "...")]` field
+    pub renamed: Vec<(String, String)>,
+}
+
+impl StructSchema {
+    /// Name serde serializes `field` under: its `#[serde(rename)]`, or else the struct's
+    /// `rename_all` applied to the field name.
+    #[must_use]
+    pub fn serialized_name(&self, field: &ParamSchema) -> String {
+        if let Some((_, renamed)) = self.renamed.iter().find(|(name, _)| *name == field.name) {
+            return renamed.clone();
+        }
+        match &self.rename_all {
+            Some(rule) => crate::rust_attr::apply_rename_all(&field.name, rule),
+            None => field.name.clone(),
+        }
+    }
+}
+
+/// A Rust `type` alias, e.g. `type ApiResult<T> = Result<T, AppError>;`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustTypeAlias {
+    pub name: String,
+    /// Generic parameter names in declaration order (`["T"]`)
+    pub generics: Vec<String>,
+    /// Aliased Rust type as written (`Result<T, AppError>`)
+    pub target: String,
+    pub source_path: PathBuf,
+    /// Range of the whole `type` item
+    pub range: Range,
+}
+
+/// Data for swapping a frontend `listen()` call to `once()` or back
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListenToggle {
+    /// Range of the callee identifier (`listen` or its local alias)
+    pub callee_range: Range,
+    /// The counterpart API: `"once"` for a `listen` call, `"listen"` for a `once` call
+    pub target_api: String,
+    /// Local name of the counterpart when it is already imported (possibly aliased)
+    pub target_local: Option<String>,
+    /// Where to add the counterpart to the callee's named import when it isn't imported yet
+    pub import_insert: Option<Position>,
 }
 
 /// A single occurrence in a file (parser result)
@@ -62,6 +323,16 @@ pub struct Finding {
     pub call_name_end: Option<Position>,       // End of "invoke" identifier (for inserting <T>)
     pub type_arg_range: Option<Range>,         // Range of <T> in invoke<T>() (for replacing)
     pub codegen_origin: Option<GeneratorKind>, // Set when call site is from typed codegen (e.g. specta events API)
+    pub payload_type: Option<String>,          // For Rust listen: type in `payload::<T>()`
+    pub call_arg_variable: Option<String>,     // For Call: unresolved variable passed as args
+    pub statement_start: Option<Position>,     // Start of the enclosing statement (lens anchor)
+    pub listen_toggle: Option<ListenToggle>,   // For frontend listen/once: callee swap data
+    pub wrapper_name: Option<String>,          // For Call through a local invoke wrapper
+    pub doc: Option<String>,                   // For Rust Definition: `///` doc comment text
+    pub test_only: bool,                       // Definition inside #[cfg(test)] code
+    pub cfg: Option<String>,                   // For Rust Definition: `#[cfg(...)]` predicate
+    pub error_type: Option<String>,            // For Rust Definition: `E` of `Result<T, E>`, as TS
+    pub unawaited: bool, // For frontend Call: result dropped by a bare statement
 }
 
 impl Finding {
@@ -79,39 +350,233 @@ impl Finding {
             call_name_end: None,
             type_arg_range: None,
             codegen_origin: None,
+            payload_type: None,
+            call_arg_variable: None,
+            statement_start: None,
+            listen_toggle: None,
+            wrapper_name: None,
+            doc: None,
+            test_only: false,
+            cfg: None,
+            error_type: None,
+            unawaited: false,
         }
     }
 }
 
-impl From<(&PathBuf, Finding)> for LocationInfo {
-    fn from((path, f): (&PathBuf, Finding)) -> Self {
+/// The one place a [`Finding`] becomes a [`LocationInfo`].
+///
+/// The finding is destructured without `..`, so a field added to `Finding` fails to compile
+/// here until it is carried over (or explicitly ignored).
+impl From<(&Path, Finding)> for LocationInfo {
+    fn from((path, finding): (&Path, Finding)) -> Self {
+        let Finding {
+            // Stored as the `IndexKey` the location is filed under
+            key: _,
+            entity: _,
+            behavior,
+            range,
+            call_arg_count,
+            call_param_keys,
+            return_type,
+            call_name_end,
+            type_arg_range,
+            codegen_origin,
+            payload_type,
+            call_arg_variable,
+            statement_start,
+            listen_toggle,
+            wrapper_name,
+            doc,
+            test_only,
+            cfg,
+            error_type,
+            unawaited,
+        } = finding;
+
         Self {
-            path: path.clone(),
-            range: f.range,
-            behavior: f.behavior,
-            call_arg_count: f.call_arg_count,
-            call_param_keys: f.call_param_keys,
-            return_type: f.return_type,
-            call_name_end: f.call_name_end,
-            type_arg_range: f.type_arg_range,
-            codegen_origin: f.codegen_origin,
+            path: path.to_path_buf(),
+            range,
+            behavior,
+            call_arg_count,
+            call_param_keys,
+            return_type,
+            call_name_end,
+            type_arg_range,
+            codegen_origin,
+            payload_type,
+            call_arg_variable,
+            statement_start,
+            listen_toggle,
+            wrapper_name,
+            doc,
+            test_only,
+            cfg,
+            error_type,
+            unawaited,
         }
     }
 }
 
-#[derive(Debug)]
+/// Rebuild the [`Finding`] a stored location came from, filed under `key`.
+impl From<(IndexKey, LocationInfo)> for Finding {
+    fn from((key, location): (IndexKey, LocationInfo)) -> Self {
+        let LocationInfo {
+            path: _,
+            range,
+            behavior,
+            call_arg_count,
+            call_param_keys,
+            return_type,
+            call_name_end,
+            type_arg_range,
+            codegen_origin,
+            payload_type,
+            call_arg_variable,
+            statement_start,
+            listen_toggle,
+            wrapper_name,
+            doc,
+            test_only,
+            cfg,
+            error_type,
+            unawaited,
+        } = location;
+
+        Self {
+            key: key.name,
+            entity: key.entity,
+            behavior,
+            range,
+            call_arg_count,
+            call_param_keys,
+            return_type,
+            call_name_end,
+            type_arg_range,
+            codegen_origin,
+            payload_type,
+            call_arg_variable,
+            statement_start,
+            listen_toggle,
+            wrapper_name,
+            doc,
+            test_only,
+            cfg,
+            error_type,
+            unawaited,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
 pub struct FileIndex {
     pub path: PathBuf,
     pub findings: Vec<Finding>,
+    pub wrappers: WrapperLinks,
+    /// Frontend types declared or imported in the file
+    pub type_names: Vec<String>,
+    /// Commands listed in the file's `generate_handler![...]` invocations (Rust)
+    pub registered_commands: Vec<String>,
+    /// App command permissions granted by the file (capability JSON)
+    pub command_permissions: Option<CommandPermissions>,
+    /// Range of the first syntax error tree-sitter recovered from (frontend)
+    pub syntax_error: Option<Range>,
+    /// Tauri API calls whose name is built at runtime (frontend)
+    pub dynamic_names: Vec<DynamicName>,
+}
+
+/// A Tauri API call whose command or event name is a template literal with substitutions,
+/// e.g. ``emit(`file-${id}`)``, so it cannot be linked to a name
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicName {
+    pub entity: EntityType,
+    /// Range of the template literal, backticks included
+    pub range: Range,
+}
+
+/// One entry of a [`ProjectIndex::apply_batch`](super::ProjectIndex::apply_batch) batch
+#[derive(Debug)]
+pub enum FileChange {
+    /// Replace the file's entries with a freshly parsed index
+    Upsert(FileIndex),
+    /// Drop the file's entries
+    Remove(PathBuf),
+}
+
+/// Invoke wrappers defined in a frontend file, and calls to functions imported from other
+/// modules that may be such wrappers
+///
+/// ```text
+/// // api.ts
+/// export const getUser = () => invoke("get_user");   // export ("getUser", "get_user")
+///
+/// // Component.tsx
+/// import { getUser } from "./api";
+/// getUser();                                         // imported call "getUser" from "./api"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WrapperLinks {
+    /// `(export name, command)` for each wrapper the file exports
+    pub exports: Vec<(String, String)>,
+    /// Calls resolved to commands through other files' exports when queried
+    pub imported_calls: Vec<ImportedCall>,
+}
+
+/// Call to a function imported from another module
+#[derive(Debug, Clone)]
+pub struct ImportedCall {
+    /// Name under which the wrapper is exported: `getUser`, or `api.getUser` for a
+    /// method of an exported object
+    pub wrapper: String,
+    /// Specifier of the module the wrapper is imported from, as written (`./api`, `@/api`)
+    pub module: String,
+    /// Range of the called name
+    pub range: Range,
+    /// Start of the enclosing statement (lens anchor)
+    pub statement_start: Option<Position>,
 }
 
 /// Search Key (Hashmap Key)
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IndexKey {
     pub entity: EntityType,
     pub name: String,
 }
 
+/// Keys that differ between two index states (see `ProjectIndex::diff`), each list sorted
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexDiff {
+    /// Keys only in the newer index
+    pub added: Vec<IndexKey>,
+    /// Keys only in the older index
+    pub removed: Vec<IndexKey>,
+    /// Keys in both whose locations (path, range, behavior) differ
+    pub changed: Vec<IndexKey>,
+}
+
+impl IndexDiff {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl std::fmt::Display for IndexDiff {
+    /// One line per key: `+ Command get_user`, `- Event saved`, `~ Command greet`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (sign, keys) in [
+            ("+", &self.added),
+            ("-", &self.removed),
+            ("~", &self.changed),
+        ] {
+            for key in keys {
+                writeln!(f, "{sign} {:?} {}", key.entity, key.name)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Location Information (Value)
 #[derive(Debug, Clone)]
 pub struct LocationInfo {
@@ -124,6 +589,16 @@ pub struct LocationInfo {
     pub call_name_end: Option<Position>,
     pub type_arg_range: Option<Range>,
     pub codegen_origin: Option<GeneratorKind>,
+    pub payload_type: Option<String>,
+    pub call_arg_variable: Option<String>,
+    pub statement_start: Option<Position>,
+    pub listen_toggle: Option<ListenToggle>,
+    pub wrapper_name: Option<String>,
+    pub doc: Option<String>,
+    pub test_only: bool,
+    pub cfg: Option<String>,
+    pub error_type: Option<String>,
+    pub unawaited: bool,
 }
 
 /// A name paired with optional location information
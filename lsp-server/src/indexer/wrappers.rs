@@ -0,0 +1,214 @@
+//! Cross-file invoke wrapper resolution
+//!
+//! Wrapper exports and imported calls are stored per file. An imported call matches the
+//! wrapper exported under its name by the file its module specifier resolves to: relative
+//! specifiers against the importing file, others through `tsconfig.json` path aliases.
+//! Resolution is lexical, against indexed paths, so unsaved files resolve too.
+//!
+//! Resolved calls are kept per command in `wrapper_calls`, so command lookups don't resolve
+//! every imported call. Whenever a file's links change, the calls of each importer they may
+//! affect are resolved again, so a call site is attributed to its command regardless of
+//! which file was indexed first.
+
+use std::path::{Path, PathBuf};
+use tower_lsp_server::lsp_types::Position;
+
+use super::types::{Finding, ImportedCall, IndexKey, LocationInfo, WrapperLinks};
+use super::ProjectIndex;
+use crate::scanner::{self, PathAliasResolver};
+use crate::syntax::{Behavior, EntityType};
+
+impl ProjectIndex {
+    /// Set the path aliases used to resolve the modules wrappers are imported from
+    pub fn set_path_aliases(&self, aliases: Option<PathAliasResolver>) {
+        *self.path_aliases.write() = aliases;
+
+        let importers: Vec<PathBuf> = self
+            .imported_calls
+            .iter()
+            .map(|e| e.key().clone())
+            .collect();
+        for importer in importers {
+            self.relink_wrapper_calls(&importer);
+        }
+    }
+
+    /// Register a file's wrapper exports and imported calls (the file must have been removed first)
+    pub(crate) fn add_wrapper_links(&self, path: &Path, links: WrapperLinks) {
+        if !links.exports.is_empty() {
+            for (wrapper, command) in &links.exports {
+                self.wrapper_commands
+                    .insert((path.to_path_buf(), wrapper.clone()), command.clone());
+            }
+            self.wrapper_exports
+                .insert(path.to_path_buf(), links.exports);
+
+            // Calls in other files may now resolve to this file
+            let importers: Vec<PathBuf> = self
+                .imported_calls
+                .iter()
+                .filter(|entry| {
+                    entry.value().iter().any(|call| {
+                        self.resolve_imported_call(entry.key(), call)
+                            .is_some_and(|(exporter, _)| exporter == path)
+                    })
+                })
+                .map(|entry| entry.key().clone())
+                .collect();
+            for importer in importers {
+                self.relink_wrapper_calls(&importer);
+            }
+        }
+
+        if !links.imported_calls.is_empty() {
+            self.imported_calls
+                .insert(path.to_path_buf(), links.imported_calls);
+            self.relink_wrapper_calls(path);
+        }
+    }
+
+    /// Forget a file's wrapper links, along with the calls resolved through them
+    pub(crate) fn remove_wrapper_links(&self, path: &Path) {
+        if self.imported_calls.remove(path).is_some() {
+            self.unlink_wrapper_calls(|importer, _| importer == path);
+        }
+
+        let Some((path, exports)) = self.wrapper_exports.remove(path) else {
+            return;
+        };
+
+        for (wrapper, _) in exports {
+            self.wrapper_commands.remove(&(path.clone(), wrapper));
+        }
+
+        // Calls that resolved to this file may fall back to another candidate module
+        let mut importers: Vec<PathBuf> = self
+            .wrapper_calls
+            .iter()
+            .flat_map(|entry| {
+                entry
+                    .value()
+                    .iter()
+                    .filter(|(exporter, _)| *exporter == path)
+                    .map(|(_, location)| location.path.clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        importers.sort();
+        importers.dedup();
+        for importer in importers {
+            self.relink_wrapper_calls(&importer);
+        }
+    }
+
+    /// Resolve the imported calls of `importer` again, replacing its entries in `wrapper_calls`
+    fn relink_wrapper_calls(&self, importer: &Path) {
+        self.unlink_wrapper_calls(|path, _| path == importer);
+
+        let resolved: Vec<(String, PathBuf, LocationInfo)> = {
+            let Some(calls) = self.imported_calls.get(importer) else {
+                return;
+            };
+            calls
+                .iter()
+                .filter_map(|call| {
+                    let (exporter, command) = self.resolve_imported_call(importer, call)?;
+                    let location = wrapper_call_location(importer, call, &command);
+                    Some((command, exporter, location))
+                })
+                .collect()
+        };
+
+        for (command, exporter, location) in resolved {
+            self.wrapper_calls
+                .entry(command)
+                .or_default()
+                .push((exporter, location));
+        }
+    }
+
+    /// Drop the resolved calls for which `remove(importer, exporter)` holds
+    fn unlink_wrapper_calls(&self, remove: impl Fn(&Path, &Path) -> bool) {
+        self.wrapper_calls.retain(|_, calls| {
+            calls.retain(|(exporter, location)| !remove(&location.path, exporter));
+            !calls.is_empty()
+        });
+    }
+
+    /// Exporting file and command of an imported call in `importer`, if its module resolves
+    /// to an indexed file exporting the wrapper
+    fn resolve_imported_call(
+        &self,
+        importer: &Path,
+        call: &ImportedCall,
+    ) -> Option<(PathBuf, String)> {
+        let aliases = self.path_aliases.read();
+        scanner::import_candidates(importer, &call.module, aliases.as_ref())
+            .into_iter()
+            .flat_map(|module| scanner::module_files(&module))
+            .find_map(|file| {
+                let key = (file, call.wrapper.clone());
+                let command = self.wrapper_commands.get(&key)?.value().clone();
+                Some((key.0, command))
+            })
+    }
+
+    /// Calls to imported wrappers of `command`, as `Call` locations
+    pub fn wrapper_call_locations(&self, command: &str) -> Vec<LocationInfo> {
+        let Some(calls) = self.wrapper_calls.get(command) else {
+            return Vec::new();
+        };
+        let mut locations: Vec<LocationInfo> =
+            calls.iter().map(|(_, location)| location.clone()).collect();
+        drop(calls);
+
+        locations.sort_by(|a, b| {
+            (&a.path, a.range.start.line, a.range.start.character).cmp(&(
+                &b.path,
+                b.range.start.line,
+                b.range.start.character,
+            ))
+        });
+        locations
+    }
+
+    /// Command called through an imported wrapper at `position`, if any
+    pub(crate) fn wrapper_call_at(
+        &self,
+        path: &Path,
+        position: Position,
+    ) -> Option<(IndexKey, LocationInfo)> {
+        let canonical = self.index_path(path);
+        let path = canonical.as_path();
+        let calls = self.imported_calls.get(path)?;
+        let call = calls
+            .iter()
+            .find(|c| crate::utils::is_position_in_range(position, c.range))?;
+        let (_, command) = self.resolve_imported_call(path, call)?;
+
+        let location = wrapper_call_location(path, call, &command);
+        Some((
+            IndexKey {
+                entity: EntityType::Command,
+                name: command,
+            },
+            location,
+        ))
+    }
+}
+
+fn wrapper_call_location(path: &Path, call: &ImportedCall, command: &str) -> LocationInfo {
+    LocationInfo::from((
+        path,
+        Finding {
+            statement_start: call.statement_start,
+            wrapper_name: Some(call.wrapper.clone()),
+            ..Finding::new(
+                command.to_string(),
+                EntityType::Command,
+                Behavior::Call,
+                call.range,
+            )
+        },
+    ))
+}
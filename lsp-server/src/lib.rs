@@ -2,12 +2,16 @@
 
 #![warn(clippy::all, clippy::pedantic)]
 
+pub mod backend;
 pub mod bindings_reader;
 pub mod capabilities;
 pub mod config_reader;
 pub mod constants;
+pub mod error;
 pub mod file_processor;
 pub mod indexer;
+pub mod permissions;
+pub mod position;
 pub mod rust_attr;
 pub mod rust_type_extractor;
 pub mod scanner;
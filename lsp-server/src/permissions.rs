@@ -0,0 +1,130 @@
+//! Tauri v2 capability files (`src-tauri/capabilities/*.json`)
+//!
+//! A capability grants permissions to windows. Once an app declares permissions for its own
+//! commands (`tauri_build::AppManifest::commands`), each command gets an `allow-<command>`
+//! permission, and invoking a command no capability lists fails at runtime.
+//!
+//! ```json
+//! { "identifier": "main", "windows": ["main"],
+//!   "permissions": ["core:default", "allow-get-user", { "identifier": "allow-save" }] }
+//! ```
+
+use crate::error::{Error, Result};
+use std::path::Path;
+
+/// Directory Tauri loads capability files from, below `src-tauri`
+const CAPABILITIES_DIR: &str = "capabilities";
+
+/// Prefix of the permission allowing one app command
+const ALLOW_PREFIX: &str = "allow-";
+/// Prefix of the permission denying one app command
+const DENY_PREFIX: &str = "deny-";
+
+/// Permissions of a capability file that concern the app's own commands
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandPermissions {
+    /// Commands granted by `allow-<command>` permissions, as Rust function names
+    pub allowed: Vec<String>,
+    /// The file also lists app permission sets (`default`, ...), which are not expanded
+    pub has_permission_sets: bool,
+}
+
+/// Whether `path` is a `.json` file in a `capabilities` directory
+#[must_use]
+pub fn is_capability_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "json")
+        && path
+            .parent()
+            .and_then(Path::file_name)
+            .is_some_and(|dir| dir == CAPABILITIES_DIR)
+}
+
+/// Command permissions of a capability file holding one capability or an array of them.
+///
+/// Permissions with a `plugin:` or `core:` prefix belong to plugins and are skipped, as are
+/// `deny-` permissions. Permission names are kebab-case; the command names are snake_case.
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] if `content` is not valid JSON.
+pub fn parse_capability(path: &Path, content: &str) -> Result<CommandPermissions> {
+    let value: serde_json::Value = serde_json::from_str(content).map_err(|e| Error::Config {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    let capabilities = match &value {
+        serde_json::Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    };
+
+    let mut permissions = CommandPermissions::default();
+    let identifiers = capabilities
+        .into_iter()
+        .filter_map(|capability| capability.get("permissions")?.as_array())
+        .flatten()
+        .filter_map(|entry| entry.as_str().or_else(|| entry.get("identifier")?.as_str()));
+
+    for identifier in identifiers {
+        if identifier.contains(':') || identifier.starts_with(DENY_PREFIX) {
+            continue;
+        }
+        match identifier.strip_prefix(ALLOW_PREFIX) {
+            Some(command) => permissions.allowed.push(command.replace('-', "_")),
+            None => permissions.has_permission_sets = true,
+        }
+    }
+
+    Ok(permissions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_capability_file() {
+        assert!(is_capability_file(Path::new(
+            "src-tauri/capabilities/default.json"
+        )));
+        assert!(!is_capability_file(Path::new("src-tauri/tauri.conf.json")));
+        assert!(!is_capability_file(Path::new(
+            "src-tauri/capabilities/default.toml"
+        )));
+    }
+
+    #[test]
+    fn test_parse_capability() {
+        let content = r#"{
+            "identifier": "main",
+            "permissions": [
+                "core:default",
+                "allow-get-user",
+                { "identifier": "allow-save_settings" },
+                "deny-delete-user"
+            ]
+        }"#;
+
+        let path = Path::new("src-tauri/capabilities/default.json");
+        assert_eq!(
+            parse_capability(path, content).unwrap(),
+            CommandPermissions {
+                allowed: vec!["get_user".to_string(), "save_settings".to_string()],
+                has_permission_sets: false,
+            }
+        );
+
+        let with_set = r#"[{ "permissions": ["default"] }, { "permissions": ["allow-ping"] }]"#;
+        assert_eq!(
+            parse_capability(path, with_set).unwrap(),
+            CommandPermissions {
+                allowed: vec!["ping".to_string()],
+                has_permission_sets: true,
+            }
+        );
+
+        assert!(matches!(
+            parse_capability(path, "{ not json"),
+            Err(Error::Config { .. })
+        ));
+    }
+}
@@ -0,0 +1,123 @@
+//! Conversions between byte offsets, tree-sitter points and LSP positions
+//!
+//! LSP positions count columns in UTF-16 code units, tree-sitter points and Rust string
+//! indices count bytes. Lines are split on `\n`; the `\r` of a `\r\n` ending belongs to
+//! the line terminator, so no LSP position ever points between `\r` and `\n`.
+
+use tower_lsp_server::lsp_types::{Position, Range};
+use tree_sitter::{Node, Point};
+
+/// Convert a tree-sitter point at byte `offset` of `source` to an LSP position.
+///
+/// The byte column is re-measured in UTF-16 units over the text of its line, so
+/// multibyte characters earlier on the line are counted as LSP clients expect.
+#[allow(clippy::cast_possible_truncation)]
+#[must_use]
+pub fn point_to_position(source: &[u8], point: Point, offset: usize) -> Position {
+    let line_start = offset.saturating_sub(point.column);
+    let character = source
+        .get(line_start..offset)
+        .and_then(|line| std::str::from_utf8(line).ok())
+        .map_or(point.column as u32, utf16_len);
+    Position {
+        line: point.row as u32,
+        character,
+    }
+}
+
+/// LSP position of the start of `node`, parsed from `source`
+#[must_use]
+pub fn node_start(node: Node<'_>, source: &[u8]) -> Position {
+    point_to_position(source, node.start_position(), node.start_byte())
+}
+
+/// LSP position of the end of `node`, parsed from `source`
+#[must_use]
+pub fn node_end(node: Node<'_>, source: &[u8]) -> Position {
+    point_to_position(source, node.end_position(), node.end_byte())
+}
+
+/// LSP range covered by `node`, parsed from `source`
+#[must_use]
+pub fn node_range(node: Node<'_>, source: &[u8]) -> Range {
+    Range {
+        start: node_start(node, source),
+        end: node_end(node, source),
+    }
+}
+
+/// Number of UTF-16 code units in `s`, i.e. its width as an LSP column delta
+#[allow(clippy::cast_possible_truncation)]
+#[must_use]
+pub fn utf16_len(s: &str) -> u32 {
+    s.encode_utf16().count() as u32
+}
+
+/// Convert LSP character offset (UTF-16 code units) to byte index in a string
+///
+/// A column inside a surrogate pair is clamped to the start of its character, and a column
+/// past the end to the length of `line`.
+#[must_use]
+pub fn lsp_character_to_byte_index(line: &str, character: usize) -> usize {
+    let mut units = 0;
+
+    for (i, c) in line.char_indices() {
+        units += c.len_utf16();
+        if units > character {
+            return i;
+        }
+    }
+
+    line.len()
+}
+
+/// Convert a byte offset into `text` to an LSP position (UTF-16 column).
+///
+/// Offsets past the end or inside a multibyte character are clamped to the previous
+/// character boundary.
+#[allow(clippy::cast_possible_truncation)]
+#[must_use]
+pub fn byte_offset_to_position(text: &str, offset: usize) -> Position {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = &text[line_start..offset];
+    let column = if text[offset..].starts_with('\n') {
+        column.strip_suffix('\r').unwrap_or(column)
+    } else {
+        column
+    };
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: utf16_len(column),
+    }
+}
+
+/// Convert an LSP position (UTF-16 columns) to a byte offset into `text`, clamped to its length.
+#[must_use]
+pub fn position_to_byte_offset(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return text.len(),
+        }
+    }
+
+    let line = text[line_start..].split('\n').next().unwrap_or_default();
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    line_start + lsp_character_to_byte_index(line, position.character as usize)
+}
+
+/// Convert a byte offset into `text` to a tree-sitter point (row, byte column).
+#[must_use]
+pub fn byte_offset_to_point(text: &str, offset: usize) -> Point {
+    let before = &text[..offset];
+    let row = before.matches('\n').count();
+    let column = before.rfind('\n').map_or(offset, |i| offset - i - 1);
+    Point { row, column }
+}
@@ -0,0 +1,5 @@
+; export enum Status { Active, Inactive = "inactive" }
+(export_statement
+  (enum_declaration
+    name: (identifier) @enum_name
+    body: (enum_body) @enum_body))
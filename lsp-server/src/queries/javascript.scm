@@ -3,12 +3,16 @@
 ; Simple function calls: invoke("cmd"), emit("event"), listen("event"), once("event")
 ; Note: We don't filter by function name here to support import aliases.
 ; Filtering is done in Rust code after alias resolution.
+; Names are string literals or template literals without substitutions (emit(`saved`));
+; anchoring the fragment as the only child leaves out `file-${id}`
 (call_expression
   function: (identifier) @func_name
   arguments: (arguments
     .
-    (string
-      (string_fragment) @arg_value))
+    [
+      (string (string_fragment) @arg_value)
+      (template_string . (string_fragment) @arg_value .)
+    ])
 ) @call_simple
 
 ; Function calls with second string argument: emitTo("target", "event")
@@ -17,8 +21,10 @@
   arguments: (arguments
     (_)
     .
-    (string
-      (string_fragment) @arg_value_second))
+    [
+      (string (string_fragment) @arg_value_second)
+      (template_string . (string_fragment) @arg_value_second .)
+    ])
 ) @call_second_arg
 
 ; Import specifiers with alias and source
@@ -6,40 +6,49 @@
 (function_item
   name: (identifier) @fn_name) @fn_item
 
-; Method calls: .emit("event"), .listen("event"), etc.
-; First argument is the event name
+; Method calls naming an event: .emit("event"), .emit_to("target", "event"), .listen("event")
+; The method and the position of its event name come from `syntax::RUST_EVENT_METHODS`
 (call_expression
   function: (field_expression
     field: (field_identifier) @method_name)
-  arguments: (arguments
-    .
-    (string_literal
-      (string_content) @event_name))
-  (#any-of? @method_name "emit" "emit_str" "emit_filter" "emit_str_filter" "listen" "listen_any" "once" "once_any")
-)
-
-; Method calls with event as second argument: app.emit_to("target", "event", payload)
-(call_expression
-  function: (field_expression
-    field: (field_identifier) @method_name)
-  arguments: (arguments
-    (_)
-    .
-    (string_literal
-      (string_content) @event_name))
-  (#any-of? @method_name "emit_to" "emit_str_to")
-)
+  arguments: (arguments) @call_args)
 
 ; Struct items — #[derive(Event)] detection is done via sibling walk in Rust code.
 ; struct_item: struct MyEvent {}
 (struct_item
   name: (type_identifier) @struct_name) @struct_item
 
-; Specta typed event emit: GlobalEvent(payload).emit_to(&app)
+; Specta typed event emit on a tuple struct: GlobalEvent(payload).emit_to(&app)
 (call_expression
   function: (field_expression
     value: (call_expression
-      function: (identifier) @specta_emit_struct)
+      function: [
+        (identifier) @specta_emit_struct
+        (scoped_identifier name: (identifier) @specta_emit_struct)
+      ])
+    field: (field_identifier) @specta_emit_method)
+  arguments: (arguments) @specta_emit_args
+  (#any-of? @specta_emit_method "emit" "emit_to" "emit_filter" "emit_str")
+)
+
+; Specta typed event emit on a struct literal: UserUpdated { id }.emit(&app)
+(call_expression
+  function: (field_expression
+    value: (struct_expression
+      name: [
+        (type_identifier) @specta_emit_struct
+        (scoped_type_identifier name: (type_identifier) @specta_emit_struct)
+      ])
+    field: (field_identifier) @specta_emit_method)
+  arguments: (arguments) @specta_emit_args
+  (#any-of? @specta_emit_method "emit" "emit_to" "emit_filter" "emit_str")
+)
+
+; Specta typed event emit on a unit struct: AppReady.emit(&app)
+(call_expression
+  function: (field_expression
+    value: (identifier) @specta_emit_struct
     field: (field_identifier) @specta_emit_method)
+  arguments: (arguments) @specta_emit_args
   (#any-of? @specta_emit_method "emit" "emit_to" "emit_filter" "emit_str")
 )
@@ -1,9 +1,7 @@
-; emit("event", payload)
+; emit("event", payload), emit_to("target", "event", payload)
+; Emit methods and the position of their event name come from `syntax::RUST_EVENT_METHODS`;
+; the payload is the argument after the event name
 (call_expression
   function: (field_expression
     field: (field_identifier) @method_name)
-  arguments: (arguments
-    (string_literal (string_content) @event_name)
-    . (_) @payload_arg)
-  (#any-of? @method_name "emit" "emit_filter" "emit_to")
-)
+  arguments: (arguments) @call_args)
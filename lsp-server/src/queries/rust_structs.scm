@@ -0,0 +1,5 @@
+; struct User { id: u32, name: String }
+(struct_item
+  name: (type_identifier) @struct_name
+  body: (field_declaration_list) @struct_fields
+) @struct_item
@@ -31,14 +31,19 @@
 
 ; === SIMPLE CALLS (no generics) ===
 
+; Names are string literals or template literals without substitutions (emit(`saved`));
+; anchoring the fragment as the only child leaves out `file-${id}`
+
 ; Simple function calls: invoke("cmd"), emit("event")
 (call_expression
   function: (identifier) @func_name
   !type_arguments
   arguments: (arguments
     .
-    (string
-      (string_fragment) @arg_value))
+    [
+      (string (string_fragment) @arg_value)
+      (template_string . (string_fragment) @arg_value .)
+    ])
 ) @call_simple
 
 ; Await expression with simple call: await invoke("cmd")
@@ -48,8 +53,10 @@
   !type_arguments
   arguments: (arguments
     .
-    (string
-      (string_fragment) @arg_value))
+    [
+      (string (string_fragment) @arg_value)
+      (template_string . (string_fragment) @arg_value .)
+    ])
 ) @call_await_simple
 
 ; Function calls with second string argument: emitTo("target", "event")
@@ -59,8 +66,10 @@
   arguments: (arguments
     (_)
     .
-    (string
-      (string_fragment) @arg_value_second))
+    [
+      (string (string_fragment) @arg_value_second)
+      (template_string . (string_fragment) @arg_value_second .)
+    ])
 ) @call_second_arg
 
 ; Await expression with second string argument: await emitTo("target", "event")
@@ -71,8 +80,10 @@
   arguments: (arguments
     (_)
     .
-    (string
-      (string_fragment) @arg_value_second))
+    [
+      (string (string_fragment) @arg_value_second)
+      (template_string . (string_fragment) @arg_value_second .)
+    ])
 ) @call_await_second_arg
 
 ; === GENERIC CALLS (with type arguments) ===
@@ -83,8 +94,10 @@
   type_arguments: (type_arguments)
   arguments: (arguments
     .
-    (string
-      (string_fragment) @arg_value))
+    [
+      (string (string_fragment) @arg_value)
+      (template_string . (string_fragment) @arg_value .)
+    ])
 ) @call_generic
 
 ; Await expression with generic call: await invoke<T>("cmd")
@@ -94,8 +107,10 @@
   type_arguments: (type_arguments)
   arguments: (arguments
     .
-    (string
-      (string_fragment) @arg_value))
+    [
+      (string (string_fragment) @arg_value)
+      (template_string . (string_fragment) @arg_value .)
+    ])
 ) @call_await_generic
 
 ; Generic calls with second string argument: emitTo<T>("target", "event")
@@ -105,8 +120,10 @@
   arguments: (arguments
     (_)
     .
-    (string
-      (string_fragment) @arg_value_second))
+    [
+      (string (string_fragment) @arg_value_second)
+      (template_string . (string_fragment) @arg_value_second .)
+    ])
 ) @call_generic_second_arg
 
 ; Await expression with second string argument and generics: await emitTo<T>("target", "event")
@@ -117,8 +134,10 @@
   arguments: (arguments
     (_)
     .
-    (string
-      (string_fragment) @arg_value_second))
+    [
+      (string (string_fragment) @arg_value_second)
+      (template_string . (string_fragment) @arg_value_second .)
+    ])
 ) @call_await_generic_second_arg
 
 ; === SPECTA CALLS (commands.methodName(...)) ===
@@ -1,15 +1,51 @@
 //! Utilities for inspecting Rust tree-sitter attribute nodes.
 //!
-//! Detects `#[tauri::command]` / `#[command]` on functions and
-//! `#[derive(...Event...)]` on structs.
+//! Detects `#[tauri::command]` / `#[command]` (with or without arguments) on functions,
+//! `#[derive(...Event...)]` on structs, `#[serde(flatten)]` and serde renames, `#[cfg(test)]`
+//! scopes and other `#[cfg(...)]` gates.
+
+/// Options given in the argumented form `#[tauri::command(async, rename_all = "snake_case")]`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandAttr {
+    /// Casing of argument names expected from the frontend (`None` means Tauri's camelCase)
+    pub rename_all: Option<String>,
+}
 
 /// Check if a function node has a `#[tauri::command]` or `#[command]` attribute
 /// among its immediately-preceding siblings, skipping other attribute items and comments.
 #[must_use]
 pub fn has_tauri_command_attr(fn_node: tree_sitter::Node<'_>, content: &str) -> bool {
-    has_preceding_attr(fn_node, content, |text| {
-        text == "#[tauri::command]" || text == "#[command]"
-    })
+    tauri_command_attr(fn_node, content).is_some()
+}
+
+/// Parse the `#[tauri::command]` / `#[command]` attribute of a function, including the
+/// argumented `#[tauri::command(...)]` form. Returns `None` if the function is not a command.
+#[must_use]
+pub fn tauri_command_attr(fn_node: tree_sitter::Node<'_>, content: &str) -> Option<CommandAttr> {
+    let text = find_preceding_attr(fn_node, content, |text| parse_command_attr(text).is_some())?;
+
+    parse_command_attr(text)
+}
+
+/// Parse `#[tauri::command]`, `#[command]` and their `(...)` argument lists.
+fn parse_command_attr(attr_text: &str) -> Option<CommandAttr> {
+    let compact: String = attr_text.chars().filter(|c| !c.is_whitespace()).collect();
+    let inner = compact.strip_prefix("#[")?.strip_suffix(']')?;
+    let args = inner
+        .strip_prefix("tauri::command")
+        .or_else(|| inner.strip_prefix("command"))?;
+
+    if args.is_empty() {
+        return Some(CommandAttr::default());
+    }
+
+    let args = args.strip_prefix('(')?.strip_suffix(')')?;
+    let rename_all = args.split(',').find_map(|arg| {
+        let value = arg.strip_prefix("rename_all=")?;
+        Some(value.trim_matches('"').to_string())
+    });
+
+    Some(CommandAttr { rename_all })
 }
 
 /// Check if a struct has a derive attribute containing `Event` (covers
@@ -25,6 +61,160 @@ pub fn has_specta_event_derive(struct_node: tree_sitter::Node<'_>, content: &str
     })
 }
 
+/// Check if a struct field carries `#[serde(flatten)]`, alone or among other serde options.
+#[must_use]
+pub fn has_serde_flatten(field_node: tree_sitter::Node<'_>, content: &str) -> bool {
+    has_preceding_attr(field_node, content, is_serde_flatten_attr)
+}
+
+/// Return true for `#[serde(flatten)]` and `#[serde(default, flatten)]`, ignoring whitespace.
+fn is_serde_flatten_attr(attr_text: &str) -> bool {
+    let compact: String = attr_text.chars().filter(|c| !c.is_whitespace()).collect();
+    compact
+        .strip_prefix("#[serde(")
+        .and_then(|s| s.strip_suffix(")]"))
+        .is_some_and(|args| args.split(',').any(|arg| arg == "flatten"))
+}
+
+/// Serialized name of a struct field from `#[serde(rename = "...")]`, alone or among other
+/// serde options.
+#[must_use]
+pub fn serde_rename(field_node: tree_sitter::Node<'_>, content: &str) -> Option<String> {
+    serde_option(field_node, content, "rename")
+}
+
+/// Casing rule of a struct from `#[serde(rename_all = "...")]`, alone or among other serde
+/// options.
+#[must_use]
+pub fn serde_rename_all(struct_node: tree_sitter::Node<'_>, content: &str) -> Option<String> {
+    serde_option(struct_node, content, "rename_all")
+}
+
+/// Value of `key = "value"` in the first preceding `#[serde(...)]` attribute that sets it.
+fn serde_option(node: tree_sitter::Node<'_>, content: &str, key: &str) -> Option<String> {
+    let text = find_preceding_attr(node, content, |text| {
+        serde_option_value(text, key).is_some()
+    })?;
+
+    serde_option_value(text, key)
+}
+
+/// `value` for `#[serde(..., key = "value", ...)]`, ignoring whitespace.
+fn serde_option_value(attr_text: &str, key: &str) -> Option<String> {
+    let compact: String = attr_text.chars().filter(|c| !c.is_whitespace()).collect();
+    let args = compact.strip_prefix("#[serde(")?.strip_suffix(")]")?;
+    args.split(',').find_map(|arg| {
+        let value = arg.strip_prefix(key)?.strip_prefix('=')?;
+        Some(value.trim_matches('"').to_string())
+    })
+}
+
+/// Apply a serde `rename_all` rule to a `snake_case` field name, as serde does.
+///
+/// Unknown rules leave the name unchanged.
+#[must_use]
+pub fn apply_rename_all(field: &str, rule: &str) -> String {
+    match rule {
+        "lowercase" | "snake_case" => field.to_string(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_ascii_uppercase(),
+        "camelCase" => crate::utils::snake_to_camel(field),
+        "PascalCase" => {
+            let camel = crate::utils::snake_to_camel(field);
+            let mut chars = camel.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        }
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.replace('_', "-").to_ascii_uppercase(),
+        _ => field.to_string(),
+    }
+}
+
+/// Check if an item only exists in test builds: it, or an enclosing module, carries
+/// `#[cfg(test)]`, or an enclosing module (or the file) starts with `#![cfg(test)]`.
+#[must_use]
+pub fn is_in_cfg_test(node: tree_sitter::Node<'_>, content: &str) -> bool {
+    if has_preceding_attr(node, content, is_cfg_test_attr) {
+        return true;
+    }
+
+    let mut ancestor = node.parent();
+    while let Some(a) = ancestor {
+        match a.kind() {
+            "mod_item" if has_preceding_attr(a, content, is_cfg_test_attr) => return true,
+            "declaration_list" | "source_file" if has_inner_cfg_test(a, content) => return true,
+            _ => {}
+        }
+        ancestor = a.parent();
+    }
+
+    false
+}
+
+/// Check for a leading `#![cfg(test)]` in a module body or file.
+fn has_inner_cfg_test(body: tree_sitter::Node<'_>, content: &str) -> bool {
+    let mut cursor = body.walk();
+    let found = body
+        .named_children(&mut cursor)
+        .take_while(|c| {
+            matches!(
+                c.kind(),
+                "inner_attribute_item" | "line_comment" | "block_comment"
+            )
+        })
+        .any(|c| {
+            c.kind() == "inner_attribute_item"
+                && is_cfg_test_attr(
+                    &c.utf8_text(content.as_bytes())
+                        .unwrap_or("")
+                        .replacen("#!", "#", 1),
+                )
+        });
+    found
+}
+
+/// Predicate of the `#[cfg(...)]` attribute on an item, as written (e.g. `feature = "desktop"`).
+///
+/// `#[cfg(test)]` gates are left to [`is_in_cfg_test`] and not returned.
+#[must_use]
+pub fn cfg_condition(node: tree_sitter::Node<'_>, content: &str) -> Option<String> {
+    let text = find_preceding_attr(node, content, |text| {
+        cfg_predicate(text).is_some() && !is_cfg_test_attr(text)
+    })?;
+
+    cfg_predicate(text).map(str::to_string)
+}
+
+/// `feature = "x"` for `#[cfg(feature = "x")]`, tolerating whitespace around the tokens.
+fn cfg_predicate(attr_text: &str) -> Option<&str> {
+    let inner = attr_text
+        .trim()
+        .strip_prefix("#[")?
+        .strip_suffix(']')?
+        .trim();
+    let args = inner.strip_prefix("cfg")?.trim_start();
+
+    Some(args.strip_prefix('(')?.strip_suffix(')')?.trim())
+}
+
+/// Return true for `#[cfg(test)]` and `#[cfg(all(test, ...))]`, ignoring whitespace.
+fn is_cfg_test_attr(attr_text: &str) -> bool {
+    let compact: String = attr_text.chars().filter(|c| !c.is_whitespace()).collect();
+    let Some(predicate) = compact
+        .strip_prefix("#[cfg(")
+        .and_then(|s| s.strip_suffix(")]"))
+    else {
+        return false;
+    };
+
+    predicate == "test"
+        || predicate
+            .strip_prefix("all(")
+            .and_then(|s| s.strip_suffix(')'))
+            .is_some_and(|args| args.split(',').any(|arg| arg == "test"))
+}
+
 /// Return true if `attr_text` is a `#[derive(...)]` attribute where one of
 /// the comma-separated arguments is exactly `Event`, `SpectaEvent`, or
 /// a path ending in `::Event` (e.g. `tauri_specta::Event`).
@@ -45,6 +235,44 @@ fn is_derive_with_event_trait(attr_text: &str) -> bool {
     })
 }
 
+/// Text of the `///` (or `/** */`) doc comment preceding an item, markers stripped and
+/// lines joined with `\n`. Attributes and regular comments around it are skipped.
+#[must_use]
+pub fn doc_comment(node: tree_sitter::Node<'_>, content: &str) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut sibling = node.prev_sibling();
+
+    while let Some(s) = sibling {
+        let text = s.utf8_text(content.as_bytes()).unwrap_or("");
+        match s.kind() {
+            "line_comment" if is_outer_line_doc(text) => {
+                let line = text.trim_end().trim_start_matches("///");
+                lines.push(line.strip_prefix(' ').unwrap_or(line).to_string());
+            }
+            "block_comment" if text.starts_with("/**") && !text.starts_with("/***") => {
+                let body = text.trim_start_matches("/**").trim_end_matches("*/");
+                let block: Vec<&str> = body
+                    .lines()
+                    .map(|l| l.trim().trim_start_matches('*').trim_start())
+                    .collect();
+                lines.extend(block.into_iter().rev().map(str::to_string));
+            }
+            "attribute_item" | "line_comment" | "block_comment" => {}
+            _ => break,
+        }
+        sibling = s.prev_sibling();
+    }
+
+    lines.reverse();
+    let doc = lines.join("\n").trim().to_string();
+    (!doc.is_empty()).then_some(doc)
+}
+
+/// `/// text`, but not `//// text` (a regular comment)
+fn is_outer_line_doc(text: &str) -> bool {
+    text.starts_with("///") && !text.starts_with("////")
+}
+
 /// Walk backwards from `node` through preceding siblings, checking each
 /// `attribute_item` with `predicate`. Skips comments; stops at any other node kind.
 fn has_preceding_attr(
@@ -52,13 +280,23 @@ fn has_preceding_attr(
     content: &str,
     predicate: impl Fn(&str) -> bool,
 ) -> bool {
+    find_preceding_attr(node, content, predicate).is_some()
+}
+
+/// Text of the first preceding `attribute_item` matching `predicate` (see [`has_preceding_attr`])
+fn find_preceding_attr<'a>(
+    node: tree_sitter::Node<'_>,
+    content: &'a str,
+    predicate: impl Fn(&str) -> bool,
+) -> Option<&'a str> {
     let mut sibling = node.prev_sibling();
 
     while let Some(s) = sibling {
         match s.kind() {
             "attribute_item" => {
-                if predicate(s.utf8_text(content.as_bytes()).unwrap_or("")) {
-                    return true;
+                let text = s.utf8_text(content.as_bytes()).unwrap_or("");
+                if predicate(text) {
+                    return Some(text);
                 }
             }
             "line_comment" | "block_comment" => {}
@@ -67,7 +305,7 @@ fn has_preceding_attr(
         sibling = s.prev_sibling();
     }
 
-    false
+    None
 }
 
 #[cfg(test)]
@@ -138,6 +376,41 @@ fn greet() {}
         assert!(has_tauri_command_attr(fn_node, src));
     }
 
+    #[test]
+    fn extracts_doc_comments() {
+        let src = r"
+/// Greets the user.
+///
+/// Returns the greeting.
+#[tauri::command]
+// not part of the docs
+fn greet() {}
+
+/**
+ * Block docs.
+ */
+#[tauri::command]
+fn farewell() {}
+
+//// Not a doc comment
+#[tauri::command]
+fn plain() {}
+";
+        let tree = parse_rust(src);
+        let mut fns = Vec::new();
+        collect_nodes(tree.root_node(), "function_item", &mut fns);
+        let docs: Vec<_> = fns.iter().map(|f| doc_comment(*f, src)).collect();
+
+        assert_eq!(
+            docs,
+            [
+                Some("Greets the user.\n\nReturns the greeting.".to_string()),
+                Some("Block docs.".to_string()),
+                None,
+            ]
+        );
+    }
+
     #[test]
     fn no_attr_returns_false() {
         let src = "fn greet() {}";
@@ -171,6 +444,35 @@ fn greet() {}
         assert!(!has_tauri_command_attr(fn_node, src));
     }
 
+    #[test]
+    fn detects_argumented_command_attr() {
+        let src = r#"
+#[tauri::command(async, rename_all = "snake_case")]
+fn greet() {}
+
+#[command(async)]
+fn farewell() {}
+
+#[tauri::commander]
+fn not_a_command() {}
+"#;
+        let tree = parse_rust(src);
+        let mut fns = Vec::new();
+        collect_nodes(tree.root_node(), "function_item", &mut fns);
+
+        assert_eq!(
+            tauri_command_attr(fns[0], src),
+            Some(CommandAttr {
+                rename_all: Some("snake_case".to_string())
+            })
+        );
+        assert_eq!(
+            tauri_command_attr(fns[1], src),
+            Some(CommandAttr::default())
+        );
+        assert_eq!(tauri_command_attr(fns[2], src), None);
+    }
+
     #[test]
     fn multiple_attrs_with_command() {
         let src = r"
@@ -257,4 +559,197 @@ struct Payload { data: u32 }
 
         assert!(!has_specta_event_derive(struct_node, src));
     }
+
+    // ── is_in_cfg_test ───────────────────────────────────────────────────
+
+    #[test]
+    fn detects_cfg_test_scopes() {
+        let src = r#"
+#[tauri::command]
+fn real() {}
+
+#[cfg(test)]
+mod tests {
+    mod nested {
+        #[tauri::command]
+        fn in_nested_test_mod() {}
+    }
+}
+
+mod helpers {
+    #![cfg(test)]
+    #[tauri::command]
+    fn in_inner_cfg_mod() {}
+}
+
+#[cfg(all(test, feature = "mock"))]
+#[tauri::command]
+fn with_all_cfg() {}
+
+#[cfg(not(test))]
+#[tauri::command]
+fn not_test() {}
+"#;
+        let tree = parse_rust(src);
+        let mut functions = Vec::new();
+        collect_nodes(tree.root_node(), "function_item", &mut functions);
+
+        let test_only: Vec<_> = functions
+            .iter()
+            .map(|f| {
+                let name = f.child_by_field_name("name").unwrap();
+                (
+                    name.utf8_text(src.as_bytes()).unwrap(),
+                    is_in_cfg_test(*f, src),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            test_only,
+            [
+                ("real", false),
+                ("in_nested_test_mod", true),
+                ("in_inner_cfg_mod", true),
+                ("with_all_cfg", true),
+                ("not_test", false),
+            ]
+        );
+    }
+
+    // ── has_serde_flatten ────────────────────────────────────────────────
+
+    #[test]
+    fn detects_serde_flatten_on_fields() {
+        let src = r#"
+struct User {
+    id: u32,
+    #[serde(flatten)]
+    base: Base,
+    #[serde(default, flatten)]
+    // extra data
+    extra: Extra,
+    #[serde(rename = "flatten")]
+    renamed: String,
+}
+"#;
+        let tree = parse_rust(src);
+        let mut fields = Vec::new();
+        collect_nodes(tree.root_node(), "field_declaration", &mut fields);
+
+        let flattened: Vec<_> = fields.iter().map(|f| has_serde_flatten(*f, src)).collect();
+
+        assert_eq!(flattened, [false, true, true, false]);
+    }
+
+    // ── serde_rename / serde_rename_all ──────────────────────────────────
+
+    #[test]
+    fn reads_serde_renames() {
+        let src = r#"
+#[derive(Serialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct User {
+    user_id: u32,
+    #[serde(default, rename = "type")]
+    kind: String,
+    #[serde(rename_all = "kebab-case")]
+    other: String,
+}
+"#;
+        let tree = parse_rust(src);
+        let mut structs = Vec::new();
+        collect_nodes(tree.root_node(), "struct_item", &mut structs);
+        let mut fields = Vec::new();
+        collect_nodes(tree.root_node(), "field_declaration", &mut fields);
+
+        assert_eq!(
+            serde_rename_all(structs[0], src),
+            Some("camelCase".to_string())
+        );
+        let renames: Vec<_> = fields.iter().map(|f| serde_rename(*f, src)).collect();
+        assert_eq!(renames, [None, Some("type".to_string()), None]);
+    }
+
+    #[test]
+    fn applies_rename_all_rules() {
+        let renamed: Vec<_> = [
+            "lowercase",
+            "UPPERCASE",
+            "PascalCase",
+            "camelCase",
+            "snake_case",
+            "SCREAMING_SNAKE_CASE",
+            "kebab-case",
+            "SCREAMING-KEBAB-CASE",
+        ]
+        .iter()
+        .map(|rule| apply_rename_all("created_at", rule))
+        .collect();
+
+        assert_eq!(
+            renamed,
+            [
+                "created_at",
+                "CREATED_AT",
+                "CreatedAt",
+                "createdAt",
+                "created_at",
+                "CREATED_AT",
+                "created-at",
+                "CREATED-AT",
+            ]
+        );
+    }
+
+    // ── cfg_condition ────────────────────────────────────────────────────
+
+    #[test]
+    fn captures_cfg_conditions() {
+        let src = r#"
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn desktop_only() {}
+
+#[tauri::command]
+#[cfg( any(target_os = "linux", target_os = "macos") )]
+fn unix_only() {}
+
+#[cfg(test)]
+#[tauri::command]
+fn test_only() {}
+
+#[tauri::command]
+fn everywhere() {}
+"#;
+        let tree = parse_rust(src);
+        let mut functions = Vec::new();
+        collect_nodes(tree.root_node(), "function_item", &mut functions);
+
+        let conditions: Vec<_> = functions.iter().map(|f| cfg_condition(*f, src)).collect();
+
+        assert_eq!(
+            conditions,
+            [
+                Some(r#"feature = "desktop""#.to_string()),
+                Some(r#"any(target_os = "linux", target_os = "macos")"#.to_string()),
+                None,
+                None,
+            ]
+        );
+    }
+
+    fn collect_nodes<'a>(
+        node: tree_sitter::Node<'a>,
+        kind: &str,
+        out: &mut Vec<tree_sitter::Node<'a>>,
+    ) {
+        if node.kind() == kind {
+            out.push(node);
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            collect_nodes(child, kind, out);
+        }
+    }
 }
@@ -1,9 +1,15 @@
 //! Extract parameter and return type information from Rust #[`tauri::command`] functions
 
-use crate::indexer::{CommandSchema, EventSchema, GeneratorKind, ParamSchema};
+use crate::indexer::{
+    CommandSchema, EventSchema, GeneratorKind, ParamSchema, RustTypeAlias, StructSchema,
+};
+use crate::position::node_range;
+use crate::syntax::{is_optional_rust_type, rust_event_method, Behavior};
+use crate::ts_tree_utils::{rust_call_arg, rust_string_arg};
 use crate::utils::{capture_text, find_capture};
 use std::path::Path;
 use streaming_iterator::StreamingIterator;
+use tower_lsp_server::lsp_types::Range;
 use tree_sitter::{Query, QueryCursor};
 
 const RUST_PARAMS_QUERY: &str = include_str!("queries/rust_params.scm");
@@ -24,7 +30,7 @@ fn setup_rust_query(query_str: &str) -> Option<(Query, QueryCursor)> {
 /// - `String` → `"string"`
 /// - `bool` → `"boolean"`
 /// - `()` → `"void"`
-/// - `Result<T, E>` → `rust_type_to_ts(T)`
+/// - `Result<T, E>` / `tauri::Result<T>` → `rust_type_to_ts(T)`
 /// - `Option<T>` → `rust_type_to_ts(T) + " | null"`
 /// - `Vec<T>` → `rust_type_to_ts(T) + "[]"`
 /// - unknown → pass through
@@ -37,13 +43,13 @@ pub fn rust_type_to_ts(rust_type: &str) -> String {
         RustType::Str => "string".to_string(),
         RustType::Bool => "boolean".to_string(),
         RustType::Unit => "void".to_string(),
-        RustType::Result => extract_first_generic_arg_from_type(t)
+        RustType::Result => extract_generic_arg_from_type(t, 0)
             .map_or_else(|| t.to_string(), |ok| rust_type_to_ts(&ok)),
-        RustType::Option => extract_first_generic_arg_from_type(t).map_or_else(
+        RustType::Option => extract_generic_arg_from_type(t, 0).map_or_else(
             || t.to_string(),
             |inner| format!("{} | null", rust_type_to_ts(&inner)),
         ),
-        RustType::Vec => extract_first_generic_arg_from_type(t).map_or_else(
+        RustType::Vec => extract_generic_arg_from_type(t, 0).map_or_else(
             || t.to_string(),
             |inner| format!("{}[]", rust_type_to_ts(&inner)),
         ),
@@ -69,19 +75,48 @@ fn classify_rust_type(t: &str) -> RustType {
         "String" | "&str" => RustType::Str,
         "bool" => RustType::Bool,
         "()" => RustType::Unit,
-        _ if t.starts_with("Result<") => RustType::Result,
-        _ if t.starts_with("Option<") => RustType::Option,
-        _ if t.starts_with("Vec<") => RustType::Vec,
-        _ => RustType::Other,
+        _ => match generic_base_name(t) {
+            Some("Result") => RustType::Result,
+            Some("Option") => RustType::Option,
+            Some("Vec") => RustType::Vec,
+            _ => RustType::Other,
+        },
     }
 }
 
-/// Extract the first generic type argument from a full generic type string
-/// (e.g. `Result<fn() -> bool, String>` → `fn() -> bool`).
+/// Last path segment of a generic type: `tauri::Result<T>` → `Result`.
+fn generic_base_name(t: &str) -> Option<&str> {
+    let (path, _) = t.split_once('<')?;
+    path.trim().rsplit("::").next()
+}
+
+/// Last path segment of a type without its generic arguments: `api::ApiResult<T>` → `ApiResult`.
+#[must_use]
+pub fn rust_type_base_name(rust_type: &str) -> &str {
+    let t = rust_type.trim();
+    let path = t.split_once('<').map_or(t, |(path, _)| path).trim();
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// TypeScript type a command returning `rust_type` rejects with: `E` of `Result<T, E>`.
+///
+/// Aliases like `tauri::Result<T>` name no error type and yield `None`.
+#[must_use]
+pub fn result_error_type(rust_type: &str) -> Option<String> {
+    let t = rust_type.trim();
+    if !matches!(classify_rust_type(t), RustType::Result) {
+        return None;
+    }
+
+    extract_generic_arg_from_type(t, 1).map(|error| rust_type_to_ts(&error))
+}
+
+/// Extract the `index`-th generic type argument from a full generic type string
+/// (e.g. index 0 of `Result<fn() -> bool, String>` → `fn() -> bool`).
 ///
 /// Uses tree-sitter to correctly parse complex types including function pointers,
 /// nested generics, and other Rust type syntax.
-fn extract_first_generic_arg_from_type(full_type: &str) -> Option<String> {
+fn extract_generic_arg_from_type(full_type: &str, index: usize) -> Option<String> {
     let wrapper = format!("type _X = {full_type};");
     let tree = crate::ts_tree_utils::parse_rust(&wrapper)?;
     let root = tree.root_node();
@@ -95,13 +130,138 @@ fn extract_first_generic_arg_from_type(full_type: &str) -> Option<String> {
     // For generic types, type_node is `generic_type` with `type_arguments`
     let type_args = type_node.child_by_field_name("type_arguments")?;
 
-    // First named child of type_arguments (skipping `<` and `,` tokens)
-    let first_arg = type_args.named_child(0)?;
-    let arg_text = &wrapper[first_arg.byte_range()];
+    // Named children of type_arguments skip the `<` and `,` tokens
+    let arg = type_args.named_child(index)?;
+    let arg_text = &wrapper[arg.byte_range()];
 
     Some(arg_text.to_string())
 }
 
+/// Expand a use of `alias` by one level: with `type ApiResult<T> = Result<T, AppError>`,
+/// `ApiResult<User>` becomes `Result<User, AppError>`.
+///
+/// Generic parameters without a matching argument are left as written. Returns `None` when
+/// `rust_type` does not name the alias or the alias refers to itself.
+#[must_use]
+pub fn expand_type_alias(rust_type: &str, alias: &RustTypeAlias) -> Option<String> {
+    let t = rust_type.trim();
+    if rust_type_base_name(t) != alias.name {
+        return None;
+    }
+
+    let wrapper = format!("type _X = {};", alias.target);
+    let tree = crate::ts_tree_utils::parse_rust(&wrapper)?;
+    let type_node = tree
+        .root_node()
+        .named_child(0)?
+        .child_by_field_name("type")?;
+    let value_start = type_node.start_byte();
+
+    // Substitute generic parameter uses back to front so earlier byte offsets stay valid
+    let mut uses = Vec::new();
+    collect_type_identifiers(type_node, &mut uses);
+    let mut expanded = wrapper[type_node.byte_range()].to_string();
+    for node in uses.into_iter().rev() {
+        let Some(index) = alias
+            .generics
+            .iter()
+            .position(|g| *g == wrapper[node.byte_range()])
+        else {
+            continue;
+        };
+        if let Some(arg) = extract_generic_arg_from_type(t, index) {
+            let range = node.start_byte() - value_start..node.end_byte() - value_start;
+            expanded.replace_range(range, &arg);
+        }
+    }
+
+    // `type Foo<T> = Foo<T>` does not compile, but guard against it for half-typed code
+    (rust_type_base_name(&expanded) != alias.name).then_some(expanded)
+}
+
+fn collect_type_identifiers<'a>(node: tree_sitter::Node<'a>, out: &mut Vec<tree_sitter::Node<'a>>) {
+    if node.kind() == "type_identifier" {
+        out.push(node);
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_type_identifiers(child, out);
+    }
+}
+
+/// Extract `type` aliases declared at file or inline module level.
+///
+/// Associated types inside `impl` and `trait` blocks are skipped, as they cannot be named
+/// on their own.
+#[must_use]
+pub fn extract_type_aliases_from_tree(
+    root: tree_sitter::Node<'_>,
+    content: &str,
+    source_path: &Path,
+) -> Vec<RustTypeAlias> {
+    let mut aliases = Vec::new();
+    collect_type_aliases(root, content, source_path, &mut aliases);
+    aliases
+}
+
+fn collect_type_aliases(
+    node: tree_sitter::Node<'_>,
+    content: &str,
+    source_path: &Path,
+    aliases: &mut Vec<RustTypeAlias>,
+) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "type_item" => aliases.extend(parse_type_alias(child, content, source_path)),
+            "mod_item" => {
+                if let Some(body) = child.child_by_field_name("body") {
+                    collect_type_aliases(body, content, source_path, aliases);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_type_alias(
+    node: tree_sitter::Node<'_>,
+    content: &str,
+    source_path: &Path,
+) -> Option<RustTypeAlias> {
+    let text = |n: tree_sitter::Node<'_>| {
+        n.utf8_text(content.as_bytes())
+            .unwrap_or_default()
+            .trim()
+            .to_string()
+    };
+
+    let name = text(node.child_by_field_name("name")?);
+    let target = text(node.child_by_field_name("type")?);
+
+    // `T`, `T: Bound`, `'a` and `const N: usize` all carry a `name` field
+    let generics = node
+        .child_by_field_name("type_parameters")
+        .map(|params| {
+            let mut cursor = params.walk();
+            params
+                .named_children(&mut cursor)
+                .filter_map(|param| param.child_by_field_name("name"))
+                .map(text)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(RustTypeAlias {
+        name,
+        generics,
+        target,
+        source_path: source_path.to_path_buf(),
+        range: node_range(node, content.as_bytes()),
+    })
+}
+
 /// Extract command schemas from a pre-parsed tree root node.
 ///
 /// Use this when you already have a parsed tree (e.g. from `parse_rust_full`).
@@ -136,10 +296,12 @@ fn try_extract_command_schemas_from_node(
 
     while let Some(m) = matches.next() {
         // Check that fn_item has a #[tauri::command] attribute
+        let mut rename_all = None;
         if let Some(item_cap) = find_capture(m, fn_item_idx) {
-            if !crate::rust_attr::has_tauri_command_attr(item_cap.node, content) {
+            let Some(attr) = crate::rust_attr::tauri_command_attr(item_cap.node, content) else {
                 continue;
-            }
+            };
+            rename_all = attr.rename_all;
         }
 
         let fn_name = capture_text(m, fn_name_idx, content.as_bytes()).to_string();
@@ -173,6 +335,7 @@ fn try_extract_command_schemas_from_node(
             return_type,
             source_path: source_path.to_path_buf(),
             generator: GeneratorKind::RustSource,
+            rename_all,
         });
     }
 
@@ -186,6 +349,7 @@ fn try_extract_command_schemas_from_node(
 ///
 /// Iterates `parameter` children, extracting name and type.
 /// Skips Tauri-injected parameters: `AppHandle`, `State<_>`, `Window`.
+/// Destructuring patterns are flattened via [`pattern_bindings`].
 fn parse_rust_params_from_node(
     params_node: tree_sitter::Node<'_>,
     content: &str,
@@ -198,16 +362,204 @@ fn parse_rust_params_from_node(
             continue;
         }
 
-        let name = child
-            .child_by_field_name("pattern")
-            .and_then(|n| n.utf8_text(content.as_bytes()).ok())
+        let (Some(pattern), Some(type_node)) = (
+            child.child_by_field_name("pattern"),
+            child.child_by_field_name("type"),
+        ) else {
+            continue;
+        };
+
+        let rust_type = type_node.utf8_text(content.as_bytes()).unwrap_or("").trim();
+        if is_tauri_injected_type(rust_type) {
+            continue;
+        }
+
+        for (name, rust_type) in pattern_bindings(pattern, type_node, content) {
+            if TAURI_SELF_PARAMS.contains(&name.as_str()) || name.is_empty() {
+                continue;
+            }
+            result.push(ParamSchema {
+                name,
+                ts_type: rust_type_to_ts(&rust_type),
+                optional: is_optional_rust_type(&rust_type),
+            });
+        }
+    }
+
+    result
+}
+
+/// Whether a parameter type is supplied by Tauri rather than the frontend.
+fn is_tauri_injected_type(rust_type: &str) -> bool {
+    let t = rust_type.trim_start_matches('&').trim();
+    let base = generic_base_name(t)
+        .unwrap_or(t)
+        .rsplit("::")
+        .next()
+        .unwrap_or(t);
+    base == "State" || TAURI_INJECTED_TYPES.contains(&base)
+}
+
+/// Pair each identifier bound by a parameter pattern with its Rust type.
+///
+/// - `name: T` / `mut name: T` → `(name, T)`
+/// - `(a, b): (A, B)` → `(a, A)`, `(b, B)`
+/// - `Json(payload): Json<P>` → `(payload, P)` (newtype extractor, innermost type argument)
+///
+/// Patterns that can't be matched against their type (wildcards, struct patterns,
+/// arity mismatches) yield nothing.
+fn pattern_bindings(
+    pattern: tree_sitter::Node<'_>,
+    type_node: tree_sitter::Node<'_>,
+    content: &str,
+) -> Vec<(String, String)> {
+    let text = |n: tree_sitter::Node<'_>| {
+        n.utf8_text(content.as_bytes())
             .unwrap_or("")
-            .to_string();
+            .trim()
+            .to_string()
+    };
+
+    match pattern.kind() {
+        "identifier" | "self" => vec![(text(pattern), text(type_node))],
+        "mut_pattern" | "ref_pattern" => pattern
+            .named_children(&mut pattern.walk())
+            .last()
+            .map(|inner| pattern_bindings(inner, type_node, content))
+            .unwrap_or_default(),
+        "tuple_pattern" if type_node.kind() == "tuple_type" => {
+            let sub_patterns: Vec<_> = pattern.named_children(&mut pattern.walk()).collect();
+            let sub_types: Vec<_> = type_node.named_children(&mut type_node.walk()).collect();
+            if sub_patterns.len() != sub_types.len() {
+                return Vec::new();
+            }
+            sub_patterns
+                .into_iter()
+                .zip(sub_types)
+                .flat_map(|(p, t)| pattern_bindings(p, t, content))
+                .collect()
+        }
+        "tuple_struct_pattern" => {
+            // Named children are the wrapper path (`type` field) followed by the sub-patterns
+            let wrapper_id = pattern.child_by_field_name("type").map(|n| n.id());
+            let mut sub_patterns = pattern
+                .named_children(&mut pattern.walk())
+                .filter(|n| Some(n.id()) != wrapper_id)
+                .collect::<Vec<_>>()
+                .into_iter();
+            let (Some(inner_pattern), None) = (sub_patterns.next(), sub_patterns.next()) else {
+                return Vec::new();
+            };
+            let Some(inner_type) =
+                type_node
+                    .child_by_field_name("type_arguments")
+                    .and_then(|args| {
+                        args.named_children(&mut args.walk())
+                            .filter(|n| n.kind() != "lifetime")
+                            .last()
+                    })
+            else {
+                return Vec::new();
+            };
+            pattern_bindings(inner_pattern, inner_type, content)
+        }
+        _ => Vec::new(),
+    }
+}
+
+// ─── Struct schema extraction from Rust source ───────────────────────────────
 
-        if TAURI_SELF_PARAMS.contains(&name.as_str()) {
+const RUST_STRUCTS_QUERY: &str = include_str!("queries/rust_structs.scm");
+
+/// Extract struct field layouts from a pre-parsed tree root node.
+///
+/// Only structs with named fields are returned; tuple and unit structs are skipped.
+#[must_use]
+pub fn extract_struct_schemas_from_tree(
+    root: tree_sitter::Node<'_>,
+    content: &str,
+    source_path: &Path,
+) -> Vec<StructSchema> {
+    let Some((query, mut cursor)) = setup_rust_query(RUST_STRUCTS_QUERY) else {
+        return Vec::new();
+    };
+
+    let struct_name_idx = query.capture_index_for_name("struct_name");
+    let struct_fields_idx = query.capture_index_for_name("struct_fields");
+    let struct_item_idx = query.capture_index_for_name("struct_item");
+
+    let mut schemas = Vec::new();
+    let mut matches = cursor.matches(&query, root, content.as_bytes());
+
+    while let Some(m) = matches.next() {
+        let struct_name = capture_text(m, struct_name_idx, content.as_bytes());
+        if struct_name.is_empty() {
             continue;
         }
 
+        let parsed = find_capture(m, struct_fields_idx)
+            .map(|cap| parse_struct_fields_from_node(cap.node, content))
+            .unwrap_or_default();
+
+        let mut fields = Vec::new();
+        let mut field_ranges = Vec::new();
+        let mut flattened = Vec::new();
+        let mut renamed = Vec::new();
+        for (field, range, flatten, rename) in parsed {
+            if flatten {
+                flattened.push(field.name.clone());
+            }
+            if let Some(rename) = rename {
+                renamed.push((field.name.clone(), rename));
+            }
+            fields.push(field);
+            field_ranges.push(range);
+        }
+
+        let (Some(item_cap), Some(name_cap)) = (
+            find_capture(m, struct_item_idx),
+            find_capture(m, struct_name_idx),
+        ) else {
+            continue;
+        };
+
+        schemas.push(StructSchema {
+            struct_name: struct_name.to_string(),
+            fields,
+            source_path: source_path.to_path_buf(),
+            range: node_range(item_cap.node, content.as_bytes()),
+            name_range: node_range(name_cap.node, content.as_bytes()),
+            field_ranges,
+            flattened,
+            rename_all: crate::rust_attr::serde_rename_all(item_cap.node, content),
+            renamed,
+        });
+    }
+
+    schemas
+}
+
+/// Extract `name: Type` pairs from a `field_declaration_list` node, mapping types to TypeScript.
+///
+/// Each field is returned with the range of its declaration, whether it is
+/// `#[serde(flatten)]` and its `#[serde(rename = "...")]` name.
+fn parse_struct_fields_from_node(
+    fields_node: tree_sitter::Node<'_>,
+    content: &str,
+) -> Vec<(ParamSchema, Range, bool, Option<String>)> {
+    let mut result = Vec::new();
+    let mut cursor = fields_node.walk();
+
+    for child in fields_node.children(&mut cursor) {
+        if child.kind() != "field_declaration" {
+            continue;
+        }
+
+        let name = child
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(content.as_bytes()).ok())
+            .unwrap_or("");
+
         let rust_type = child
             .child_by_field_name("type")
             .and_then(|n| n.utf8_text(content.as_bytes()).ok())
@@ -218,13 +570,16 @@ fn parse_rust_params_from_node(
             continue;
         }
 
-        if TAURI_INJECTED_TYPES.contains(&rust_type) || rust_type.starts_with("State<") {
-            continue;
-        }
-
-        let ts_type = rust_type_to_ts(rust_type);
-
-        result.push(ParamSchema { name, ts_type });
+        result.push((
+            ParamSchema {
+                name: name.to_string(),
+                ts_type: rust_type_to_ts(rust_type),
+                optional: is_optional_rust_type(rust_type),
+            },
+            node_range(child, content.as_bytes()),
+            crate::rust_attr::has_serde_flatten(child, content),
+            crate::rust_attr::serde_rename(child, content),
+        ));
     }
 
     result
@@ -258,21 +613,32 @@ fn try_extract_event_schemas_from_node(
     let (query, mut cursor) =
         setup_rust_query(RUST_EMIT_QUERY).ok_or("failed to build rust_emit query")?;
 
-    let event_name_idx = query.capture_index_for_name("event_name");
-    let payload_arg_idx = query.capture_index_for_name("payload_arg");
+    let method_name_idx = query.capture_index_for_name("method_name");
+    let call_args_idx = query.capture_index_for_name("call_args");
 
     let mut schemas = Vec::new();
     let mut seen_events = std::collections::HashSet::new();
     let mut matches = cursor.matches(&query, root, content.as_bytes());
 
     while let Some(m) = matches.next() {
-        let event_name = capture_text(m, event_name_idx, content.as_bytes());
+        let method_name = capture_text(m, method_name_idx, content.as_bytes());
+        let Some(method) = rust_event_method(method_name).filter(|m| m.behavior == Behavior::Emit)
+        else {
+            continue;
+        };
+        let Some(args) = find_capture(m, call_args_idx).map(|cap| cap.node) else {
+            continue;
+        };
+
+        let event_name = rust_string_arg(args, method.arg_index)
+            .and_then(|node| node.utf8_text(content.as_bytes()).ok())
+            .unwrap_or_default();
         if event_name.is_empty() || !seen_events.insert(event_name.to_string()) {
             continue;
         }
 
-        let payload_type = find_capture(m, payload_arg_idx)
-            .and_then(|cap| resolve_emit_payload_type(cap.node, content))
+        let payload_type = rust_call_arg(args, method.arg_index + 1)
+            .and_then(|node| resolve_emit_payload_type(node, content))
             .unwrap_or_else(|| "unknown".to_string());
 
         if payload_type != "unknown" {
@@ -1,4 +1,5 @@
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use walkdir::{DirEntry, WalkDir};
 
 /// Ignored folder list
@@ -17,25 +18,17 @@ const EXCLUDED_DIRS: &[&str] = &[
 /// Ignored files list
 const EXCLUDED_FILES: &[&str] = &["vite.config.ts"];
 
-/// Ignored file suffixes list
-const EXCLUDED_FILE_SUFFIXES: &[&str] = &[".d.ts"];
-
-use crate::constants::SUPPORTED_EXTENSIONS;
+use crate::constants::{
+    DEFAULT_SCAN_MAX_FILES, SCAN_TIME_BUDGET_MS, SUPPORTED_EXTENSIONS, TEXT_SCAN_EXTENSIONS,
+};
+use crate::error::{Error, Result};
 
 /// Determines if a file or directory name matches the ignore rules
 fn is_ignored_entry_name(name: &str, is_dir: bool) -> bool {
     if is_dir {
         EXCLUDED_DIRS.contains(&name)
     } else {
-        let name_lc = name.to_lowercase();
-
-        let is_excluded_file = EXCLUDED_FILES.contains(&name);
-
-        let is_excluded_suffix = EXCLUDED_FILE_SUFFIXES
-            .iter()
-            .any(|suffix| name_lc.ends_with(suffix));
-
-        is_excluded_file || is_excluded_suffix
+        EXCLUDED_FILES.contains(&name)
     }
 }
 
@@ -98,26 +91,532 @@ pub fn find_src_tauri_dir(root: &Path) -> Option<PathBuf> {
     find_tauri_config(root).and_then(|p| p.parent().map(std::path::Path::to_path_buf))
 }
 
+/// Bounds of a workspace scan, so opening a huge folder (a home directory, a monorepo with
+/// vendored trees) cannot enumerate files forever
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanLimits {
+    /// Maximum number of files collected (`tarus.scan.maxFiles`)
+    pub max_files: usize,
+    /// Wall-clock budget for walking the file system; `None` walks until done
+    pub time_budget: Option<Duration>,
+}
+
+impl Default for ScanLimits {
+    fn default() -> Self {
+        Self {
+            max_files: DEFAULT_SCAN_MAX_FILES,
+            time_budget: Some(Duration::from_millis(SCAN_TIME_BUDGET_MS)),
+        }
+    }
+}
+
+/// The limit that cut a workspace scan short
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanLimitHit {
+    MaxFiles(usize),
+    TimeBudget(Duration),
+}
+
+/// Files found by [`scan_workspace_files_with_limits`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkspaceScan {
+    pub files: Vec<PathBuf>,
+    /// Set when the scan stopped early; `files` then holds what was found until then
+    pub limit_hit: Option<ScanLimitHit>,
+}
+
+/// Running file count and clock of a scan, shared by every directory it walks
+struct ScanBudget {
+    limits: ScanLimits,
+    started: Instant,
+    found: usize,
+    hit: Option<ScanLimitHit>,
+}
+
+impl ScanBudget {
+    fn new(limits: ScanLimits) -> Self {
+        Self {
+            limits,
+            started: Instant::now(),
+            found: 0,
+            hit: None,
+        }
+    }
+
+    /// Whether the scan must stop, recording the first limit reached
+    fn exhausted(&mut self) -> bool {
+        if self.hit.is_none() {
+            if let Some(budget) = self.limits.time_budget {
+                if self.started.elapsed() >= budget {
+                    self.hit = Some(ScanLimitHit::TimeBudget(budget));
+                }
+            }
+        }
+        self.hit.is_some()
+    }
+
+    /// Count a scannable file, unless `max_files` were already collected: the limit is only
+    /// hit by a file past it, so a workspace of exactly `max_files` files is scanned in full
+    fn take_file(&mut self) -> bool {
+        if self.found >= self.limits.max_files {
+            self.hit = Some(ScanLimitHit::MaxFiles(self.limits.max_files));
+            return false;
+        }
+        self.found += 1;
+        true
+    }
+}
+
 /// Basic scan of files in the working directory
-/// Returns a list of all files to be indexed
+/// Returns a list of all files that may be indexed (text-scan files are skipped unless enabled)
+///
+/// The scan is bounded by [`ScanLimits::default`]; see [`scan_workspace_files_with_limits`].
 #[must_use]
-pub fn scan_workspace_files(root: &Path) -> Vec<PathBuf> {
-    WalkDir::new(root)
+pub fn scan_workspace_files(root: &Path, extra_paths: &[PathBuf]) -> Vec<PathBuf> {
+    scan_workspace_files_with_limits(root, extra_paths, ScanLimits::default()).files
+}
+
+/// Scan of files in the working directory that stops once `limits` are reached
+///
+/// Also scans the `src` directories of Cargo workspace member crates, of crates `src-tauri`
+/// depends on by path, and tsconfig path alias targets that live outside `root`, so commands
+/// defined in sibling library crates and invokes in shared frontend packages are indexed too.
+///
+/// `extra_paths` (resolved `tarus.scan.extraPaths` entries, see [`resolve_extra_paths`]) are
+/// scanned as well, even inside otherwise ignored directories such as `target`, so files that
+/// `build.rs` generates into `OUT_DIR` can be indexed.
+///
+/// When a limit is hit the walk stops and the files found so far are returned, with
+/// [`WorkspaceScan::limit_hit`] set.
+#[must_use]
+pub fn scan_workspace_files_with_limits(
+    root: &Path,
+    extra_paths: &[PathBuf],
+    limits: ScanLimits,
+) -> WorkspaceScan {
+    let mut budget = ScanBudget::new(limits);
+    let mut files = scan_dir(root, &mut budget);
+
+    let mut crate_src_dirs = crate::config_reader::discover_workspace_member_src_dirs(root);
+    crate_src_dirs.extend(crate::config_reader::discover_path_dependency_src_dirs(
+        root,
+    ));
+    crate_src_dirs.sort();
+    crate_src_dirs.dedup();
+
+    for src_dir in crate_src_dirs {
+        if !src_dir.starts_with(root) {
+            files.extend(scan_dir(&src_dir, &mut budget));
+        }
+    }
+
+    if let Some(resolver) = PathAliasResolver::from_workspace(root) {
+        for alias_dir in resolver.alias_dirs() {
+            if !alias_dir.starts_with(root) {
+                files.extend(scan_dir(&alias_dir, &mut budget));
+            }
+        }
+    }
+
+    let mut seen: std::collections::HashSet<PathBuf> = files.iter().cloned().collect();
+    for extra_path in extra_paths {
+        for file in scan_dir(extra_path, &mut budget) {
+            if seen.insert(file.clone()) {
+                files.push(file);
+            }
+        }
+    }
+
+    WorkspaceScan {
+        files,
+        limit_hit: budget.hit,
+    }
+}
+
+/// Resolve `tarus.scan.extraPaths` entries, relative ones against `root`.
+///
+/// Returns the existing files and directories, and the entries that do not exist.
+#[must_use]
+pub fn resolve_extra_paths(root: &Path, entries: &[String]) -> (Vec<PathBuf>, Vec<String>) {
+    let mut existing = Vec::new();
+    let mut missing = Vec::new();
+
+    for entry in entries {
+        let path = root.join(entry);
+        if path.exists() {
+            existing.push(path);
+        } else {
+            missing.push(entry.clone());
+        }
+    }
+
+    (existing, missing)
+}
+
+/// Collect supported, text-scan and capability files under `dir`, respecting ignore rules
+/// below it
+///
+/// Stops as soon as `budget` is exhausted.
+fn scan_dir(dir: &Path, budget: &mut ScanBudget) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let entries = WalkDir::new(dir)
         .into_iter()
-        .filter_entry(|e| !should_skip(e))
-        .filter_map(std::result::Result::ok)
-        .filter(|e| {
-            if !e.file_type().is_file() {
-                return false;
+        .filter_entry(|e| e.depth() == 0 || !should_skip(e))
+        .filter_map(std::result::Result::ok);
+
+    for entry in entries {
+        if budget.exhausted() {
+            break;
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let is_scannable = entry
+            .path()
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| {
+                SUPPORTED_EXTENSIONS.contains(&ext) || TEXT_SCAN_EXTENSIONS.contains(&ext)
+            })
+            || crate::permissions::is_capability_file(entry.path());
+        if is_scannable {
+            if !budget.take_file() {
+                break;
             }
+            files.push(entry.into_path());
+        }
+    }
 
-            e.path()
-                .extension()
-                .and_then(|ext| ext.to_str())
-                .is_some_and(|ext| SUPPORTED_EXTENSIONS.contains(&ext))
-        })
-        .map(walkdir::DirEntry::into_path)
-        .collect()
+    files
+}
+
+/// Frontend configuration files that may declare `compilerOptions.paths`, in lookup order
+const TS_CONFIG_FILES: &[&str] = &["tsconfig.json", "jsconfig.json"];
+
+/// Extensions tried when an import specifier omits one, in TypeScript's resolution order
+const MODULE_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "mts", "mjs", "vue", "svelte"];
+
+/// Resolves import specifiers through `tsconfig.json` / `jsconfig.json` path aliases
+///
+/// ```text
+/// "baseUrl": ".", "paths": { "@/*": ["src/*"] }
+///
+/// "@/utils/api"  →  <config dir>/src/utils/api.ts
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathAliasResolver {
+    /// Directory `paths` targets are relative to (`baseUrl`, or the declaring config's directory)
+    base_dir: PathBuf,
+    /// Whether `baseUrl` was set explicitly, enabling bare `src/utils/api` specifiers
+    has_base_url: bool,
+    /// `(pattern, targets)` pairs, each pattern containing at most one `*`
+    paths: Vec<(String, Vec<String>)>,
+}
+
+impl PathAliasResolver {
+    /// Load the resolver from the first `tsconfig.json`/`jsconfig.json` at or below `root`.
+    ///
+    /// The workspace root is preferred; otherwise the shallowest config found while respecting
+    /// ignore rules is used (e.g. `app/tsconfig.json` in a monorepo). An unreadable or
+    /// malformed config is treated as absent.
+    #[must_use]
+    pub fn from_workspace(root: &Path) -> Option<Self> {
+        let config = TS_CONFIG_FILES
+            .iter()
+            .map(|name| root.join(name))
+            .find(|p| p.is_file())
+            .or_else(|| {
+                WalkDir::new(root)
+                    .max_depth(3)
+                    .sort_by_key(walkdir::DirEntry::depth)
+                    .into_iter()
+                    .filter_entry(|e| !should_skip(e))
+                    .filter_map(std::result::Result::ok)
+                    .find(|e| {
+                        e.file_type().is_file()
+                            && TS_CONFIG_FILES.contains(&e.file_name().to_str().unwrap_or(""))
+                    })
+                    .map(walkdir::DirEntry::into_path)
+            })?;
+
+        Self::from_config_file(&config).ok().flatten()
+    }
+
+    /// Load the resolver from a specific config file, following relative `extends` chains.
+    ///
+    /// Returns `Ok(None)` if the chain declares neither `paths` nor `baseUrl`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if a config in the chain cannot be read, or [`Error::Config`] if
+    /// one is not valid JSON(C).
+    pub fn from_config_file(config_path: &Path) -> Result<Option<Self>> {
+        let mut resolver = Self {
+            base_dir: config_path.parent().unwrap_or(Path::new("")).to_path_buf(),
+            has_base_url: false,
+            paths: Vec::new(),
+        };
+        let mut paths_dir = None;
+
+        // Walk from the config up its `extends` chain; the closest definition of each option wins
+        let mut current = Some(config_path.to_path_buf());
+        for _ in 0..8 {
+            let Some(path) = current.take() else { break };
+            let content = std::fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+            let json =
+                serde_json::from_str::<serde_json::Value>(&strip_jsonc(&content)).map_err(|e| {
+                    Error::Config {
+                        path: path.clone(),
+                        message: e.to_string(),
+                    }
+                })?;
+            let dir = path.parent().unwrap_or(Path::new("")).to_path_buf();
+            let options = json.get("compilerOptions");
+
+            if !resolver.has_base_url {
+                if let Some(base_url) = options
+                    .and_then(|o| o.get("baseUrl"))
+                    .and_then(|v| v.as_str())
+                {
+                    resolver.base_dir = normalize(&dir.join(base_url));
+                    resolver.has_base_url = true;
+                }
+            }
+
+            if paths_dir.is_none() {
+                if let Some(paths) = options
+                    .and_then(|o| o.get("paths"))
+                    .and_then(|v| v.as_object())
+                {
+                    resolver.paths = paths
+                        .iter()
+                        .map(|(pattern, targets)| {
+                            let targets = targets
+                                .as_array()
+                                .map(|a| {
+                                    a.iter()
+                                        .filter_map(|t| t.as_str().map(str::to_string))
+                                        .collect()
+                                })
+                                .unwrap_or_default();
+                            (pattern.clone(), targets)
+                        })
+                        .collect();
+                    paths_dir = Some(dir.clone());
+                }
+            }
+
+            current = json
+                .get("extends")
+                .and_then(|v| v.as_str())
+                .filter(|e| e.starts_with('.'))
+                .map(|e| {
+                    let extended = normalize(&dir.join(e));
+                    if extended.is_file() || extended.extension().is_some_and(|ext| ext == "json") {
+                        extended
+                    } else {
+                        let mut with_json = extended.into_os_string();
+                        with_json.push(".json");
+                        PathBuf::from(with_json)
+                    }
+                });
+        }
+
+        // Without `baseUrl`, `paths` are relative to the config that declares them
+        if !resolver.has_base_url {
+            resolver.base_dir = paths_dir.unwrap_or(resolver.base_dir);
+        }
+
+        Ok((resolver.has_base_url || !resolver.paths.is_empty()).then_some(resolver))
+    }
+
+    /// Map an import specifier to an existing file (or directory) on disk.
+    ///
+    /// Patterns are tried longest-prefix first, like the TypeScript compiler. A matched
+    /// target is probed as-is, with each known module extension, and as `<dir>/index.<ext>`.
+    /// Relative specifiers (`./x`) are not aliases and always return `None`.
+    #[must_use]
+    pub fn resolve(&self, specifier: &str) -> Option<PathBuf> {
+        self.candidates(specifier)
+            .iter()
+            .find_map(|candidate| probe_module(candidate))
+    }
+
+    /// Module paths an aliased specifier may refer to, in the order [`Self::resolve`] probes
+    /// them, without touching the filesystem (extensions and `index` files are not added).
+    #[must_use]
+    pub fn candidates(&self, specifier: &str) -> Vec<PathBuf> {
+        if specifier.starts_with('.') {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<(usize, &Vec<String>, &str)> = self
+            .paths
+            .iter()
+            .filter_map(|(pattern, targets)| {
+                let (prefix_len, captured) = match pattern.split_once('*') {
+                    Some((prefix, suffix)) => {
+                        if specifier.len() < prefix.len() + suffix.len() {
+                            return None;
+                        }
+                        let captured = specifier.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                        (prefix.len(), captured)
+                    }
+                    None if pattern == specifier => (pattern.len(), ""),
+                    None => return None,
+                };
+                Some((prefix_len, targets, captured))
+            })
+            .collect();
+        matches.sort_by_key(|(prefix_len, _, _)| std::cmp::Reverse(*prefix_len));
+
+        let mut candidates: Vec<PathBuf> = matches
+            .into_iter()
+            .flat_map(|(_, targets, captured)| {
+                targets.iter().map(move |target| {
+                    normalize(&self.base_dir.join(target.replacen('*', captured, 1)))
+                })
+            })
+            .collect();
+
+        if self.has_base_url {
+            candidates.push(normalize(&self.base_dir.join(specifier)));
+        }
+
+        candidates
+    }
+
+    /// Existing directories targeted by wildcard aliases (`"@shared/*": ["../shared/src/*"]`)
+    #[must_use]
+    pub fn alias_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs: Vec<PathBuf> = self
+            .paths
+            .iter()
+            .filter_map(|(pattern, _)| {
+                let (prefix, _) = pattern.split_once('*')?;
+                self.resolve(prefix)
+            })
+            .filter(|p| p.is_dir())
+            .collect();
+        dirs.sort();
+        dirs.dedup();
+        dirs
+    }
+}
+
+/// Module paths an import specifier written in `importer` may refer to: the specifier joined
+/// to the importer's directory when relative, otherwise the `aliases` candidates.
+///
+/// Purely lexical; pair with [`module_files`] to get the files each path may name.
+#[must_use]
+pub fn import_candidates(
+    importer: &Path,
+    specifier: &str,
+    aliases: Option<&PathAliasResolver>,
+) -> Vec<PathBuf> {
+    if specifier.starts_with('.') {
+        let dir = importer.parent().unwrap_or(Path::new(""));
+        return vec![normalize(&dir.join(specifier))];
+    }
+    aliases.map(|a| a.candidates(specifier)).unwrap_or_default()
+}
+
+/// Files an extensionless module path may name, in resolution order: the path itself, with
+/// each known module extension, then `<dir>/index.<ext>`.
+#[must_use]
+pub fn module_files(path: &Path) -> Vec<PathBuf> {
+    let mut files = vec![path.to_path_buf()];
+    if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
+        files.extend(
+            MODULE_EXTENSIONS
+                .iter()
+                .map(|ext| path.with_file_name(format!("{file_name}.{ext}"))),
+        );
+    }
+    files.extend(
+        MODULE_EXTENSIONS
+            .iter()
+            .map(|ext| path.join(format!("index.{ext}"))),
+    );
+    files
+}
+
+/// Find the file an extensionless module path refers to, falling back to the directory itself.
+fn probe_module(path: &Path) -> Option<PathBuf> {
+    module_files(path)
+        .into_iter()
+        .find(|p| p.is_file())
+        .or_else(|| path.is_dir().then(|| path.to_path_buf()))
+}
+
+/// Lexically resolve `.` and `..` components without touching the filesystem.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Strip `//` and `/* */` comments and trailing commas so JSONC configs parse as JSON.
+fn strip_jsonc(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(ch) = chars.next() {
+        if in_string {
+            out.push(ch);
+            match ch {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (ch, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(ch);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            ('}' | ']', _) => {
+                let trimmed_len = out.trim_end().len();
+                if out[..trimmed_len].ends_with(',') {
+                    out.remove(trimmed_len - 1);
+                }
+                out.push(ch);
+            }
+            _ => out.push(ch),
+        }
+    }
+
+    out
 }
 
 #[cfg(test)]
@@ -139,10 +638,10 @@ mod tests {
 
         // File exclusions
         assert!(is_ignored_entry_name("vite.config.ts", false));
-        assert!(is_ignored_entry_name("index.d.ts", false));
-        assert!(is_ignored_entry_name("types.d.ts", false));
 
-        // Allowed files
+        // Allowed files; declaration files are scanned for the ambient types they declare
+        assert!(!is_ignored_entry_name("index.d.ts", false));
+        assert!(!is_ignored_entry_name("types.d.ts", false));
         assert!(!is_ignored_entry_name("main.rs", false));
         assert!(!is_ignored_entry_name("app.tsx", false));
         assert!(!is_ignored_entry_name("Component.vue", false));
@@ -204,6 +703,368 @@ mod tests {
         fs::remove_dir_all(&temp_dir).unwrap();
     }
 
+    #[test]
+    fn test_scan_includes_cargo_workspace_member_crates() {
+        use crate::indexer::ProjectIndex;
+        use crate::syntax::{Behavior, EntityType};
+        use std::fs;
+
+        // repo/Cargo.toml is the workspace; the editor opens repo/app only
+        let repo = tempfile::tempdir().unwrap();
+        let write = |rel: &str, content: &str| {
+            let path = repo.path().join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app/src-tauri\", \"crates/*\"]\nexclude = [\"crates/legacy\"]\n",
+        );
+        write("app/src-tauri/tauri.conf.json", "{}");
+        write("app/src-tauri/Cargo.toml", "[package]\nname = \"app\"\n");
+        write("app/src-tauri/src/lib.rs", "fn run() {}\n");
+        write(
+            "app/src/main.ts",
+            "import { invoke } from \"@tauri-apps/api/core\";\ninvoke(\"load_settings\");\n",
+        );
+        write("crates/core/Cargo.toml", "[package]\nname = \"core\"\n");
+        write(
+            "crates/core/src/lib.rs",
+            "#[tauri::command]\npub fn load_settings() {}\n",
+        );
+        write("crates/legacy/Cargo.toml", "[package]\nname = \"legacy\"\n");
+        write(
+            "crates/legacy/src/lib.rs",
+            "#[tauri::command]\nfn old() {}\n",
+        );
+
+        let app_root = repo.path().join("app");
+        let files = scan_workspace_files(&app_root, &[]);
+        assert!(files.contains(&repo.path().join("crates/core/src/lib.rs")));
+        assert!(!files.contains(&repo.path().join("crates/legacy/src/lib.rs")));
+        assert_eq!(
+            files
+                .iter()
+                .filter(|f| f.ends_with("src-tauri/src/lib.rs"))
+                .count(),
+            1,
+            "Members inside the root are not scanned twice"
+        );
+
+        let index = ProjectIndex::new();
+        for file in files {
+            crate::file_processor::process_file_index(&file, &index).unwrap();
+        }
+        let locations = index.get_locations(EntityType::Command, "load_settings");
+        assert!(locations.iter().any(|l| l.behavior == Behavior::Definition));
+        assert!(locations.iter().any(|l| l.behavior == Behavior::Call));
+    }
+
+    #[test]
+    fn test_scan_includes_path_dependency_crates() {
+        use crate::indexer::ProjectIndex;
+        use crate::syntax::{Behavior, EntityType};
+        use std::fs;
+
+        // The editor opens repo/app; app/src-tauri depends on repo/core by path
+        let repo = tempfile::tempdir().unwrap();
+        let write = |rel: &str, content: &str| {
+            let path = repo.path().join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write("app/src-tauri/tauri.conf.json", "{}");
+        write(
+            "app/src-tauri/Cargo.toml",
+            "[package]\nname = \"app\"\n\n[dependencies]\ntauri = \"2\"\ncore = { path = \"../../core\" }\nfar = { path = \"../../../../../far\" }\n",
+        );
+        write("app/src-tauri/src/lib.rs", "fn run() {}\n");
+        write(
+            "app/src/main.ts",
+            "import { invoke } from \"@tauri-apps/api/core\";\ninvoke(\"load_settings\");\n",
+        );
+        write("core/Cargo.toml", "[package]\nname = \"core\"\n");
+        write(
+            "core/src/lib.rs",
+            "#[tauri::command]\npub fn load_settings() {}\n",
+        );
+
+        let app_root = repo.path().join("app");
+        let files = scan_workspace_files(&app_root, &[]);
+        assert!(files.contains(&repo.path().join("core/src/lib.rs")));
+
+        let index = ProjectIndex::new();
+        for file in files {
+            crate::file_processor::process_file_index(&file, &index).unwrap();
+        }
+        let locations = index.get_locations(EntityType::Command, "load_settings");
+        assert!(locations.iter().any(|l| l.behavior == Behavior::Definition));
+        assert!(locations.iter().any(|l| l.behavior == Behavior::Call));
+    }
+
+    #[test]
+    fn test_scan_stops_at_max_files() {
+        let root = tempfile::tempdir().unwrap();
+        for dir in 0..10 {
+            let dir = root.path().join(format!("pkg{dir}/src"));
+            std::fs::create_dir_all(&dir).unwrap();
+            for file in 0..50 {
+                std::fs::write(dir.join(format!("mod{file}.ts")), "").unwrap();
+            }
+        }
+        let limits = ScanLimits {
+            max_files: 120,
+            time_budget: None,
+        };
+
+        let scan = scan_workspace_files_with_limits(root.path(), &[], limits);
+        assert_eq!(scan.files.len(), 120);
+        assert_eq!(scan.limit_hit, Some(ScanLimitHit::MaxFiles(120)));
+
+        let unbounded = ScanLimits {
+            max_files: usize::MAX,
+            time_budget: None,
+        };
+        let scan = scan_workspace_files_with_limits(root.path(), &[], unbounded);
+        assert_eq!(scan.files.len(), 500);
+        assert_eq!(scan.limit_hit, None);
+
+        // Only a file past the limit hits it
+        let exact = ScanLimits {
+            max_files: 500,
+            time_budget: None,
+        };
+        let scan = scan_workspace_files_with_limits(root.path(), &[], exact);
+        assert_eq!(scan.files.len(), 500);
+        assert_eq!(scan.limit_hit, None);
+
+        let one_short = ScanLimits {
+            max_files: 499,
+            time_budget: None,
+        };
+        let scan = scan_workspace_files_with_limits(root.path(), &[], one_short);
+        assert_eq!(scan.files.len(), 499);
+        assert_eq!(scan.limit_hit, Some(ScanLimitHit::MaxFiles(499)));
+    }
+
+    #[test]
+    fn test_scan_stops_when_time_budget_is_spent() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("main.ts"), "").unwrap();
+        let limits = ScanLimits {
+            max_files: usize::MAX,
+            time_budget: Some(Duration::ZERO),
+        };
+
+        let scan = scan_workspace_files_with_limits(root.path(), &[], limits);
+        assert!(scan.files.is_empty());
+        assert_eq!(
+            scan.limit_hit,
+            Some(ScanLimitHit::TimeBudget(Duration::ZERO))
+        );
+    }
+
+    #[test]
+    fn test_scan_includes_extra_paths() {
+        use crate::indexer::ProjectIndex;
+        use crate::syntax::{Behavior, EntityType};
+        use std::fs;
+
+        // build.rs writes commands into OUT_DIR under target/, which the normal scan skips
+        let repo = tempfile::tempdir().unwrap();
+        let write = |rel: &str, content: &str| {
+            let path = repo.path().join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write("app/src-tauri/tauri.conf.json", "{}");
+        write("app/src-tauri/src/lib.rs", "fn run() {}\n");
+        write(
+            "app/src/main.ts",
+            "import { invoke } from \"@tauri-apps/api/core\";\ninvoke(\"generated_cmd\");\n",
+        );
+        write(
+            "app/src-tauri/target/debug/build/app-1/out/commands.rs",
+            "#[tauri::command]\npub fn generated_cmd() {}\n",
+        );
+        write(
+            "shared/extra.rs",
+            "#[tauri::command]\npub fn shared_cmd() {}\n",
+        );
+
+        let app_root = repo.path().join("app");
+        let entries = vec![
+            "src-tauri/target/debug/build/app-1/out".to_string(),
+            repo.path().join("shared/extra.rs").display().to_string(),
+            "missing/dir".to_string(),
+        ];
+        let (extra_paths, missing) = resolve_extra_paths(&app_root, &entries);
+        assert_eq!(missing, vec!["missing/dir".to_string()]);
+        assert_eq!(extra_paths.len(), 2);
+
+        let files = scan_workspace_files(&app_root, &extra_paths);
+        assert!(!scan_workspace_files(&app_root, &[])
+            .iter()
+            .any(|f| f.ends_with("out/commands.rs")));
+
+        let index = ProjectIndex::new();
+        for file in files {
+            crate::file_processor::process_file_index(&file, &index).unwrap();
+        }
+        let locations = index.get_locations(EntityType::Command, "generated_cmd");
+        assert!(locations.iter().any(|l| l.behavior == Behavior::Definition));
+        assert!(locations.iter().any(|l| l.behavior == Behavior::Call));
+        assert!(!index
+            .get_locations(EntityType::Command, "shared_cmd")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_path_alias_resolver_resolves_tsconfig_paths() {
+        use std::fs;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let write = |rel: &str, content: &str| {
+            let path = root.join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write(
+            "tsconfig.json",
+            r#"{
+  // Vite template style config with comments and trailing commas
+  "compilerOptions": {
+    "baseUrl": ".",
+    "paths": {
+      "@/*": ["src/*"],
+      "@/utils/*": ["src/lib/utils/*",],
+      "~config": ["src/config.ts"], /* exact alias */
+    },
+  },
+}"#,
+        );
+        write("src/utils/api.ts", "");
+        write("src/lib/utils/api.ts", "");
+        write("src/components/index.tsx", "");
+        write("src/config.ts", "");
+
+        let resolver = PathAliasResolver::from_workspace(root).unwrap();
+        let resolve = |spec: &str| {
+            resolver
+                .resolve(spec)
+                .map(|p| p.strip_prefix(root).unwrap().to_path_buf())
+        };
+
+        assert_eq!(
+            resolve("@/utils/api"),
+            Some(PathBuf::from("src/lib/utils/api.ts")),
+            "The longest matching prefix wins"
+        );
+        assert_eq!(
+            resolve("@/components"),
+            Some(PathBuf::from("src/components/index.tsx"))
+        );
+        assert_eq!(resolve("~config"), Some(PathBuf::from("src/config.ts")));
+        assert_eq!(
+            resolve("src/utils/api"),
+            Some(PathBuf::from("src/utils/api.ts")),
+            "baseUrl allows bare specifiers"
+        );
+        assert_eq!(resolve("./utils/api"), None);
+        assert_eq!(resolve("@/missing"), None);
+    }
+
+    #[test]
+    fn test_path_alias_resolver_follows_extends_without_base_url() {
+        use std::fs;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("app/src/utils")).unwrap();
+        fs::create_dir_all(root.join("shared/src")).unwrap();
+        fs::write(root.join("app/src/utils/api.ts"), "").unwrap();
+        fs::write(
+            root.join("tsconfig.base.json"),
+            r#"{ "compilerOptions": { "paths": { "@/*": ["./app/src/*"], "@shared/*": ["./shared/src/*"] } } }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("app/tsconfig.json"),
+            r#"{ "extends": "../tsconfig.base" }"#,
+        )
+        .unwrap();
+
+        let resolver = PathAliasResolver::from_workspace(&root.join("app")).unwrap();
+        assert_eq!(
+            resolver.resolve("@/utils/api"),
+            Some(root.join("app/src/utils/api.ts")),
+            "Inherited paths are relative to the config that declares them"
+        );
+        assert_eq!(
+            resolver.alias_dirs(),
+            [root.join("app/src"), root.join("shared/src")]
+        );
+    }
+
+    #[test]
+    fn test_import_candidates_are_lexical() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(
+            root.join("tsconfig.json"),
+            r#"{ "compilerOptions": { "paths": { "@/*": ["./src/*"] } } }"#,
+        )
+        .unwrap();
+        let resolver = PathAliasResolver::from_workspace(root).unwrap();
+        let importer = root.join("src/components/User.tsx");
+
+        assert_eq!(
+            import_candidates(&importer, "../api", Some(&resolver)),
+            [root.join("src/api")]
+        );
+        assert_eq!(
+            import_candidates(&importer, "@/api", Some(&resolver)),
+            [root.join("src/api")],
+            "Aliases resolve to files that do not exist (yet)"
+        );
+        assert!(import_candidates(&importer, "@/api", None).is_empty());
+        assert!(module_files(&root.join("src/api")).contains(&root.join("src/api/index.ts")));
+    }
+
+    #[test]
+    fn test_path_alias_resolver_reports_malformed_configs() {
+        use crate::error::Error;
+        use std::fs;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join("tsconfig.json"),
+            r#"{ "compilerOptions": { "paths": { "@/*": ["src/*"] }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("jsconfig.json"),
+            r#"{ "extends": "./missing.json" }"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            PathAliasResolver::from_config_file(&root.join("tsconfig.json")),
+            Err(Error::Config { path, .. }) if path == root.join("tsconfig.json")
+        ));
+        assert!(matches!(
+            PathAliasResolver::from_config_file(&root.join("jsconfig.json")),
+            Err(Error::Io { path, .. }) if path == root.join("missing.json")
+        ));
+        assert_eq!(
+            PathAliasResolver::from_workspace(root),
+            None,
+            "A malformed workspace config disables alias resolution"
+        );
+    }
+
     #[test]
     fn test_is_tauri_config_path() {
         use std::path::Path;
@@ -6,7 +6,7 @@
 use serde::Deserialize;
 
 /// Type of entity - either a Command or an Event
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Hash, Copy)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum EntityType {
     /// Tauri command (invoke/definition)
@@ -16,7 +16,7 @@ pub enum EntityType {
 }
 
 /// Behavior of the entity - how it's used in code
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Hash, Copy)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum Behavior {
     /// Command definition (Rust: #[`tauri::command`] fn `name()`)
@@ -57,3 +57,231 @@ impl std::error::Error for ParseError {}
 
 /// Result type for parsing operations
 pub type ParseResult<T> = Result<T, ParseError>;
+
+/// Events emitted by Tauri itself (window, webview, drag-and-drop and updater lifecycle),
+/// so listening to them without an emitter in user code is expected
+pub const TAURI_FRAMEWORK_EVENTS: &[&str] = &[
+    "tauri://blur",
+    "tauri://close-requested",
+    "tauri://created",
+    "tauri://destroyed",
+    "tauri://drag-drop",
+    "tauri://drag-enter",
+    "tauri://drag-leave",
+    "tauri://drag-over",
+    "tauri://error",
+    "tauri://focus",
+    "tauri://move",
+    "tauri://resize",
+    "tauri://scale-change",
+    "tauri://theme-changed",
+    "tauri://update",
+    "tauri://update-available",
+    "tauri://update-status",
+    "tauri://webview-created",
+    "tauri://window-created",
+];
+
+/// Whether `name` is emitted by the framework: a [`TAURI_FRAMEWORK_EVENTS`] entry, a
+/// plugin event (`plugin:…`), or a match of one of the configured `extra` names, where a
+/// trailing `*` matches any suffix (`tarus.events.frameworkEvents`)
+#[must_use]
+pub fn is_framework_event(name: &str, extra: &[String]) -> bool {
+    TAURI_FRAMEWORK_EVENTS.contains(&name)
+        || name.starts_with("plugin:")
+        || extra.iter().any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => name.starts_with(prefix),
+            None => name == pattern,
+        })
+}
+
+/// Command name prefixes of Tauri plugins and core modules (`plugin:fs|read_file`,
+/// `plugin:dialog|open`, `plugin:window|close`)
+pub const PLUGIN_COMMAND_PREFIXES: &[&str] = &["plugin:"];
+
+/// Whether `name` is served by a plugin rather than the app: it has a
+/// [`PLUGIN_COMMAND_PREFIXES`] prefix
+#[must_use]
+pub fn is_plugin_command(name: &str) -> bool {
+    PLUGIN_COMMAND_PREFIXES
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
+
+/// Whether the Rust type `ty` is an `Option<T>` (`std::option::Option<T>`, `&Option<T>`),
+/// which the frontend may omit; names merely containing "Option" (`MyOptionalThing`) aren't
+#[must_use]
+pub fn is_optional_rust_type(ty: &str) -> bool {
+    let ty = ty.trim().trim_start_matches('&').trim();
+    let Some((path, rest)) = ty.split_once('<') else {
+        return false;
+    };
+    path.trim().rsplit("::").next() == Some("Option") && rest.trim_end().ends_with('>')
+}
+
+/// A Rust method whose string argument names an event (`app.emit("saved", payload)`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RustEventMethod {
+    pub method: &'static str,
+    pub behavior: Behavior,
+    /// Zero-based index of the event name argument; for emits the payload follows it
+    pub arg_index: usize,
+}
+
+impl RustEventMethod {
+    const fn new(method: &'static str, behavior: Behavior, arg_index: usize) -> Self {
+        Self {
+            method,
+            behavior,
+            arg_index,
+        }
+    }
+}
+
+/// Event methods of the Tauri v1 and v2 `Manager`/`Emitter`/`Listener` APIs
+pub const RUST_EVENT_METHODS: &[RustEventMethod] = &[
+    // v2 `Emitter`
+    RustEventMethod::new("emit", Behavior::Emit, 0),
+    RustEventMethod::new("emit_str", Behavior::Emit, 0),
+    RustEventMethod::new("emit_filter", Behavior::Emit, 0),
+    RustEventMethod::new("emit_str_filter", Behavior::Emit, 0),
+    RustEventMethod::new("emit_to", Behavior::Emit, 1),
+    RustEventMethod::new("emit_str_to", Behavior::Emit, 1),
+    // v1 `Manager` / `Window`
+    RustEventMethod::new("emit_all", Behavior::Emit, 0),
+    RustEventMethod::new("emit_and_trigger", Behavior::Emit, 0),
+    RustEventMethod::new("trigger", Behavior::Emit, 0),
+    RustEventMethod::new("trigger_global", Behavior::Emit, 0),
+    // v2 `Listener`
+    RustEventMethod::new("listen", Behavior::Listen, 0),
+    RustEventMethod::new("listen_any", Behavior::Listen, 0),
+    RustEventMethod::new("once", Behavior::Listen, 0),
+    RustEventMethod::new("once_any", Behavior::Listen, 0),
+    // v1 `Manager`
+    RustEventMethod::new("listen_global", Behavior::Listen, 0),
+    RustEventMethod::new("once_global", Behavior::Listen, 0),
+];
+
+/// The [`RUST_EVENT_METHODS`] entry for `method`
+#[must_use]
+pub fn rust_event_method(method: &str) -> Option<&'static RustEventMethod> {
+    RUST_EVENT_METHODS.iter().find(|m| m.method == method)
+}
+
+/// Properties naming the command in the object form of the Tauri `invoke` API,
+/// `invoke({ cmd: "get_user", args: { id } })` (the Tauri v1 `__invoke` shape)
+pub const INVOKE_NAME_PROPERTIES: &[&str] = &["cmd", "command"];
+
+/// A frontend function whose string argument names a command or event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontendPattern {
+    /// Function name as written at the call site (`invoke`, `trackEvent`)
+    pub function: String,
+    pub entity: EntityType,
+    pub behavior: Behavior,
+    /// Zero-based index of the name argument
+    pub arg_index: usize,
+    /// When set, the argument is an object holding the name in this property
+    /// (`track({ event: "saved" })` with `"event"`) instead of a string
+    pub name_property: Option<String>,
+    /// Built-in Tauri API, matched only when imported from `@tauri-apps/` (possibly aliased)
+    pub tauri_api: bool,
+}
+
+impl FrontendPattern {
+    fn tauri_api(function: &str, entity: EntityType, behavior: Behavior, arg_index: usize) -> Self {
+        Self {
+            function: function.to_string(),
+            entity,
+            behavior,
+            arg_index,
+            name_property: None,
+            tauri_api: true,
+        }
+    }
+}
+
+/// Frontend call patterns recognized by the parser (`tarus.frontend.patterns`)
+///
+/// The default holds the built-in Tauri APIs; custom patterns are matched by the called
+/// name, wherever the function comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontendPatterns(Vec<FrontendPattern>);
+
+impl Default for FrontendPatterns {
+    fn default() -> Self {
+        Self(vec![
+            FrontendPattern::tauri_api("invoke", EntityType::Command, Behavior::Call, 0),
+            FrontendPattern::tauri_api("emit", EntityType::Event, Behavior::Emit, 0),
+            FrontendPattern::tauri_api("listen", EntityType::Event, Behavior::Listen, 0),
+            FrontendPattern::tauri_api("once", EntityType::Event, Behavior::Listen, 0),
+            FrontendPattern::tauri_api("emitTo", EntityType::Event, Behavior::Emit, 1),
+        ])
+    }
+}
+
+impl FrontendPatterns {
+    /// Built-in patterns plus the custom ones of the setting value:
+    ///
+    /// ```json
+    /// [{ "function": "trackEvent", "argIndex": 2, "kind": "emit" },
+    ///  { "function": "call", "property": "cmd", "kind": "invoke" }]
+    /// ```
+    ///
+    /// `kind` is `"invoke"`, `"emit"`, `"listen"` or `"define"`; invalid entries are skipped.
+    /// With `property`, the name is read from that property of an object argument.
+    ///
+    /// `"define"` makes the call a command definition, for projects that declare commands
+    /// in TypeScript and generate the Rust side: invokes of it are no longer undefined, an
+    /// unused one is reported, and it is exempt from the `generate_handler![]` check.
+    #[must_use]
+    pub fn from_setting(value: &serde_json::Value) -> Self {
+        let mut patterns = Self::default();
+
+        for entry in value.as_array().into_iter().flatten() {
+            let Some(function) = entry.get("function").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let (entity, behavior) = match entry.get("kind").and_then(|v| v.as_str()) {
+                Some("invoke") => (EntityType::Command, Behavior::Call),
+                Some("emit") => (EntityType::Event, Behavior::Emit),
+                Some("listen") => (EntityType::Event, Behavior::Listen),
+                Some("define") => (EntityType::Command, Behavior::Definition),
+                _ => continue,
+            };
+            let arg_index = entry
+                .get("argIndex")
+                .and_then(serde_json::Value::as_u64)
+                .and_then(|i| usize::try_from(i).ok())
+                .unwrap_or(0);
+            let name_property = entry
+                .get("property")
+                .and_then(|v| v.as_str())
+                .map(str::to_string);
+
+            patterns.0.push(FrontendPattern {
+                function: function.to_string(),
+                entity,
+                behavior,
+                arg_index,
+                name_property,
+                tauri_api: false,
+            });
+        }
+
+        patterns
+    }
+
+    /// Built-in Tauri API `function` (the imported name) reading its name at `arg_index`
+    #[must_use]
+    pub fn tauri_api(&self, function: &str, arg_index: usize) -> Option<&FrontendPattern> {
+        self.0
+            .iter()
+            .find(|p| p.tauri_api && p.function == function && p.arg_index == arg_index)
+    }
+
+    /// Custom (non-Tauri) patterns
+    pub fn custom(&self) -> impl Iterator<Item = &FrontendPattern> {
+        self.0.iter().filter(|p| !p.tauri_api)
+    }
+}
@@ -1,7 +1,8 @@
-//! Type argument extraction helpers for generic call expressions
+//! Type argument and call argument extraction helpers for call expressions
 
-use crate::utils::{find_capture, point_to_position};
-use tower_lsp_server::lsp_types::Range;
+use crate::position::{node_range, node_start};
+use crate::utils::find_capture;
+use tower_lsp_server::lsp_types::{Position, Range};
 
 /// Result of extracting type argument info from a generic call expression.
 pub(super) struct TypeArgInfo {
@@ -32,10 +33,7 @@ pub(super) fn extract_type_argument_info(
     for child in node.children(&mut tree_cursor) {
         if child.kind() == "type_arguments" {
             let text = child.utf8_text(content.as_bytes()).unwrap_or_default();
-            let type_arg_range = Range {
-                start: point_to_position(child.start_position()),
-                end: point_to_position(child.end_position()),
-            };
+            let type_arg_range = node_range(child, content.as_bytes());
             // Strip angle brackets: "<User>" → "User"
             let trimmed = text.strip_prefix('<').unwrap_or(text);
             let trimmed = trimmed.strip_suffix('>').unwrap_or(trimmed);
@@ -87,3 +85,389 @@ pub(super) fn count_specta_call_args(
     let _ = content;
     0
 }
+
+/// Start position of the statement that encloses a call argument.
+///
+/// Walks up from `node` until reaching a node whose parent is a statement container,
+/// stopping early at expression-bodied arrow functions and JSX expressions so that
+/// `onClick={() => invoke(...)}` anchors at the call rather than the whole component.
+pub(super) fn statement_start(node: tree_sitter::Node<'_>, bytes: &[u8]) -> Position {
+    let mut current = node;
+
+    while let Some(parent) = current.parent() {
+        if matches!(
+            parent.kind(),
+            "program"
+                | "statement_block"
+                | "class_body"
+                | "switch_case"
+                | "switch_default"
+                | "arrow_function"
+                | "jsx_expression"
+        ) {
+            break;
+        }
+        current = parent;
+    }
+
+    node_start(current, bytes)
+}
+
+/// Type names usable in a file without being indexed: its own `interface`, `type`, `class`
+/// and `enum` declarations, the type parameters of its generic declarations, and every name
+/// bound by an import.
+///
+/// Type parameters are collected file-wide rather than per scope: a `T` used outside the
+/// declaration binding it is a TypeScript error already, not a Tauri one.
+pub(super) fn local_type_names(root: tree_sitter::Node<'_>, bytes: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut push = |node: Option<tree_sitter::Node<'_>>| {
+        if let Some(text) = node.and_then(|n| n.utf8_text(bytes).ok()) {
+            names.push(text.to_string());
+        }
+    };
+
+    super::wrappers::visit(root, &mut |node| match node.kind() {
+        "interface_declaration"
+        | "type_alias_declaration"
+        | "class_declaration"
+        | "abstract_class_declaration"
+        | "enum_declaration"
+        | "type_parameter" => push(node.child_by_field_name("name")),
+        "import_specifier" => push(
+            node.child_by_field_name("alias")
+                .or_else(|| node.child_by_field_name("name")),
+        ),
+        "import_clause" | "namespace_import" => {
+            let mut cursor = node.walk();
+            let identifiers: Vec<_> = node
+                .named_children(&mut cursor)
+                .filter(|c| c.kind() == "identifier")
+                .collect();
+            for identifier in identifiers {
+                push(Some(identifier));
+            }
+        }
+        _ => {}
+    });
+
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Arguments object passed to `invoke("cmd", args)`.
+pub(super) enum InvokeArgs {
+    /// Keys of the arguments object (inline literal or resolved from a variable)
+    Keys(Vec<String>),
+    /// A variable whose shape could not be resolved within the file
+    Variable(String),
+}
+
+/// Extract the argument keys of an `invoke("cmd", args)` call.
+///
+/// `arg_node` is the command name node (`string_fragment`). Handles:
+/// - inline objects: `invoke("cmd", { id, name: "x" })`
+/// - variables declared in an enclosing scope with an object literal or a type annotation
+///   naming a local `interface` (including what it `extends`) or object `type`:
+///   `const args: GreetArgs = ...`
+///
+/// Returns `None` when there is no second argument or it can't be checked
+/// (e.g. an object with spread elements or a non-identifier expression).
+pub(super) fn extract_invoke_args(
+    arg_node: tree_sitter::Node<'_>,
+    bytes: &[u8],
+) -> Option<InvokeArgs> {
+    let mut call = arg_node.parent()?;
+    while call.kind() != "call_expression" {
+        call = call.parent()?;
+    }
+
+    let args = call.child_by_field_name("arguments")?;
+    let second = args.named_child(1)?;
+
+    match second.kind() {
+        "object" => object_keys(second, bytes).map(InvokeArgs::Keys),
+        "identifier" => {
+            let name = second.utf8_text(bytes).ok()?;
+            Some(
+                resolve_variable_keys(name, second, bytes)
+                    .map_or_else(|| InvokeArgs::Variable(name.to_string()), InvokeArgs::Keys),
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Whether the call taking `arg_node` is a bare expression statement, dropping the Promise it
+/// returns instead of awaiting, returning, chaining or storing it.
+///
+/// Parentheses are looked through. `void invoke(...)` is an explicit discard and not reported,
+/// and neither is `await invoke(...)` when the grammar parses it as `(await invoke)(...)`.
+pub(super) fn is_discarded_call(arg_node: tree_sitter::Node<'_>) -> bool {
+    let mut call = arg_node;
+    while call.kind() != "call_expression" {
+        let Some(parent) = call.parent() else {
+            return false;
+        };
+        call = parent;
+    }
+
+    if call
+        .child_by_field_name("function")
+        .is_some_and(|f| f.kind() == "await_expression")
+    {
+        return false;
+    }
+
+    let mut current = call;
+    while let Some(parent) = current.parent() {
+        match parent.kind() {
+            "parenthesized_expression" => current = parent,
+            "expression_statement" => return true,
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Collect the property names of an object literal; `None` if it contains a spread.
+fn object_keys(object: tree_sitter::Node<'_>, bytes: &[u8]) -> Option<Vec<String>> {
+    let mut keys = Vec::new();
+    let mut cursor = object.walk();
+
+    for child in object.named_children(&mut cursor) {
+        let key = match child.kind() {
+            "pair" => child.child_by_field_name("key")?,
+            "shorthand_property_identifier" => child,
+            "comment" => continue,
+            _ => return None,
+        };
+        let text = key.utf8_text(bytes).ok()?;
+        keys.push(text.trim_matches(|c| c == '"' || c == '\'').to_string());
+    }
+
+    Some(keys)
+}
+
+/// Function-like nodes whose parameters shadow declarations of enclosing scopes
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "generator_function_declaration",
+    "generator_function",
+    "arrow_function",
+    "method_definition",
+];
+
+/// Levels of `interface ... extends ...` followed before giving up (guards against cycles)
+const MAX_EXTENDS_DEPTH: usize = 8;
+
+/// Resolve the keys of a variable from the nearest declaration visible from `usage`.
+///
+/// Enclosing blocks are searched from the innermost one out to the program; a function
+/// parameter of the same name shadows outer declarations and leaves the variable
+/// unresolved.
+fn resolve_variable_keys(
+    name: &str,
+    usage: tree_sitter::Node<'_>,
+    bytes: &[u8],
+) -> Option<Vec<String>> {
+    let mut declarator = None;
+    let mut scope = usage.parent();
+    while let Some(node) = scope {
+        if FUNCTION_KINDS.contains(&node.kind()) && binds_parameter(node, name, bytes) {
+            return None;
+        }
+        if matches!(node.kind(), "statement_block" | "program") {
+            declarator = find_in_block(node, |statement| {
+                variable_declarator(statement, name, bytes)
+            });
+            if declarator.is_some() {
+                break;
+            }
+        }
+        scope = node.parent();
+    }
+    let declarator = declarator?;
+
+    if let Some(annotation) = declarator.child_by_field_name("type") {
+        let type_node = annotation.named_child(0)?;
+        return match type_node.kind() {
+            "object_type" => Some(property_signature_names(type_node, bytes)),
+            "type_identifier" => {
+                resolve_type_keys(type_node.utf8_text(bytes).ok()?, declarator, bytes, 0)
+            }
+            _ => None,
+        };
+    }
+
+    let value = declarator.child_by_field_name("value")?;
+    if value.kind() == "object" {
+        object_keys(value, bytes)
+    } else {
+        None
+    }
+}
+
+/// Resolve the property names of an `interface` or object `type` alias visible from `usage`.
+///
+/// Properties inherited through `interface ... extends ...` are included when every
+/// extended interface resolves too; otherwise the keys are unknown and `None` is returned.
+fn resolve_type_keys(
+    type_name: &str,
+    usage: tree_sitter::Node<'_>,
+    bytes: &[u8],
+    depth: usize,
+) -> Option<Vec<String>> {
+    if depth > MAX_EXTENDS_DEPTH {
+        return None;
+    }
+
+    let mut scope = usage.parent();
+    let declaration = loop {
+        let node = scope?;
+        if matches!(node.kind(), "statement_block" | "program") {
+            let found = find_in_block(node, |statement| {
+                if !matches!(
+                    statement.kind(),
+                    "interface_declaration" | "type_alias_declaration"
+                ) {
+                    return None;
+                }
+                let name = statement
+                    .child_by_field_name("name")?
+                    .utf8_text(bytes)
+                    .ok()?;
+                (name == type_name).then_some(statement)
+            });
+            if let Some(found) = found {
+                break found;
+            }
+        }
+        scope = node.parent();
+    };
+
+    if declaration.kind() != "interface_declaration" {
+        let value = declaration
+            .child_by_field_name("value")
+            .filter(|v| v.kind() == "object_type")?;
+        return Some(property_signature_names(value, bytes));
+    }
+
+    let mut keys = property_signature_names(declaration.child_by_field_name("body")?, bytes);
+    let mut cursor = declaration.walk();
+    let extends = declaration
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "extends_type_clause");
+    if let Some(extends) = extends {
+        let mut cursor = extends.walk();
+        for base in extends.children_by_field_name("type", &mut cursor) {
+            let base_name = match base.kind() {
+                "type_identifier" => base,
+                "generic_type" => base
+                    .child_by_field_name("name")
+                    .filter(|n| n.kind() == "type_identifier")?,
+                _ => return None,
+            };
+            let base_name = base_name.utf8_text(bytes).ok()?;
+            keys.extend(resolve_type_keys(base_name, declaration, bytes, depth + 1)?);
+        }
+    }
+
+    Some(keys)
+}
+
+/// First `found` result over the statements directly in `block`, looking inside
+/// `export` statements. Nested blocks are separate scopes and are not searched.
+fn find_in_block<'t>(
+    block: tree_sitter::Node<'t>,
+    found: impl Fn(tree_sitter::Node<'t>) -> Option<tree_sitter::Node<'t>>,
+) -> Option<tree_sitter::Node<'t>> {
+    let mut cursor = block.walk();
+    if !cursor.goto_first_child() {
+        return None;
+    }
+    loop {
+        let statement = cursor.node();
+        let statement = if statement.kind() == "export_statement" {
+            statement
+                .child_by_field_name("declaration")
+                .unwrap_or(statement)
+        } else {
+            statement
+        };
+        if let Some(node) = found(statement) {
+            return Some(node);
+        }
+        if !cursor.goto_next_sibling() {
+            return None;
+        }
+    }
+}
+
+/// The `variable_declarator` named `name` in a `const`/`let`/`var` statement
+fn variable_declarator<'t>(
+    statement: tree_sitter::Node<'t>,
+    name: &str,
+    bytes: &[u8],
+) -> Option<tree_sitter::Node<'t>> {
+    if !matches!(
+        statement.kind(),
+        "lexical_declaration" | "variable_declaration"
+    ) {
+        return None;
+    }
+    let mut cursor = statement.walk();
+    let declarator = statement.named_children(&mut cursor).find(|n| {
+        n.kind() == "variable_declarator"
+            && n.child_by_field_name("name")
+                .and_then(|id| id.utf8_text(bytes).ok())
+                == Some(name)
+    });
+    declarator
+}
+
+/// Whether a function's parameters bind `name`, destructured parameters included.
+fn binds_parameter(function: tree_sitter::Node<'_>, name: &str, bytes: &[u8]) -> bool {
+    let Some(parameters) = function
+        .child_by_field_name("parameters")
+        .or_else(|| function.child_by_field_name("parameter"))
+    else {
+        return false;
+    };
+
+    // Depth-first walk; identifiers in default values count too, which only makes the
+    // check more conservative
+    let mut cursor = parameters.walk();
+    loop {
+        let node = cursor.node();
+        if matches!(
+            node.kind(),
+            "identifier" | "shorthand_property_identifier_pattern"
+        ) && node.utf8_text(bytes).ok() == Some(name)
+        {
+            return true;
+        }
+        if cursor.goto_first_child() || cursor.goto_next_sibling() {
+            continue;
+        }
+        loop {
+            if !cursor.goto_parent() {
+                return false;
+            }
+            if cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+}
+
+fn property_signature_names(body: tree_sitter::Node<'_>, bytes: &[u8]) -> Vec<String> {
+    let mut cursor = body.walk();
+    body.named_children(&mut cursor)
+        .filter(|n| n.kind() == "property_signature")
+        .filter_map(|n| n.child_by_field_name("name")?.utf8_text(bytes).ok())
+        .map(str::to_string)
+        .collect()
+}
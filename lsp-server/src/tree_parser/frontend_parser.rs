@@ -1,66 +1,23 @@
 //! TypeScript/JavaScript/Vue/Svelte/Angular parsing for Tauri invoke/emit/listen calls
 
-use crate::indexer::Finding;
-use crate::syntax::{Behavior, EntityType, ParseError, ParseResult};
-use crate::utils::{find_capture, point_to_position};
+use crate::indexer::{DynamicName, Finding, ListenToggle, WrapperLinks};
+use crate::position::{node_end, node_range};
+use crate::syntax::{
+    Behavior, EntityType, FrontendPattern, FrontendPatterns, ParseError, ParseResult,
+    INVOKE_NAME_PROPERTIES,
+};
+use crate::utils::find_capture;
 use std::collections::HashMap;
-use std::sync::LazyLock;
 use streaming_iterator::StreamingIterator;
-use tower_lsp_server::lsp_types::Range;
-use tree_sitter::{Language, Parser, Query, QueryCursor};
+use tree_sitter::{Language, Parser, Query, QueryCursor, Tree};
 
-use super::extractors::{count_specta_call_args, extract_type_argument_info};
+use super::extractors::{
+    count_specta_call_args, extract_invoke_args, extract_type_argument_info, is_discarded_call,
+    local_type_names, statement_start, InvokeArgs,
+};
 use super::lang_config::{get_query_source, LangType};
 use super::sfc_parser::{adjust_position, adjust_range};
-
-/// Function patterns with their argument position
-struct FunctionPatternWithPos {
-    name: &'static str,
-    entity: EntityType,
-    behavior: Behavior,
-    arg_position: ArgPosition,
-}
-
-#[derive(Clone, Copy, PartialEq)]
-enum ArgPosition {
-    First,
-    Second,
-}
-
-static ALL_FRONTEND_PATTERNS: LazyLock<Vec<FunctionPatternWithPos>> = LazyLock::new(|| {
-    vec![
-        FunctionPatternWithPos {
-            name: "invoke",
-            entity: EntityType::Command,
-            behavior: Behavior::Call,
-            arg_position: ArgPosition::First,
-        },
-        FunctionPatternWithPos {
-            name: "emit",
-            entity: EntityType::Event,
-            behavior: Behavior::Emit,
-            arg_position: ArgPosition::First,
-        },
-        FunctionPatternWithPos {
-            name: "listen",
-            entity: EntityType::Event,
-            behavior: Behavior::Listen,
-            arg_position: ArgPosition::First,
-        },
-        FunctionPatternWithPos {
-            name: "once",
-            entity: EntityType::Event,
-            behavior: Behavior::Listen,
-            arg_position: ArgPosition::First,
-        },
-        FunctionPatternWithPos {
-            name: "emitTo",
-            entity: EntityType::Event,
-            behavior: Behavior::Emit,
-            arg_position: ArgPosition::Second,
-        },
-    ]
-});
+use super::wrappers::{add_forwarding_invoke_wrappers, extract_wrapper_calls, visit};
 
 /// Capture indices extracted from the query, grouped for readability
 struct FrontendCaptures {
@@ -99,12 +56,25 @@ impl FrontendCaptures {
     }
 }
 
-/// Parse TypeScript/JavaScript source code
+/// Result of parsing one TypeScript/JavaScript source
+pub(super) struct FrontendParse {
+    pub findings: Vec<Finding>,
+    pub wrappers: WrapperLinks,
+    /// Types declared or imported in the source (see [`local_type_names`])
+    pub type_names: Vec<String>,
+    /// Tauri API calls whose name is built at runtime
+    pub dynamic_names: Vec<DynamicName>,
+    pub tree: Tree,
+}
+
+/// Parse TypeScript/JavaScript source code, incrementally when an edited previous tree is given
 pub(super) fn parse_frontend(
     content: &str,
     lang: LangType,
     line_offset: usize,
-) -> ParseResult<Vec<Finding>> {
+    old_tree: Option<&Tree>,
+    patterns: &FrontendPatterns,
+) -> ParseResult<FrontendParse> {
     let ts_lang: Language = match lang {
         LangType::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
         _ => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
@@ -116,7 +86,7 @@ pub(super) fn parse_frontend(
         .map_err(|e| ParseError::LanguageError(format!("Failed to set {lang:?} language: {e}")))?;
 
     let tree = parser
-        .parse(content, None)
+        .parse(content, old_tree)
         .ok_or_else(|| ParseError::SyntaxError(format!("Failed to parse {lang:?} file")))?;
 
     let query_src = get_query_source(lang);
@@ -127,8 +97,9 @@ pub(super) fn parse_frontend(
     let root = tree.root_node();
     let bytes = content.as_bytes();
 
-    // First pass: collect import aliases
-    let aliases = collect_aliases(&query, root, bytes, &caps);
+    // First pass: collect import aliases; local functions forwarding to `invoke` count as one
+    let mut aliases = collect_aliases(&query, root, bytes, &caps);
+    add_forwarding_invoke_wrappers(root, bytes, &mut aliases);
 
     // Second pass: collect function calls
     let mut findings = Vec::new();
@@ -136,11 +107,14 @@ pub(super) fn parse_frontend(
     let mut matches = cursor.matches(&query, root, bytes);
 
     while let Some(m) = matches.next() {
-        if let Some(f) = process_first_arg_pattern(m, &caps, bytes, &aliases, content, line_offset)
+        if let Some(f) =
+            process_first_arg_pattern(m, &caps, &aliases, patterns, content, root, line_offset)
         {
             findings.push(f);
         }
-        if let Some(f) = process_second_arg_pattern(m, &caps, bytes, &aliases, line_offset) {
+        if let Some(f) =
+            process_second_arg_pattern(m, &caps, bytes, &aliases, patterns, line_offset)
+        {
             findings.push(f);
         }
         if let Some(f) = process_specta_call(m, &caps, bytes, content, line_offset) {
@@ -151,16 +125,41 @@ pub(super) fn parse_frontend(
         }
     }
 
-    Ok(findings)
+    findings.extend(visited_pattern_calls(
+        root,
+        bytes,
+        &aliases,
+        patterns,
+        line_offset,
+    ));
+
+    let (wrapper_calls, links) = extract_wrapper_calls(root, bytes, &aliases, line_offset);
+    findings.extend(wrapper_calls);
+    let type_names = local_type_names(root, bytes);
+    let dynamic_names = dynamic_name_calls(root, bytes, &aliases, patterns, line_offset);
+
+    Ok(FrontendParse {
+        findings,
+        wrappers: links,
+        type_names,
+        dynamic_names,
+        tree,
+    })
 }
 
+/// Map local names bound by `@tauri-apps/` imports to the imported API names.
+///
+/// A local name bound by several imports (`import { listen as invoke }` next to
+/// `import { invoke }`, a TypeScript error but common mid-edit) resolves to the import that
+/// comes last in the file, whatever order the query yields the matches in.
 fn collect_aliases<'a>(
     query: &Query,
     root: tree_sitter::Node<'_>,
     bytes: &'a [u8],
     caps: &FrontendCaptures,
 ) -> HashMap<&'a str, &'a str> {
-    let mut aliases = HashMap::new();
+    // (position of the local name, local name, imported name)
+    let mut bindings = Vec::new();
     let mut cursor = QueryCursor::new();
     let mut matches = cursor.matches(query, root, bytes);
 
@@ -178,27 +177,33 @@ fn collect_aliases<'a>(
                     let imported = imp_cap.node.utf8_text(bytes).unwrap_or_default();
                     let local = loc_cap.node.utf8_text(bytes).unwrap_or_default();
 
-                    aliases.insert(local, imported);
+                    bindings.push((loc_cap.node.start_byte(), local, imported));
                 } else if let Some(imp_cap) = imp {
                     let imported = imp_cap.node.utf8_text(bytes).unwrap_or_default();
 
-                    aliases.insert(imported, imported);
+                    bindings.push((imp_cap.node.start_byte(), imported, imported));
                 }
             }
         }
     }
 
-    aliases
+    bindings.sort_unstable();
+    bindings
+        .into_iter()
+        .map(|(_, local, imported)| (local, imported))
+        .collect()
 }
 
 fn process_first_arg_pattern<'a>(
     m: &tree_sitter::QueryMatch<'_, '_>,
     caps: &FrontendCaptures,
-    bytes: &'a [u8],
     aliases: &HashMap<&'a str, &'a str>,
-    content: &str,
+    patterns: &FrontendPatterns,
+    content: &'a str,
+    root: tree_sitter::Node<'_>,
     line_offset: usize,
 ) -> Option<Finding> {
+    let bytes = content.as_bytes();
     let func_cap = find_capture(m, caps.func_name)?;
     let arg_cap = find_capture(m, caps.arg_value)?;
 
@@ -206,27 +211,48 @@ fn process_first_arg_pattern<'a>(
     let arg_value = arg_cap.node.utf8_text(bytes).unwrap_or_default();
     let original_name = *aliases.get(func_name)?;
 
-    let pattern = ALL_FRONTEND_PATTERNS
-        .iter()
-        .find(|p| p.name == original_name && p.arg_position == ArgPosition::First)?;
+    let pattern = patterns.tauri_api(original_name, 0)?;
 
-    let range = Range {
-        start: point_to_position(arg_cap.node.start_position()),
-        end: point_to_position(arg_cap.node.end_position()),
-    };
-    let call_name_end = Some(adjust_position(
-        point_to_position(func_cap.node.end_position()),
-        line_offset,
-    ));
+    let range = node_range(arg_cap.node, bytes);
+    let call_name_end = Some(adjust_position(node_end(func_cap.node, bytes), line_offset));
     let type_arg_info =
         extract_type_argument_info(m, caps.call_generic, caps.call_await_generic, content);
     let return_type = type_arg_info.as_ref().map(|i| i.type_text.clone());
     let type_arg_range = type_arg_info.map(|i| adjust_range(i.type_arg_range, line_offset));
 
+    let (call_param_keys, call_arg_variable) = if pattern.behavior == Behavior::Call {
+        match extract_invoke_args(arg_cap.node, bytes) {
+            Some(InvokeArgs::Keys(keys)) => (Some(keys), None),
+            Some(InvokeArgs::Variable(name)) => (None, Some(name)),
+            None => (None, None),
+        }
+    } else {
+        (None, None)
+    };
+
+    let listen_toggle =
+        listen_toggle(func_cap.node, original_name, aliases, root, bytes).map(|toggle| {
+            ListenToggle {
+                callee_range: adjust_range(toggle.callee_range, line_offset),
+                import_insert: toggle
+                    .import_insert
+                    .map(|pos| adjust_position(pos, line_offset)),
+                ..toggle
+            }
+        });
+
     Some(Finding {
+        call_param_keys,
         return_type,
         call_name_end,
         type_arg_range,
+        call_arg_variable,
+        statement_start: Some(adjust_position(
+            statement_start(arg_cap.node, bytes),
+            line_offset,
+        )),
+        listen_toggle,
+        unawaited: pattern.behavior == Behavior::Call && is_discarded_call(arg_cap.node),
         ..Finding::new(
             arg_value.to_string(),
             pattern.entity,
@@ -236,11 +262,85 @@ fn process_first_arg_pattern<'a>(
     })
 }
 
+/// Build the `listen` ↔ `once` swap data for a call to either API.
+///
+/// The counterpart is referenced by its local (possibly aliased) name when it is already
+/// imported from `@tauri-apps/`; otherwise the edit must also add it next to the callee's
+/// own import specifier.
+fn listen_toggle(
+    callee: tree_sitter::Node<'_>,
+    original_name: &str,
+    aliases: &HashMap<&str, &str>,
+    root: tree_sitter::Node<'_>,
+    bytes: &[u8],
+) -> Option<ListenToggle> {
+    let target_api = match original_name {
+        "listen" => "once",
+        "once" => "listen",
+        _ => return None,
+    };
+
+    let target_local = aliases
+        .iter()
+        .filter(|(_, imported)| **imported == target_api)
+        .map(|(local, _)| *local)
+        .min()
+        .map(str::to_string);
+
+    let import_insert = if target_local.is_some() {
+        None
+    } else {
+        let local_name = callee.utf8_text(bytes).ok()?;
+        Some(node_end(
+            find_import_specifier(root, local_name, bytes)?,
+            bytes,
+        ))
+    };
+
+    Some(ListenToggle {
+        callee_range: node_range(callee, bytes),
+        target_api: target_api.to_string(),
+        target_local,
+        import_insert,
+    })
+}
+
+/// Find the `import_specifier` that binds `local_name` (by alias, or by name when unaliased).
+fn find_import_specifier<'t>(
+    node: tree_sitter::Node<'t>,
+    local_name: &str,
+    bytes: &[u8],
+) -> Option<tree_sitter::Node<'t>> {
+    if node.kind() == "import_specifier" {
+        let bound = node
+            .child_by_field_name("alias")
+            .or_else(|| node.child_by_field_name("name"))?;
+        return (bound.utf8_text(bytes).ok()? == local_name).then_some(node);
+    }
+
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.named_children(&mut cursor).collect();
+    children
+        .into_iter()
+        .filter(|child| {
+            matches!(
+                child.kind(),
+                "program"
+                    | "import_statement"
+                    | "import_clause"
+                    | "named_imports"
+                    | "import_specifier"
+            )
+        })
+        .find_map(|child| find_import_specifier(child, local_name, bytes))
+}
+
 fn process_second_arg_pattern<'a>(
     m: &tree_sitter::QueryMatch<'_, '_>,
     caps: &FrontendCaptures,
     bytes: &'a [u8],
     aliases: &HashMap<&'a str, &'a str>,
+    patterns: &FrontendPatterns,
     line_offset: usize,
 ) -> Option<Finding> {
     let func_cap = find_capture(m, caps.func_name_second)?;
@@ -250,21 +350,310 @@ fn process_second_arg_pattern<'a>(
     let arg_value = arg_cap.node.utf8_text(bytes).unwrap_or_default();
     let original_name = *aliases.get(func_name)?;
 
-    let pattern = ALL_FRONTEND_PATTERNS
-        .iter()
-        .find(|p| p.name == original_name && p.arg_position == ArgPosition::Second)?;
+    let pattern = patterns.tauri_api(original_name, 1)?;
+
+    let range = node_range(arg_cap.node, bytes);
+
+    Some(Finding {
+        statement_start: Some(adjust_position(
+            statement_start(arg_cap.node, bytes),
+            line_offset,
+        )),
+        ..Finding::new(
+            arg_value.to_string(),
+            pattern.entity,
+            pattern.behavior,
+            adjust_range(range, line_offset),
+        )
+    })
+}
 
-    let range = Range {
-        start: point_to_position(arg_cap.node.start_position()),
-        end: point_to_position(arg_cap.node.end_position()),
+/// Calls the query-based passes do not cover:
+/// - the custom functions of `tarus.frontend.patterns`, matched by the called name;
+/// - the object form of the Tauri `invoke` API, `invoke({ cmd: "get_user" })`;
+/// - Tauri API calls naming the command or event through a local string binding,
+///   `const cmd = "get_user"; invoke(cmd)` (see [`resolve_string_binding`]).
+///
+/// Other calls of names bound to Tauri API imports are left to the query-based passes.
+fn visited_pattern_calls(
+    root: tree_sitter::Node<'_>,
+    bytes: &[u8],
+    aliases: &HashMap<&str, &str>,
+    patterns: &FrontendPatterns,
+    line_offset: usize,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    visit(root, &mut |node| {
+        if node.kind() != "call_expression" {
+            return;
+        }
+        let Some(callee) = node
+            .child_by_field_name("function")
+            .filter(|c| c.kind() == "identifier")
+        else {
+            return;
+        };
+        let name = callee.utf8_text(bytes).unwrap_or_default();
+        let Some(arguments) = node.child_by_field_name("arguments") else {
+            return;
+        };
+
+        if let Some(original) = aliases.get(name) {
+            let first_arg = patterns
+                .tauri_api(original, 0)
+                .zip(arguments.named_child(0));
+            let object_form = first_arg
+                .filter(|(pattern, _)| pattern.entity == EntityType::Command)
+                .and_then(|(pattern, arg)| {
+                    let fragment = property_string(arg, INVOKE_NAME_PROPERTIES, bytes)?;
+                    Some((pattern, fragment))
+                });
+            let bound_form = first_arg
+                .and_then(|(pattern, arg)| Some((pattern, resolve_string_binding(arg, bytes)?)));
+
+            if let Some((pattern, fragment)) = object_form.or(bound_form) {
+                let finding = pattern_finding(node, fragment, pattern, bytes, line_offset);
+                // Calls sharing one binding all resolve to the same literal
+                if !findings
+                    .iter()
+                    .any(|f| f.range == finding.range && f.behavior == finding.behavior)
+                {
+                    findings.push(finding);
+                }
+            }
+            return;
+        }
+
+        for pattern in patterns.custom().filter(|p| p.function == name) {
+            let Some(arg) = arguments.named_child(pattern.arg_index) else {
+                continue;
+            };
+            let fragment = match &pattern.name_property {
+                Some(property) => property_string(arg, &[property.as_str()], bytes),
+                None => string_fragment(arg),
+            };
+            let Some(fragment) = fragment else {
+                continue;
+            };
+
+            findings.push(pattern_finding(node, fragment, pattern, bytes, line_offset));
+        }
+    });
+
+    findings
+}
+
+/// Finding for the name `fragment` of a `call` matched by `pattern`
+fn pattern_finding(
+    call: tree_sitter::Node<'_>,
+    fragment: tree_sitter::Node<'_>,
+    pattern: &FrontendPattern,
+    bytes: &[u8],
+    line_offset: usize,
+) -> Finding {
+    let range = node_range(fragment, bytes);
+
+    Finding {
+        statement_start: Some(adjust_position(statement_start(call, bytes), line_offset)),
+        ..Finding::new(
+            fragment.utf8_text(bytes).unwrap_or_default().to_string(),
+            pattern.entity,
+            pattern.behavior,
+            adjust_range(range, line_offset),
+        )
+    }
+}
+
+/// The `string_fragment` of a plain string literal or template literal (no escapes or
+/// substitutions)
+fn string_fragment(node: tree_sitter::Node<'_>) -> Option<tree_sitter::Node<'_>> {
+    node.named_child(0).filter(|child| {
+        matches!(node.kind(), "string" | "template_string")
+            && node.named_child_count() == 1
+            && child.kind() == "string_fragment"
+    })
+}
+
+/// Tauri API calls whose name argument is a template literal with substitutions
+/// (``emit(`file-${id}`)``), which no finding can represent
+fn dynamic_name_calls(
+    root: tree_sitter::Node<'_>,
+    bytes: &[u8],
+    aliases: &HashMap<&str, &str>,
+    patterns: &FrontendPatterns,
+    line_offset: usize,
+) -> Vec<DynamicName> {
+    let mut names = Vec::new();
+
+    visit(root, &mut |node| {
+        if node.kind() != "call_expression" {
+            return;
+        }
+        let Some(original) = node
+            .child_by_field_name("function")
+            .filter(|c| c.kind() == "identifier")
+            .and_then(|c| aliases.get(c.utf8_text(bytes).unwrap_or_default()))
+        else {
+            return;
+        };
+        let Some(arguments) = node.child_by_field_name("arguments") else {
+            return;
+        };
+
+        let mut cursor = arguments.walk();
+        for (index, arg) in arguments.named_children(&mut cursor).enumerate() {
+            let Some(pattern) = patterns.tauri_api(original, index) else {
+                continue;
+            };
+            let mut arg_cursor = arg.walk();
+            let interpolated = arg.kind() == "template_string"
+                && arg
+                    .named_children(&mut arg_cursor)
+                    .any(|child| child.kind() == "template_substitution");
+            if interpolated {
+                let range = node_range(arg, bytes);
+                names.push(DynamicName {
+                    entity: pattern.entity,
+                    range: adjust_range(range, line_offset),
+                });
+            }
+        }
+    });
+
+    names
+}
+
+/// Nodes opening the function scope `const`/`let` bindings are resolved in
+const BINDING_SCOPES: &[&str] = &[
+    "program",
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function_declaration",
+    "generator_function",
+];
+
+/// The string fragment an identifier argument is bound to in its own function scope.
+///
+/// The name must have exactly one `const`/`let` declarator in that scope (nested functions
+/// excluded) whose value is a plain string literal. A `let` assigned anywhere in the scope,
+/// nested functions included, is left dynamic, as are bindings from enclosing scopes.
+fn resolve_string_binding<'t>(
+    ident: tree_sitter::Node<'t>,
+    bytes: &[u8],
+) -> Option<tree_sitter::Node<'t>> {
+    if ident.kind() != "identifier" {
+        return None;
+    }
+    let name = ident.utf8_text(bytes).ok()?;
+
+    let mut scope = ident.parent()?;
+    while !BINDING_SCOPES.contains(&scope.kind()) {
+        scope = scope.parent()?;
+    }
+
+    let mut declarators = Vec::new();
+    let mut reassigned = false;
+    collect_bindings(scope, scope, name, bytes, &mut declarators, &mut reassigned);
+
+    let &[declarator] = declarators.as_slice() else {
+        return None;
     };
+    let declaration = declarator
+        .parent()
+        .filter(|d| d.kind() == "lexical_declaration")?;
+    let is_const = declaration
+        .child_by_field_name("kind")
+        .is_some_and(|k| k.kind() == "const");
+    if !is_const && reassigned {
+        return None;
+    }
+
+    declarator
+        .child_by_field_name("value")
+        .and_then(string_fragment)
+}
+
+/// Collect the declarators of `name` directly in `scope`, and whether `name` is assigned to
+/// anywhere below it
+fn collect_bindings<'t>(
+    node: tree_sitter::Node<'t>,
+    scope: tree_sitter::Node<'t>,
+    name: &str,
+    bytes: &[u8],
+    declarators: &mut Vec<tree_sitter::Node<'t>>,
+    reassigned: &mut bool,
+) {
+    let names = |field: &str| {
+        node.child_by_field_name(field)
+            .and_then(|n| n.utf8_text(bytes).ok())
+            == Some(name)
+    };
+
+    match node.kind() {
+        "variable_declarator" if names("name") && !is_nested_scope(node, scope) => {
+            declarators.push(node);
+        }
+        "assignment_expression" | "augmented_assignment_expression" if names("left") => {
+            *reassigned = true;
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.named_children(&mut cursor).collect();
+    for child in children {
+        collect_bindings(child, scope, name, bytes, declarators, reassigned);
+    }
+}
 
-    Some(Finding::new(
-        arg_value.to_string(),
-        pattern.entity,
-        pattern.behavior,
-        adjust_range(range, line_offset),
-    ))
+/// Whether a function scope other than `scope` lies between `node` and `scope`
+fn is_nested_scope(node: tree_sitter::Node<'_>, scope: tree_sitter::Node<'_>) -> bool {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if parent.id() == scope.id() {
+            return false;
+        }
+        if BINDING_SCOPES.contains(&parent.kind()) {
+            return true;
+        }
+        current = parent.parent();
+    }
+    true
+}
+
+/// The string fragment of the first of `keys` set to a plain string in an object literal
+fn property_string<'t>(
+    object: tree_sitter::Node<'t>,
+    keys: &[&str],
+    bytes: &[u8],
+) -> Option<tree_sitter::Node<'t>> {
+    if object.kind() != "object" {
+        return None;
+    }
+
+    let mut cursor = object.walk();
+    let pairs: Vec<_> = object
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "pair")
+        .collect();
+
+    keys.iter().find_map(|key| {
+        pairs.iter().find_map(|pair| {
+            let name = pair.child_by_field_name("key")?;
+            let text = name.utf8_text(bytes).unwrap_or_default();
+            let text = match name.kind() {
+                "string" => text.trim_matches(|c| c == '"' || c == '\''),
+                _ => text,
+            };
+            (text == *key)
+                .then(|| pair.child_by_field_name("value"))
+                .flatten()
+                .and_then(string_fragment)
+        })
+    })
 }
 
 fn process_specta_call(
@@ -278,10 +667,7 @@ fn process_specta_call(
 
     let camel_name = specta_cap.node.utf8_text(bytes).unwrap_or_default();
     let snake_name = crate::utils::camel_to_snake(camel_name);
-    let method_range = Range {
-        start: point_to_position(specta_cap.node.start_position()),
-        end: point_to_position(specta_cap.node.end_position()),
-    };
+    let method_range = node_range(specta_cap.node, bytes);
     let arg_count = count_specta_call_args(m, caps.specta_call, content);
 
     Some(Finding {
@@ -314,10 +700,7 @@ fn process_specta_event(
     };
 
     let kebab_name = crate::utils::camel_to_kebab(camel_name);
-    let name_range = Range {
-        start: point_to_position(name_cap.node.start_position()),
-        end: point_to_position(name_cap.node.end_position()),
-    };
+    let name_range = node_range(name_cap.node, bytes);
 
     Some(Finding {
         codegen_origin: Some(crate::indexer::GeneratorKind::Specta),
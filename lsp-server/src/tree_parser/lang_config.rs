@@ -13,6 +13,7 @@ pub enum LangType {
     JavaScript,
     Vue,
     Svelte,
+    Astro,
     Angular,
 }
 
@@ -26,6 +27,7 @@ impl LangType {
             "js" | "jsx" => Some(Self::JavaScript),
             "vue" => Some(Self::Vue),
             "svelte" => Some(Self::Svelte),
+            "astro" => Some(Self::Astro),
             _ => None,
         }
     }
@@ -35,7 +37,11 @@ impl LangType {
 pub(super) fn get_query_source(lang: LangType) -> &'static str {
     match lang {
         LangType::Rust => RUST_QUERY,
-        LangType::TypeScript | LangType::Vue | LangType::Svelte | LangType::Angular => TS_QUERY,
+        LangType::TypeScript
+        | LangType::Vue
+        | LangType::Svelte
+        | LangType::Astro
+        | LangType::Angular => TS_QUERY,
         LangType::JavaScript => JS_QUERY,
     }
 }
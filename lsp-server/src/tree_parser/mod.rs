@@ -5,40 +5,57 @@
 //!
 //! ## Submodules
 //! - `lang_config` — language detection, query routing, Angular detection
-//! - `sfc_parser` — Vue/Svelte `<script>` block extraction
+//! - `sfc_parser` — Vue/Svelte `<script>` block and Astro frontmatter extraction
 //! - `rust_parser` — Rust `#[tauri::command]` and event parsing
 //! - `frontend_parser` — TypeScript/JavaScript invoke/emit/listen parsing
 //! - `extractors` — type argument and call argument extraction helpers
+//! - `wrappers` — calls through local functions wrapping a single `invoke("cmd")`
+//! - `text_scan` — plain-text fallback for files without a grammar (`.html`, `.mdx`)
 
 mod extractors;
 mod frontend_parser;
 mod lang_config;
 mod rust_parser;
 mod sfc_parser;
+mod text_scan;
+mod wrappers;
 
 pub use lang_config::LangType;
+pub use text_scan::scan_text;
 
-use crate::indexer::{CommandSchema, EventSchema, FileIndex};
+use crate::indexer::{
+    CommandSchema, EventSchema, FileIndex, RustTypeAlias, StructSchema, WrapperLinks,
+};
 use crate::rust_type_extractor;
-use crate::syntax::{ParseError, ParseResult};
+use crate::syntax::{FrontendPatterns, ParseError, ParseResult};
+use crate::ts_tree_utils::first_error_range;
 use std::path::Path;
-use tree_sitter::{Language, Parser};
+use tower_lsp_server::lsp_types::Range;
+use tree_sitter::{Language, Parser, Tree};
 
 use frontend_parser::parse_frontend;
 use lang_config::is_angular_file;
-use rust_parser::extract_rust_findings;
-use sfc_parser::extract_script_blocks;
+use rust_parser::{extract_registered_commands, extract_rust_findings};
+use sfc_parser::{adjust_position, extract_astro_script, extract_script_blocks};
 
 /// Main parsing function - entry point for all file types
 ///
-/// # Errors
+/// Pass the file's previous syntax tree as `old_tree` (with every edit since then applied via
+/// `Tree::edit`) for an incremental re-parse. The new tree is returned for Rust and
+/// TypeScript/JavaScript files so the caller can cache it for the next edit; Vue/Svelte/Astro
+/// files are parsed per script block and always return `None`.
 ///
-/// Returns error if tree-sitter fails to parse the file or query execution fails
+/// `patterns` selects the frontend functions whose arguments name commands and events.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Panics if language detection succeeds but lang is None (should never happen due to match guards)
-pub fn parse(path: &Path, content: &str) -> ParseResult<FileIndex> {
+/// Returns error if tree-sitter fails to parse the file or query execution fails
+pub fn parse(
+    path: &Path,
+    content: &str,
+    old_tree: Option<&Tree>,
+    patterns: &FrontendPatterns,
+) -> ParseResult<(FileIndex, Option<Tree>)> {
     let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
 
     // Check for Angular: content-based detection for .ts files
@@ -50,39 +67,95 @@ pub fn parse(path: &Path, content: &str) -> ParseResult<FileIndex> {
         LangType::from_extension(ext)
     };
 
-    let findings = match lang {
+    let mut registered_commands = Vec::new();
+    let mut syntax_error = None;
+    let mut dynamic_names = Vec::new();
+    let (findings, wrappers, type_names, tree) = match lang {
         Some(LangType::Rust) => {
             let ts_lang: Language = tree_sitter_rust::LANGUAGE.into();
-            let mut parser = Parser::new();
-            parser.set_language(&ts_lang).map_err(|e| {
-                ParseError::LanguageError(format!("Failed to set Rust language: {e}"))
-            })?;
-            let tree = parser
-                .parse(content, None)
-                .ok_or_else(|| ParseError::SyntaxError("Failed to parse Rust file".to_string()))?;
-            extract_rust_findings(tree.root_node(), content, &ts_lang)?
+            let tree = parse_rust_tree(content, &ts_lang, old_tree)?;
+            let findings = extract_rust_findings(tree.root_node(), content, &ts_lang, path)?;
+            registered_commands = extract_registered_commands(tree.root_node(), content.as_bytes());
+            (findings, WrapperLinks::default(), Vec::new(), Some(tree))
         }
         Some(lang_val @ (LangType::TypeScript | LangType::JavaScript | LangType::Angular)) => {
-            parse_frontend(content, lang_val, 0)?
+            let parsed = parse_frontend(content, lang_val, 0, old_tree, patterns)?;
+            syntax_error = first_error_range(&parsed.tree, content);
+            dynamic_names = parsed.dynamic_names;
+            (
+                parsed.findings,
+                parsed.wrappers,
+                parsed.type_names,
+                Some(parsed.tree),
+            )
         }
-        Some(LangType::Vue | LangType::Svelte) => {
-            let blocks = extract_script_blocks(content);
+        Some(sfc_lang @ (LangType::Vue | LangType::Svelte | LangType::Astro)) => {
+            let blocks = if sfc_lang == LangType::Astro {
+                extract_astro_script(content)
+            } else {
+                extract_script_blocks(content)
+            };
             let mut all_findings = Vec::new();
+            let mut all_wrappers = WrapperLinks::default();
+            let mut all_type_names = Vec::new();
 
             for (script_content, line_offset) in blocks {
-                let findings = parse_frontend(&script_content, LangType::TypeScript, line_offset)?;
-                all_findings.extend(findings);
+                let parsed = parse_frontend(
+                    &script_content,
+                    LangType::TypeScript,
+                    line_offset,
+                    None,
+                    patterns,
+                )?;
+                if syntax_error.is_none() {
+                    syntax_error = first_error_range(&parsed.tree, &script_content).map(|range| Range {
+                        start: adjust_position(range.start, line_offset),
+                        end: adjust_position(range.end, line_offset),
+                    });
+                }
+                all_findings.extend(parsed.findings);
+                all_wrappers.exports.extend(parsed.wrappers.exports);
+                all_wrappers
+                    .imported_calls
+                    .extend(parsed.wrappers.imported_calls);
+                all_type_names.extend(parsed.type_names);
+                dynamic_names.extend(parsed.dynamic_names);
             }
 
-            all_findings
+            (all_findings, all_wrappers, all_type_names, None)
         }
-        None => Vec::new(),
+        None => (Vec::new(), WrapperLinks::default(), Vec::new(), None),
     };
 
-    Ok(FileIndex {
-        path: path.to_path_buf(),
-        findings,
-    })
+    Ok((
+        FileIndex {
+            path: path.to_path_buf(),
+            findings,
+            wrappers,
+            type_names,
+            registered_commands,
+            syntax_error,
+            dynamic_names,
+            ..FileIndex::default()
+        },
+        tree,
+    ))
+}
+
+/// Parse Rust source, incrementally when an edited previous tree is given
+fn parse_rust_tree(
+    content: &str,
+    ts_lang: &Language,
+    old_tree: Option<&Tree>,
+) -> ParseResult<Tree> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(ts_lang)
+        .map_err(|e| ParseError::LanguageError(format!("Failed to set Rust language: {e}")))?;
+
+    parser
+        .parse(content, old_tree)
+        .ok_or_else(|| ParseError::SyntaxError("Failed to parse Rust file".to_string()))
 }
 
 /// Combined result of parsing a Rust file: findings + schemas from a single parse pass.
@@ -90,30 +163,37 @@ pub struct RustFileIndex {
     pub file_index: FileIndex,
     pub command_schemas: Vec<CommandSchema>,
     pub event_schemas: Vec<EventSchema>,
+    pub struct_schemas: Vec<StructSchema>,
+    pub type_aliases: Vec<RustTypeAlias>,
+    /// `true` when tree-sitter had to recover from syntax errors (e.g. mid-edit)
+    pub has_syntax_errors: bool,
+    /// Syntax tree of the parsed content, reusable for the next incremental parse
+    pub tree: Tree,
 }
 
 /// Parse a Rust file in a single pass: one `Parser::new()` + `parser.parse()`,
-/// then run the findings query, command schema query, and event schema query
-/// sequentially on the same tree.
+/// then run the findings query, command schema query, event schema query, struct schema
+/// query and type alias walk sequentially on the same tree.
+///
+/// `old_tree` enables an incremental re-parse, as in [`parse`].
 ///
 /// # Errors
 ///
 /// Returns error if tree-sitter fails to parse the file or query execution fails
-pub fn parse_rust_full(content: &str, path: &Path) -> ParseResult<RustFileIndex> {
+pub fn parse_rust_full(
+    content: &str,
+    path: &Path,
+    old_tree: Option<&Tree>,
+) -> ParseResult<RustFileIndex> {
     let ts_lang: Language = tree_sitter_rust::LANGUAGE.into();
-    let mut parser = Parser::new();
-    parser
-        .set_language(&ts_lang)
-        .map_err(|e| ParseError::LanguageError(format!("Failed to set Rust language: {e}")))?;
-
-    let tree = parser
-        .parse(content, None)
-        .ok_or_else(|| ParseError::SyntaxError("Failed to parse Rust file".to_string()))?;
+    let tree = parse_rust_tree(content, &ts_lang, old_tree)?;
 
     let root = tree.root_node();
 
     // 1. Extract findings (commands + events) using the main query
-    let findings = extract_rust_findings(root, content, &ts_lang)?;
+    let findings = extract_rust_findings(root, content, &ts_lang, path)?;
+
+    let registered_commands = extract_registered_commands(root, content.as_bytes());
 
     // 2. Extract command schemas
     let command_schemas =
@@ -122,12 +202,24 @@ pub fn parse_rust_full(content: &str, path: &Path) -> ParseResult<RustFileIndex>
     // 3. Extract event schemas
     let event_schemas = rust_type_extractor::extract_event_schemas_from_tree(root, content, path);
 
+    // 4. Extract struct field layouts
+    let struct_schemas = rust_type_extractor::extract_struct_schemas_from_tree(root, content, path);
+
+    // 5. Extract `type` aliases, expanded when a command schema names one
+    let type_aliases = rust_type_extractor::extract_type_aliases_from_tree(root, content, path);
+
     Ok(RustFileIndex {
         file_index: FileIndex {
             path: path.to_path_buf(),
             findings,
+            registered_commands,
+            ..FileIndex::default()
         },
         command_schemas,
         event_schemas,
+        struct_schemas,
+        type_aliases,
+        has_syntax_errors: root.has_error(),
+        tree,
     })
 }
@@ -1,38 +1,28 @@
 //! Rust source code parsing for Tauri commands and events
 
 use crate::indexer::Finding;
-use crate::syntax::{Behavior, EntityType, ParseError, ParseResult};
-use crate::utils::{find_capture, point_to_position};
-use std::collections::HashMap;
-use std::sync::LazyLock;
+use crate::position::node_range;
+use crate::syntax::{rust_event_method, Behavior, EntityType, ParseError, ParseResult};
+use crate::ts_tree_utils::{rust_call_arg, rust_string_arg};
+use crate::utils::find_capture;
+use std::path::Path;
 use streaming_iterator::StreamingIterator;
-use tower_lsp_server::lsp_types::Range;
 use tree_sitter::{Language, Query, QueryCursor};
 
 use super::lang_config::RUST_QUERY;
 
-static RUST_EVENT_PATTERNS: LazyLock<HashMap<&'static str, (EntityType, Behavior)>> =
-    LazyLock::new(|| {
-        let mut m = HashMap::new();
-        m.insert("emit", (EntityType::Event, Behavior::Emit));
-        m.insert("emit_to", (EntityType::Event, Behavior::Emit));
-        m.insert("emit_str", (EntityType::Event, Behavior::Emit));
-        m.insert("emit_str_to", (EntityType::Event, Behavior::Emit));
-        m.insert("emit_filter", (EntityType::Event, Behavior::Emit));
-        m.insert("emit_str_filter", (EntityType::Event, Behavior::Emit));
-        m.insert("listen", (EntityType::Event, Behavior::Listen));
-        m.insert("listen_any", (EntityType::Event, Behavior::Listen));
-        m.insert("once", (EntityType::Event, Behavior::Listen));
-        m.insert("once_any", (EntityType::Event, Behavior::Listen));
-        m
-    });
-
 /// Extract findings from a pre-parsed Rust tree root node.
+///
+/// Every command in a Cargo integration test file (`<crate>/tests/`) is marked `test_only`,
+/// like commands inside `#[cfg(test)]` code.
 pub(super) fn extract_rust_findings(
     root: tree_sitter::Node<'_>,
     content: &str,
     ts_lang: &Language,
+    path: &Path,
 ) -> ParseResult<Vec<Finding>> {
+    let is_test_file = is_cargo_test_file(path);
+
     let query = Query::new(ts_lang, RUST_QUERY)
         .map_err(|e| ParseError::QueryError(format!("Failed to create Rust query: {e}")))?;
 
@@ -42,16 +32,18 @@ pub(super) fn extract_rust_findings(
     let fn_name_idx = query.capture_index_for_name("fn_name");
     let fn_item_idx = query.capture_index_for_name("fn_item");
     let method_name_idx = query.capture_index_for_name("method_name");
-    let event_name_idx = query.capture_index_for_name("event_name");
+    let call_args_idx = query.capture_index_for_name("call_args");
     let struct_name_idx = query.capture_index_for_name("struct_name");
     let struct_item_idx = query.capture_index_for_name("struct_item");
     let specta_emit_struct_idx = query.capture_index_for_name("specta_emit_struct");
+    let specta_emit_args_idx = query.capture_index_for_name("specta_emit_args");
 
     let mut findings = Vec::new();
     let mut matches = cursor.matches(&query, root, bytes);
 
     while let Some(m) = matches.next() {
-        if let Some(f) = process_specta_emit(m, specta_emit_struct_idx, bytes) {
+        if let Some(f) = process_specta_emit(m, specta_emit_struct_idx, specta_emit_args_idx, bytes)
+        {
             findings.push(f);
             continue;
         }
@@ -59,11 +51,12 @@ pub(super) fn extract_rust_findings(
             findings.push(f);
             continue;
         }
-        if let Some(f) = process_fn(m, fn_name_idx, fn_item_idx, bytes, content) {
+        if let Some(mut f) = process_fn(m, fn_name_idx, fn_item_idx, bytes, content) {
+            f.test_only |= is_test_file;
             findings.push(f);
             continue;
         }
-        if let Some(f) = process_event_call(m, method_name_idx, event_name_idx, bytes) {
+        if let Some(f) = process_event_call(m, method_name_idx, call_args_idx, bytes) {
             findings.push(f);
         }
     }
@@ -71,9 +64,76 @@ pub(super) fn extract_rust_findings(
     Ok(findings)
 }
 
+/// Macros whose arguments register commands with the builder: Tauri's own, and
+/// tauri-specta's `collect_commands!` (whose builder provides the invoke handler)
+const REGISTRATION_MACROS: &[&str] = &["generate_handler", "collect_commands"];
+
+/// Names of the commands listed in every `generate_handler![...]` of the file, whether the
+/// macro is a statement of its own or nested in `.invoke_handler(...)` of a builder chain.
+///
+/// Paths are reduced to the function name (`commands::greet` → `greet`).
+pub(super) fn extract_registered_commands(
+    root: tree_sitter::Node<'_>,
+    bytes: &[u8],
+) -> Vec<String> {
+    let mut names = Vec::new();
+    collect_registered_commands(root, bytes, &mut names);
+    names
+}
+
+fn collect_registered_commands(node: tree_sitter::Node<'_>, bytes: &[u8], names: &mut Vec<String>) {
+    if node.kind() == "macro_invocation" {
+        let macro_name = node
+            .child_by_field_name("macro")
+            .and_then(|m| m.utf8_text(bytes).ok())
+            .map(|m| m.rsplit("::").next().unwrap_or(m).trim());
+        if macro_name.is_some_and(|m| REGISTRATION_MACROS.contains(&m)) {
+            let mut cursor = node.walk();
+            let args = node
+                .named_children(&mut cursor)
+                .find(|n| n.kind() == "token_tree")
+                .and_then(|t| t.utf8_text(bytes).ok())
+                .unwrap_or_default();
+            let inner = args
+                .get(1..args.len().saturating_sub(1))
+                .unwrap_or_default();
+            names.extend(
+                inner
+                    .split(',')
+                    .map(|path| path.rsplit("::").next().unwrap_or(path).trim())
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string),
+            );
+            return;
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_registered_commands(child, bytes, names);
+    }
+}
+
+/// Whether `path` is inside a crate's `tests/` directory (and not under a `src/` below it,
+/// so a workspace that merely lives in a folder named `tests` is not affected).
+fn is_cargo_test_file(path: &Path) -> bool {
+    let components: Vec<_> = path
+        .components()
+        .map(std::path::Component::as_os_str)
+        .collect();
+    components
+        .iter()
+        .rposition(|c| *c == "tests")
+        .is_some_and(|i| !components[i..].iter().any(|c| *c == "src"))
+}
+
+/// tauri-specta typed event emitted from a tuple struct, struct literal or unit struct
+/// (`UserUpdated { id }.emit(&app)`), named after the struct in kebab-case like its
+/// `#[derive(Event)]` definition
 fn process_specta_emit(
     m: &tree_sitter::QueryMatch<'_, '_>,
     specta_emit_struct_idx: Option<u32>,
+    specta_emit_args_idx: Option<u32>,
     bytes: &[u8],
 ) -> Option<Finding> {
     let cap = find_capture(m, specta_emit_struct_idx)?;
@@ -81,15 +141,17 @@ fn process_specta_emit(
     if !struct_name.starts_with(|c: char| c.is_ascii_uppercase()) {
         return None;
     }
+    // `APP_HANDLE.emit("saved", ())` is a plain emit on a static handle
+    let args = find_capture(m, specta_emit_args_idx)?;
+    if rust_call_arg(args.node, 0).is_some_and(|arg| arg.kind() == "string_literal") {
+        return None;
+    }
     let kebab_name = crate::utils::camel_to_kebab(struct_name);
     Some(Finding::new(
         kebab_name,
         EntityType::Event,
         Behavior::Emit,
-        Range {
-            start: point_to_position(cap.node.start_position()),
-            end: point_to_position(cap.node.end_position()),
-        },
+        node_range(cap.node, bytes),
     ))
 }
 
@@ -113,10 +175,7 @@ fn process_struct(
         kebab_name,
         EntityType::Event,
         Behavior::Definition,
-        Range {
-            start: point_to_position(name_cap.node.start_position()),
-            end: point_to_position(name_cap.node.end_position()),
-        },
+        node_range(name_cap.node, bytes),
     ))
 }
 
@@ -135,37 +194,107 @@ fn process_fn(
     }
 
     let name = name_cap.node.utf8_text(bytes).unwrap_or_default();
-    Some(Finding::new(
-        name.to_string(),
-        EntityType::Command,
-        Behavior::Definition,
-        Range {
-            start: point_to_position(name_cap.node.start_position()),
-            end: point_to_position(name_cap.node.end_position()),
-        },
-    ))
+    let error_type = item_cap
+        .node
+        .child_by_field_name("return_type")
+        .and_then(|n| n.utf8_text(bytes).ok())
+        .and_then(crate::rust_type_extractor::result_error_type);
+    Some(Finding {
+        test_only: crate::rust_attr::is_in_cfg_test(item_cap.node, content),
+        doc: crate::rust_attr::doc_comment(item_cap.node, content),
+        cfg: crate::rust_attr::cfg_condition(item_cap.node, content),
+        error_type,
+        ..Finding::new(
+            name.to_string(),
+            EntityType::Command,
+            Behavior::Definition,
+            node_range(name_cap.node, bytes),
+        )
+    })
 }
 
+/// Event emit/listen through one of the [`crate::syntax::RUST_EVENT_METHODS`], named by a
+/// string literal at the method's argument index
 fn process_event_call(
     m: &tree_sitter::QueryMatch<'_, '_>,
     method_name_idx: Option<u32>,
-    event_name_idx: Option<u32>,
+    call_args_idx: Option<u32>,
     bytes: &[u8],
 ) -> Option<Finding> {
     let method_cap = find_capture(m, method_name_idx)?;
-    let event_cap = find_capture(m, event_name_idx)?;
+    let args_cap = find_capture(m, call_args_idx)?;
 
     let method_name = method_cap.node.utf8_text(bytes).unwrap_or_default();
-    let event_name = event_cap.node.utf8_text(bytes).unwrap_or_default();
+    let method = rust_event_method(method_name)?;
+    let event_node = rust_string_arg(args_cap.node, method.arg_index)?;
+    let event_name = event_node.utf8_text(bytes).unwrap_or_default();
 
-    let (entity, behavior) = RUST_EVENT_PATTERNS.get(method_name)?;
-    Some(Finding::new(
-        event_name.to_string(),
-        *entity,
-        *behavior,
-        Range {
-            start: point_to_position(event_cap.node.start_position()),
-            end: point_to_position(event_cap.node.end_position()),
-        },
-    ))
+    let payload_type = if method.behavior == Behavior::Listen {
+        extract_listen_payload_type(event_node, bytes)
+    } else {
+        None
+    };
+
+    Some(Finding {
+        payload_type,
+        ..Finding::new(
+            event_name.to_string(),
+            EntityType::Event,
+            method.behavior,
+            node_range(event_node, bytes),
+        )
+    })
+}
+
+/// Find the payload type of a Rust `listen`/`once` handler, mapped to TypeScript.
+///
+/// Looks inside the handler closure for a turbofish that deserializes the payload:
+/// - `event.payload::<T>()`
+/// - `serde_json::from_str::<T>(event.payload())`
+///
+/// Returns `None` when the handler is not a closure or the payload is untyped.
+fn extract_listen_payload_type(event_node: tree_sitter::Node<'_>, bytes: &[u8]) -> Option<String> {
+    let mut call = event_node.parent()?;
+    while call.kind() != "call_expression" {
+        call = call.parent()?;
+    }
+
+    let args = call.child_by_field_name("arguments")?;
+    let mut cursor = args.walk();
+    let closure = args
+        .named_children(&mut cursor)
+        .find(|n| n.kind() == "closure_expression")?;
+
+    find_payload_turbofish(closure.child_by_field_name("body")?, bytes)
+        .map(|t| crate::rust_type_extractor::rust_type_to_ts(&t))
+}
+
+/// Depth-first search for the first payload-deserializing `generic_function` node.
+fn find_payload_turbofish(node: tree_sitter::Node<'_>, bytes: &[u8]) -> Option<String> {
+    if node.kind() == "generic_function" {
+        let callee = node
+            .child_by_field_name("function")
+            .and_then(|f| f.utf8_text(bytes).ok())
+            .unwrap_or_default();
+        let is_payload_call = callee.ends_with(".payload")
+            || (callee.ends_with("from_str")
+                && node
+                    .parent()
+                    .and_then(|p| p.child_by_field_name("arguments"))
+                    .and_then(|a| a.utf8_text(bytes).ok())
+                    .is_some_and(|a| a.contains(".payload()")));
+
+        if is_payload_call {
+            let type_args = node.child_by_field_name("type_arguments")?;
+            let mut cursor = type_args.walk();
+            let first = type_args.named_children(&mut cursor).next()?;
+            return first.utf8_text(bytes).ok().map(str::to_string);
+        }
+    }
+
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.named_children(&mut cursor).collect();
+    children
+        .into_iter()
+        .find_map(|child| find_payload_turbofish(child, bytes))
 }
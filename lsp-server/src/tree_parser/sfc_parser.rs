@@ -1,5 +1,6 @@
 //! SFC (Single File Component) parsing for Vue and Svelte
 
+use crate::position::byte_offset_to_position;
 use tower_lsp_server::lsp_types::{Position, Range};
 
 /// Extract ALL script blocks from SFC (Single File Component: Vue, Svelte, etc.)
@@ -12,10 +13,9 @@ pub(super) fn extract_script_blocks(content: &str) -> Vec<(String, usize)> {
         let absolute_tag_start = search_pos + tag_start;
 
         // Find end of opening tag (>)
-        let Some(tag_close_offset) = content[absolute_tag_start..].find('>') else {
+        let Some(tag_close) = opening_tag_end(content, absolute_tag_start) else {
             break;
         };
-        let tag_close = absolute_tag_start + tag_close_offset + 1;
 
         // Find closing </script>
         let Some(end_tag_offset) = content[tag_close..].find("</script>") else {
@@ -27,7 +27,7 @@ pub(super) fn extract_script_blocks(content: &str) -> Vec<(String, usize)> {
         let script_content = &content[tag_close..script_end];
 
         // Calculate line offset
-        let line_offset = content[..tag_close].lines().count().saturating_sub(1);
+        let line_offset = byte_offset_to_position(content, tag_close).line as usize;
 
         blocks.push((script_content.to_string(), line_offset));
 
@@ -38,6 +38,69 @@ pub(super) fn extract_script_blocks(content: &str) -> Vec<(String, usize)> {
     blocks
 }
 
+/// Byte offset just past the `>` closing the tag that starts at `tag_start`.
+///
+/// A `>` inside a quoted attribute value does not close the tag, as in Vue's
+/// `<script setup lang="ts" generic="T extends Record<string, unknown>">`.
+fn opening_tag_end(content: &str, tag_start: usize) -> Option<usize> {
+    let mut quote = None;
+    for (i, ch) in content[tag_start..].char_indices() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '>') => return Some(tag_start + i + 1),
+            (None, _) => {}
+        }
+    }
+    None
+}
+
+/// Extract the frontmatter and all `<script>` blocks of an Astro component
+/// Returns tuples of (`script_content`, `line_offset`) like [`extract_script_blocks`]
+///
+/// The frontmatter is the script between a `---` fence on the first line and the next line
+/// that is only `---`. Without a (closed) frontmatter only the `<script>` blocks are returned.
+pub(super) fn extract_astro_script(content: &str) -> Vec<(String, usize)> {
+    let mut blocks = Vec::new();
+    let mut body_start = 0;
+
+    if let Some((start, end, body)) = astro_frontmatter(content) {
+        let line_offset = byte_offset_to_position(content, start).line as usize;
+        blocks.push((content[start..end].to_string(), line_offset));
+        body_start = body;
+    }
+
+    // Scripts are searched after the frontmatter, so a "<script" string in it is not a tag
+    let body_line = byte_offset_to_position(content, body_start).line as usize;
+    blocks.extend(
+        extract_script_blocks(&content[body_start..])
+            .into_iter()
+            .map(|(script, line_offset)| (script, line_offset + body_line)),
+    );
+
+    blocks
+}
+
+/// Byte offsets of the frontmatter content (start, end) and of the line after its closing fence
+fn astro_frontmatter(content: &str) -> Option<(usize, usize, usize)> {
+    let first_line_end = content.find('\n')?;
+    if content[..first_line_end].trim_end() != "---" {
+        return None;
+    }
+
+    let start = first_line_end + 1;
+    let mut line_start = start;
+    for line in content[start..].split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return Some((start, line_start, line_start + line.len()));
+        }
+        line_start += line.len();
+    }
+
+    None
+}
+
 /// Adjust position by line offset (for Vue/Svelte script extraction)
 #[allow(clippy::cast_possible_truncation)]
 pub(super) fn adjust_position(pos: Position, line_offset: usize) -> Position {
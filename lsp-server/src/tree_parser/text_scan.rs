@@ -0,0 +1,191 @@
+//! Plain-text fallback for files without a tree-sitter grammar (`.html`, `.mdx`)
+//!
+//! Finds calls whose first argument is a string literal on the same line:
+//!
+//! ```text
+//! invoke("greet", { name })      →  Command Call "greet"
+//! await invoke<User>('get_user') →  Command Call "get_user"
+//! listen(`file-saved`, handler)  →  Event Listen "file-saved"
+//! ```
+//!
+//! Only the built-in Tauri APIs are recognized; imports are not resolved, and template
+//! literals with `${}` substitutions are skipped.
+
+use crate::indexer::Finding;
+use crate::position::utf16_len;
+use crate::syntax::FrontendPatterns;
+use tower_lsp_server::lsp_types::{Position, Range};
+
+/// Scan `content` line by line for Tauri API calls with a literal name argument
+#[must_use]
+pub fn scan_text(content: &str, patterns: &FrontendPatterns) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for (row, line) in content.lines().enumerate() {
+        let mut i = 0;
+
+        while i < line.len() {
+            let rest = &line[i..];
+            let ident_len = rest.find(|c: char| !is_ident_char(c)).unwrap_or(rest.len());
+
+            if ident_len == 0 {
+                i += rest.chars().next().map_or(1, char::len_utf8);
+                continue;
+            }
+
+            let ident = &rest[..ident_len];
+            let ident_end = i + ident_len;
+
+            if let Some(pattern) = patterns.tauri_api(ident, 0) {
+                if let Some((start, end)) = string_argument(line, ident_end) {
+                    findings.push(Finding {
+                        call_name_end: Some(position(line, row, ident_end)),
+                        statement_start: Some(position(line, row, i)),
+                        ..Finding::new(
+                            line[start..end].to_string(),
+                            pattern.entity,
+                            pattern.behavior,
+                            Range {
+                                start: position(line, row, start),
+                                end: position(line, row, end),
+                            },
+                        )
+                    });
+                }
+            }
+
+            i = ident_end;
+        }
+    }
+
+    findings
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Byte range of the string literal contents passed as the first argument of a call whose
+/// name ends at `from`, skipping an optional `<T>` type argument
+fn string_argument(line: &str, from: usize) -> Option<(usize, usize)> {
+    let bytes = line.as_bytes();
+    let mut i = skip_whitespace(bytes, from);
+
+    if bytes.get(i) == Some(&b'<') {
+        let mut depth = 0;
+        while let Some(&b) = bytes.get(i) {
+            i += 1;
+            match b {
+                b'<' => depth += 1,
+                b'>' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        if depth != 0 {
+            return None;
+        }
+        i = skip_whitespace(bytes, i);
+    }
+
+    if bytes.get(i) != Some(&b'(') {
+        return None;
+    }
+    i = skip_whitespace(bytes, i + 1);
+
+    let quote = *bytes.get(i).filter(|b| matches!(b, b'"' | b'\'' | b'`'))?;
+    let start = i + 1;
+    let len = line[start..].find(char::from(quote))?;
+    let value = &line[start..start + len];
+
+    if value.is_empty() || value.contains('\\') || value.contains("${") {
+        return None;
+    }
+
+    Some((start, start + len))
+}
+
+fn skip_whitespace(bytes: &[u8], mut i: usize) -> usize {
+    while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+        i += 1;
+    }
+    i
+}
+
+/// LSP position of byte offset `byte` in `line`, counting UTF-16 code units
+#[allow(clippy::cast_possible_truncation)]
+fn position(line: &str, row: usize, byte: usize) -> Position {
+    Position {
+        line: row as u32,
+        character: utf16_len(&line[..byte]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::{Behavior, EntityType};
+
+    #[test]
+    fn finds_calls_with_literal_names() {
+        let content = "<button onclick=\"invoke('greet')\">Hi</button>\n\
+                       <script>await invoke<User>(\"get_user\"); listen(`saved`, cb);</script>\n\
+                       reinvoke('no'); invoke(name); emit(`x-${id}`);";
+        let findings = scan_text(content, &FrontendPatterns::default());
+
+        let found: Vec<_> = findings
+            .iter()
+            .map(|f| {
+                (
+                    f.key.as_str(),
+                    f.entity,
+                    f.behavior,
+                    f.range.start,
+                    f.range.end,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "greet",
+                    EntityType::Command,
+                    Behavior::Call,
+                    Position::new(0, 25),
+                    Position::new(0, 30)
+                ),
+                (
+                    "get_user",
+                    EntityType::Command,
+                    Behavior::Call,
+                    Position::new(1, 28),
+                    Position::new(1, 36)
+                ),
+                (
+                    "saved",
+                    EntityType::Event,
+                    Behavior::Listen,
+                    Position::new(1, 48),
+                    Position::new(1, 53)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn columns_count_utf16_code_units() {
+        let findings = scan_text(
+            "<p>😀 é</p><i onclick=\"emit('ping')\">",
+            &FrontendPatterns::default(),
+        );
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].range.start, Position::new(0, 29));
+    }
+}
@@ -0,0 +1,637 @@
+//! Local invoke wrappers: functions whose body is a single `invoke("literal")`
+//!
+//! ```text
+//! const api = { getUser: () => invoke("get_user") };
+//! function ping() { return invoke("ping"); }
+//!
+//! api.getUser();   // extra Call reference for `get_user`
+//! ping();          // extra Call reference for `ping`
+//! ```
+//!
+//! Wrappers declared in the same file are resolved here. Module-level wrappers the file exports
+//! are also reported as exports, and calls to functions imported from other modules as
+//! imported calls with their module specifier, which the index resolves across files
+//! (`import { getUser } from "./api"; getUser();`).
+//!
+//! Functions forwarding their first parameter to `invoke` as the command name are registered
+//! as `invoke` aliases by [`add_forwarding_invoke_wrappers`].
+
+use crate::indexer::{Finding, ImportedCall, WrapperLinks};
+use crate::position::node_range;
+use crate::syntax::{Behavior, EntityType};
+use std::collections::HashMap;
+
+use super::extractors::statement_start;
+use super::sfc_parser::{adjust_position, adjust_range};
+
+/// How a wrapper is referenced at its call sites: `name()` or `object.name()`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct WrapperPath {
+    object: Option<String>,
+    name: String,
+}
+
+impl WrapperPath {
+    /// Local binding the wrapper is reachable through: the owning object, or the function
+    fn binding(&self) -> &str {
+        self.object.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Name the wrapper is exported under when its binding is exported as `exported`:
+    /// `exported` or `exported.name`
+    fn export_name(&self, exported: &str) -> String {
+        match &self.object {
+            Some(_) => format!("{exported}.{}", self.name),
+            None => exported.to_string(),
+        }
+    }
+}
+
+/// A local name imported from a non-Tauri module
+struct ModuleImport {
+    /// Module specifier, without quotes (`./api`, `@/api`)
+    module: String,
+    binding: ImportBinding,
+}
+
+/// How a local name was imported from a non-Tauri module
+enum ImportBinding {
+    /// `import { getUser } from "./api"` (or `{ getUser as fetchUser }`): the exported name
+    Named(String),
+    /// `import * as api from "./api"`
+    Namespace,
+}
+
+/// Find calls to local invoke wrappers and report them as `Call` findings for the wrapped command,
+/// along with the file's wrapper exports and calls to imported functions.
+///
+/// `aliases` maps local import names to the imported Tauri API names, so aliased
+/// `invoke` imports are recognized inside wrapper bodies.
+pub(super) fn extract_wrapper_calls(
+    root: tree_sitter::Node<'_>,
+    bytes: &[u8],
+    aliases: &HashMap<&str, &str>,
+    line_offset: usize,
+) -> (Vec<Finding>, WrapperLinks) {
+    let exported = module_exports(root, bytes);
+    let mut wrappers = HashMap::new();
+    let mut exports = Vec::new();
+    visit(root, &mut |node| {
+        if let Some((path, command)) = wrapper_definition(node, bytes, aliases) {
+            if is_module_level(node) {
+                for name in exported.get(path.binding()).into_iter().flatten() {
+                    exports.push((path.export_name(name), command.clone()));
+                }
+            }
+            wrappers.insert(path, command);
+        }
+    });
+    let imports = collect_module_imports(root, bytes);
+
+    exports.sort();
+    let mut links = WrapperLinks {
+        exports,
+        imported_calls: Vec::new(),
+    };
+
+    if wrappers.is_empty() && imports.is_empty() {
+        return (Vec::new(), links);
+    }
+
+    let mut findings = Vec::new();
+    visit(root, &mut |node| {
+        if node.kind() != "call_expression" {
+            return;
+        }
+        let Some(callee) = node.child_by_field_name("function") else {
+            return;
+        };
+        let Some((path, name_node)) = wrapper_path_of_callee(callee, bytes) else {
+            return;
+        };
+
+        let range = adjust_range(node_range(name_node, bytes), line_offset);
+        let statement_start = Some(adjust_position(statement_start(node, bytes), line_offset));
+
+        if let Some(command) = wrappers.get(&path) {
+            findings.push(Finding {
+                statement_start,
+                wrapper_name: Some(path.name.clone()),
+                ..Finding::new(command.clone(), EntityType::Command, Behavior::Call, range)
+            });
+        } else if let Some((module, wrapper)) = imported_wrapper(&path, &imports) {
+            links.imported_calls.push(ImportedCall {
+                wrapper,
+                module: module.to_string(),
+                range,
+                statement_start,
+            });
+        }
+    });
+
+    (findings, links)
+}
+
+/// Module-level names the file exports, with the names each is exported under
+///
+/// ```text
+/// export const getUser = ...;         // getUser -> [getUser]
+/// export function ping() {}           // ping -> [ping]
+/// export { api, ping as pingServer }; // api -> [api], ping -> [pingServer]
+/// ```
+///
+/// Default exports and re-exports from other modules are not tracked.
+fn module_exports(root: tree_sitter::Node<'_>, bytes: &[u8]) -> HashMap<String, Vec<String>> {
+    let text = |n: tree_sitter::Node<'_>| n.utf8_text(bytes).ok().map(str::to_string);
+    let mut exports: HashMap<String, Vec<String>> = HashMap::new();
+
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        if statement.kind() != "export_statement" {
+            continue;
+        }
+        let mut token_cursor = statement.walk();
+        if statement
+            .children(&mut token_cursor)
+            .any(|n| n.kind() == "default")
+        {
+            continue;
+        }
+
+        if let Some(declaration) = statement.child_by_field_name("declaration") {
+            let mut names = Vec::new();
+            match declaration.kind() {
+                "function_declaration" | "generator_function_declaration" => {
+                    names.extend(declaration.child_by_field_name("name").and_then(text));
+                }
+                "lexical_declaration" | "variable_declaration" => {
+                    let mut declarator_cursor = declaration.walk();
+                    for declarator in declaration.named_children(&mut declarator_cursor) {
+                        names.extend(
+                            declarator
+                                .child_by_field_name("name")
+                                .filter(|n| n.kind() == "identifier")
+                                .and_then(text),
+                        );
+                    }
+                }
+                _ => {}
+            }
+            for name in names {
+                exports.entry(name.clone()).or_default().push(name);
+            }
+        } else if statement.child_by_field_name("source").is_none() {
+            let mut clause_cursor = statement.walk();
+            let clauses: Vec<_> = statement
+                .named_children(&mut clause_cursor)
+                .filter(|n| n.kind() == "export_clause")
+                .collect();
+            for clause in clauses {
+                let mut spec_cursor = clause.walk();
+                for spec in clause.named_children(&mut spec_cursor) {
+                    let Some(local) = spec.child_by_field_name("name").and_then(text) else {
+                        continue;
+                    };
+                    let exported = spec
+                        .child_by_field_name("alias")
+                        .and_then(text)
+                        .unwrap_or_else(|| local.clone());
+                    exports.entry(local).or_default().push(exported);
+                }
+            }
+        }
+    }
+
+    exports
+}
+
+/// Whether a definition is declared at module level, outside any function or block body
+fn is_module_level(node: tree_sitter::Node<'_>) -> bool {
+    let mut ancestor = node.parent();
+    while let Some(n) = ancestor {
+        if n.kind() == "statement_block" {
+            return false;
+        }
+        ancestor = n.parent();
+    }
+    true
+}
+
+/// Local names bound by imports from non-Tauri modules.
+fn collect_module_imports(
+    root: tree_sitter::Node<'_>,
+    bytes: &[u8],
+) -> HashMap<String, ModuleImport> {
+    let text = |n: tree_sitter::Node<'_>| n.utf8_text(bytes).ok().map(str::to_string);
+    let mut imports = HashMap::new();
+
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        if statement.kind() != "import_statement" {
+            continue;
+        }
+        let Some(source) = statement.child_by_field_name("source").and_then(text) else {
+            continue;
+        };
+        let module = source.trim_matches(|c| c == '"' || c == '\'' || c == '`');
+        if module.starts_with("@tauri-apps/") {
+            continue;
+        }
+
+        let mut clause_cursor = statement.walk();
+        let Some(clause) = statement
+            .named_children(&mut clause_cursor)
+            .find(|n| n.kind() == "import_clause")
+        else {
+            continue;
+        };
+
+        let mut binding_cursor = clause.walk();
+        for binding in clause.named_children(&mut binding_cursor) {
+            match binding.kind() {
+                "named_imports" => {
+                    let mut spec_cursor = binding.walk();
+                    for spec in binding.named_children(&mut spec_cursor) {
+                        let Some(name) = spec.child_by_field_name("name").and_then(text) else {
+                            continue;
+                        };
+                        let local = spec
+                            .child_by_field_name("alias")
+                            .and_then(text)
+                            .unwrap_or_else(|| name.clone());
+                        imports.insert(
+                            local,
+                            ModuleImport {
+                                module: module.to_string(),
+                                binding: ImportBinding::Named(name),
+                            },
+                        );
+                    }
+                }
+                "namespace_import" => {
+                    let mut ns_cursor = binding.walk();
+                    let local = binding
+                        .named_children(&mut ns_cursor)
+                        .find(|n| n.kind() == "identifier")
+                        .and_then(text);
+                    if let Some(local) = local {
+                        imports.insert(
+                            local,
+                            ModuleImport {
+                                module: module.to_string(),
+                                binding: ImportBinding::Namespace,
+                            },
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    imports
+}
+
+/// Module specifier and export name a call through an imported binding refers to, if the
+/// callee is imported.
+fn imported_wrapper<'i>(
+    path: &WrapperPath,
+    imports: &'i HashMap<String, ModuleImport>,
+) -> Option<(&'i str, String)> {
+    let import = imports.get(path.binding())?;
+    let wrapper = match (&import.binding, &path.object) {
+        (ImportBinding::Named(exported), _) => path.export_name(exported),
+        (ImportBinding::Namespace, Some(_)) => path.name.clone(),
+        (ImportBinding::Namespace, None) => return None,
+    };
+    Some((&import.module, wrapper))
+}
+
+/// Register local functions that pass their first parameter to the Tauri `invoke` as the
+/// command name as `invoke` aliases, so calls to them are parsed like `invoke` itself:
+///
+/// ```text
+/// import * as core from "@tauri-apps/api/core";
+/// const invoke = <T>(cmd: string, args?: object) => core.invoke<T>(cmd, args);
+/// async function call(cmd: string) { return await invoke(cmd); }
+/// ```
+///
+/// `aliases` maps local import names to the imported Tauri API names. Wrappers of wrappers
+/// are found by repeating until no new one turns up.
+pub(super) fn add_forwarding_invoke_wrappers<'a>(
+    root: tree_sitter::Node<'_>,
+    bytes: &'a [u8],
+    aliases: &mut HashMap<&'a str, &'a str>,
+) {
+    let namespaces = tauri_namespace_imports(root, bytes);
+
+    // Single-call functions passing their first parameter on: (name, call)
+    let mut candidates = Vec::new();
+    visit(root, &mut |node| {
+        let name_node = match node.kind() {
+            "function_declaration" => node.child_by_field_name("name"),
+            "arrow_function" | "function_expression" => node
+                .parent()
+                .filter(|p| p.kind() == "variable_declarator")
+                .and_then(|p| p.child_by_field_name("name")),
+            _ => None,
+        };
+        let Some(name) = name_node
+            .filter(|n| n.kind() == "identifier")
+            .and_then(|n| n.utf8_text(bytes).ok())
+        else {
+            return;
+        };
+        let Some(param) = first_parameter_name(node, bytes) else {
+            return;
+        };
+        let Some(call) = node.child_by_field_name("body").and_then(single_call) else {
+            return;
+        };
+        let forwards_param = call
+            .child_by_field_name("arguments")
+            .and_then(|args| args.named_child(0))
+            .is_some_and(|arg| {
+                arg.kind() == "identifier" && arg.utf8_text(bytes).ok() == Some(param)
+            });
+        if forwards_param {
+            candidates.push((name, call));
+        }
+    });
+
+    loop {
+        let found: Vec<&str> = candidates
+            .iter()
+            .filter(|(name, call)| {
+                aliases.get(name) != Some(&"invoke")
+                    && call
+                        .child_by_field_name("function")
+                        .is_some_and(|callee| is_tauri_invoke(callee, bytes, aliases, &namespaces))
+            })
+            .map(|(name, _)| *name)
+            .collect();
+        if found.is_empty() {
+            break;
+        }
+        for name in found {
+            aliases.insert(name, "invoke");
+        }
+    }
+}
+
+/// Local names of `import * as core from "@tauri-apps/..."` namespace imports
+fn tauri_namespace_imports<'a>(root: tree_sitter::Node<'_>, bytes: &'a [u8]) -> Vec<&'a str> {
+    let mut names = Vec::new();
+
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        if statement.kind() != "import_statement" {
+            continue;
+        }
+        let is_tauri = statement
+            .child_by_field_name("source")
+            .and_then(|n| n.utf8_text(bytes).ok())
+            .is_some_and(|s| {
+                s.trim_matches(|c| c == '"' || c == '\'' || c == '`')
+                    .starts_with("@tauri-apps/")
+            });
+        if !is_tauri {
+            continue;
+        }
+
+        let mut clause_cursor = statement.walk();
+        for clause in statement
+            .named_children(&mut clause_cursor)
+            .filter(|n| n.kind() == "import_clause")
+        {
+            let mut binding_cursor = clause.walk();
+            for binding in clause
+                .named_children(&mut binding_cursor)
+                .filter(|n| n.kind() == "namespace_import")
+            {
+                let mut ns_cursor = binding.walk();
+                let local = binding
+                    .named_children(&mut ns_cursor)
+                    .find(|n| n.kind() == "identifier")
+                    .and_then(|n| n.utf8_text(bytes).ok());
+                names.extend(local);
+            }
+        }
+    }
+
+    names
+}
+
+/// Whether `callee` is the Tauri `invoke`: an (aliased) import or `namespace.invoke`
+fn is_tauri_invoke(
+    callee: tree_sitter::Node<'_>,
+    bytes: &[u8],
+    aliases: &HashMap<&str, &str>,
+    namespaces: &[&str],
+) -> bool {
+    let text = |n: tree_sitter::Node<'_>| n.utf8_text(bytes).unwrap_or_default();
+
+    match callee.kind() {
+        "identifier" => aliases.get(text(callee)) == Some(&"invoke"),
+        "member_expression" => {
+            let (Some(object), Some(property)) = (
+                callee.child_by_field_name("object"),
+                callee.child_by_field_name("property"),
+            ) else {
+                return false;
+            };
+            object.kind() == "identifier"
+                && namespaces.contains(&text(object))
+                && text(property) == "invoke"
+        }
+        _ => false,
+    }
+}
+
+/// Name of a function's first parameter, when it is a plain identifier
+fn first_parameter_name<'a>(function: tree_sitter::Node<'_>, bytes: &'a [u8]) -> Option<&'a str> {
+    // `cmd => invoke(cmd)` has a single parameter without parentheses
+    if let Some(param) = function.child_by_field_name("parameter") {
+        return param.utf8_text(bytes).ok();
+    }
+
+    let params = function.child_by_field_name("parameters")?;
+    let mut cursor = params.walk();
+    let first = params
+        .named_children(&mut cursor)
+        .find(|n| n.kind() != "comment")?;
+    let pattern = match first.kind() {
+        "required_parameter" | "optional_parameter" => first.child_by_field_name("pattern")?,
+        _ => first,
+    };
+    if pattern.kind() != "identifier" {
+        return None;
+    }
+    pattern.utf8_text(bytes).ok()
+}
+
+/// Pre-order traversal over named nodes.
+pub(super) fn visit<'t>(node: tree_sitter::Node<'t>, f: &mut dyn FnMut(tree_sitter::Node<'t>)) {
+    f(node);
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.named_children(&mut cursor).collect();
+    for child in children {
+        visit(child, f);
+    }
+}
+
+/// If `node` is a function whose whole body is `invoke("cmd", ...)`, return how it is called
+/// and the wrapped command name.
+fn wrapper_definition(
+    node: tree_sitter::Node<'_>,
+    bytes: &[u8],
+    aliases: &HashMap<&str, &str>,
+) -> Option<(WrapperPath, String)> {
+    if !matches!(
+        node.kind(),
+        "arrow_function" | "function_expression" | "function_declaration" | "method_definition"
+    ) {
+        return None;
+    }
+
+    let command = invoked_command(node.child_by_field_name("body")?, bytes, aliases)?;
+    let path = wrapper_path_of_definition(node, bytes)?;
+    Some((path, command))
+}
+
+/// The command name of a body consisting only of `invoke("cmd", ...)`,
+/// optionally awaited or returned from a single-statement block.
+fn invoked_command(
+    body: tree_sitter::Node<'_>,
+    bytes: &[u8],
+    aliases: &HashMap<&str, &str>,
+) -> Option<String> {
+    let call = single_call(body)?;
+
+    let function = call.child_by_field_name("function")?;
+    if function.kind() != "identifier" {
+        return None;
+    }
+    let local = function.utf8_text(bytes).ok()?;
+    if aliases.get(local) != Some(&"invoke") {
+        return None;
+    }
+
+    let first_arg = call.child_by_field_name("arguments")?.named_child(0)?;
+    if first_arg.kind() != "string" {
+        return None;
+    }
+    let fragment = first_arg.named_child(0)?;
+    if fragment.kind() != "string_fragment" {
+        return None;
+    }
+    Some(fragment.utf8_text(bytes).ok()?.to_string())
+}
+
+/// The call a function body consists of, optionally awaited or returned from a
+/// single-statement block.
+fn single_call(body: tree_sitter::Node<'_>) -> Option<tree_sitter::Node<'_>> {
+    let expr = if body.kind() == "statement_block" {
+        let mut cursor = body.walk();
+        let mut statements = body
+            .named_children(&mut cursor)
+            .filter(|n| n.kind() != "comment");
+        let (Some(statement), None) = (statements.next(), statements.next()) else {
+            return None;
+        };
+        if !matches!(
+            statement.kind(),
+            "return_statement" | "expression_statement"
+        ) {
+            return None;
+        }
+        statement.named_child(0)?
+    } else {
+        body
+    };
+
+    let call = if expr.kind() == "await_expression" {
+        expr.named_child(0)?
+    } else {
+        expr
+    };
+    (call.kind() == "call_expression").then_some(call)
+}
+
+/// Name (and owning object, if any) a wrapper function is declared under.
+fn wrapper_path_of_definition(node: tree_sitter::Node<'_>, bytes: &[u8]) -> Option<WrapperPath> {
+    let text = |n: tree_sitter::Node<'_>| n.utf8_text(bytes).ok().map(str::to_string);
+
+    match node.kind() {
+        "function_declaration" => Some(WrapperPath {
+            object: None,
+            name: text(node.child_by_field_name("name")?)?,
+        }),
+        "method_definition" => Some(WrapperPath {
+            object: Some(object_binding_name(node.parent()?, bytes)?),
+            name: text(node.child_by_field_name("name")?)?,
+        }),
+        _ => {
+            let parent = node.parent()?;
+            match parent.kind() {
+                // const getUser = () => invoke(...)
+                "variable_declarator" => Some(WrapperPath {
+                    object: None,
+                    name: text(parent.child_by_field_name("name")?)?,
+                }),
+                // const api = { getUser: () => invoke(...) }
+                "pair" => Some(WrapperPath {
+                    object: Some(object_binding_name(parent.parent()?, bytes)?),
+                    name: text(parent.child_by_field_name("key")?)?,
+                }),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Variable name an object literal is assigned to (`const api = { ... }`).
+fn object_binding_name(object: tree_sitter::Node<'_>, bytes: &[u8]) -> Option<String> {
+    if object.kind() != "object" {
+        return None;
+    }
+    let declarator = object
+        .parent()
+        .filter(|p| p.kind() == "variable_declarator")?;
+    let name = declarator.child_by_field_name("name")?;
+    if name.kind() != "identifier" {
+        return None;
+    }
+    Some(name.utf8_text(bytes).ok()?.to_string())
+}
+
+/// Wrapper path of a call's callee (`name` or `object.name`) and the node naming the wrapper.
+fn wrapper_path_of_callee<'t>(
+    callee: tree_sitter::Node<'t>,
+    bytes: &[u8],
+) -> Option<(WrapperPath, tree_sitter::Node<'t>)> {
+    match callee.kind() {
+        "identifier" => Some((
+            WrapperPath {
+                object: None,
+                name: callee.utf8_text(bytes).ok()?.to_string(),
+            },
+            callee,
+        )),
+        "member_expression" => {
+            let object = callee.child_by_field_name("object")?;
+            let property = callee.child_by_field_name("property")?;
+            if object.kind() != "identifier" {
+                return None;
+            }
+            Some((
+                WrapperPath {
+                    object: Some(object.utf8_text(bytes).ok()?.to_string()),
+                    name: property.utf8_text(bytes).ok()?.to_string(),
+                },
+                property,
+            ))
+        }
+        _ => None,
+    }
+}
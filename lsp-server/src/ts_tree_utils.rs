@@ -1,6 +1,8 @@
 //! Shared tree-sitter helpers for parsing TypeScript content.
 
-use tree_sitter::{Language, Parser, Tree};
+use crate::position::{node_range, node_start};
+use tower_lsp_server::lsp_types::{Position, Range};
+use tree_sitter::{Language, Node, Parser, Tree};
 
 /// Parse a string as TypeScript and return the tree.
 ///
@@ -23,3 +25,77 @@ pub fn parse_rust(content: &str) -> Option<Tree> {
     parser.set_language(&ts_lang).ok()?;
     parser.parse(content, None)
 }
+
+/// The `index`-th argument of a Rust call's `arguments` node, skipping comments.
+#[must_use]
+pub fn rust_call_arg(args: Node<'_>, index: usize) -> Option<Node<'_>> {
+    let mut cursor = args.walk();
+    let arg = args
+        .named_children(&mut cursor)
+        .filter(|n| !matches!(n.kind(), "line_comment" | "block_comment"))
+        .nth(index);
+    arg
+}
+
+/// The `string_content` of the `index`-th argument of a Rust call, when that argument is
+/// a non-empty string literal (`"saved"` in `app.emit("saved", payload)`).
+#[must_use]
+pub fn rust_string_arg(args: Node<'_>, index: usize) -> Option<Node<'_>> {
+    let arg = rust_call_arg(args, index)?;
+    if arg.kind() != "string_literal" {
+        return None;
+    }
+    let mut cursor = arg.walk();
+    let content = arg
+        .named_children(&mut cursor)
+        .find(|n| n.kind() == "string_content");
+    content
+}
+
+/// Range of the first `ERROR` or `MISSING` node of `tree` (parsed from `content`), in
+/// document order.
+///
+/// Returns `None` when the tree parsed cleanly.
+#[must_use]
+pub fn first_error_range(tree: &Tree, content: &str) -> Option<Range> {
+    let root = tree.root_node();
+    if !root.has_error() {
+        return None;
+    }
+    let node = first_error_node(root)?;
+    Some(node_range(node, content.as_bytes()))
+}
+
+/// Start of the top-level item holding the first `ERROR` or `MISSING` node of `tree`
+/// (parsed from `content`).
+///
+/// A `MISSING` node is often placed far from the edit that caused it (an unclosed block is
+/// closed at the end of the file), so the enclosing item is where the damage starts.
+/// Returns `None` when the tree parsed cleanly.
+#[must_use]
+pub fn first_error_item_start(tree: &Tree, content: &str) -> Option<Position> {
+    let root = tree.root_node();
+    if !root.has_error() {
+        return None;
+    }
+    let mut node = first_error_node(root)?;
+    while let Some(parent) = node.parent() {
+        if parent.id() == root.id() {
+            break;
+        }
+        node = parent;
+    }
+    Some(node_start(node, content.as_bytes()))
+}
+
+fn first_error_node(node: Node<'_>) -> Option<Node<'_>> {
+    if node.is_error() || node.is_missing() {
+        return Some(node);
+    }
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .filter(|child| child.has_error())
+        .find_map(first_error_node);
+    found
+}
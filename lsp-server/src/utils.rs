@@ -1,6 +1,8 @@
 //! Shared utility functions
 
-use tower_lsp_server::lsp_types::{Position, Range};
+use std::path::{Path, PathBuf};
+use tower_lsp_server::lsp_types::{Position, Range, Uri};
+use tower_lsp_server::UriExt;
 
 /// Find a tree-sitter capture by its `Option<u32>` index within a match.
 ///
@@ -38,14 +40,28 @@ pub fn camel_to_snake(s: &str) -> String {
     camel_to_separated(s, '_')
 }
 
-/// Convert tree-sitter Point to LSP Position
-#[allow(clippy::cast_possible_truncation)]
+/// Convert a `snake_case` identifier to camelCase, as Tauri does for command arguments
+///
+/// Examples:
+/// - `user_name` → `userName`
+/// - `ping` → `ping`
 #[must_use]
-pub fn point_to_position(point: tree_sitter::Point) -> Position {
-    Position {
-        line: point.row as u32,
-        character: point.column as u32,
+pub fn snake_to_camel(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut upper_next = false;
+
+    for ch in s.chars() {
+        if ch == '_' && !out.is_empty() {
+            upper_next = true;
+        } else if upper_next {
+            out.push(ch.to_ascii_uppercase());
+            upper_next = false;
+        } else {
+            out.push(ch);
+        }
     }
+
+    out
 }
 
 /// Check if a cursor position is inside an LSP range (inclusive start, exclusive end)
@@ -110,23 +126,61 @@ fn camel_to_separated(s: &str, separator: char) -> String {
     result
 }
 
-/// Convert LSP character offset (UTF-16 code units) to byte index in a string
+/// Split an identifier into lowercase words on `-`, `_`, spaces and camelCase boundaries
+///
+/// Examples:
+/// - `userUpdated` → `["user", "updated"]`
+/// - `file-saved_ok` → `["file", "saved", "ok"]`
 #[must_use]
-pub fn lsp_character_to_byte_index(line: &str, character: usize) -> usize {
-    let mut byte_index = 0;
-    let mut char_count = 0;
+pub fn split_words(s: &str) -> Vec<String> {
+    camel_to_separated(s, '_')
+        .split(['_', '-', ' '])
+        .filter(|w| !w.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect()
+}
 
-    for (i, c) in line.char_indices() {
-        if char_count == character {
-            return i;
+/// Levenshtein edit distance between two strings (counted in chars)
+#[must_use]
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut curr = vec![0; b_chars.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, &cb) in b_chars.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
         }
-        char_count += c.len_utf16();
-        byte_index = i + c.len_utf8();
+        std::mem::swap(&mut prev, &mut curr);
     }
 
-    if char_count <= character {
-        return byte_index;
+    prev[b_chars.len()]
+}
+
+/// Resolve symlinks and `..` so one physical file always maps to one index path.
+///
+/// Relative paths and paths that don't exist on disk (unsaved or test files) are returned
+/// unchanged.
+#[must_use]
+pub fn canonical_path(path: &Path) -> PathBuf {
+    if !path.is_absolute() {
+        return path.to_path_buf();
     }
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
 
-    line.len()
+/// Path of a document URI as the client names it: the file path of a `file://` URI, or the
+/// URI text itself for virtual documents (`untitled:Untitled-1`), which exist only in the
+/// document cache.
+///
+/// Symlinks are not resolved here; index lookups map the path to its canonical index path
+/// (see [`crate::indexer::ProjectIndex::register_path`]).
+#[must_use]
+pub fn uri_to_path(uri: &Uri) -> PathBuf {
+    match uri.to_file_path() {
+        Some(path) => path.into_owned(),
+        None => PathBuf::from(uri.as_str()),
+    }
 }
@@ -5,7 +5,9 @@ mod common_paths;
 
 use common_fixtures::load_fixture;
 use common_paths::test_path;
-use lsp_server::bindings_reader::{parse_specta_bindings, parse_typescript_types};
+use lsp_server::bindings_reader::{
+    parse_specta_bindings, parse_string_union_variants, parse_typescript_types,
+};
 use lsp_server::file_processor::process_file_content;
 use lsp_server::indexer::{DiscoveredGenerator, GeneratorKind, ProjectIndex};
 use lsp_server::utils::camel_to_snake;
@@ -115,6 +117,47 @@ fn test_parse_ts_rs_type_aliases() {
     assert!(user_profile.contains("name"));
 }
 
+#[test]
+fn test_parse_ts_enum_and_string_union() {
+    let content = r#"
+export enum Status { Active, Inactive }
+export enum Level { Low = "low", High = "high" }
+export type Phase = "not-started" | "in-progress" | "done";
+"#;
+    let aliases = parse_typescript_types(content);
+
+    assert_eq!(aliases["Status"], r#""Active" | "Inactive""#);
+    assert_eq!(aliases["Level"], r#""low" | "high""#);
+
+    assert_eq!(
+        parse_string_union_variants(&aliases["Phase"]),
+        Some(vec![
+            "not-started".to_string(),
+            "in-progress".to_string(),
+            "done".to_string(),
+        ])
+    );
+    assert_eq!(
+        parse_string_union_variants(&aliases["Status"]),
+        Some(vec!["Active".to_string(), "Inactive".to_string()])
+    );
+    assert_eq!(parse_string_union_variants("string | null"), None);
+}
+
+#[test]
+fn test_parse_object_type_alias_like_interface() {
+    let content = load_fixture("bindings/object_type_aliases.ts");
+    let aliases = parse_typescript_types(&content);
+
+    assert_eq!(
+        aliases["UserProfile"],
+        "{ id: number; name: string; email: string | null }"
+    );
+    assert_eq!(aliases["UserProfileAlias"], aliases["UserProfile"]);
+    assert_eq!(aliases["Settings"], r#"{ theme: "light" | "dark" }"#);
+    assert_eq!(aliases["Status"], r#""active" | "inactive""#);
+}
+
 // ============================================================
 // typegen type alias parsing
 // ============================================================
@@ -225,6 +268,32 @@ fn test_process_specta_file_populates_schema() {
     assert_eq!(schema.unwrap().generator, GeneratorKind::Specta);
 }
 
+#[test]
+fn test_reindex_after_generator_swap_picks_up_bindings() {
+    let index = ProjectIndex::new();
+    let content = load_fixture("bindings/specta_bindings.ts");
+    let path = test_path("specta_bindings.ts");
+
+    // No generator configured yet: the file is indexed as a plain frontend file
+    process_file_content(&path, &content, &index);
+    assert!(index.get_schema("get_user_profile").is_none());
+
+    // Config reload: swap in the discovered generator, clear, and rebuild
+    index.set_generator_bindings(vec![DiscoveredGenerator {
+        kind: GeneratorKind::Specta,
+        output_path: path.clone(),
+        is_directory: false,
+    }]);
+    index.clear();
+    process_file_content(&path, &content, &index);
+
+    assert_eq!(
+        index.get_schema("get_user_profile").map(|s| s.generator),
+        Some(GeneratorKind::Specta),
+        "Schema from the newly configured generator should appear after reload"
+    );
+}
+
 #[test]
 fn test_rust_file_populates_rust_source_schema() {
     let index = ProjectIndex::new();
@@ -342,3 +411,22 @@ export async function onAppReady(
     assert!(ready.is_some(), "Should find app-ready");
     assert_eq!(ready.unwrap().payload_type, "string");
 }
+
+#[test]
+fn test_parse_object_type_fields() {
+    let fields =
+        lsp_server::bindings_reader::parse_object_type_fields("{ id: number; tags: string[] }");
+    assert_eq!(
+        fields,
+        Some(vec![
+            ("id".to_string(), "number".to_string()),
+            ("tags".to_string(), "string[]".to_string()),
+        ])
+    );
+
+    assert_eq!(
+        lsp_server::bindings_reader::parse_object_type_fields("string | null"),
+        None,
+        "Non-object definitions have no fields"
+    );
+}
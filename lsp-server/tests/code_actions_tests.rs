@@ -99,7 +99,10 @@ fn emit_event(app: &AppHandle) {
 "#,
         expect![[r#"
             "Add payload type 'UserPayload'" [quickfix]
-              edit /frontend.ts 1:6 insert "<UserPayload>""#]],
+              edit /frontend.ts 1:6 insert "<UserPayload>"
+            "Convert to once()" [refactor.rewrite]
+              edit /frontend.ts 1:0..1:6 replace "once"
+              edit /frontend.ts 0:15 insert ", once""#]],
     );
 }
 
@@ -122,10 +125,394 @@ listen("$0weather", (e) => {});
 "#,
         expect![[r#"
             "Add payload type 'WeatherForecastEntry[]'" [quickfix]
-              edit /frontend.ts 1:6 insert "<WeatherForecastEntry[]>""#]],
+              edit /frontend.ts 1:6 insert "<WeatherForecastEntry[]>"
+            "Convert to once()" [refactor.rewrite]
+              edit /frontend.ts 1:0..1:6 replace "once"
+              edit /frontend.ts 0:15 insert ", once""#]],
     );
 }
 
+// ===========================================================================
+// Event name typo → replace with emitted name
+// ===========================================================================
+
+#[test]
+fn code_action_event_name_typo() {
+    helpers::check_code_actions(
+        r#"
+//- /backend.rs
+fn save(app: &AppHandle) {
+    app.emit("file-saved", ()).unwrap();
+}
+
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("$0file-svaed", (e) => console.log(e));
+"#,
+        expect![[r#"
+            "Change event name to 'file-saved'" [quickfix]
+              edit /frontend.ts 1:8..1:18 replace "file-saved"
+            "Convert to once()" [refactor.rewrite]
+              edit /frontend.ts 1:0..1:6 replace "once"
+              edit /frontend.ts 0:15 insert ", once""#]],
+    );
+}
+
+// ===========================================================================
+// listen ↔ once refactor
+// ===========================================================================
+
+#[test]
+fn code_action_listen_to_once_adds_import() {
+    helpers::check_code_actions(
+        r#"
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("$0ready", (e) => console.log(e));
+"#,
+        expect![[r#"
+            "Convert to once()" [refactor.rewrite]
+              edit /frontend.ts 1:0..1:6 replace "once"
+              edit /frontend.ts 0:15 insert ", once""#]],
+    );
+}
+
+#[test]
+fn code_action_once_to_listen_uses_alias() {
+    helpers::check_code_actions(
+        r#"
+//- /frontend.ts
+import { listen as subscribe, once as subscribeOnce } from "@tauri-apps/api/event";
+subscribeOnce("$0ready", (e) => console.log(e));
+"#,
+        expect![[r#"
+            "Convert to listen()" [refactor.rewrite]
+              edit /frontend.ts 1:0..1:13 replace "subscribe""#]],
+    );
+}
+
+#[test]
+fn code_action_event_naming_renames_everywhere() {
+    helpers::check_code_actions(
+        r#"
+$EVENT_NAMING snake
+//- /backend.rs
+fn notify(app: AppHandle) {
+    app.emit("user-updated", ()).unwrap();
+}
+
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("$0user-updated", () => {});
+listen("user-updated", () => {});
+"#,
+        expect![[r#"
+            "Rename event to 'user_updated' everywhere" [quickfix]
+              edit /backend.rs 1:14..1:26 replace "user_updated"
+              edit /frontend.ts 1:8..1:20 replace "user_updated"
+              edit /frontend.ts 2:8..2:20 replace "user_updated"
+            "Convert to once()" [refactor.rewrite]
+              edit /frontend.ts 1:0..1:6 replace "once"
+              edit /frontend.ts 0:15 insert ", once""#]],
+    );
+}
+
+// ===========================================================================
+// Undefined command returning a TS union → Rust enum + command stub
+// ===========================================================================
+
+#[test]
+fn code_action_command_stub_generates_rust_enum() {
+    let dir = tempfile::tempdir().unwrap();
+    let src_tauri = dir.path().join("src-tauri");
+    std::fs::create_dir_all(src_tauri.join("src")).unwrap();
+    std::fs::write(src_tauri.join("tauri.conf.json"), "{}").unwrap();
+    std::fs::write(src_tauri.join("src/lib.rs"), "use tauri::Manager;\n").unwrap();
+    let root = dir.path().to_path_buf();
+
+    let out = tokio::runtime::Builder::new_multi_thread()
+        .build()
+        .unwrap()
+        .block_on(async {
+            helpers::render_code_actions(
+                r#"
+//- /frontend.ts
+$TYPE_ALIAS Phase = "not-started" | "in-progress" | "done"
+import { invoke } from "@tauri-apps/api/core";
+invoke<Phase>("$0get_phase");
+"#,
+                Some(&root),
+            )
+        });
+
+    expect![[r#"
+        "Create Rust command 'get_phase' in lib.rs" [quickfix]
+          edit lib.rs 2:0 insert "\n#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]\npub enum Phase {\n    #[serde(rename = \"not-started\")]\n    NotStarted,\n    #[serde(rename = \"in-progress\")]\n    InProgress,\n    #[serde(rename = \"done\")]\n    Done,\n}\n\n#[tauri::command]\nfn get_phase() -> Result<Phase, String> {\n    Ok(Phase::NotStarted)\n}\n""#]]
+    .assert_eq(&out.replace(&src_tauri.join("src/").to_string_lossy().into_owned(), ""));
+}
+
+#[test]
+fn preview_generated_type_matches_applied_edit() {
+    use tower_lsp_server::lsp_types::{CodeActionOrCommand, DocumentChanges, OneOf};
+
+    let dir = tempfile::tempdir().unwrap();
+    let src_tauri = dir.path().join("src-tauri");
+    std::fs::create_dir_all(src_tauri.join("src")).unwrap();
+    std::fs::write(src_tauri.join("tauri.conf.json"), "{}").unwrap();
+    std::fs::write(src_tauri.join("src/lib.rs"), "").unwrap();
+    let root = dir.path().to_path_buf();
+    let fixture = r#"
+//- /frontend.ts
+$TYPE_ALIAS Phase = "not-started" | "done"
+import { invoke } from "@tauri-apps/api/core";
+invoke<Phase>("$0get_phase");
+"#;
+
+    let actions = tokio::runtime::Builder::new_multi_thread()
+        .build()
+        .unwrap()
+        .block_on(async { helpers::code_actions(fixture, Some(&root)) })
+        .unwrap();
+    let Some(CodeActionOrCommand::CodeAction(action)) = actions.first() else {
+        panic!("expected a code action");
+    };
+    let Some(DocumentChanges::Edits(edits)) =
+        action.edit.as_ref().unwrap().document_changes.as_ref()
+    else {
+        panic!("expected document edits");
+    };
+    let OneOf::Left(edit) = &edits[0].edits[0] else {
+        panic!("expected a text edit");
+    };
+
+    let preview = helpers::execute_command(
+        fixture,
+        "tarus.previewGeneratedType",
+        vec![serde_json::json!("get_phase")],
+    )
+    .unwrap();
+
+    assert_eq!(preview, edit.new_text.as_str());
+    assert_eq!(
+        action.data,
+        Some(serde_json::json!({ "generatedText": edit.new_text }))
+    );
+    assert!(edit.new_text.contains("pub enum Phase"));
+}
+
+#[test]
+fn code_action_command_stub_respects_max_candidates() {
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("src-tauri/src");
+    std::fs::create_dir_all(&src).unwrap();
+    std::fs::write(dir.path().join("src-tauri/tauri.conf.json"), "{}").unwrap();
+    std::fs::write(src.join("lib.rs"), "pub fn run() {}\n").unwrap();
+    // Tauri v2 entry point: delegates to lib.rs, so it is not offered separately
+    std::fs::write(src.join("main.rs"), "fn main() {\n    app_lib::run();\n}\n").unwrap();
+    std::fs::write(src.join("commands.rs"), "").unwrap();
+    std::fs::write(src.join("api.rs"), "").unwrap();
+    std::fs::write(src.join("utils.rs"), "").unwrap();
+    let root = dir.path().to_path_buf();
+
+    let out = tokio::runtime::Builder::new_multi_thread()
+        .build()
+        .unwrap()
+        .block_on(async {
+            helpers::render_code_actions(
+                r#"
+$MAX_CANDIDATES 2
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("$0greet");
+"#,
+                Some(&root),
+            )
+        });
+
+    let titles: Vec<&str> = out.lines().filter(|l| !l.starts_with(' ')).collect();
+    assert_eq!(
+        titles,
+        [
+            "\"Create Rust command 'greet' in lib.rs\" [quickfix]",
+            "\"Create Rust command 'greet' in api.rs\" [quickfix]",
+        ]
+    );
+}
+
+/// `src-tauri/src` with commands organized under a nested `commands/` module
+fn nested_commands_project() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("src-tauri/src");
+    std::fs::create_dir_all(src.join("commands")).unwrap();
+    std::fs::write(dir.path().join("src-tauri/tauri.conf.json"), "{}").unwrap();
+    std::fs::write(src.join("lib.rs"), "mod commands;\n").unwrap();
+    std::fs::write(src.join("commands/mod.rs"), "pub mod user;\n").unwrap();
+    std::fs::write(
+        src.join("commands/user.rs"),
+        "#[tauri::command]\npub fn get_user() {}\n",
+    )
+    .unwrap();
+    dir
+}
+
+fn command_stub_titles(fixture: &str, root: &std::path::Path) -> Vec<String> {
+    let root = root.to_path_buf();
+    let out = tokio::runtime::Builder::new_multi_thread()
+        .build()
+        .unwrap()
+        .block_on(async { helpers::render_code_actions(fixture, Some(&root)) });
+
+    out.lines()
+        .filter(|l| !l.starts_with(' '))
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn code_action_command_stub_finds_nested_command_files() {
+    let dir = nested_commands_project();
+
+    let titles = command_stub_titles(
+        r#"
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("$0greet");
+"#,
+        dir.path(),
+    );
+
+    assert_eq!(
+        titles,
+        [
+            "\"Create Rust command 'greet' in lib.rs\" [quickfix]",
+            "\"Create Rust command 'greet' in user.rs\" [quickfix]",
+            "\"Create Rust command 'greet' in mod.rs\" [quickfix]",
+        ]
+    );
+}
+
+#[test]
+fn code_action_command_stub_prefers_command_dir() {
+    let dir = nested_commands_project();
+
+    let titles = command_stub_titles(
+        r#"
+$COMMAND_DIR src-tauri/src/commands
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("$0greet");
+"#,
+        dir.path(),
+    );
+
+    assert_eq!(
+        titles,
+        [
+            "\"Create Rust command 'greet' in user.rs\" [quickfix]",
+            "\"Create Rust command 'greet' in mod.rs\" [quickfix]",
+            "\"Create Rust command 'greet' in lib.rs\" [quickfix]",
+        ]
+    );
+}
+
+#[test]
+fn rust_enum_template_keeps_pascal_case_variants() {
+    let out = lsp_server::capabilities::code_actions::rust_enum_template(
+        "Status",
+        &["Active".to_string(), "Inactive".to_string()],
+    );
+    expect![[r#"
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+        pub enum Status {
+            Active,
+            Inactive,
+        }
+    "#]]
+    .assert_eq(&out);
+}
+
+// ===========================================================================
+// Uninvoked Rust command → frontend invoke call
+// ===========================================================================
+
+/// Frontend files ranked as invoke targets: an API module and a file that already invoke
+/// commands, a plain-JS API module and an unrelated component
+fn frontend_targets_project() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("src");
+    std::fs::create_dir_all(&src).unwrap();
+    std::fs::create_dir_all(dir.path().join("src-tauri")).unwrap();
+    std::fs::write(dir.path().join("src-tauri/tauri.conf.json"), "{}").unwrap();
+    let import = "import { invoke } from \"@tauri-apps/api/core\";\n";
+    std::fs::write(src.join("api.ts"), import).unwrap();
+    std::fs::write(
+        src.join("main.ts"),
+        format!("{import}await invoke(\"ping\");\n"),
+    )
+    .unwrap();
+    std::fs::write(src.join("tauri.js"), "export const app = \"demo\";").unwrap();
+    std::fs::write(src.join("App.tsx"), "export default function App() {}\n").unwrap();
+    dir
+}
+
+#[test]
+fn code_action_generates_invoke_call_for_uninvoked_command() {
+    let dir = frontend_targets_project();
+    let root = dir.path().to_path_buf();
+
+    let out = tokio::runtime::Builder::new_multi_thread()
+        .build()
+        .unwrap()
+        .block_on(async {
+            helpers::render_code_actions(
+                r#"
+//- /backend.rs
+#[tauri::command]
+fn $0get_user(user_id: u32, state: tauri::State<'_, Db>) -> Result<User, String> {
+    todo!()
+}
+"#,
+                Some(&root),
+            )
+        });
+
+    expect![[r#"
+        "Generate frontend invoke call for 'get_user' in api.ts" [quickfix]
+          edit api.ts 1:0 insert "\nexport async function getUser(userId: number): Promise<User> {\n  return invoke<User>(\"get_user\", { userId });\n}\n"
+        "Generate frontend invoke call for 'get_user' in main.ts" [quickfix]
+          edit main.ts 2:0 insert "\nexport async function getUser(userId: number): Promise<User> {\n  return invoke<User>(\"get_user\", { userId });\n}\n"
+        "Generate frontend invoke call for 'get_user' in tauri.js" [quickfix]
+          edit tauri.js 0:0 insert "import { invoke } from \"@tauri-apps/api/core\";\n"
+          edit tauri.js 0:26 insert "\n\nexport async function getUser(userId) {\n  return invoke(\"get_user\", { userId });\n}\n""#]]
+    .assert_eq(&out.replace(&dir.path().join("src/").to_string_lossy().into_owned(), ""));
+}
+
+#[test]
+fn code_action_no_invoke_call_for_invoked_command() {
+    let dir = frontend_targets_project();
+    let root = dir.path().to_path_buf();
+
+    let out = tokio::runtime::Builder::new_multi_thread()
+        .build()
+        .unwrap()
+        .block_on(async {
+            helpers::render_code_actions(
+                r#"
+//- /backend.rs
+#[tauri::command]
+fn $0ping() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("ping");
+"#,
+                Some(&root),
+            )
+        });
+
+    expect!["(none)"].assert_eq(&out);
+}
+
 // ===========================================================================
 // No bindings → no type actions
 // ===========================================================================
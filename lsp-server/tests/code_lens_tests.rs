@@ -62,7 +62,7 @@ import { invoke } from "@tauri-apps/api/core";
 invoke("greet");
 $0
 "#,
-        expect![[r#"1:8 "Go to backend.rs""#]],
+        expect![[r#"1:0 "Go to backend.rs""#]],
     );
 }
 
@@ -79,7 +79,48 @@ import { invoke } from "@tauri-apps/api/core";
 invoke("greet", { name: "Bob" });
 $0
 "#,
-        expect![[r#"1:8 "Go to backend.rs""#]],
+        expect![[r#"1:0 "Go to backend.rs""#]],
+    );
+}
+
+#[test]
+fn code_lens_multiline_invoke_anchored_at_statement() {
+    helpers::check_code_lens(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+async function run() {
+    const result = await invoke<string>(
+        "greet",
+        { name: "Bob" },
+    );
+}
+$0
+"#,
+        expect![[r#"2:4 "Go to backend.rs""#]],
+    );
+}
+
+#[test]
+fn code_lens_arrow_body_invoke_anchored_at_call() {
+    helpers::check_code_lens(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+button.addEventListener("click", () =>
+    invoke("greet"),
+);
+$0
+"#,
+        expect![[r#"2:4 "Go to backend.rs""#]],
     );
 }
 
@@ -111,3 +152,75 @@ $0
         expect!["(none)"],
     );
 }
+
+// ===========================================================================
+// Settings (tarus.codeLens.mode / tarus.codeLens.enabled)
+// ===========================================================================
+
+const MODE_FIXTURE_FILES: &str = r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+invoke("greet");
+"#;
+
+fn mode_fixture(directive: &str, cursor_file: &str) -> String {
+    MODE_FIXTURE_FILES
+        .replace("//- /backend.rs", &format!("{directive}\n//- /backend.rs"))
+        .replacen(cursor_file, &format!("{cursor_file}\n$0"), 1)
+}
+
+#[test]
+fn code_lens_mode_all() {
+    helpers::check_code_lens(
+        &mode_fixture("$CODE_LENS_MODE all", "fn greet() {}"),
+        expect![[r#"1:3 "Go to frontend.ts""#]],
+    );
+    helpers::check_code_lens(
+        &mode_fixture(
+            "$CODE_LENS_MODE all",
+            "invoke(\"greet\");\ninvoke(\"greet\");",
+        ),
+        expect![[r#"
+            1:0 "Go to backend.rs"
+            2:0 "Go to backend.rs""#]],
+    );
+}
+
+#[test]
+fn code_lens_mode_definitions_only() {
+    helpers::check_code_lens(
+        &mode_fixture("$CODE_LENS_MODE definitionsOnly", "fn greet() {}"),
+        expect![[r#"1:3 "Go to frontend.ts""#]],
+    );
+    helpers::check_code_lens(
+        &mode_fixture(
+            "$CODE_LENS_MODE definitionsOnly",
+            "invoke(\"greet\");\ninvoke(\"greet\");",
+        ),
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn code_lens_mode_off() {
+    helpers::check_code_lens(
+        &mode_fixture("$CODE_LENS_MODE off", "fn greet() {}"),
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn code_lens_disabled_overrides_mode() {
+    helpers::check_code_lens(
+        &mode_fixture(
+            "$CODE_LENS_MODE all\n$CODE_LENS_ENABLED false",
+            "fn greet() {}",
+        ),
+        expect!["(none)"],
+    );
+}
@@ -0,0 +1,64 @@
+//! Common test utilities for counting allocations
+//!
+//! Including this module installs a global allocator that counts the allocations of each
+//! thread, so tests running in parallel don't see each other's.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Allocations made while running a closure
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Allocations {
+    pub count: usize,
+    pub bytes: usize,
+}
+
+thread_local! {
+    static ALLOCATIONS: Cell<Allocations> = const {
+        Cell::new(Allocations { count: 0, bytes: 0 })
+    };
+}
+
+struct CountingAllocator;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn record(bytes: usize) {
+    // Thread-locals are unavailable while a thread shuts down; those allocations are not counted
+    let _ = ALLOCATIONS.try_with(|allocations| {
+        let mut counted = allocations.get();
+        counted.count += 1;
+        counted.bytes += bytes;
+        allocations.set(counted);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Run `f`, returning its result and the allocations it made on this thread
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    let after = ALLOCATIONS.with(Cell::get);
+
+    let allocations = Allocations {
+        count: after.count - before.count,
+        bytes: after.bytes - before.bytes,
+    };
+    (result, allocations)
+}
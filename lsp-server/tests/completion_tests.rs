@@ -32,6 +32,133 @@ invoke("$0");
     );
 }
 
+#[test]
+fn completion_invoke_shows_arguments_with_optional_marker() {
+    helpers::check_completion_details(
+        r#"
+//- /backend.rs
+struct MyOptionalThing;
+
+#[tauri::command]
+fn greet(first_name: String, nickname: Option<String>, mode: MyOptionalThing) {}
+
+#[tauri::command]
+fn ping() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("$0");
+"#,
+        expect![[r#"
+            greet { firstName: string, nickname?: string | null, mode: MyOptionalThing }
+            ping"#]],
+    );
+}
+
+#[test]
+fn completion_lists_plugin_commands_by_default() {
+    helpers::check_completion(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /node_modules/tauri-plugin-store/src/lib.rs
+#[tauri::command]
+fn store_get() {}
+
+//- /files.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("plugin:fs|read_file");
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("$0");
+"#,
+        expect![[r#"
+            greet
+            plugin:fs|read_file
+            store_get"#]],
+    );
+}
+
+#[test]
+fn completion_excludes_plugin_commands_when_enabled() {
+    helpers::check_completion(
+        r#"
+$EXCLUDE_PLUGIN_COMMANDS true
+
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /node_modules/tauri-plugin-store/src/lib.rs
+#[tauri::command]
+fn store_get() {}
+
+//- /files.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("plugin:fs|read_file");
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("$0");
+"#,
+        expect!["greet"],
+    );
+}
+
+#[test]
+fn completion_in_untitled_buffer_returns_commands() {
+    helpers::check_untitled_completion(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        "import { invoke } from \"@tauri-apps/api/core\";\ninvoke(\"$0\");\n",
+        expect![[r#"greet"#]],
+    );
+}
+
+#[test]
+fn completion_invoke_generic_suggests_unwrapped_return_type() {
+    helpers::check_completion(
+        r#"
+//- /backend.rs
+struct User { id: u32 }
+
+#[tauri::command]
+async fn get_user(id: u32) -> Result<User, String> { todo!() }
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+const user = await invoke<$0>("get_user", { id: 1 });
+"#,
+        expect!["User"],
+    );
+}
+
+#[test]
+fn completion_invoke_generic_uses_bindings_interface() {
+    helpers::check_completion(
+        r#"
+//- /bindings.ts [specta]
+export const commands = {
+    async getUserProfile(id: number): Promise<Result<UserProfile, string>> {
+        return await TAURI_INVOKE("get_user_profile", { id });
+    },
+}
+export type UserProfile = { id: number; name: string }
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+const user = await invoke<$0>('get_user_profile', { id: 1 });
+"#,
+        expect!["UserProfile"],
+    );
+}
+
 #[test]
 fn completion_inside_invoke_with_generic() {
     helpers::check_completion(
@@ -48,6 +175,47 @@ const r = await invoke<string>("$0");
     );
 }
 
+#[test]
+fn completion_inside_jsx_event_handler() {
+    helpers::check_completion(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+#[tauri::command]
+fn get_user() {}
+
+//- /Button.tsx
+import { invoke } from "@tauri-apps/api/core";
+export const Button = () => (
+    <button className="primary" onClick={() => invoke("$0")}>Greet once</button>
+);
+"#,
+        expect![[r#"
+            get_user
+            greet"#]],
+    );
+}
+
+#[test]
+fn completion_inside_jsx_handler_block_with_generic() {
+    helpers::check_completion(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /Form.tsx
+import { invoke } from "@tauri-apps/api/core";
+export function Form() {
+    return <form onSubmit={async (e) => { await invoke<string>('$0', { e }); }} />;
+}
+"#,
+        expect!["greet"],
+    );
+}
+
 // ===========================================================================
 // emit/listen context
 // ===========================================================================
@@ -28,6 +28,24 @@ invoke("gre$0et", { name: "World" });
     );
 }
 
+#[test]
+fn definition_object_form_invoke_to_rust() {
+    helpers::check_definition(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn get_user(id: u32) -> String {
+    id.to_string()
+}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke({ cmd: "get_$0user", args: { id: 1 } });
+"#,
+        expect!["/backend.rs 1:3..1:11"],
+    );
+}
+
 #[test]
 fn definition_rust_definition_to_ts_calls() {
     helpers::check_definition(
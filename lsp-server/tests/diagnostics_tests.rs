@@ -6,6 +6,8 @@
 mod helpers;
 
 use expect_test::expect;
+use lsp_server::capabilities::diagnostics;
+use tower_lsp_server::lsp_types::NumberOrString;
 
 // ===========================================================================
 // Layer 1: Structural diagnostics
@@ -19,7 +21,9 @@ fn diag_undefined_command() {
 import { invoke } from "@tauri-apps/api/core";
 invoke("$0nonexistent");
 "#,
-        expect![[r#"WARNING 1:8..1:19 "Command 'nonexistent' is not defined in Rust backend""#]],
+        expect![[
+            r#"WARNING 1:8..1:19 "Command 'nonexistent' is not defined in Rust backend" [tarus/command-undefined]"#
+        ]],
     );
 }
 
@@ -31,7 +35,36 @@ fn diag_unused_command() {
 #[tauri::command]
 fn gre$0et() {}
 "#,
-        expect![[r#"WARNING 1:3..1:8 "Command 'greet' is defined but never invoked in frontend""#]],
+        expect![[
+            r#"WARNING 1:3..1:8 "Command 'greet' is defined but never invoked in frontend" [tarus/command-unused]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_unused_command_in_cfg_test_module_no_warning() {
+    helpers::check_diagnostics(
+        r#"
+//- /backend.rs
+#[cfg(test)]
+mod tests {
+    #[tauri::command]
+    fn mock_$0greet() {}
+}
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_unused_command_in_integration_test_file_no_warning() {
+    helpers::check_diagnostics(
+        r#"
+//- /src-tauri/tests/commands.rs
+#[tauri::command]
+fn fixture_$0command() {}
+"#,
+        expect!["(none)"],
     );
 }
 
@@ -51,6 +84,201 @@ invoke("$0greet");
     );
 }
 
+#[test]
+fn diag_command_not_registered_in_invoke_handler_chain() {
+    helpers::check_diagnostics(
+        r#"
+//- /src-tauri/src/lib.rs
+mod commands {
+    #[tauri::command]
+    pub fn greet() {}
+
+    #[tauri::command]
+    pub fn fare$0well() {}
+}
+
+pub fn run() {
+    tauri::Builder::default()
+        .invoke_handler(tauri::generate_handler![commands::greet])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+invoke("farewell");
+"#,
+        expect![[
+            r#"WARNING 5:11..5:19 "Command 'farewell' is not registered in generate_handler![]" [tarus/command-not-registered]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_command_registered_via_standalone_handler_no_warning() {
+    helpers::check_diagnostics(
+        r#"
+//- /src-tauri/src/lib.rs
+#[tauri::command]
+fn gre$0et() {}
+
+pub fn run() {
+    let handler = tauri::generate_handler![greet];
+    tauri::Builder::default().invoke_handler(handler);
+}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_command_registration_skipped_without_generate_handler() {
+    helpers::check_diagnostics(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn gre$0et() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_frontend_defined_command_satisfies_invoke() {
+    helpers::check_diagnostics(
+        r#"
+$FRONTEND_PATTERNS [{"function": "defineCommand", "kind": "define"}]
+//- /src/commands.ts
+defineCommand("greet", (name: string) => `Hello ${name}`);
+
+//- /src/app.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("gre$0et");
+invoke("farewell");
+"#,
+        expect![[
+            r#"WARNING 2:8..2:16 "Command 'farewell' is not defined in Rust backend" [tarus/command-undefined]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_frontend_defined_command_unused_but_not_unregistered() {
+    helpers::check_diagnostics(
+        r#"
+$FRONTEND_PATTERNS [{"function": "defineCommand", "kind": "define"}]
+//- /src-tauri/src/lib.rs
+#[tauri::command]
+fn ping() {}
+
+pub fn run() {
+    tauri::Builder::default().invoke_handler(tauri::generate_handler![ping]);
+}
+
+//- /src/commands.ts
+defineCommand("gre$0et", (name: string) => `Hello ${name}`);
+"#,
+        expect![[
+            r#"WARNING 0:15..0:20 "Command 'greet' is defined but never invoked in frontend" [tarus/command-unused]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_command_not_permitted_by_capability() {
+    helpers::check_diagnostics(
+        r#"
+//- /src-tauri/capabilities/default.json
+{
+  "identifier": "default",
+  "windows": ["main"],
+  "permissions": ["core:default", "allow-greet"]
+}
+
+//- /src-tauri/src/lib.rs
+#[tauri::command]
+fn greet() {}
+
+#[tauri::command]
+fn delete_user() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+invoke("delete_$0user");
+invoke("delete_user");
+"#,
+        expect![[
+            r#"WARNING 2:8..2:19 "Command 'delete_user' is not permitted by any capability (missing 'allow-delete-user')" [tarus/command-not-permitted]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_command_permission_skipped_without_command_permissions() {
+    helpers::check_diagnostics(
+        r#"
+//- /src-tauri/capabilities/default.json
+{ "identifier": "default", "permissions": ["core:default"] }
+
+//- /src-tauri/src/lib.rs
+#[tauri::command]
+fn greet() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("gre$0et");
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_syntax_error_location_in_developer_mode() {
+    helpers::check_diagnostics(
+        r#"
+$DEVELOPER_MODE true
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("gre$0et");
+}
+"#,
+        expect![[
+            r#"INFO 2:0..2:1 "Syntax error: commands and events after this point may not be indexed" [tarus/syntax-error]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_syntax_error_hidden_outside_developer_mode() {
+    helpers::check_diagnostics(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("gre$0et");
+}
+"#,
+        expect!["(none)"],
+    );
+}
+
 #[test]
 fn diag_event_emitted_but_no_listeners() {
     helpers::check_diagnostics(
@@ -59,7 +287,9 @@ fn diag_event_emitted_but_no_listeners() {
 import { emit } from "@tauri-apps/api/event";
 emit("$0my-event");
 "#,
-        expect![[r#"WARNING 1:6..1:14 "Event 'my-event' is emitted but no listeners found""#]],
+        expect![[
+            r#"WARNING 1:6..1:14 "Event 'my-event' is emitted but no listeners found" [tarus/event-no-listeners]"#
+        ]],
     );
 }
 
@@ -71,7 +301,83 @@ fn diag_event_listened_but_no_emitters() {
 import { listen } from "@tauri-apps/api/event";
 listen("$0my-event", (e) => console.log(e));
 "#,
-        expect![[r#"WARNING 1:8..1:16 "Event 'my-event' is listened for but never emitted""#]],
+        expect![[
+            r#"WARNING 1:8..1:16 "Event 'my-event' is listened for but never emitted" [tarus/event-not-emitted]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_event_listen_typo_suggests_emitted_name() {
+    helpers::check_diagnostics(
+        r#"
+//- /backend.rs
+fn save(app: &AppHandle) {
+    app.emit("file-saved", ()).unwrap();
+}
+
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("$0file-svaed", (e) => console.log(e));
+listen("file-saved", (e) => console.log(e));
+"#,
+        expect![[
+            r#"WARNING 1:8..1:18 "Event 'file-svaed' is listened for but never emitted, did you mean 'file-saved'?" [tarus/event-name-typo]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_event_template_literal_names() {
+    // `file-saved` links like a string literal; `file-${id}` is only hinted at
+    helpers::check_diagnostics(
+        r#"
+//- /frontend.ts
+import { emit, listen } from "@tauri-apps/api/event";
+emit(`file-saved`);
+listen("$0file-saved", () => {});
+emit(`file-${id}`);
+"#,
+        expect![[
+            r#"HINT 3:5..3:17 "Event name is built at runtime, so this call is not linked or checked" [tarus/dynamic-name]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_template_literal_name_in_file_without_keys() {
+    helpers::check_diagnostics(
+        r#"
+//- /frontend.ts
+import { emit } from "@tauri-apps/api/event";
+$0emit(`file-${id}`);
+"#,
+        expect![[
+            r#"HINT 1:5..1:17 "Event name is built at runtime, so this call is not linked or checked" [tarus/dynamic-name]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_event_listen_new_event_no_suggestion() {
+    helpers::check_diagnostics(
+        r#"
+//- /backend.rs
+fn notify(app: &AppHandle) {
+    app.emit("open", ()).unwrap();
+    app.emit("file-saved", ()).unwrap();
+}
+
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("$0opex", (e) => console.log(e));
+listen("upload-done", (e) => console.log(e));
+listen("open", (e) => console.log(e));
+listen("file-saved", (e) => console.log(e));
+"#,
+        expect![[r#"
+            WARNING 1:8..1:12 "Event 'opex' is listened for but never emitted" [tarus/event-not-emitted]
+            WARNING 2:8..2:19 "Event 'upload-done' is listened for but never emitted" [tarus/event-not-emitted]"#]],
     );
 }
 
@@ -85,7 +391,9 @@ import { invoke } from "@tauri-apps/api/core";
 invoke("$0missing");
 invoke("missing");
 "#,
-        expect![[r#"WARNING 1:8..1:15 "Command 'missing' is not defined in Rust backend""#]],
+        expect![[
+            r#"WARNING 1:8..1:15 "Command 'missing' is not defined in Rust backend" [tarus/command-undefined]"#
+        ]],
     );
 }
 
@@ -133,6 +441,102 @@ fn greet() -> String { String::new() }
     );
 }
 
+#[test]
+fn diag_invoke_generic_unknown_type() {
+    helpers::check_diagnostics(
+        r#"
+$SCHEMA get_user(): UserProfile
+$TYPE_ALIAS UserProfile = { id: number }
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+const u = await invoke<UserDto>("$0get_user");
+
+//- /backend.rs
+#[tauri::command]
+fn get_user() -> UserProfile { todo!() }
+"#,
+        expect![[r#"
+            WARNING 1:22..1:31 "Unknown type 'UserDto' in invoke generic" [tarus/unknown-invoke-type]
+            WARNING 1:33..1:41 "invoke<UserDto>('get_user') return type mismatch: expected 'UserProfile'" [tarus/return-type-mismatch]"#]],
+    );
+}
+
+#[test]
+fn diag_return_type_conflicting_bindings_use_declaration_file() {
+    helpers::check_diagnostics(
+        r#"
+$SCHEMA get_user(): User
+
+//- /src/bindings/User.ts [ts-rs]
+export type User = { id: number };
+export type LegacyUser = { id: number };
+
+//- /src/types/api.d.ts [ts-rs]
+export interface User { id: string }
+export type ApiUser = { id: string };
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+const a = await invoke<ApiUser>("$0get_user");
+const b = await invoke<LegacyUser>("get_user");
+
+//- /backend.rs
+#[tauri::command]
+fn get_user() -> User { todo!() }
+"#,
+        expect![[
+            r#"WARNING 2:36..2:44 "invoke<LegacyUser>('get_user') return type mismatch: expected 'User'" [tarus/return-type-mismatch]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_invoke_generic_known_types() {
+    helpers::check_diagnostics(
+        r#"
+$TYPE_ALIAS UserProfile = { id: number }
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+import type { Profile } from "./types";
+interface LocalUser { id: number }
+const a = await invoke<UserProfile>("$0list_users");
+const b = await invoke<LocalUser>("list_users");
+const c = await invoke<Profile[] | null>("list_users");
+const d = await invoke<Record<string, unknown>>("list_users");
+
+//- /backend.rs
+#[tauri::command]
+fn list_users() {}
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_invoke_generic_type_parameters_globals_and_ambient_types() {
+    helpers::check_diagnostics(
+        r#"
+//- /src/env.d.ts
+interface AppConfig { theme: string }
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+async function load<T>() { return invoke<T>("$0list_users"); }
+const a = await invoke<Blob>("list_users");
+const b = await invoke<Awaited<ReturnType<() => Blob>>>("list_users");
+const c = await invoke<Int32Array>("list_users");
+const d = await invoke<AppConfig>("list_users");
+
+//- /backend.rs
+#[tauri::command]
+fn list_users() {}
+"#,
+        expect!["(none)"],
+    );
+}
+
 #[test]
 fn diag_return_type_void_skipped() {
     helpers::check_diagnostics(
@@ -169,43 +573,219 @@ fn greet() -> String { String::new() }
     );
 }
 
-// ===========================================================================
-// Layer 2: Type diagnostics — param keys
-// ===========================================================================
-
+// ===========================================================================
+// Layer 2: Type diagnostics — param keys
+// ===========================================================================
+
+#[test]
+fn diag_param_key_missing() {
+    helpers::check_diagnostics(
+        r#"
+$SCHEMA greet(name: string, age: number): void
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("$0greet", { name: "Alice" });
+
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        expect![[
+            r#"WARNING 1:8..1:13 "invoke('greet') is missing required argument: age" [tarus/missing-argument]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_param_key_extra() {
+    helpers::check_diagnostics(
+        r#"
+$SCHEMA greet(name: string): void
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("$0greet", { name: "Alice", extra: 42 });
+
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        expect![[
+            r#"WARNING 1:8..1:13 "invoke('greet') has unexpected argument: extra" [tarus/unexpected-argument]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_param_key_optional_may_be_omitted() {
+    helpers::check_diagnostics(
+        r#"
+$SCHEMA greet(name: string, nickname?: string | null): void
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("$0greet", { name: "Alice" });
+
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_param_key_variable_resolved_from_interface() {
+    helpers::check_diagnostics(
+        r#"
+$SCHEMA greet(name: string, age: number): void
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+interface GreetArgs { name: string; }
+const userData: GreetArgs = { name: "Alice" };
+invoke("$0greet", userData);
+
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        expect![[
+            r#"WARNING 3:8..3:13 "invoke('greet') is missing required argument: age" [tarus/missing-argument]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_param_key_variable_resolved_from_object_literal() {
+    helpers::check_diagnostics(
+        r#"
+$SCHEMA greet(name: string): void
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+const userData = { name: "Alice" };
+invoke("$0greet", userData);
+
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_param_key_variable_unresolved() {
+    helpers::check_diagnostics(
+        r#"
+$SCHEMA greet(name: string): void
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+export function run(userData: unknown) {
+    invoke("$0greet", userData);
+}
+
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        expect![[
+            r#"INFO 2:12..2:17 "invoke('greet') argument 'userData' passed as variable, parameters not verified" [tarus/args-not-verified]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_param_key_variable_resolved_from_nearest_scope() {
+    // The declaration inside the function shadows the module-level one
+    helpers::check_diagnostics(
+        r#"
+$SCHEMA greet(name: string, age: number): void
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+const userData = { name: "Alice" };
+export function run() {
+    const userData = { name: "Bob", age: 42 };
+    invoke("$0greet", userData);
+}
+
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_param_key_variable_shadowed_by_parameter() {
+    helpers::check_diagnostics(
+        r#"
+$SCHEMA greet(name: string, age: number): void
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+const userData = { name: "Alice" };
+export const run = (userData: unknown) => invoke("$0greet", userData);
+
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        expect![[
+            r#"INFO 2:50..2:55 "invoke('greet') argument 'userData' passed as variable, parameters not verified" [tarus/args-not-verified]"#
+        ]],
+    );
+}
+
 #[test]
-fn diag_param_key_missing() {
+fn diag_param_key_variable_resolved_through_interface_extends() {
     helpers::check_diagnostics(
         r#"
-$SCHEMA greet(name: string, age: number): void
+$SCHEMA greet(name: string, age: number, email: string): void
 
 //- /frontend.ts
 import { invoke } from "@tauri-apps/api/core";
-invoke("$0greet", { name: "Alice" });
+interface Person { name: string; }
+interface GreetArgs extends Person { age: number; }
+const userData: GreetArgs = { name: "Alice", age: 42 };
+invoke("$0greet", userData);
 
 //- /backend.rs
 #[tauri::command]
 fn greet() {}
 "#,
-        expect!["(none)"],
+        expect![[
+            r#"WARNING 4:8..4:13 "invoke('greet') is missing required argument: email" [tarus/missing-argument]"#
+        ]],
     );
 }
 
 #[test]
-fn diag_param_key_extra() {
+fn diag_param_key_variable_unresolved_base_interface() {
+    // Keys inherited from an interface declared elsewhere are unknown
     helpers::check_diagnostics(
         r#"
-$SCHEMA greet(name: string): void
+$SCHEMA greet(name: string, age: number): void
 
 //- /frontend.ts
 import { invoke } from "@tauri-apps/api/core";
-invoke("$0greet", { name: "Alice", extra: 42 });
+import type { Person } from "./types";
+interface GreetArgs extends Person { age: number; }
+const userData: GreetArgs = { name: "Alice", age: 42 };
+invoke("$0greet", userData);
 
 //- /backend.rs
 #[tauri::command]
 fn greet() {}
 "#,
-        expect!["(none)"],
+        expect![[
+            r#"INFO 4:8..4:13 "invoke('greet') argument 'userData' passed as variable, parameters not verified" [tarus/args-not-verified]"#
+        ]],
     );
 }
 
@@ -259,6 +839,50 @@ fn emit_event(app: &AppHandle) {
     );
 }
 
+#[test]
+fn diag_rust_listen_payload_mismatch() {
+    helpers::check_diagnostics(
+        r#"
+$EVENT_SCHEMA my-event(UserPayload)
+
+//- /backend.rs
+fn setup(app: &AppHandle) {
+    app.listen("$0my-event", |event| {
+        let payload = event.payload::<String>();
+    });
+}
+
+//- /frontend.ts
+import { emit } from "@tauri-apps/api/event";
+emit<UserPayload>("my-event", {});
+"#,
+        expect![[
+            r#"WARNING 1:16..1:24 "listen('my-event') payload type mismatch: handler reads 'string', expected 'UserPayload'" [tarus/event-payload-mismatch]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_rust_listen_untyped_payload_skipped() {
+    helpers::check_diagnostics(
+        r#"
+$EVENT_SCHEMA my-event(UserPayload)
+
+//- /backend.rs
+fn setup(app: &AppHandle) {
+    app.listen("$0my-event", |event| {
+        println!("{}", event.payload());
+    });
+}
+
+//- /frontend.ts
+import { emit } from "@tauri-apps/api/event";
+emit<UserPayload>("my-event", {});
+"#,
+        expect!["(none)"],
+    );
+}
+
 #[test]
 fn diag_event_payload_null_skipped() {
     helpers::check_diagnostics(
@@ -410,6 +1034,76 @@ fn greet(name: String) -> String { name }
     );
 }
 
+#[test]
+fn diag_param_names_colliding_after_camel_case() {
+    helpers::check_diagnostics(
+        r#"
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("rename_user", { userName: "Alice" });
+
+//- /backend.rs
+#[tauri::command]
+fn $0rename_user(user_name: String, userName: String) {}
+"#,
+        expect![[
+            r#"WARNING 1:3..1:14 "Command 'rename_user' parameters 'user_name', 'userName' map to the same frontend argument 'userName'" [tarus/param-name-collision]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_param_names_distinct_with_snake_case_rename() {
+    helpers::check_diagnostics(
+        r#"
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("rename_user", { user_name: "Alice", userName: "Bob" });
+
+//- /backend.rs
+#[tauri::command(rename_all = "snake_case")]
+fn $0rename_user(user_name: String, userName: String) {}
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_command_prefix_filters_unused() {
+    helpers::check_diagnostics(
+        r#"
+$COMMAND_PREFIX myplugin_
+
+//- /backend.rs
+#[tauri::command]
+fn $0myplugin_ping() {}
+
+#[tauri::command]
+fn app_save() {}
+"#,
+        expect![[
+            r#"WARNING 1:3..1:16 "Command 'myplugin_ping' is defined but never invoked in frontend" [tarus/command-unused]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_command_prefix_filters_undefined() {
+    helpers::check_diagnostics(
+        r#"
+$COMMAND_PREFIX myplugin_
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("$0myplugin_missing");
+invoke("app_missing");
+"#,
+        expect![[
+            r#"WARNING 1:8..1:24 "Command 'myplugin_missing' is not defined in Rust backend" [tarus/command-undefined]"#
+        ]],
+    );
+}
+
 // ===========================================================================
 // Layer 2: Type diagnostics — SpectaCall argument count
 // ===========================================================================
@@ -528,7 +1222,9 @@ const u = await invoke<User>("$0get_user");
 #[tauri::command]
 fn get_user() -> User { todo!() }
 "#,
-        expect!["(none)"],
+        expect![[
+            r#"WARNING 1:22..1:28 "Unknown type 'User' in invoke generic" [tarus/unknown-invoke-type]"#
+        ]],
     );
 }
 
@@ -574,6 +1270,29 @@ fn get_user() -> User { todo!() }
     );
 }
 
+#[test]
+fn diag_return_type_rust_source_expands_type_alias() {
+    // `ApiResult<User>` expands to `Result<User, String>`, whose success type is `User`
+    helpers::check_diagnostics(
+        r#"
+$TYPE_ALIAS User = { id: number }
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+const u = await invoke<string>("$0get_user");
+
+//- /backend.rs
+pub type ApiResult<T> = Result<T, String>;
+
+#[tauri::command]
+fn get_user() -> ApiResult<User> { todo!() }
+"#,
+        expect![[
+            r#"WARNING 1:32..1:40 "invoke<string>('get_user') return type mismatch: expected 'User'" [tarus/return-type-mismatch]"#
+        ]],
+    );
+}
+
 // ===========================================================================
 // Layer 2: Type diagnostics — event payload (additional)
 // ===========================================================================
@@ -641,3 +1360,291 @@ listen("$0weather", (e) => {});
         ]],
     );
 }
+
+#[test]
+fn diag_framework_event_listen_not_reported() {
+    helpers::check_diagnostics(
+        r#"
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("tauri://close-requested", () => {});
+listen("tauri://focus", () => {});
+listen("plugin:updater|progress", () => {});
+listen("never-emitted", () => {});
+$0
+"#,
+        expect![[
+            r#"WARNING 4:8..4:21 "Event 'never-emitted' is listened for but never emitted" [tarus/event-not-emitted]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_configured_framework_events_not_reported() {
+    helpers::check_diagnostics(
+        r#"
+$FRAMEWORK_EVENTS ["deep-link://*", "single-instance"]
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("deep-link://new-url", () => {});
+listen("single-instance", () => {});
+listen("single-instance-2", () => {});
+$0
+"#,
+        expect![[
+            r#"WARNING 3:8..3:25 "Event 'single-instance-2' is listened for but never emitted" [tarus/event-not-emitted]"#
+        ]],
+    );
+}
+
+// ===========================================================================
+// Diagnostics scope (tarus.diagnostics.scope)
+// ===========================================================================
+
+const SCOPE_FIXTURE: &str = r#"
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("undefined_cmd");
+"#;
+
+#[test]
+fn diag_scope_open_files_skips_closed_file() {
+    use dashmap::DashMap;
+    use lsp_server::capabilities::diagnostics::compute_scoped_file_diagnostics;
+    use std::path::PathBuf;
+
+    let data = helpers::parse_fixture(SCOPE_FIXTURE);
+    let path = PathBuf::from("/test/frontend.ts");
+    let open_files = DashMap::new();
+
+    assert!(
+        compute_scoped_file_diagnostics(&path, &data.index, &open_files).is_none(),
+        "Closed file must not get diagnostics in openFiles mode"
+    );
+
+    open_files.insert(path.clone(), data.contents[&path].clone());
+    let diags = compute_scoped_file_diagnostics(&path, &data.index, &open_files)
+        .expect("Open file must get diagnostics");
+    assert_eq!(diags.len(), 1);
+}
+
+#[test]
+fn diag_scope_workspace_includes_closed_file() {
+    use dashmap::DashMap;
+    use lsp_server::capabilities::diagnostics::compute_scoped_file_diagnostics;
+    use lsp_server::indexer::DiagnosticsScope;
+    use std::path::PathBuf;
+
+    let data = helpers::parse_fixture(SCOPE_FIXTURE);
+    data.index.set_diagnostics_scope(DiagnosticsScope::Workspace);
+    let path = PathBuf::from("/test/frontend.ts");
+
+    let diags = compute_scoped_file_diagnostics(&path, &data.index, &DashMap::new())
+        .expect("Workspace mode publishes for every file");
+    assert_eq!(diags.len(), 1);
+}
+
+// ===========================================================================
+// Cross-file propagation (tarus.diagnostics.propagate)
+// ===========================================================================
+
+#[test]
+fn diag_propagates_to_callers_when_command_is_defined() {
+    use lsp_server::capabilities::diagnostics::{affected_files, compute_file_diagnostics};
+    use lsp_server::file_processor::process_file_content;
+    use std::path::PathBuf;
+
+    let data = helpers::parse_fixture(SCOPE_FIXTURE);
+    let frontend = PathBuf::from("/test/frontend.ts");
+    let backend = PathBuf::from("/test/backend.rs");
+    assert_eq!(compute_file_diagnostics(&frontend, &data.index).len(), 1);
+
+    // Save a Rust file that defines the command the frontend already calls
+    let old_keys = data.index.get_file_keys(&backend);
+    let rust = "#[tauri::command]\nfn undefined_cmd() {}\n";
+    assert!(process_file_content(&backend, rust, &data.index));
+
+    let affected = affected_files(&backend, &old_keys, &data.index);
+    assert_eq!(affected, [backend.clone(), frontend.clone()]);
+    assert!(
+        compute_file_diagnostics(&frontend, &data.index).is_empty(),
+        "The undefined-command warning is gone once the caller is recomputed"
+    );
+
+    // Removing the definition again brings the warning back on the caller
+    let old_keys = data.index.get_file_keys(&backend);
+    assert!(process_file_content(
+        &backend,
+        "fn helper() {}\n",
+        &data.index
+    ));
+    assert_eq!(
+        affected_files(&backend, &old_keys, &data.index),
+        [backend, frontend.clone()]
+    );
+    assert_eq!(compute_file_diagnostics(&frontend, &data.index).len(), 1);
+}
+
+#[test]
+fn diag_propagation_disabled_only_affects_changed_file() {
+    use lsp_server::capabilities::diagnostics::affected_files;
+    use lsp_server::file_processor::process_file_content;
+    use std::path::PathBuf;
+
+    let data = helpers::parse_fixture(SCOPE_FIXTURE);
+    data.index.set_propagate_diagnostics(false);
+    let backend = PathBuf::from("/test/backend.rs");
+
+    let rust = "#[tauri::command]\nfn undefined_cmd() {}\n";
+    assert!(process_file_content(&backend, rust, &data.index));
+
+    assert_eq!(affected_files(&backend, &[], &data.index), [backend]);
+}
+
+#[test]
+fn diag_event_naming_convention_conforming_name() {
+    helpers::check_diagnostics(
+        r#"
+$EVENT_NAMING kebab
+//- /backend.rs
+fn notify(app: AppHandle) {
+    app.emit("user-updated", ()).unwrap();
+}
+
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("$0user-updated", () => {});
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_event_naming_convention_violating_name() {
+    helpers::check_diagnostics(
+        r#"
+$EVENT_NAMING kebab
+//- /backend.rs
+fn notify(app: AppHandle) {
+    app.emit("userUpdated", ()).unwrap();
+}
+
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("$0userUpdated", () => {});
+"#,
+        expect![[
+            r#"WARNING 1:8..1:19 "Event 'userUpdated' does not follow the kebab-case naming convention (expected 'user-updated')" [tarus/event-naming-convention]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_unawaited_invoke_when_require_await() {
+    helpers::check_diagnostics(
+        r#"
+$REQUIRE_AWAIT true
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+async function run() {
+    invoke("gre$0et");
+    (invoke("greet"));
+    await invoke("greet");
+    invoke("greet").then(console.log);
+    const pending = invoke("greet");
+    void invoke("greet");
+    return invoke("greet");
+}
+const handler = () => invoke("greet");
+"#,
+        expect![[r#"
+            WARNING 2:12..2:17 "Result of invoke('greet') is not awaited; use `await`, `.then()`, or `void` to discard it" [tarus/invoke-not-awaited]
+            WARNING 3:13..3:18 "Result of invoke('greet') is not awaited; use `await`, `.then()`, or `void` to discard it" [tarus/invoke-not-awaited]"#]],
+    );
+}
+
+#[test]
+fn diag_unawaited_invoke_in_jsx_handler_block() {
+    helpers::check_diagnostics(
+        r#"
+$REQUIRE_AWAIT true
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /App.tsx
+import { invoke } from "@tauri-apps/api/core";
+export const App = () => (
+    <button onClick={() => { invoke("gre$0et"); }}>Greet</button>
+);
+"#,
+        expect![[
+            r#"WARNING 2:37..2:42 "Result of invoke('greet') is not awaited; use `await`, `.then()`, or `void` to discard it" [tarus/invoke-not-awaited]"#
+        ]],
+    );
+}
+
+#[test]
+fn diag_unawaited_invoke_off_by_default() {
+    helpers::check_diagnostics(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("gre$0et");
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn diag_codes_link_to_rule_docs() {
+    let data = helpers::parse_fixture(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn unused() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+import { emit, listen } from "@tauri-apps/api/event";
+invoke("missing");
+emit("orphan-emit");
+listen("orphan-listen", () => {});
+"#,
+    );
+
+    let mut codes = Vec::new();
+    for path in data.contents.keys() {
+        for diagnostic in diagnostics::compute_file_diagnostics(path, &data.index) {
+            let Some(NumberOrString::String(code)) = diagnostic.code else {
+                panic!("diagnostic without code: {}", diagnostic.message);
+            };
+            let href = diagnostic.code_description.expect("code description").href;
+            let rule = code.strip_prefix("tarus/").expect("tarus/ code prefix");
+            assert_eq!(
+                href.as_str(),
+                format!("https://github.com/mvoof/tarus/blob/main/DIAGNOSTICS.md#{rule}")
+            );
+            codes.push(code);
+        }
+    }
+
+    codes.sort();
+    assert_eq!(
+        codes,
+        [
+            "tarus/command-undefined",
+            "tarus/command-unused",
+            "tarus/event-no-listeners",
+            "tarus/event-not-emitted",
+        ]
+    );
+}
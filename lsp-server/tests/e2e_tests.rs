@@ -0,0 +1,401 @@
+//! End-to-end tests
+//!
+//! Drive the full language server over a JSON-RPC pipe (see `lsp_client`).
+
+mod lsp_client;
+
+use lsp_client::{TestClient, TestProject};
+use serde_json::json;
+use std::time::Duration;
+
+const LIB_RS: &str = r#"#[tauri::command]
+fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
+"#;
+
+const MAIN_TS: &str = r#"import { invoke } from "@tauri-apps/api/core";
+invoke("greet", { name: "Tauri" });
+"#;
+
+fn greet_project() -> TestProject {
+    TestProject::new(&[
+        ("src-tauri/tauri.conf.json", "{}"),
+        ("src-tauri/src/lib.rs", LIB_RS),
+        ("src/main.ts", MAIN_TS),
+    ])
+}
+
+#[tokio::test]
+async fn e2e_initialize_registers_capabilities() {
+    let project = greet_project();
+    let mut client = TestClient::start();
+
+    let result = client.initialize(&project.root()).await;
+
+    let capabilities = &result["capabilities"];
+    assert_eq!(capabilities["definitionProvider"], json!(true));
+    assert_eq!(capabilities["hoverProvider"], json!(true));
+}
+
+#[tokio::test]
+async fn e2e_goto_definition_round_trip() {
+    let project = greet_project();
+    let mut client = TestClient::start();
+    client.initialize(&project.root()).await;
+
+    client
+        .did_open(&project.path("src/main.ts"), "typescript", MAIN_TS)
+        .await;
+    let result = client
+        .request(
+            "textDocument/definition",
+            json!({
+                "textDocument": { "uri": project.uri("src/main.ts") },
+                "position": { "line": 1, "character": 10 },
+            }),
+        )
+        .await;
+
+    assert_eq!(
+        result,
+        json!([{
+            "originSelectionRange": {
+                "start": { "line": 1, "character": 8 },
+                "end": { "line": 1, "character": 13 },
+            },
+            "targetUri": project.uri("src-tauri/src/lib.rs"),
+            "targetRange": {
+                "start": { "line": 1, "character": 3 },
+                "end": { "line": 1, "character": 8 },
+            },
+            "targetSelectionRange": {
+                "start": { "line": 1, "character": 3 },
+                "end": { "line": 1, "character": 8 },
+            },
+        }])
+    );
+}
+
+#[tokio::test]
+async fn e2e_non_tauri_workspace_has_no_capabilities() {
+    let project = TestProject::new(&[("src/main.ts", MAIN_TS)]);
+    let mut client = TestClient::start();
+
+    let uri = lsp_client::file_uri(&project.root());
+    let result = client
+        .request(
+            "initialize",
+            json!({ "processId": null, "rootUri": uri, "capabilities": {} }),
+        )
+        .await;
+
+    assert!(result["capabilities"].get("definitionProvider").is_none());
+}
+
+#[tokio::test]
+async fn e2e_watched_file_burst_coalesces_publishes() {
+    let project = TestProject::new(&[
+        ("src-tauri/tauri.conf.json", "{}"),
+        ("src-tauri/src/lib.rs", LIB_RS),
+    ]);
+    let mut client = TestClient::start();
+    // None of the files are opened, so their diagnostics are only published workspace-wide
+    client
+        .settings
+        .insert("tarus.diagnostics.scope".to_string(), json!("workspace"));
+    client.initialize(&project.root()).await;
+    let lib_uri = project.uri("src-tauri/src/lib.rs");
+    client.wait_for_diagnostics(&[lib_uri.clone()]).await;
+    client.published_diagnostics.clear();
+
+    let pages: Vec<String> = (0..100).map(|i| format!("src/page{i}.ts")).collect();
+    std::fs::create_dir_all(project.path("src")).unwrap();
+    for page in &pages {
+        std::fs::write(project.path(page), MAIN_TS).unwrap();
+        client
+            .notify(
+                "workspace/didChangeWatchedFiles",
+                json!({ "changes": [{ "uri": project.uri(page), "type": 1 }] }),
+            )
+            .await;
+    }
+
+    let mut uris: Vec<String> = pages.iter().map(|page| project.uri(page)).collect();
+    uris.push(lib_uri.clone());
+    client.wait_for_diagnostics(&uris).await;
+
+    // Let any trailing batch flush, then read everything sent before the response
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    client
+        .request("workspace/symbol", json!({ "query": "" }))
+        .await;
+
+    // How many batches the burst spans depends on how fast the server gets through it, so
+    // only the final state is exact; `lib.rs` is referenced by every page and would be
+    // published once per page without batching
+    for uri in &uris {
+        let publishes = client.diagnostics_for(uri);
+        assert_eq!(
+            publishes.last().unwrap()["diagnostics"],
+            json!([]),
+            "{uri} ends up without diagnostics"
+        );
+    }
+    assert!(
+        client.diagnostics_for(&lib_uri).len() < pages.len(),
+        "publishes of lib.rs were not batched"
+    );
+}
+
+#[tokio::test]
+async fn e2e_file_with_only_runtime_names_gets_its_hints_published() {
+    let project = TestProject::new(&[
+        ("src-tauri/tauri.conf.json", "{}"),
+        ("src-tauri/src/lib.rs", LIB_RS),
+        (
+            "src/notify.ts",
+            "import { emit } from \"@tauri-apps/api/event\";\nemit(`file-${id}`);\n",
+        ),
+    ]);
+    let mut client = TestClient::start();
+    // notify.ts is never opened, so its diagnostics are only published workspace-wide
+    client
+        .settings
+        .insert("tarus.diagnostics.scope".to_string(), json!("workspace"));
+    client.initialize(&project.root()).await;
+    let notify_uri = project.uri("src/notify.ts");
+    client.wait_for_diagnostics(&[notify_uri.clone()]).await;
+
+    let publishes = client.diagnostics_for(&notify_uri);
+    let diagnostics = publishes[0]["diagnostics"].as_array().unwrap();
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d["code"] == "tarus/dynamic-name"),
+        "a file without keys still gets its hints: {diagnostics:?}"
+    );
+}
+
+#[tokio::test]
+async fn e2e_did_change_without_did_open_edits_the_file_on_disk() {
+    let project = greet_project();
+    let mut client = TestClient::start();
+    // The document is never opened, so its diagnostics are only published workspace-wide
+    client
+        .settings
+        .insert("tarus.diagnostics.scope".to_string(), json!("workspace"));
+    client.initialize(&project.root()).await;
+    let main_uri = project.uri("src/main.ts");
+    client.wait_for_diagnostics(&[main_uri.clone()]).await;
+    client.published_diagnostics.clear();
+
+    // Rename `"greet"` to `"greeet"` with a ranged edit and no prior didOpen
+    client
+        .notify(
+            "textDocument/didChange",
+            json!({
+                "textDocument": { "uri": main_uri, "version": 2 },
+                "contentChanges": [{
+                    "range": {
+                        "start": { "line": 1, "character": 10 },
+                        "end": { "line": 1, "character": 10 },
+                    },
+                    "text": "e",
+                }],
+            }),
+        )
+        .await;
+    client.wait_for_diagnostics(&[main_uri.clone()]).await;
+
+    let publishes = client.diagnostics_for(&main_uri);
+    let diagnostics = publishes[0]["diagnostics"].as_array().unwrap();
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d["message"].as_str().unwrap().contains("'greeet'")),
+        "the edit should apply to the disk content: {diagnostics:?}"
+    );
+
+    // The edit doesn't open the document, so changes on disk still re-index it
+    client.published_diagnostics.clear();
+    client
+        .notify(
+            "workspace/didChangeWatchedFiles",
+            json!({ "changes": [{ "uri": main_uri, "type": 2 }] }),
+        )
+        .await;
+    client.wait_for_diagnostics(&[main_uri.clone()]).await;
+
+    let publishes = client.diagnostics_for(&main_uri);
+    let diagnostics = publishes[0]["diagnostics"].as_array().unwrap();
+    assert!(
+        !diagnostics
+            .iter()
+            .any(|d| d["message"].as_str().unwrap().contains("'greeet'")),
+        "the file on disk still calls 'greet': {diagnostics:?}"
+    );
+}
+
+#[tokio::test]
+async fn e2e_lazy_indexing_defers_frontend_files_until_opened() {
+    let project = greet_project();
+    let mut client = TestClient::start();
+    client
+        .settings
+        .insert("tarus.indexing.mode".to_string(), json!("lazy"));
+    client.initialize(&project.root()).await;
+
+    let references = json!({
+        "textDocument": { "uri": project.uri("src-tauri/src/lib.rs") },
+        "position": { "line": 1, "character": 4 },
+        "context": { "includeDeclaration": false },
+    });
+    let result = client
+        .request("textDocument/references", references.clone())
+        .await;
+    assert_eq!(
+        result,
+        json!(null),
+        "main.ts is not indexed before it is opened"
+    );
+
+    client
+        .did_open(&project.path("src/main.ts"), "typescript", MAIN_TS)
+        .await;
+    let result = client.request("textDocument/references", references).await;
+    assert_eq!(result[0]["uri"], json!(project.uri("src/main.ts")));
+}
+
+#[tokio::test]
+async fn e2e_scan_stops_at_max_files_and_server_stays_responsive() {
+    let components: Vec<(String, String)> = (0..200)
+        .map(|i| {
+            (
+                format!("src/components/c{i}.ts"),
+                format!("export const c{i} = {i};\n"),
+            )
+        })
+        .collect();
+    let mut files: Vec<(&str, &str)> = vec![
+        ("src-tauri/tauri.conf.json", "{}"),
+        ("src-tauri/src/lib.rs", LIB_RS),
+    ];
+    files.extend(components.iter().map(|(p, c)| (p.as_str(), c.as_str())));
+    let project = TestProject::new(&files);
+
+    let mut client = TestClient::start();
+    client
+        .settings
+        .insert("tarus.scan.maxFiles".to_string(), json!(25));
+    client.initialize(&project.root()).await;
+
+    assert!(client.log_messages.iter().any(|m| {
+        m.contains("reached tarus.scan.maxFiles (25 files)")
+            && m.contains("Indexing the 25 file(s) found so far")
+    }));
+
+    let result = client
+        .request("workspace/symbol", json!({ "query": "" }))
+        .await;
+    assert!(result.is_null() || result.is_array());
+}
+
+#[tokio::test]
+async fn e2e_status_notification_tracks_diagnostics_totals() {
+    let project = greet_project();
+    let mut client = TestClient::start();
+    client.settings.insert(
+        "tarus.diagnostics.statusNotification".to_string(),
+        json!(true),
+    );
+    client.initialize(&project.root()).await;
+
+    let broken = "import { invoke } from \"@tauri-apps/api/core\";\ninvoke(\"grete\");\n";
+    client
+        .did_open(&project.path("src/main.ts"), "typescript", broken)
+        .await;
+    client
+        .wait_for_status(|status| status["byCode"]["tarus/command-undefined"] == 1)
+        .await;
+    let status = client.status_notifications.last().unwrap();
+    assert_eq!(status["errors"], 0);
+    assert!(status["warnings"].as_u64().unwrap() >= 1);
+
+    client
+        .notify(
+            "textDocument/didClose",
+            json!({ "textDocument": { "uri": project.uri("src/main.ts") } }),
+        )
+        .await;
+    client
+        .wait_for_status(|status| status["byCode"].get("tarus/command-undefined").is_none())
+        .await;
+
+    // Only changed totals are sent
+    assert!(client
+        .status_notifications
+        .windows(2)
+        .all(|pair| pair[0] != pair[1]));
+}
+
+#[tokio::test]
+async fn e2e_diagnostics_scope_change_republishes() {
+    let project = TestProject::new(&[
+        ("src-tauri/tauri.conf.json", "{}"),
+        ("src-tauri/src/lib.rs", LIB_RS),
+        (
+            "src/main.ts",
+            "import { invoke } from \"@tauri-apps/api/core\";\ninvoke(\"grete\");\n",
+        ),
+    ]);
+    let mut client = TestClient::start();
+    client
+        .settings
+        .insert("tarus.diagnostics.scope".to_string(), json!("workspace"));
+    client.initialize(&project.root()).await;
+    let main_uri = project.uri("src/main.ts");
+    client.wait_for_diagnostics(&[main_uri.clone()]).await;
+    assert_ne!(
+        client.diagnostics_for(&main_uri)[0]["diagnostics"],
+        json!([])
+    );
+
+    // main.ts is not open, so narrowing the scope clears its diagnostics
+    client.published_diagnostics.clear();
+    client
+        .settings
+        .insert("tarus.diagnostics.scope".to_string(), json!("openFiles"));
+    client
+        .notify(
+            "workspace/didChangeConfiguration",
+            json!({ "settings": {} }),
+        )
+        .await;
+    client.wait_for_diagnostics(&[main_uri.clone()]).await;
+    assert_eq!(
+        client.diagnostics_for(&main_uri).last().unwrap()["diagnostics"],
+        json!([])
+    );
+
+    // Widening it again publishes them without any file changing
+    client.published_diagnostics.clear();
+    client
+        .settings
+        .insert("tarus.diagnostics.scope".to_string(), json!("workspace"));
+    client
+        .notify(
+            "workspace/didChangeConfiguration",
+            json!({ "settings": {} }),
+        )
+        .await;
+    client.wait_for_diagnostics(&[main_uri.clone()]).await;
+    let publishes = client.diagnostics_for(&main_uri);
+    let diagnostics = publishes.last().unwrap()["diagnostics"].as_array().unwrap();
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d["message"].as_str().unwrap().contains("'grete'")),
+        "the undefined command is reported again: {diagnostics:?}"
+    );
+}
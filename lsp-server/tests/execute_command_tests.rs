@@ -0,0 +1,504 @@
+//! Execute command tests
+//!
+//! Server-side commands dispatched through `workspace/executeCommand`.
+
+mod helpers;
+
+use expect_test::expect;
+use helpers::check_execute_command;
+
+// ===========================================================================
+// tarus.checkTypeSync
+// ===========================================================================
+
+#[test]
+fn type_sync_all_in_sync() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+struct User { id: u32, name: String }
+
+#[tauri::command]
+fn get_user(id: u32) -> User { todo!() }
+
+//- /bindings.ts [specta]
+export type User = { id: number; name: string }
+"#,
+        "tarus.checkTypeSync",
+        expect![[r#"
+            {
+              "checked": 1,
+              "outOfSync": []
+            }"#]],
+    );
+}
+
+#[test]
+fn type_sync_reports_field_differences() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+struct User { id: u32, name: String, email: String }
+
+#[tauri::command]
+fn get_user(id: u32) -> User { todo!() }
+
+//- /bindings.ts [specta]
+export type User = { id: string; name: string; age: number }
+"#,
+        "tarus.checkTypeSync",
+        expect![[r#"
+            {
+              "checked": 1,
+              "outOfSync": [
+                {
+                  "differences": [
+                    "field 'email' is missing in TypeScript",
+                    "field 'age' does not exist in Rust",
+                    "field 'id': Rust has 'number', TypeScript has 'string'"
+                  ],
+                  "status": "out of sync",
+                  "type": "User"
+                }
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn type_sync_interface_absent_for_nested_param_struct() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+struct Address { city: String }
+struct NewUser { name: String, address: Address }
+
+#[tauri::command]
+fn create_user(user: NewUser) {}
+
+//- /bindings.ts [specta]
+export interface NewUser { name: string; address: Address; }
+"#,
+        "tarus.checkTypeSync",
+        expect![[r#"
+            {
+              "checked": 2,
+              "outOfSync": [
+                {
+                  "differences": [],
+                  "status": "interface absent",
+                  "type": "Address"
+                }
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn type_sync_inlines_serde_flatten_fields() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+struct Base { id: u32, created_at: String }
+struct User {
+    name: String,
+    #[serde(flatten)]
+    base: Base,
+}
+
+#[tauri::command]
+fn get_user() -> User { todo!() }
+
+//- /bindings.ts [specta]
+export type Base = { id: number; created_at: string }
+export type User = { name: string; id: number; created_at: string }
+"#,
+        "tarus.checkTypeSync",
+        expect![[r#"
+            {
+              "checked": 2,
+              "outOfSync": []
+            }"#]],
+    );
+}
+
+#[test]
+fn type_sync_flatten_of_unindexed_type_skips_extra_fields() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+struct User {
+    name: String,
+    email: String,
+    #[serde(flatten)]
+    meta: external::Meta,
+}
+
+#[tauri::command]
+fn get_user() -> User { todo!() }
+
+//- /bindings.ts [specta]
+export type User = { name: string; version: number }
+"#,
+        "tarus.checkTypeSync",
+        expect![[r#"
+            {
+              "checked": 1,
+              "outOfSync": [
+                {
+                  "differences": [
+                    "field 'email' is missing in TypeScript"
+                  ],
+                  "status": "out of sync",
+                  "type": "User"
+                }
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn type_sync_applies_serde_renames() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct User {
+    user_id: u32,
+    created_at: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+#[tauri::command]
+fn get_user() -> User { todo!() }
+
+//- /bindings.ts [specta]
+export type User = { userId: number; createdAt: string; type: string }
+"#,
+        "tarus.checkTypeSync",
+        expect![[r#"
+            {
+              "checked": 1,
+              "outOfSync": []
+            }"#]],
+    );
+}
+
+#[test]
+fn type_sync_checks_same_named_structs_in_each_file() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/admin.rs
+pub struct User { id: u32, role: String }
+
+//- /src-tauri/src/lib.rs
+struct User { id: u32, name: String }
+
+#[tauri::command]
+fn get_user(id: u32) -> User { todo!() }
+
+//- /bindings.ts [specta]
+export type User = { id: number; name: string }
+"#,
+        "tarus.checkTypeSync",
+        expect![[r#"
+            {
+              "checked": 2,
+              "outOfSync": [
+                {
+                  "differences": [
+                    "field 'role' is missing in TypeScript",
+                    "field 'name' does not exist in Rust"
+                  ],
+                  "status": "out of sync",
+                  "type": "User"
+                }
+              ]
+            }"#]],
+    );
+}
+
+#[test]
+fn unknown_command_returns_none() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        "tarus.unknown",
+        expect!["(none)"],
+    );
+}
+
+// ===========================================================================
+// tarus.generateApiDoc
+// ===========================================================================
+
+#[test]
+fn generate_api_doc_groups_by_backend_file() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/users.rs
+#[tauri::command]
+fn get_user(id: u32) -> Result<User, String> { todo!() }
+
+#[tauri::command]
+fn ping() {}
+
+fn notify(app: AppHandle) {
+    app.emit("user-updated", ()).unwrap();
+}
+
+//- /src-tauri/src/files.rs
+#[tauri::command]
+fn save_file(path: String, contents: Vec<u8>) -> bool { todo!() }
+
+//- /src/App.tsx
+import { invoke } from "@tauri-apps/api/core";
+import { listen, emit } from "@tauri-apps/api/event";
+
+await invoke("get_user", { id: 1 });
+await invoke("save_file", { path, contents });
+await invoke("get_user", { id: 2 });
+await invoke("missing_cmd");
+listen("user-updated", () => {});
+emit("theme-changed", "dark");
+"#,
+        "tarus.generateApiDoc",
+        expect![[r##"
+            # Tauri API
+
+            ## Commands
+
+            ### `Not defined in Rust`
+
+            #### `missing_cmd`
+
+            ```ts
+            invoke("missing_cmd")
+            ```
+
+            Called from:
+
+            - `src/App.tsx:7`
+
+            ### `src-tauri/src/files.rs`
+
+            #### `save_file`
+
+            ```ts
+            invoke<boolean>("save_file", { path: string, contents: number[] })
+            ```
+
+            Called from:
+
+            - `src/App.tsx:5`
+
+            ### `src-tauri/src/users.rs`
+
+            #### `get_user`
+
+            ```ts
+            invoke<User>("get_user", { id: number })
+            ```
+
+            Called from:
+
+            - `src/App.tsx:4`
+            - `src/App.tsx:6`
+
+            #### `ping`
+
+            ```ts
+            invoke<void>("ping")
+            ```
+
+            Called from: _none_
+
+            ## Events
+
+            ### `Frontend only`
+
+            #### `theme-changed`
+
+            Emitted from:
+
+            - `src/App.tsx:9`
+
+            Listened for in: _none_
+
+            ### `src-tauri/src/users.rs`
+
+            #### `user-updated`
+
+            Emitted from:
+
+            - `src-tauri/src/users.rs:8`
+
+            Listened for in:
+
+            - `src/App.tsx:8`
+        "##]],
+    );
+}
+
+// ===========================================================================
+// tarus.gotoCommand
+// ===========================================================================
+
+#[test]
+fn goto_command_returns_definition_locations() {
+    let result = helpers::execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+#[tauri::command]
+fn greet() {}
+
+//- /src/app.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+"#,
+        "tarus.gotoCommand",
+        vec![serde_json::json!("greet")],
+    );
+
+    expect![[r#"
+        [
+          {
+            "range": {
+              "end": {
+                "character": 8,
+                "line": 1
+              },
+              "start": {
+                "character": 3,
+                "line": 1
+              }
+            },
+            "uri": "file:///test/src-tauri/src/lib.rs"
+          }
+        ]"#]]
+    .assert_eq(&serde_json::to_string_pretty(&result).unwrap());
+}
+
+#[test]
+fn goto_command_unknown_returns_empty_array() {
+    let result = helpers::execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+#[tauri::command]
+fn greet() {}
+"#,
+        "tarus.gotoCommand",
+        vec![serde_json::json!("missing")],
+    );
+
+    assert_eq!(result, Some(serde_json::json!([])));
+}
+
+// ===========================================================================
+// tarus.orphanedListeners
+// ===========================================================================
+
+#[test]
+fn orphaned_listeners_lists_listeners_without_emitters() {
+    let result = helpers::execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+fn notify(app: AppHandle) {
+    app.emit("user-updated", ()).unwrap();
+}
+
+//- /src/app.ts
+import { listen } from "@tauri-apps/api/event";
+listen("user-updated", () => {});
+listen("never-sent", () => {});
+listen("tauri://focus", () => {});
+"#,
+        "tarus.orphanedListeners",
+        vec![],
+    );
+
+    expect![[r#"
+        [
+          {
+            "event": "never-sent",
+            "range": {
+              "end": {
+                "character": 18,
+                "line": 2
+              },
+              "start": {
+                "character": 8,
+                "line": 2
+              }
+            },
+            "uri": "file:///test/src/app.ts"
+          }
+        ]"#]]
+    .assert_eq(&serde_json::to_string_pretty(&result).unwrap());
+}
+
+// ===========================================================================
+// tarus.diagnosticsJson
+// ===========================================================================
+
+#[test]
+fn diagnostics_json_lists_workspace_diagnostics_sorted() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+#[tauri::command]
+fn greet() {}
+
+#[tauri::command]
+fn unused_cmd() {}
+
+//- /src/app.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+invoke("missing");
+invoke("missing");
+"#,
+        "tarus.diagnosticsJson",
+        expect![[r#"
+            [
+              {
+                "column": 9,
+                "file": "/test/src/app.ts",
+                "line": 3,
+                "message": "Command 'missing' is not defined in Rust backend",
+                "severity": "warning",
+                "source": "tarus"
+              },
+              {
+                "column": 4,
+                "file": "/test/src-tauri/src/lib.rs",
+                "line": 5,
+                "message": "Command 'unused_cmd' is defined but never invoked in frontend",
+                "severity": "warning",
+                "source": "tarus"
+              }
+            ]"#]],
+    );
+}
+
+#[test]
+fn diagnostics_json_clean_workspace_is_empty() {
+    check_execute_command(
+        r#"
+//- /src-tauri/src/lib.rs
+#[tauri::command]
+fn greet() {}
+
+//- /src/app.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+"#,
+        "tarus.diagnosticsJson",
+        expect!["[]"],
+    );
+}
@@ -0,0 +1,17 @@
+// Hand-written bindings mixing interfaces and object type aliases
+
+export interface UserProfile {
+  id: number;
+  name: string;
+  email: string | null;
+}
+
+export type UserProfileAlias = {
+  id: number,
+  name: string,
+  email: string | null,
+};
+
+export type Settings = { theme: "light" | "dark"; [key: string]: unknown };
+
+export type Status = "active" | "inactive";
@@ -27,6 +27,20 @@ fn find_user(id: u32) -> Option<String> {
     None
 }
 
+#[tauri::command]
+#[doc = "Renames a user."]
+#[allow(clippy::needless_pass_by_value)]
+fn rename_user(id: u32, new_name: String) -> Result<String, String> {
+    Ok(new_name)
+}
+
+#[allow(unused_variables)]
+#[tauri::command]
+#[tracing::instrument(skip(token))]
+fn delete_user(id: u32, token: String) -> bool {
+    true
+}
+
 // This function has NO #[tauri::command] attribute and should NOT be extracted
 fn helper_fn(x: u32) -> u32 {
     x + 1
@@ -14,10 +14,26 @@
 //! - `//- /path` — file separator, extension determines language
 //! - `$0` — cursor position (removed before parsing)
 //! - `//- /bindings.ts [specta]` — routes file through bindings reader
+//! - `//- /src-tauri/capabilities/default.json` — capability file (command permissions)
 //! - `$SCHEMA greet(name: string): string` — inject CommandSchema (bindings generator)
 //! - `$RUST_SCHEMA greet(name: string): string` — inject CommandSchema (RustSource generator)
 //! - `$EVENT_SCHEMA user-updated(UserPayload)` — inject EventSchema
 //! - `$TYPE_ALIAS UserPayload = { id: number; name: string }` — inject type alias
+//! - `$EVENT_NAMING kebab` — set `tarus.events.namingConvention` (before the first file block)
+//! - `$MAX_CANDIDATES 2` — set `tarus.codeActions.maxCandidates` (before the first file block)
+//! - `$COMMAND_DIR src-tauri/src/commands` — set `tarus.commandDir` (before the first file block)
+//! - `$FRONTEND_PATTERNS [{"function": "track", "argIndex": 1, "kind": "emit"}]` — set
+//!   `tarus.frontend.patterns` (before the first file block)
+//! - `$FRAMEWORK_EVENTS ["deep-link://*"]` — set `tarus.events.frameworkEvents` (before the
+//!   first file block)
+//! - `$COMMAND_PREFIX myplugin_` — set `tarus.commandPrefix` (before the first file block)
+//! - `$HOVER_MAX_REFERENCES 2` — set `tarus.hover.maxReferences` (before the first file block)
+//! - `$CODE_LENS_MODE definitionsOnly` / `$CODE_LENS_ENABLED false` — set `tarus.codeLens.mode`
+//!   / `tarus.codeLens.enabled` (before the first file block)
+//! - `$DEVELOPER_MODE true` — set `tarus.developerMode` (before the first file block)
+//! - `$REQUIRE_AWAIT true` — set `tarus.diagnostics.requireAwait` (before the first file block)
+//! - `$EXCLUDE_PLUGIN_COMMANDS true` — set `tarus.completion.excludePluginCommands` (before the
+//!   first file block)
 
 #![allow(dead_code)]
 
@@ -29,11 +45,17 @@ use std::sync::Arc;
 use dashmap::DashMap;
 use expect_test::Expect;
 use lsp_server::capabilities::{
-    code_actions, code_lens, completion, definition, diagnostics, hover, references, symbols,
+    code_actions, code_lens, completion, definition, diagnostics, execute_command, hover,
+    inlay_hints, references, rename, symbols,
 };
-use lsp_server::indexer::{CommandSchema, EventSchema, GeneratorKind, ParamSchema, ProjectIndex};
-use lsp_server::syntax::{Behavior, EntityType};
+use lsp_server::error::Error;
+use lsp_server::indexer::{
+    CodeLensMode, CommandSchema, EventNamingConvention, EventSchema, GeneratorKind, ParamSchema,
+    ProjectIndex,
+};
+use lsp_server::syntax::{Behavior, EntityType, FrontendPatterns};
 use lsp_server::tree_parser;
+use lsp_server::utils::uri_to_path;
 
 use tower_lsp_server::lsp_types::*;
 use tower_lsp_server::UriExt;
@@ -80,6 +102,40 @@ pub fn parse_fixture(input: &str) -> FixtureData {
             event_schemas.push(rest.to_string());
         } else if let Some(rest) = trimmed.strip_prefix("$TYPE_ALIAS ") {
             type_aliases.push(rest.to_string());
+        } else if let Some(rest) = trimmed.strip_prefix("$EVENT_NAMING ") {
+            let convention = EventNamingConvention::from_setting(rest.trim())
+                .unwrap_or_else(|| panic!("Unknown naming convention: {rest}"));
+            index.set_event_naming(convention);
+        } else if let Some(rest) = trimmed.strip_prefix("$MAX_CANDIDATES ") {
+            index.set_code_action_candidates(rest.trim().parse().expect("Invalid $MAX_CANDIDATES"));
+        } else if let Some(rest) = trimmed.strip_prefix("$COMMAND_DIR ") {
+            index.set_command_dir(Some(rest.trim().to_string()));
+        } else if let Some(rest) = trimmed.strip_prefix("$FRONTEND_PATTERNS ") {
+            let setting = serde_json::from_str(rest).expect("Invalid $FRONTEND_PATTERNS");
+            index.set_frontend_patterns(FrontendPatterns::from_setting(&setting));
+        } else if let Some(rest) = trimmed.strip_prefix("$FRAMEWORK_EVENTS ") {
+            let events = serde_json::from_str(rest).expect("Invalid $FRAMEWORK_EVENTS");
+            index.set_framework_events(events);
+        } else if let Some(rest) = trimmed.strip_prefix("$COMMAND_PREFIX ") {
+            index.set_command_prefix(rest.trim().to_string());
+        } else if let Some(rest) = trimmed.strip_prefix("$HOVER_MAX_REFERENCES ") {
+            let max = rest.trim().parse().expect("Invalid $HOVER_MAX_REFERENCES");
+            index.set_hover_max_references(max);
+        } else if let Some(rest) = trimmed.strip_prefix("$CODE_LENS_MODE ") {
+            let mode = CodeLensMode::from_setting(rest.trim()).expect("Invalid $CODE_LENS_MODE");
+            index.set_code_lens_mode(mode);
+        } else if let Some(rest) = trimmed.strip_prefix("$CODE_LENS_ENABLED ") {
+            index.set_code_lens_enabled(rest.trim().parse().expect("Invalid $CODE_LENS_ENABLED"));
+        } else if let Some(rest) = trimmed.strip_prefix("$DEVELOPER_MODE ") {
+            index.set_developer_mode(rest.trim().parse().expect("Invalid $DEVELOPER_MODE"));
+        } else if let Some(rest) = trimmed.strip_prefix("$REQUIRE_AWAIT ") {
+            index.set_require_await(rest.trim().parse().expect("Invalid $REQUIRE_AWAIT"));
+        } else if let Some(rest) = trimmed.strip_prefix("$EXCLUDE_PLUGIN_COMMANDS ") {
+            let enabled = rest
+                .trim()
+                .parse()
+                .expect("Invalid $EXCLUDE_PLUGIN_COMMANDS");
+            index.set_exclude_plugin_commands(enabled);
         }
     }
 
@@ -164,8 +220,11 @@ pub fn parse_fixture(input: &str) -> FixtureData {
         }
 
         // Always parse with tree_parser (even bindings files get normal parsing too)
-        if path.extension().is_some_and(|ext| ext == "rs") {
-            if let Ok(rust_index) = lsp_server::tree_parser::parse_rust_full(&content, &path) {
+        if lsp_server::permissions::is_capability_file(&path) {
+            lsp_server::file_processor::process_file_content(&path, &content, &index);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            if let Ok(rust_index) = lsp_server::tree_parser::parse_rust_full(&content, &path, None)
+            {
                 index.add_file(rust_index.file_index);
                 for schema in rust_index.command_schemas {
                     index.add_schema(schema);
@@ -173,15 +232,29 @@ pub fn parse_fixture(input: &str) -> FixtureData {
                 for schema in rust_index.event_schemas {
                     index.add_event_schema(schema);
                 }
+                for schema in rust_index.struct_schemas {
+                    index.add_struct_schema(schema);
+                }
+                for alias in rust_index.type_aliases {
+                    index.add_rust_type_alias(alias);
+                }
             }
         } else {
-            let parse_result = tree_parser::parse(&path, &content);
+            let parse_result =
+                tree_parser::parse(&path, &content, None, &index.frontend_patterns())
+                    .map(|(file_index, _)| file_index);
             match parse_result {
                 Ok(file_index) => {
                     index.add_file(file_index);
                 }
                 Err(e) => {
-                    index.set_parse_error(path.clone(), format!("{e:?}"));
+                    index.set_parse_error(
+                        &path,
+                        Error::Parse {
+                            path: path.clone(),
+                            source: e,
+                        },
+                    );
                 }
             }
         }
@@ -314,12 +387,14 @@ fn parse_schema_directive(s: &str) -> CommandSchema {
             .split(',')
             .map(|p| {
                 let parts: Vec<&str> = p.trim().splitn(2, ':').collect();
+                let name = parts[0].trim();
                 ParamSchema {
-                    name: parts[0].trim().to_string(),
+                    name: name.trim_end_matches('?').to_string(),
                     ts_type: parts
                         .get(1)
                         .map(|s| s.trim().to_string())
                         .unwrap_or_default(),
+                    optional: name.ends_with('?'),
                 }
             })
             .collect()
@@ -331,6 +406,7 @@ fn parse_schema_directive(s: &str) -> CommandSchema {
         return_type,
         source_path: PathBuf::new(),
         generator: GeneratorKind::Specta,
+        rename_all: None,
     }
 }
 
@@ -408,7 +484,9 @@ pub fn check_parse(fixture: &str, expect: Expect) {
 
     for file_path in &files {
         let content = &data.contents[file_path];
-        let parse_result = tree_parser::parse(file_path, content);
+        let parse_result =
+            tree_parser::parse(file_path, content, None, &data.index.frontend_patterns())
+                .map(|(file_index, _)| file_index);
 
         match parse_result {
             Ok(file_index) => {
@@ -435,6 +513,9 @@ pub fn check_parse(fixture: &str, expect: Expect) {
                     if let Some(count) = f.call_arg_count {
                         write!(line, " args={count}").unwrap();
                     }
+                    if let Some(pt) = &f.payload_type {
+                        write!(line, " payload={pt}").unwrap();
+                    }
                     writeln!(out, "{line}").unwrap();
                 }
             }
@@ -496,9 +577,15 @@ pub fn check_definition(fixture: &str, expect: Expect) {
 
 /// Check Find References results (cursor at $0)
 pub fn check_references(fixture: &str, expect: Expect) {
+    check_references_with_context(fixture, true, expect);
+}
+
+/// Check find-references results with an explicit `context.includeDeclaration`
+pub fn check_references_with_context(fixture: &str, include_declaration: bool, expect: Expect) {
     let data = parse_fixture(fixture);
     let (file, pos) = cursor(&data);
-    let params = make_reference_params(&file, pos);
+    let mut params = make_reference_params(&file, pos);
+    params.context.include_declaration = include_declaration;
     let result = references::handle_references(params, &data.index);
 
     let out = match result {
@@ -519,6 +606,60 @@ pub fn check_references(fixture: &str, expect: Expect) {
     expect.assert_eq(&out);
 }
 
+/// Check prepare-rename and rename of the symbol at $0 to `new_name`
+///
+/// Prints the prepared range and placeholder, then one `path range "new"` line per edit.
+pub fn check_rename(fixture: &str, new_name: &str, expect: Expect) {
+    let data = parse_fixture(fixture);
+    let (file, pos) = cursor(&data);
+    let position_params = TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier {
+            uri: Uri::from_file_path(&file).unwrap(),
+        },
+        position: pos,
+    };
+
+    let mut lines = Vec::new();
+    match rename::handle_prepare_rename(&position_params, &data.index) {
+        Some(PrepareRenameResponse::RangeWithPlaceholder { range, placeholder }) => {
+            lines.push(format!("prepare {} {placeholder:?}", format_range(range)));
+        }
+        Some(other) => lines.push(format!("prepare {other:?}")),
+        None => lines.push("prepare (none)".to_string()),
+    }
+
+    let params = RenameParams {
+        text_document_position: position_params,
+        new_name: new_name.to_string(),
+        work_done_progress_params: Default::default(),
+    };
+    let mut edits = Vec::new();
+    if let Some(DocumentChanges::Edits(doc_edits)) =
+        rename::handle_rename(&params, &data.index).and_then(|edit| edit.document_changes)
+    {
+        for doc_edit in doc_edits {
+            let path = doc_edit.text_document.uri.to_file_path().unwrap();
+            for edit in doc_edit.edits {
+                if let OneOf::Left(text_edit) = edit {
+                    edits.push(format!(
+                        "{} {} {:?}",
+                        short_path(&path),
+                        format_range(text_edit.range),
+                        text_edit.new_text
+                    ));
+                }
+            }
+        }
+    }
+    if edits.is_empty() {
+        lines.push("(no edits)".to_string());
+    }
+    edits.sort();
+    lines.extend(edits);
+
+    expect.assert_eq(&lines.join("\n"));
+}
+
 /// Check CodeLens results ($0 marks the target file)
 pub fn check_code_lens(fixture: &str, expect: Expect) {
     let data = parse_fixture(fixture);
@@ -583,6 +724,53 @@ pub fn check_hover(fixture: &str, expect: Expect) {
     expect.assert_eq(out.trim_end());
 }
 
+/// Check inlay hints over the whole file ($0 marks the target file)
+pub fn check_inlay_hints(fixture: &str, expect: Expect) {
+    check_inlay_hints_in_range(fixture, [0, 0, u32::MAX, 0], expect);
+}
+
+/// Check inlay hints requested for `[start line, start char, end line, end char]`
+pub fn check_inlay_hints_in_range(fixture: &str, range: [u32; 4], expect: Expect) {
+    let data = parse_fixture(fixture);
+    let file = data
+        .cursor_file
+        .as_ref()
+        .expect("fixture must mark a file with $0");
+    let params = InlayHintParams {
+        text_document: TextDocumentIdentifier {
+            uri: Uri::from_file_path(file).unwrap(),
+        },
+        range: Range {
+            start: Position::new(range[0], range[1]),
+            end: Position::new(range[2], range[3]),
+        },
+        work_done_progress_params: Default::default(),
+    };
+    let result = inlay_hints::handle_inlay_hint(&params, &data.index);
+
+    let out = match result {
+        None => "(none)".to_string(),
+        Some(hints) => hints
+            .iter()
+            .map(|hint| {
+                let label = match &hint.label {
+                    InlayHintLabel::String(s) => s.clone(),
+                    InlayHintLabel::LabelParts(parts) => {
+                        parts.iter().map(|p| p.value.as_str()).collect()
+                    }
+                };
+                format!(
+                    "{}:{} \"{}\"",
+                    hint.position.line, hint.position.character, label
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+
+    expect.assert_eq(&out);
+}
+
 /// Check Completion results (cursor at $0)
 pub fn check_completion(fixture: &str, expect: Expect) {
     let data = parse_fixture(fixture);
@@ -610,6 +798,70 @@ pub fn check_completion(fixture: &str, expect: Expect) {
     expect.assert_eq(&out);
 }
 
+/// Check completion items with their label details, one `label detail` per line
+pub fn check_completion_details(fixture: &str, expect: Expect) {
+    let data = parse_fixture(fixture);
+    let (file, pos) = cursor(&data);
+    let params = make_completion_params(&file, pos);
+    let doc_cache = make_document_cache(&data.contents);
+    let result = completion::handle_completion(&params, &data.index, &doc_cache);
+
+    let items = match result {
+        None => Vec::new(),
+        Some(CompletionResponse::Array(items)) => items,
+        Some(CompletionResponse::List(list)) => list.items,
+    };
+    let mut lines: Vec<String> = items
+        .iter()
+        .map(|item| {
+            let detail = item
+                .label_details
+                .as_ref()
+                .and_then(|details| details.detail.as_deref())
+                .unwrap_or_default();
+            format!("{}{detail}", item.label)
+        })
+        .collect();
+    lines.sort();
+
+    expect.assert_eq(&if lines.is_empty() {
+        "(none)".to_string()
+    } else {
+        lines.join("\n")
+    });
+}
+
+/// Check completion in an unsaved `untitled:` buffer whose text (with `$0`) is `buffer`;
+/// the fixture files only provide the indexed commands and events
+pub fn check_untitled_completion(fixture: &str, buffer: &str, expect: Expect) {
+    let data = parse_fixture(fixture);
+    let uri: Uri = "untitled:Untitled-1".parse().unwrap();
+    let pos = find_cursor(buffer).expect("buffer must contain $0 cursor marker");
+
+    let doc_cache = Arc::new(DashMap::new());
+    doc_cache.insert(uri_to_path(&uri), buffer.replace("$0", ""));
+
+    let mut params = make_completion_params(Path::new("/test/unused.ts"), pos);
+    params.text_document_position.text_document.uri = uri;
+    let result = completion::handle_completion(&params, &data.index, &doc_cache);
+
+    let out = match result {
+        None => "(none)".to_string(),
+        Some(CompletionResponse::Array(items)) => {
+            let mut labels: Vec<String> = items.iter().map(|i| i.label.clone()).collect();
+            labels.sort();
+            labels.join("\n")
+        }
+        Some(CompletionResponse::List(list)) => {
+            let mut labels: Vec<String> = list.items.iter().map(|i| i.label.clone()).collect();
+            labels.sort();
+            labels.join("\n")
+        }
+    };
+
+    expect.assert_eq(&out);
+}
+
 /// Check Diagnostics for the file containing $0
 pub fn check_diagnostics(fixture: &str, expect: Expect) {
     let data = parse_fixture(fixture);
@@ -655,14 +907,43 @@ pub fn check_diagnostics(fixture: &str, expect: Expect) {
     expect.assert_eq(&out);
 }
 
+/// Check the pretty-printed JSON result of a server-side `workspace/executeCommand`
+pub fn check_execute_command(fixture: &str, command: &str, expect: Expect) {
+    let result = execute_command(fixture, command, Vec::new());
+
+    let out = match result {
+        None => "(none)".to_string(),
+        // Markdown reports are shown verbatim rather than as an escaped JSON string
+        Some(serde_json::Value::String(text)) => text,
+        Some(value) => serde_json::to_string_pretty(&value).expect("result must serialize"),
+    };
+
+    expect.assert_eq(&out);
+}
+
+/// Run a server-side `workspace/executeCommand` against the fixture's index
+pub fn execute_command(
+    fixture: &str,
+    command: &str,
+    arguments: Vec<serde_json::Value>,
+) -> Option<serde_json::Value> {
+    let data = parse_fixture(fixture);
+    let params = ExecuteCommandParams {
+        command: command.to_string(),
+        arguments,
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+    execute_command::handle_execute_command(&params, &data.index, Some(Path::new("/test")))
+}
+
 /// Check Code Actions at $0
 pub fn check_code_actions(fixture: &str, expect: Expect) {
-    let data = parse_fixture(fixture);
-    let (file, pos) = cursor(&data);
-    let params = make_code_action_params(&file, pos);
-    let result = code_actions::handle_code_action(&params, &data.index, None);
+    expect.assert_eq(&render_code_actions(fixture, None));
+}
 
-    let out = match result {
+/// Render Code Actions at $0 with an optional workspace root (enables Rust stub candidates)
+pub fn render_code_actions(fixture: &str, workspace_root: Option<&PathBuf>) -> String {
+    match code_actions(fixture, workspace_root) {
         None => "(none)".to_string(),
         Some(actions) => {
             let mut lines = Vec::new();
@@ -717,13 +998,28 @@ pub fn check_code_actions(fixture: &str, expect: Expect) {
             }
             lines.join("\n")
         }
-    };
+    }
+}
 
-    expect.assert_eq(&out);
+/// Code Actions at $0 with an optional workspace root
+pub fn code_actions(fixture: &str, workspace_root: Option<&PathBuf>) -> Option<CodeActionResponse> {
+    let data = parse_fixture(fixture);
+    let (file, pos) = cursor(&data);
+    let params = make_code_action_params(&file, pos);
+    code_actions::handle_code_action(&params, &data.index, workspace_root)
 }
 
 /// Check Document Symbols ($0 marks the target file)
 pub fn check_document_symbols(fixture: &str, expect: Expect) {
+    check_document_symbols_with(fixture, false, expect);
+}
+
+/// Check nested Document Symbols ($0 marks the target file); children are indented
+pub fn check_document_symbols_hierarchical(fixture: &str, expect: Expect) {
+    check_document_symbols_with(fixture, true, expect);
+}
+
+fn check_document_symbols_with(fixture: &str, hierarchical: bool, expect: Expect) {
     let data = parse_fixture(fixture);
     let file = data.cursor_file.as_ref().unwrap_or_else(|| {
         data.contents
@@ -732,7 +1028,7 @@ pub fn check_document_symbols(fixture: &str, expect: Expect) {
             .expect("fixture must have files")
     });
     let params = make_document_symbol_params(file);
-    let result = symbols::handle_document_symbol(params, &data.index);
+    let result = symbols::handle_document_symbol(params, &data.index, hierarchical);
 
     let out = match result {
         None => "(none)".to_string(),
@@ -752,11 +1048,26 @@ pub fn check_document_symbols(fixture: &str, expect: Expect) {
             lines.join("\n")
         }
         Some(DocumentSymbolResponse::Nested(syms)) => {
-            let mut lines: Vec<String> = syms
-                .iter()
-                .map(|s| format!("{:?} \"{}\" {}", s.kind, s.name, format_range(s.range),))
-                .collect();
-            lines.sort();
+            fn render(syms: &[DocumentSymbol], depth: usize, lines: &mut Vec<String>) {
+                for s in syms {
+                    let detail = s
+                        .detail
+                        .as_ref()
+                        .map(|d| format!(" : {d}"))
+                        .unwrap_or_default();
+                    lines.push(format!(
+                        "{}{:?} \"{}\" {}{detail}",
+                        "  ".repeat(depth),
+                        s.kind,
+                        s.name,
+                        format_range(s.range),
+                    ));
+                    render(s.children.as_deref().unwrap_or_default(), depth + 1, lines);
+                }
+            }
+
+            let mut lines = Vec::new();
+            render(&syms, 0, &mut lines);
             lines.join("\n")
         }
     };
@@ -768,7 +1079,11 @@ pub fn check_document_symbols(fixture: &str, expect: Expect) {
 pub fn check_workspace_symbols(fixture: &str, query: &str, expect: Expect) {
     let data = parse_fixture(fixture);
     let params = make_workspace_symbol_params(query);
-    let result = symbols::handle_workspace_symbol(&params, &data.index);
+    let result = symbols::handle_workspace_symbol(
+        &params,
+        &data.index,
+        &std::sync::atomic::AtomicBool::new(false),
+    );
 
     let out = match result {
         None => "(none)".to_string(),
@@ -35,7 +35,78 @@ invoke("greet");
             - ⚡ 1 call(s)
 
             **Sample References:**
-            - ⚡️ `[CALL] frontend.ts:2`"#]],
+            - ⚡️ `[CALL] frontend.ts:2` TypeScript"#]],
+    );
+}
+
+#[test]
+fn hover_on_command_call_shows_doc_comment() {
+    helpers::check_hover(
+        r#"
+//- /backend.rs
+/// Greets the user by name.
+///
+/// Falls back to "stranger" when `name` is empty.
+#[tauri::command]
+fn greet(name: String) -> String { name }
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("gre$0et", { name: "Ada" });
+"#,
+        expect![[r####"
+            ### ⚙️ Command: `greet`
+
+            Greets the user by name.
+
+            Falls back to "stranger" when `name` is empty.
+
+            **Arguments:** `{ name: string }`
+
+            **Returns:** `string`
+
+            **Definition:**
+            - 🦀 `backend.rs:5`
+
+            **References (2 total)**
+            - 🦀 1 definition(s)
+            - ⚡ 1 call(s)
+
+            **Sample References:**
+            - ⚡️ `[CALL] frontend.ts:2` TypeScript"####]],
+    );
+}
+
+#[test]
+fn hover_on_command_call_shows_result_error_type() {
+    helpers::check_hover(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn get_user(id: u32) -> Result<User, AppError> { todo!() }
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("get_$0user", { id: 1 });
+"#,
+        expect![[r#"
+            ### ⚙️ Command: `get_user`
+
+            **Arguments:** `{ id: number }`
+
+            **Returns:** `User`
+
+            **Rejects with:** `AppError`
+
+            **Definition:**
+            - 🦀 `backend.rs:2`
+
+            **References (2 total)**
+            - 🦀 1 definition(s)
+            - ⚡ 1 call(s)
+
+            **Sample References:**
+            - ⚡️ `[CALL] frontend.ts:2` TypeScript"#]],
     );
 }
 
@@ -64,7 +135,39 @@ invoke("gre$0et");
             - ⚡ 1 call(s)
 
             **Sample References:**
-            - ⚡️ `[CALL] frontend.ts:2`"#]],
+            - ⚡️ `[CALL] frontend.ts:2` TypeScript"#]],
+    );
+}
+
+#[test]
+fn hover_shows_cfg_feature_of_command() {
+    helpers::check_hover(
+        r#"
+//- /backend.rs
+#[cfg(feature = "desktop")]
+#[tauri::command]
+fn open_$0window() {}
+
+//- /main.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("open_window");
+"#,
+        expect![[r#"
+            ### ⚙️ Command: `open_window`
+
+            **Available under feature:** `desktop`
+
+            **Returns:** `void`
+
+            **Definition:**
+            - 🦀 `backend.rs:3`
+
+            **References (2 total)**
+            - 🦀 1 definition(s)
+            - ⚡ 1 call(s)
+
+            **Sample References:**
+            - ⚡️ `[CALL] main.ts:2` TypeScript"#]],
     );
 }
 
@@ -72,6 +175,123 @@ invoke("gre$0et");
 // Event hover
 // ===========================================================================
 
+#[test]
+fn hover_references_show_source_framework() {
+    helpers::check_hover(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn gre$0et() {}
+
+//- /App.vue
+<script setup lang="ts">
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+</script>
+
+//- /Button.tsx
+import { invoke } from "@tauri-apps/api/core";
+const onClick = () => invoke("greet");
+export const Button = () => <button onClick={onClick} />;
+
+//- /greeter.component.ts
+import { Component } from "@angular/core";
+import { invoke } from "@tauri-apps/api/core";
+@Component({ selector: "app-greeter", template: "" })
+export class GreeterComponent {
+  greet() { invoke("greet"); }
+}
+"#,
+        expect![[r#"
+            ### ⚙️ Command: `greet`
+
+            **Returns:** `void`
+
+            **Definition:**
+            - 🦀 `backend.rs:2`
+
+            **References (4 total)**
+            - 🦀 1 definition(s)
+            - ⚡ 3 call(s)
+
+            **Sample References:**
+            - 💚 `[CALL] App.vue:3` Vue
+            - ⚛️ `[CALL] Button.tsx:2` React
+            - 🅰️ `[CALL] greeter.component.ts:5` Angular"#]],
+    );
+}
+
+#[test]
+fn hover_caps_sample_references() {
+    helpers::check_hover(
+        r#"
+$HOVER_MAX_REFERENCES 2
+//- /backend.rs
+#[tauri::command]
+fn gre$0et() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+invoke("greet");
+invoke("greet");
+"#,
+        expect![[r#"
+            ### ⚙️ Command: `greet`
+
+            **Returns:** `void`
+
+            **Definition:**
+            - 🦀 `backend.rs:2`
+
+            **References (4 total)**
+            - 🦀 1 definition(s)
+            - ⚡ 3 call(s)
+
+            **Sample References:**
+            - ⚡️ `[CALL] frontend.ts:2` TypeScript
+            - ⚡️ `[CALL] frontend.ts:3` TypeScript
+            - *...and 1 more*"#]],
+    );
+}
+
+#[test]
+fn hover_caps_event_listeners() {
+    helpers::check_hover(
+        r#"
+$HOVER_MAX_REFERENCES 2
+//- /backend.rs
+use tauri::{AppHandle, Manager};
+
+fn notify(app: &AppHandle) {
+    app.emit("user-notificatio$0n", "Hello").unwrap();
+}
+
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("user-notification", (e) => console.log(e));
+listen("user-notification", (e) => console.warn(e));
+listen("user-notification", (e) => console.error(e));
+"#,
+        expect![[r#"
+            ### 📡 Event: `user-notification`
+
+            **Definition:**
+            - ⚡️ `frontend.ts:2`
+            - ⚡️ `frontend.ts:3`
+            - *...and 1 more*
+
+            **Go to listener:** [frontend.ts:2](file:///test/frontend.ts#L2), [frontend.ts:3](file:///test/frontend.ts#L3), [frontend.ts:4](file:///test/frontend.ts#L4)
+
+            **References (4 total)**
+            - 📤 1 emit(s)
+            - 👂 3 listener(s)
+
+            **Sample References:**
+            - 🦀 `[EMIT] backend.rs:4` Rust"#]],
+    );
+}
+
 #[test]
 fn hover_on_event_emit() {
     helpers::check_hover(
@@ -93,12 +313,74 @@ listen("user-notification", (e) => console.log(e));
             **Definition:**
             - ⚡️ `frontend.ts:2`
 
+            **Go to listener:** [frontend.ts:2](file:///test/frontend.ts#L2)
+
             **References (2 total)**
             - 📤 1 emit(s)
             - 👂 1 listener(s)
 
             **Sample References:**
-            - 🦀 `[EMIT] backend.rs:4`"#]],
+            - 🦀 `[EMIT] backend.rs:4` Rust"#]],
+    );
+}
+
+#[test]
+fn hover_on_event_listen_links_emitters() {
+    helpers::check_hover(
+        r#"
+//- /backend.rs
+use tauri::{AppHandle, Manager};
+
+fn notify(app: &AppHandle) {
+    app.emit("user-notification", "Hello").unwrap();
+}
+
+fn remind(app: &AppHandle) {
+    app.emit("user-notification", "Again").unwrap();
+}
+
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("user-notif$0ication", (e) => console.log(e));
+"#,
+        expect![[r#"
+            ### 📡 Event: `user-notification`
+
+            **Definition:**
+            - ⚡️ `frontend.ts:2`
+
+            **Go to emitter:** [backend.rs:4](file:///test/backend.rs#L4), [backend.rs:8](file:///test/backend.rs#L8)
+
+            **References (3 total)**
+            - 📤 2 emit(s)
+            - 👂 1 listener(s)
+
+            **Sample References:**
+            - 🦀 `[EMIT] backend.rs:4` Rust
+            - 🦀 `[EMIT] backend.rs:8` Rust"#]],
+    );
+}
+
+#[test]
+fn hover_on_event_listen_without_emitter_shows_plain_text() {
+    helpers::check_hover(
+        r#"
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("user-notif$0ication", (e) => console.log(e));
+"#,
+        expect![[r#"
+            ### 📡 Event: `user-notification`
+
+            **Definition:**
+            - ⚡️ `frontend.ts:2`
+
+            **Go to emitter:** _none found_
+
+            **References (1 total)**
+            - 👂 1 listener(s)
+
+            💡 *Event listened for but never emitted*"#]],
     );
 }
 
@@ -121,7 +403,7 @@ invoke("gre$0et");
             - ⚡ 1 call(s)
 
             **Sample References:**
-            - ⚡️ `[CALL] frontend.ts:2`
+            - ⚡️ `[CALL] frontend.ts:2` TypeScript
 
             ⚠️ *No backend implementation found*"#]],
     );
@@ -145,6 +427,40 @@ fn gre$0et() {}
 
             **References (1 total)**
             - 🦀 1 definition(s)
+            - ⚡ 0 call(s)
+
+            💡 *Defined but never called in frontend*"#]],
+    );
+}
+
+#[test]
+fn hover_unused_command_shows_signature() {
+    helpers::check_hover(
+        r#"
+//- /backend.rs
+/// Saves the note of a user.
+#[tauri::command]
+fn save_$0note(state: State<'_, Db>, user_id: u32, note: Option<String>) -> Result<bool, String> {
+    todo!()
+}
+"#,
+        expect![[r#"
+            ### ⚙️ Command: `save_note`
+
+            Saves the note of a user.
+
+            **Arguments:** `{ userId: number, note?: string | null }`
+
+            **Returns:** `boolean`
+
+            **Rejects with:** `string`
+
+            **Definition:**
+            - 🦀 `backend.rs:3`
+
+            **References (1 total)**
+            - 🦀 1 definition(s)
+            - ⚡ 0 call(s)
 
             💡 *Defined but never called in frontend*"#]],
     );
@@ -1,14 +1,17 @@
 //! Indexer functionality tests
 
+mod common_alloc;
 mod common_paths;
 
+use common_alloc::count_allocations;
 use common_paths::test_path;
 use lsp_server::indexer::{
-    CommandSchema, EventSchema, FileIndex, Finding, GeneratorKind, IndexKey, ParamSchema,
-    ProjectIndex,
+    CodeLensMode, CommandSchema, EventSchema, FileChange, FileIndex, Finding, GeneratorKind,
+    IndexDiff, IndexKey, LocationInfo, ParamSchema, ProjectIndex,
 };
-use lsp_server::syntax::{Behavior, EntityType};
-use tower_lsp_server::lsp_types::{Position, Range};
+use lsp_server::syntax::{Behavior, EntityType, FrontendPatterns};
+use tower_lsp_server::lsp_types::{Position, Range, Uri};
+use tower_lsp_server::UriExt;
 
 fn create_test_finding(key: &str, entity: EntityType, behavior: Behavior) -> Finding {
     Finding {
@@ -31,6 +34,16 @@ fn create_test_finding(key: &str, entity: EntityType, behavior: Behavior) -> Fin
         call_name_end: None,
         type_arg_range: None,
         codegen_origin: None,
+        payload_type: None,
+        call_arg_variable: None,
+        statement_start: None,
+        listen_toggle: None,
+        wrapper_name: None,
+        doc: None,
+        test_only: false,
+        cfg: None,
+        error_type: None,
+        unawaited: false,
     }
 }
 
@@ -46,6 +59,7 @@ fn test_add_file_to_index() {
             EntityType::Command,
             Behavior::Definition,
         )],
+        ..Default::default()
     };
 
     index.add_file(file_index);
@@ -67,6 +81,7 @@ fn test_remove_file_from_index() {
             EntityType::Command,
             Behavior::Definition,
         )],
+        ..Default::default()
     };
 
     index.add_file(file_index);
@@ -97,6 +112,7 @@ fn test_get_locations() {
             EntityType::Command,
             Behavior::Definition,
         )],
+        ..Default::default()
     };
 
     // Add frontend command call
@@ -107,6 +123,7 @@ fn test_get_locations() {
             EntityType::Command,
             Behavior::Call,
         )],
+        ..Default::default()
     };
 
     index.add_file(backend_file);
@@ -122,6 +139,125 @@ fn test_get_locations() {
     assert!(has_call);
 }
 
+#[test]
+fn test_get_definition() {
+    let index = ProjectIndex::new();
+    let backend = test_path("backend.rs");
+    let frontend = test_path("frontend.ts");
+
+    index.add_file(FileIndex {
+        path: backend.clone(),
+        findings: vec![
+            create_test_finding("greet", EntityType::Command, Behavior::Definition),
+            create_test_finding("saved", EntityType::Event, Behavior::Emit),
+        ],
+        ..Default::default()
+    });
+    index.add_file(FileIndex {
+        path: frontend.clone(),
+        findings: vec![
+            create_test_finding("greet", EntityType::Command, Behavior::Call),
+            create_test_finding("ping", EntityType::Command, Behavior::Call),
+            create_test_finding("saved", EntityType::Event, Behavior::Listen),
+        ],
+        ..Default::default()
+    });
+
+    let command = index.get_definition(EntityType::Command, "greet").unwrap();
+    assert_eq!(command.behavior, Behavior::Definition);
+    assert_eq!(command.path, backend);
+
+    // Events have no Rust definition: the listener stands in for it
+    let event = index.get_definition(EntityType::Event, "saved").unwrap();
+    assert_eq!(event.behavior, Behavior::Listen);
+    assert_eq!(event.path, frontend);
+
+    assert!(index.get_definition(EntityType::Command, "ping").is_none());
+    assert!(index.get_definition(EntityType::Event, "missing").is_none());
+}
+
+#[test]
+fn test_commands_summary() {
+    let index = ProjectIndex::new();
+    let backend = test_path("backend.rs");
+    let frontend = test_path("frontend.ts");
+
+    index.add_file(FileIndex {
+        path: backend.clone(),
+        findings: vec![
+            create_test_finding("get_user", EntityType::Command, Behavior::Definition),
+            create_test_finding("ping", EntityType::Command, Behavior::Definition),
+        ],
+        ..Default::default()
+    });
+    index.add_file(FileIndex {
+        path: frontend.clone(),
+        findings: vec![
+            create_test_finding("get_user", EntityType::Command, Behavior::Call),
+            create_test_finding("get_user", EntityType::Command, Behavior::SpectaCall),
+            create_test_finding("missing", EntityType::Command, Behavior::Call),
+            create_test_finding("saved", EntityType::Event, Behavior::Emit),
+        ],
+        ..Default::default()
+    });
+    index.add_schema(CommandSchema {
+        command_name: "get_user".to_string(),
+        params: vec![ParamSchema {
+            name: "user_id".to_string(),
+            ts_type: "number".to_string(),
+            optional: false,
+        }],
+        return_type: "User".to_string(),
+        source_path: backend.clone(),
+        generator: GeneratorKind::RustSource,
+        rename_all: None,
+    });
+    index.add_schema(CommandSchema {
+        command_name: "ping".to_string(),
+        params: vec![],
+        return_type: "void".to_string(),
+        source_path: backend.clone(),
+        generator: GeneratorKind::RustSource,
+        rename_all: None,
+    });
+
+    let summary: Vec<_> = index
+        .commands_summary()
+        .into_iter()
+        .map(|s| {
+            let params: Vec<_> = s.params.iter().map(|p| p.name.clone()).collect();
+            (
+                s.name,
+                params,
+                s.return_type,
+                s.definition_path,
+                s.call_count,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "get_user".to_string(),
+                vec!["userId".to_string()],
+                Some("User".to_string()),
+                Some(backend.clone()),
+                2
+            ),
+            ("missing".to_string(), vec![], None, None, 1),
+            (
+                "ping".to_string(),
+                vec![],
+                Some("void".to_string()),
+                Some(backend),
+                0
+            ),
+        ]
+    );
+}
+
 #[test]
 fn test_get_key_at_position() {
     let index = ProjectIndex::new();
@@ -147,11 +283,22 @@ fn test_get_key_at_position() {
         call_name_end: None,
         type_arg_range: None,
         codegen_origin: None,
+        payload_type: None,
+        call_arg_variable: None,
+        statement_start: None,
+        listen_toggle: None,
+        wrapper_name: None,
+        doc: None,
+        test_only: false,
+        cfg: None,
+        error_type: None,
+        unawaited: false,
     };
 
     let file_index = FileIndex {
         path: path.clone(),
         findings: vec![finding],
+        ..Default::default()
     };
 
     index.add_file(file_index);
@@ -183,6 +330,7 @@ fn test_get_diagnostic_info() {
             EntityType::Command,
             Behavior::Definition,
         )],
+        ..Default::default()
     };
 
     // Add a command call
@@ -193,6 +341,7 @@ fn test_get_diagnostic_info() {
             EntityType::Command,
             Behavior::Call,
         )],
+        ..Default::default()
     };
 
     index.add_file(backend_file);
@@ -220,6 +369,7 @@ fn test_multiple_files_same_command() {
             EntityType::Command,
             Behavior::Definition,
         )],
+        ..Default::default()
     });
 
     // Add multiple calls from different files
@@ -230,6 +380,7 @@ fn test_multiple_files_same_command() {
             EntityType::Command,
             Behavior::Call,
         )],
+        ..Default::default()
     });
 
     index.add_file(FileIndex {
@@ -239,22 +390,113 @@ fn test_multiple_files_same_command() {
             EntityType::Command,
             Behavior::Call,
         )],
+        ..Default::default()
     });
 
     let locations = index.get_locations(EntityType::Command, "get_user");
     assert_eq!(locations.len(), 3, "Should find definition + 2 calls");
 }
 
+#[test]
+fn test_get_locations_iter_borrows_large_index() {
+    let index = ProjectIndex::new();
+
+    index.add_file(FileIndex {
+        path: test_path("backend.rs"),
+        findings: vec![create_test_finding(
+            "save",
+            EntityType::Command,
+            Behavior::Definition,
+        )],
+        ..Default::default()
+    });
+
+    // Thousands of call sites spread over many frontend files
+    for file in 0..500 {
+        index.add_file(FileIndex {
+            path: test_path(&format!("views/view{file}.ts")),
+            findings: (0..10)
+                .map(|_| create_test_finding("save", EntityType::Command, Behavior::Call))
+                .collect(),
+            ..Default::default()
+        });
+    }
+
+    let view = index.get_locations_iter(EntityType::Command, "save");
+    assert_eq!(view.len(), 5001);
+    assert_eq!(
+        view.iter().filter(|l| l.behavior == Behavior::Call).count(),
+        5000
+    );
+
+    // Other keys stay readable while the view is held
+    assert!(index
+        .get_locations_iter(EntityType::Event, "missing")
+        .is_empty());
+
+    let owned = index.get_locations(EntityType::Command, "save");
+    assert!(view
+        .iter()
+        .zip(&owned)
+        .all(|(a, b)| a.path == b.path && a.behavior == b.behavior));
+}
+
+#[test]
+fn test_files_referencing_distinct_sorted() {
+    let index = ProjectIndex::new();
+
+    index.add_file(FileIndex {
+        path: test_path("src/lib.rs"),
+        findings: vec![create_test_finding(
+            "get_user",
+            EntityType::Command,
+            Behavior::Definition,
+        )],
+        ..Default::default()
+    });
+    index.add_file(FileIndex {
+        path: test_path("ui/profile.ts"),
+        findings: vec![
+            create_test_finding("get_user", EntityType::Command, Behavior::Call),
+            create_test_finding("get_user", EntityType::Command, Behavior::Call),
+        ],
+        ..Default::default()
+    });
+    index.add_file(FileIndex {
+        path: test_path("ui/app.ts"),
+        findings: vec![create_test_finding(
+            "get_user",
+            EntityType::Command,
+            Behavior::Call,
+        )],
+        ..Default::default()
+    });
+
+    assert_eq!(
+        index.files_referencing(EntityType::Command, "get_user"),
+        vec![
+            test_path("src/lib.rs"),
+            test_path("ui/app.ts"),
+            test_path("ui/profile.ts"),
+        ]
+    );
+    assert!(index
+        .files_referencing(EntityType::Command, "missing")
+        .is_empty());
+}
+
 fn make_schema(command_name: &str, path: &str, generator: GeneratorKind) -> CommandSchema {
     CommandSchema {
         command_name: command_name.to_string(),
         params: vec![ParamSchema {
             name: "id".to_string(),
             ts_type: "number".to_string(),
+            optional: false,
         }],
         return_type: "string".to_string(),
         source_path: test_path(path),
         generator,
+        rename_all: None,
     }
 }
 
@@ -308,6 +550,7 @@ fn test_schema_survives_file_map_remove() {
             EntityType::Command,
             Behavior::Definition,
         )],
+        ..Default::default()
     });
 
     // Add a schema from bindings
@@ -343,15 +586,18 @@ fn test_overwrite_schema() {
             ParamSchema {
                 name: "id".to_string(),
                 ts_type: "number".to_string(),
+                optional: false,
             },
             ParamSchema {
                 name: "token".to_string(),
                 ts_type: "string".to_string(),
+                optional: false,
             },
         ],
         return_type: "User".to_string(),
         source_path: test_path("bindings.ts"),
         generator: GeneratorKind::Specta,
+        rename_all: None,
     };
     index.add_schema(schema2);
 
@@ -373,6 +619,7 @@ fn test_stale_rust_command_schema_cleared_on_reparse() {
             EntityType::Command,
             Behavior::Definition,
         )],
+        ..Default::default()
     };
     index.add_file(file_index);
     index.add_schema(CommandSchema {
@@ -381,6 +628,7 @@ fn test_stale_rust_command_schema_cleared_on_reparse() {
         return_type: "void".to_string(),
         source_path: path.clone(),
         generator: GeneratorKind::RustSource,
+        rename_all: None,
     });
 
     assert!(index.get_schema("old_cmd").is_some());
@@ -390,6 +638,7 @@ fn test_stale_rust_command_schema_cleared_on_reparse() {
     let file_index = FileIndex {
         path: path.clone(),
         findings: vec![],
+        ..Default::default()
     };
     index.add_file(file_index);
 
@@ -412,6 +661,7 @@ fn test_stale_rust_event_schema_cleared_on_reparse() {
             EntityType::Event,
             Behavior::Emit,
         )],
+        ..Default::default()
     };
     index.add_file(file_index);
     index.add_event_schema(EventSchema {
@@ -428,6 +678,7 @@ fn test_stale_rust_event_schema_cleared_on_reparse() {
     let file_index = FileIndex {
         path: path.clone(),
         findings: vec![],
+        ..Default::default()
     };
     index.add_file(file_index);
 
@@ -436,3 +687,593 @@ fn test_stale_rust_event_schema_cleared_on_reparse() {
         "Stale event schema should be cleared on reparse"
     );
 }
+
+#[test]
+fn test_clear_drops_files_and_schemas() {
+    let index = ProjectIndex::new();
+    index.add_file(FileIndex {
+        path: test_path("lib.rs"),
+        findings: vec![create_test_finding(
+            "greet",
+            EntityType::Command,
+            Behavior::Definition,
+        )],
+        ..Default::default()
+    });
+    index.add_schema(make_schema("greet", "bindings.ts", GeneratorKind::Specta));
+
+    index.clear();
+
+    assert!(index.get_locations(EntityType::Command, "greet").is_empty());
+    assert!(index.get_schema("greet").is_none());
+    assert!(index.get_indexed_paths().is_empty());
+}
+
+#[test]
+fn test_event_naming_convention_suggest() {
+    use lsp_server::indexer::EventNamingConvention;
+
+    assert_eq!(EventNamingConvention::Off.suggest("userUpdated"), None);
+    assert_eq!(EventNamingConvention::Kebab.suggest("user-updated"), None);
+    assert_eq!(
+        EventNamingConvention::Kebab.suggest("app:userUpdated"),
+        Some("app:user-updated".to_string()),
+        "Namespace separators are preserved"
+    );
+    assert_eq!(
+        EventNamingConvention::Snake.suggest("file-savedOK"),
+        Some("file_saved_ok".to_string())
+    );
+    assert_eq!(
+        EventNamingConvention::Camel.suggest("download_progress"),
+        Some("downloadProgress".to_string())
+    );
+}
+
+#[test]
+fn test_imported_wrapper_calls_follow_wrapper_file() {
+    let index = ProjectIndex::new();
+    let api_path = test_path("api.ts");
+    let component_path = test_path("Component.tsx");
+    let add = |path: &std::path::Path, content: &str| {
+        let (file_index, _) =
+            lsp_server::tree_parser::parse(path, content, None, &FrontendPatterns::default())
+                .unwrap();
+        index.add_file(file_index);
+    };
+    let call_paths = || {
+        index
+            .get_locations(EntityType::Command, "get_user")
+            .into_iter()
+            .map(|l| l.path)
+            .collect::<Vec<_>>()
+    };
+
+    // The call site is indexed before the wrapper it calls
+    add(
+        &component_path,
+        "import { getUser } from \"./api\";\ngetUser();\n",
+    );
+    assert!(call_paths().is_empty());
+
+    add(
+        &api_path,
+        "import { invoke } from \"@tauri-apps/api/core\";\nexport const getUser = () => invoke(\"get_user\");\n",
+    );
+    assert_eq!(call_paths(), [api_path.clone(), component_path.clone()]);
+
+    index.remove_file(&api_path);
+    assert!(
+        call_paths().is_empty(),
+        "Removing the wrapper file drops calls attributed through it"
+    );
+}
+
+#[test]
+fn test_imported_wrapper_calls_resolve_their_module() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::write(
+        root.join("tsconfig.json"),
+        r#"{ "compilerOptions": { "paths": { "@/*": ["./src/*"] } } }"#,
+    )
+    .unwrap();
+
+    let index = ProjectIndex::new();
+    index.set_path_aliases(lsp_server::scanner::PathAliasResolver::from_workspace(root));
+    let add = |path: &str, content: &str| {
+        let (file_index, _) = lsp_server::tree_parser::parse(
+            &root.join(path),
+            content,
+            None,
+            &FrontendPatterns::default(),
+        )
+        .unwrap();
+        index.add_file(file_index);
+    };
+    let calls = |command: &str| {
+        index
+            .wrapper_call_locations(command)
+            .into_iter()
+            .map(|l| l.path.strip_prefix(root).unwrap().display().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    add(
+        "src/users/api.ts",
+        "import { invoke } from \"@tauri-apps/api/core\";\nexport const load = () => invoke(\"get_user\");\n",
+    );
+    add(
+        "src/posts/api.ts",
+        "import { invoke } from \"@tauri-apps/api/core\";\nconst load = () => invoke(\"get_post\");\nconst hidden = () => invoke(\"get_secret\");\nexport { load };\n",
+    );
+    add(
+        "src/UserView.tsx",
+        "import { load } from \"./users/api\";\nimport { hidden } from \"./posts/api\";\nload();\nhidden();\n",
+    );
+    add(
+        "src/posts/PostView.tsx",
+        "import * as posts from \"@/posts/api\";\nposts.load();\n",
+    );
+
+    assert_eq!(
+        calls("get_user"),
+        ["src/UserView.tsx"],
+        "Same-named wrappers are told apart by the module they are imported from"
+    );
+    assert_eq!(calls("get_post"), ["src/posts/PostView.tsx"]);
+    assert!(
+        calls("get_secret").is_empty(),
+        "Wrappers the module does not export are not importable"
+    );
+
+    index.set_path_aliases(None);
+    assert!(
+        calls("get_post").is_empty(),
+        "Aliased imports need the aliases"
+    );
+    index.set_path_aliases(lsp_server::scanner::PathAliasResolver::from_workspace(root));
+    assert_eq!(calls("get_post"), ["src/posts/PostView.tsx"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_symlinked_file_is_indexed_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let real = dir.path().join("real.ts");
+    let link = dir.path().join("link.ts");
+    std::fs::write(
+        &real,
+        "import { invoke } from \"@tauri-apps/api/core\";\ninvoke(\"get_user\");\n",
+    )
+    .unwrap();
+    std::os::unix::fs::symlink(&real, &link).unwrap();
+
+    let index = ProjectIndex::new();
+    // Scanned under the real path, then opened through the symlink
+    lsp_server::file_processor::process_file_index(&real, &index).unwrap();
+    lsp_server::file_processor::process_file_index(&link, &index).unwrap();
+
+    let locations = index.get_locations(EntityType::Command, "get_user");
+    assert_eq!(locations.len(), 1);
+    assert_eq!(index.get_indexed_paths().len(), 1);
+
+    let uri = Uri::from_file_path(&link).unwrap();
+    let path = lsp_server::utils::uri_to_path(&uri);
+    assert_eq!(index.get_file_keys(&path).len(), 1);
+    let real_path = index.index_path(&link);
+    assert_eq!(
+        index.original_path(&real_path),
+        link,
+        "Results are published under the path the file was opened with"
+    );
+
+    // The link no longer resolves once deleted; removal goes through the recorded path
+    std::fs::remove_file(&link).unwrap();
+    index.remove_file(&link);
+    assert!(index.get_indexed_paths().is_empty());
+    assert_eq!(index.original_path(&real_path), real_path);
+}
+
+#[test]
+fn test_locations_iter_borrows_call_sites() {
+    const CALL_SITES: u32 = 5_000;
+
+    let index = ProjectIndex::new();
+    index.add_file(FileIndex {
+        path: test_path("ui/app.ts"),
+        findings: (0..CALL_SITES)
+            .map(|line| Finding {
+                range: Range {
+                    start: Position { line, character: 0 },
+                    end: Position { line, character: 9 },
+                },
+                ..create_test_finding("save_file", EntityType::Command, Behavior::Call)
+            })
+            .collect(),
+        ..Default::default()
+    });
+
+    let (cloned, copying) = count_allocations(|| {
+        index
+            .get_locations(EntityType::Command, "save_file")
+            .iter()
+            .filter(|l| l.behavior == Behavior::Call)
+            .count()
+    });
+    let (borrowed, borrowing) = count_allocations(|| {
+        index
+            .get_locations_iter(EntityType::Command, "save_file")
+            .iter()
+            .filter(|l| l.behavior == Behavior::Call)
+            .count()
+    });
+
+    assert_eq!(cloned, CALL_SITES as usize);
+    assert_eq!(borrowed, CALL_SITES as usize);
+    assert!(
+        copying.bytes >= CALL_SITES as usize * std::mem::size_of::<LocationInfo>(),
+        "{copying:?}"
+    );
+    // Only the lookup key is allocated, however many call sites there are
+    assert!(borrowing.count <= 2, "{borrowing:?}");
+    assert!(borrowing.bytes < 1024, "{borrowing:?}");
+}
+
+#[test]
+fn test_lens_data_many_occurrences() {
+    const COMMANDS: u32 = 40;
+    const CALLS_PER_COMMAND: u32 = 5;
+
+    let index = ProjectIndex::new();
+    let at_line = |key: &str, behavior: Behavior, line: u32| Finding {
+        range: Range {
+            start: Position { line, character: 0 },
+            end: Position { line, character: 4 },
+        },
+        ..create_test_finding(key, EntityType::Command, behavior)
+    };
+    let names: Vec<String> = (0..COMMANDS).map(|i| format!("command_{i}")).collect();
+
+    index.add_file(FileIndex {
+        path: test_path("src/lib.rs"),
+        findings: (0..COMMANDS)
+            .map(|i| at_line(&names[i as usize], Behavior::Definition, i))
+            .collect(),
+        ..Default::default()
+    });
+    index.add_file(FileIndex {
+        path: test_path("ui/other.ts"),
+        findings: (0..COMMANDS)
+            .map(|i| at_line(&names[i as usize], Behavior::Call, i))
+            .collect(),
+        ..Default::default()
+    });
+    let app = test_path("ui/app.ts");
+    index.add_file(FileIndex {
+        path: app.clone(),
+        findings: (0..COMMANDS * CALLS_PER_COMMAND)
+            .map(|line| at_line(&names[(line % COMMANDS) as usize], Behavior::Call, line))
+            .collect(),
+        ..Default::default()
+    });
+
+    // Each call gets a lens for the definition file and one for the other caller
+    let (lenses, allocations) = count_allocations(|| index.get_lens_data(&app));
+    assert_eq!(lenses.len(), (COMMANDS * CALLS_PER_COMMAND * 2) as usize);
+    // A lens allocates its title, its target list and its one target's path; everything
+    // else is built once per key, not per occurrence
+    assert!(
+        allocations.count <= 3 * lenses.len() + 16 * COMMANDS as usize,
+        "{allocations:?} for {} lenses",
+        lenses.len()
+    );
+    for (range, title, targets) in &lenses {
+        let name = &names[(range.start.line % COMMANDS) as usize];
+        assert_eq!(targets.len(), 1, "{title} at {range:?}");
+        assert!(title == "Go to lib.rs" || title == "Go to other.ts");
+        assert!(targets[0].path != app);
+        let target_key = index.get_key_at_position(&targets[0].path, targets[0].range.start);
+        assert_eq!(target_key.map(|(key, _)| key.name).as_ref(), Some(name));
+    }
+
+    // Calls are no definitions, so nothing is built or cloned in definitions-only mode
+    index.set_code_lens_mode(CodeLensMode::DefinitionsOnly);
+    let (lenses, allocations) = count_allocations(|| index.get_lens_data(&app));
+    assert!(lenses.is_empty());
+    assert!(
+        allocations.count <= 2 * COMMANDS as usize,
+        "{allocations:?}"
+    );
+}
+
+#[test]
+fn test_document_symbols_cached_until_file_changes() {
+    let index = ProjectIndex::new();
+    let path = test_path("src/lib.rs");
+    let file = |findings: Vec<Finding>| FileIndex {
+        path: path.clone(),
+        findings,
+        ..Default::default()
+    };
+    let names = |index: &ProjectIndex| -> Vec<String> {
+        index
+            .get_document_symbols(&path)
+            .into_iter()
+            .map(|s| s.name)
+            .collect()
+    };
+
+    index.add_file(file(vec![create_test_finding(
+        "greet",
+        EntityType::Command,
+        Behavior::Definition,
+    )]));
+    let fresh = index.get_document_symbols(&path);
+    assert_eq!(index.get_document_symbols(&path), fresh);
+    assert_eq!(names(&index), vec!["greet (command)"]);
+
+    index.add_file(file(vec![create_test_finding(
+        "saved",
+        EntityType::Event,
+        Behavior::Emit,
+    )]));
+    assert_eq!(names(&index), vec!["saved (emit)"]);
+
+    index.remove_file(&path);
+    assert!(index.get_document_symbols(&path).is_empty());
+}
+
+#[test]
+fn test_apply_batch_matches_sequential_changes() {
+    let file = |name: &str, findings: &[(&str, EntityType, Behavior)]| FileIndex {
+        path: test_path(name),
+        findings: findings
+            .iter()
+            .map(|(key, entity, behavior)| create_test_finding(key, *entity, *behavior))
+            .collect(),
+        ..Default::default()
+    };
+    let initial = || {
+        let index = ProjectIndex::new();
+        index.add_file(file(
+            "src/lib.rs",
+            &[("greet", EntityType::Command, Behavior::Definition)],
+        ));
+        index.add_file(file(
+            "ui/old.ts",
+            &[("greet", EntityType::Command, Behavior::Call)],
+        ));
+        // Warm the name caches so a missed invalidation would show up
+        let _ = index.get_all_names(EntityType::Command);
+        index
+    };
+    let changes = || {
+        vec![
+            FileChange::Remove(test_path("ui/old.ts")),
+            FileChange::Upsert(file(
+                "ui/new.ts",
+                &[
+                    ("greet", EntityType::Command, Behavior::Call),
+                    ("saved", EntityType::Event, Behavior::Listen),
+                ],
+            )),
+            FileChange::Upsert(file(
+                "src/lib.rs",
+                &[("farewell", EntityType::Command, Behavior::Definition)],
+            )),
+            FileChange::Remove(test_path("ui/missing.ts")),
+        ]
+    };
+    let snapshot = |index: &ProjectIndex| {
+        let mut paths = index.get_indexed_paths();
+        paths.sort();
+        let mut names: Vec<_> = [EntityType::Command, EntityType::Event]
+            .into_iter()
+            .flat_map(|entity| {
+                index
+                    .get_all_names(entity)
+                    .iter()
+                    .map(|(name, _)| {
+                        (
+                            name.clone(),
+                            index
+                                .get_locations(entity, name)
+                                .into_iter()
+                                .map(|l| (l.path, l.behavior))
+                                .collect::<Vec<_>>(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        names.sort_by(|a, b| a.0.cmp(&b.0));
+        (paths, names)
+    };
+
+    let sequential = initial();
+    for change in changes() {
+        match change {
+            FileChange::Upsert(file_index) => sequential.add_file(file_index),
+            FileChange::Remove(path) => sequential.remove_file(&path),
+        }
+    }
+
+    let batched = initial();
+    batched.apply_batch(changes());
+
+    assert_eq!(snapshot(&batched), snapshot(&sequential));
+    assert_eq!(
+        snapshot(&batched).0,
+        [test_path("src/lib.rs"), test_path("ui/new.ts")]
+    );
+}
+
+#[test]
+fn test_gc_prunes_parse_errors_of_deleted_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let broken = dir.path().join("broken.rs");
+    let deleted = dir.path().join("deleted.rs");
+    std::fs::write(&broken, "fn (").unwrap();
+
+    let index = ProjectIndex::new();
+    let error = |path: &std::path::Path| {
+        lsp_server::error::Error::io(path, std::io::Error::other("unreadable"))
+    };
+    index.set_parse_error(&broken, error(&broken));
+    index.set_parse_error(&deleted, error(&deleted));
+
+    assert_eq!(index.gc(), 1);
+    assert!(index.get_parse_error(&deleted).is_none());
+    assert!(
+        index.get_parse_error(&broken).is_some(),
+        "Errors of files still on disk are kept"
+    );
+    assert_eq!(index.gc(), 0);
+}
+
+#[test]
+fn test_diff_reports_added_removed_and_changed_keys() {
+    let file = |name: &str, findings: Vec<Finding>| FileIndex {
+        path: test_path(name),
+        findings,
+        ..Default::default()
+    };
+    let key = |entity, name: &str| IndexKey {
+        entity,
+        name: name.to_string(),
+    };
+
+    let old = ProjectIndex::new();
+    old.add_file(file(
+        "main.rs",
+        vec![
+            create_test_finding("greet", EntityType::Command, Behavior::Definition),
+            create_test_finding("saved", EntityType::Event, Behavior::Emit),
+            create_test_finding("stable", EntityType::Command, Behavior::Definition),
+        ],
+    ));
+
+    let new = ProjectIndex::new();
+    let mut moved = create_test_finding("greet", EntityType::Command, Behavior::Definition);
+    moved.range.start.line = 4;
+    moved.range.end.line = 4;
+    new.add_file(file(
+        "main.rs",
+        vec![
+            moved,
+            create_test_finding("get_user", EntityType::Command, Behavior::Definition),
+            create_test_finding("stable", EntityType::Command, Behavior::Definition),
+        ],
+    ));
+
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff,
+        IndexDiff {
+            added: vec![key(EntityType::Command, "get_user")],
+            removed: vec![key(EntityType::Event, "saved")],
+            changed: vec![key(EntityType::Command, "greet")],
+        }
+    );
+    assert_eq!(
+        diff.to_string(),
+        "+ Command get_user\n- Event saved\n~ Command greet\n"
+    );
+
+    assert!(new.diff(&new).is_empty());
+    assert_eq!(new.diff(&old).added, diff.removed);
+}
+
+#[test]
+fn test_file_snapshot_holds_only_that_file() {
+    let index = ProjectIndex::new();
+    index.add_file(FileIndex {
+        path: test_path("main.rs"),
+        findings: vec![create_test_finding(
+            "greet",
+            EntityType::Command,
+            Behavior::Definition,
+        )],
+        ..Default::default()
+    });
+    index.add_file(FileIndex {
+        path: test_path("app.ts"),
+        findings: vec![
+            create_test_finding("greet", EntityType::Command, Behavior::Call),
+            create_test_finding("saved", EntityType::Event, Behavior::Listen),
+        ],
+        ..Default::default()
+    });
+
+    let snapshot = index.file_snapshot(&test_path("main.rs"));
+    assert_eq!(snapshot.get_indexed_paths(), vec![test_path("main.rs")]);
+    assert_eq!(
+        snapshot.get_locations(EntityType::Command, "greet").len(),
+        1
+    );
+    assert!(snapshot
+        .get_locations(EntityType::Event, "saved")
+        .is_empty());
+}
+
+#[test]
+fn test_technical_report_summarizes_index() {
+    let index = ProjectIndex::new();
+    let finding = create_test_finding;
+    index.add_file(FileIndex {
+        path: test_path("main.rs"),
+        findings: vec![
+            finding("greet", EntityType::Command, Behavior::Definition),
+            finding("save", EntityType::Command, Behavior::Definition),
+            finding("ready", EntityType::Event, Behavior::Emit),
+            finding("progress", EntityType::Event, Behavior::Emit),
+        ],
+        ..Default::default()
+    });
+    index.add_file(FileIndex {
+        path: test_path("app.ts"),
+        findings: vec![
+            finding("greet", EntityType::Command, Behavior::Call),
+            finding("greet", EntityType::Command, Behavior::Call),
+            finding("missing", EntityType::Command, Behavior::Call),
+            finding("ready", EntityType::Event, Behavior::Listen),
+            finding("tick", EntityType::Event, Behavior::Listen),
+        ],
+        ..Default::default()
+    });
+    let broken = test_path("broken.ts");
+    index.set_parse_error(
+        &broken,
+        lsp_server::error::Error::io(&broken, std::io::Error::other("unreadable")),
+    );
+
+    let report = index.technical_report();
+
+    assert!(report.contains("Files: 2 indexed, 1 failed"), "{report}");
+    assert!(
+        report.contains("Commands: 3 (2 definitions, 3 references)"),
+        "{report}"
+    );
+    assert!(
+        report.contains("  most referenced: greet (2), missing (1)"),
+        "{report}"
+    );
+    assert!(
+        report.contains("Events: 3 (0 definitions, 4 references)"),
+        "{report}"
+    );
+    assert!(
+        report.contains("  most referenced: ready (2), progress (1), tick (1)"),
+        "{report}"
+    );
+    assert!(
+        report.contains(
+            "Orphans: 1 commands never invoked, 1 commands not defined, \
+             1 events never emitted, 1 events never listened for"
+        ),
+        "{report}"
+    );
+    assert!(report.contains("Failed files:\n  - "), "{report}");
+    assert!(!report.contains("LocationInfo"), "{report}");
+}
@@ -0,0 +1,71 @@
+//! Inlay hint tests
+//!
+//! Tests event payload type hints shown after the event name at frontend emit sites.
+
+mod helpers;
+
+use expect_test::expect;
+
+#[test]
+fn inlay_hint_emit_payload_from_schema() {
+    helpers::check_inlay_hints(
+        r#"
+$EVENT_SCHEMA progress(ProgressPayload)
+//- /frontend.ts
+import { emit } from "@tauri-apps/api/event";
+emit("progress", { done: 1, total: 3 });
+$0
+"#,
+        expect![[r#"1:15 ": ProgressPayload""#]],
+    );
+}
+
+#[test]
+fn inlay_hint_emit_payload_from_listener_generic() {
+    helpers::check_inlay_hints(
+        r#"
+//- /listener.ts
+import { listen } from "@tauri-apps/api/event";
+listen<number>("progress", (e) => console.log(e.payload));
+
+//- /emitter.ts
+import { emit } from "@tauri-apps/api/event";
+emit("progress", 42);
+$0
+"#,
+        expect![[r#"1:15 ": number""#]],
+    );
+}
+
+#[test]
+fn inlay_hint_skips_typed_and_unknown_emits() {
+    helpers::check_inlay_hints(
+        r#"
+$EVENT_SCHEMA progress(ProgressPayload)
+$EVENT_SCHEMA closed
+//- /frontend.ts
+import { emit } from "@tauri-apps/api/event";
+emit<ProgressPayload>("progress", { done: 1, total: 3 });
+emit("closed");
+emit("unknown", 1);
+$0
+"#,
+        expect!["(none)"],
+    );
+}
+
+#[test]
+fn inlay_hint_honors_requested_range() {
+    helpers::check_inlay_hints_in_range(
+        r#"
+$EVENT_SCHEMA progress(ProgressPayload)
+//- /frontend.ts
+import { emit } from "@tauri-apps/api/event";
+emit("progress", { done: 1, total: 3 });
+emit("progress", { done: 2, total: 3 });
+$0
+"#,
+        [2, 0, 2, 40],
+        expect![[r#"2:15 ": ProgressPayload""#]],
+    );
+}
@@ -0,0 +1,275 @@
+//! End-to-end test client: drives the real `Backend` over an in-memory duplex pipe
+//!
+//! Messages are framed JSON-RPC exactly as on stdio, so these tests cover the handler
+//! wiring (URI conversion, capability registration, notifications) that the pure
+//! capability tests skip.
+//!
+//! ```ignore
+//! let project = TestProject::new(&[("src-tauri/tauri.conf.json", "{}"), ...]);
+//! let mut client = TestClient::start();
+//! client.initialize(project.root()).await;
+//! let result = client.request("textDocument/definition", params).await;
+//! ```
+
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, DuplexStream};
+use tokio::io::{ReadHalf, WriteHalf};
+use tower_lsp_server::lsp_types::Uri;
+use tower_lsp_server::{Server, UriExt};
+
+/// How long to wait for any single server message before failing the test
+const RECV_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Log line the server sends once the initial workspace scan is indexed
+const INDEXING_COMPLETE: &str = "Indexing complete";
+
+/// A Tauri project written to a temporary directory
+pub struct TestProject {
+    dir: tempfile::TempDir,
+}
+
+impl TestProject {
+    /// Write `(relative path, content)` files; include a `tauri.conf.json` for the server to
+    /// treat the directory as a Tauri project
+    pub fn new(files: &[(&str, &str)]) -> Self {
+        let dir = tempfile::tempdir().expect("create temp project");
+        for (relative, content) in files {
+            let path = dir.path().join(relative);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, content).unwrap();
+        }
+        Self { dir }
+    }
+
+    /// Canonical root, so paths match the ones the server derives from URIs
+    pub fn root(&self) -> PathBuf {
+        self.dir.path().canonicalize().unwrap()
+    }
+
+    pub fn path(&self, relative: &str) -> PathBuf {
+        self.root().join(relative)
+    }
+
+    pub fn uri(&self, relative: &str) -> String {
+        file_uri(&self.path(relative))
+    }
+}
+
+/// `file://` URI of `path` as the server renders it
+pub fn file_uri(path: &Path) -> String {
+    Uri::from_file_path(path).unwrap().to_string()
+}
+
+/// LSP client connected to a `Backend` running on the test runtime
+pub struct TestClient {
+    reader: BufReader<ReadHalf<DuplexStream>>,
+    writer: WriteHalf<DuplexStream>,
+    next_id: i64,
+    /// `window/logMessage` texts received so far
+    pub log_messages: Vec<String>,
+    /// `textDocument/publishDiagnostics` params received so far
+    pub published_diagnostics: Vec<Value>,
+    /// `tarus/status` params received so far
+    pub status_notifications: Vec<Value>,
+    /// `workspace/configuration` answers by section (`"tarus.indexing.mode"`); others are unset
+    pub settings: serde_json::Map<String, Value>,
+}
+
+impl TestClient {
+    /// Spawn the server on the current runtime and connect to it
+    pub fn start() -> Self {
+        let (client_io, server_io) = tokio::io::duplex(64 * 1024);
+        let (server_read, server_write) = tokio::io::split(server_io);
+        let (service, socket) = lsp_server::backend::build_service();
+        tokio::spawn(Server::new(server_read, server_write, socket).serve(service));
+
+        let (reader, writer) = tokio::io::split(client_io);
+        Self {
+            reader: BufReader::new(reader),
+            writer,
+            next_id: 1,
+            log_messages: Vec::new(),
+            published_diagnostics: Vec::new(),
+            status_notifications: Vec::new(),
+            settings: serde_json::Map::new(),
+        }
+    }
+
+    /// `initialize` + `initialized` with `root` as the workspace folder, then wait for the
+    /// initial indexing to finish. Returns the `initialize` result.
+    pub async fn initialize(&mut self, root: &Path) -> Value {
+        let uri = file_uri(root);
+        let result = self
+            .request(
+                "initialize",
+                json!({
+                    "processId": null,
+                    "rootUri": uri,
+                    "capabilities": {},
+                    "workspaceFolders": [{ "uri": uri, "name": "test" }],
+                }),
+            )
+            .await;
+        self.notify("initialized", json!({})).await;
+        self.wait_for_log(INDEXING_COMPLETE).await;
+        result
+    }
+
+    /// Open `path` with `text` as its buffer content
+    pub async fn did_open(&mut self, path: &Path, language_id: &str, text: &str) {
+        self.notify(
+            "textDocument/didOpen",
+            json!({
+                "textDocument": {
+                    "uri": file_uri(path),
+                    "languageId": language_id,
+                    "version": 1,
+                    "text": text,
+                }
+            }),
+        )
+        .await;
+    }
+
+    /// Send a request and return its `result`, answering server requests meanwhile.
+    ///
+    /// Panics on an error response.
+    pub async fn request(&mut self, method: &str, params: Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.send(&json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }))
+            .await;
+
+        loop {
+            let message = self.recv().await;
+            if message.get("method").is_none() && message["id"] == id {
+                if let Some(error) = message.get("error") {
+                    panic!("{method} failed: {error}");
+                }
+                return message["result"].clone();
+            }
+            self.handle_server_message(message).await;
+        }
+    }
+
+    pub async fn notify(&mut self, method: &str, params: Value) {
+        self.send(&json!({ "jsonrpc": "2.0", "method": method, "params": params }))
+            .await;
+    }
+
+    /// Process server messages until a `window/logMessage` containing `needle` arrives
+    pub async fn wait_for_log(&mut self, needle: &str) {
+        while !self.log_messages.iter().any(|m| m.contains(needle)) {
+            let message = self.recv().await;
+            self.handle_server_message(message).await;
+        }
+    }
+
+    /// Process server messages until diagnostics were published for every URI in `uris`
+    pub async fn wait_for_diagnostics(&mut self, uris: &[String]) {
+        while !uris.iter().all(|uri| {
+            self.published_diagnostics
+                .iter()
+                .any(|params| params["uri"] == uri.as_str())
+        }) {
+            let message = self.recv().await;
+            self.handle_server_message(message).await;
+        }
+    }
+
+    /// Process server messages until a `tarus/status` notification satisfying `matches` arrives
+    pub async fn wait_for_status(&mut self, matches: impl Fn(&Value) -> bool) {
+        while !self.status_notifications.iter().any(&matches) {
+            let message = self.recv().await;
+            self.handle_server_message(message).await;
+        }
+    }
+
+    /// Diagnostics params published for `uri` so far, oldest first
+    pub fn diagnostics_for(&self, uri: &str) -> Vec<&Value> {
+        self.published_diagnostics
+            .iter()
+            .filter(|params| params["uri"] == uri)
+            .collect()
+    }
+
+    /// Record notifications and answer server-to-client requests: `workspace/configuration`
+    /// items come from `settings`, other requests get a `null` result
+    async fn handle_server_message(&mut self, message: Value) {
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            return;
+        };
+
+        match method {
+            "window/logMessage" => {
+                let text = message["params"]["message"].as_str().unwrap_or_default();
+                self.log_messages.push(text.to_string());
+            }
+            "textDocument/publishDiagnostics" => {
+                self.published_diagnostics.push(message["params"].clone());
+            }
+            "tarus/status" => {
+                self.status_notifications.push(message["params"].clone());
+            }
+            _ => {}
+        }
+
+        if let Some(id) = message.get("id") {
+            let result = if method == "workspace/configuration" {
+                let items = message["params"]["items"].as_array().cloned();
+                items
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|item| {
+                        let section = item["section"].as_str().unwrap_or_default();
+                        self.settings.get(section).cloned().unwrap_or(Value::Null)
+                    })
+                    .collect()
+            } else {
+                Value::Null
+            };
+            self.send(&json!({ "jsonrpc": "2.0", "id": id, "result": result }))
+                .await;
+        }
+    }
+
+    async fn send(&mut self, message: &Value) {
+        let body = message.to_string();
+        let frame = format!("Content-Length: {}\r\n\r\n{body}", body.len());
+        self.writer.write_all(frame.as_bytes()).await.unwrap();
+        self.writer.flush().await.unwrap();
+    }
+
+    /// Read one framed message from the server
+    async fn recv(&mut self) -> Value {
+        tokio::time::timeout(RECV_TIMEOUT, self.read_frame())
+            .await
+            .expect("timed out waiting for a server message")
+    }
+
+    async fn read_frame(&mut self) -> Value {
+        let mut content_length = None;
+        loop {
+            let mut line = String::new();
+            let read = self.reader.read_line(&mut line).await.unwrap();
+            assert!(read > 0, "server closed the connection");
+
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some(value) = line.strip_prefix("Content-Length:") {
+                content_length = Some(value.trim().parse::<usize>().unwrap());
+            }
+        }
+
+        let mut body = vec![0; content_length.expect("missing Content-Length header")];
+        self.reader.read_exact(&mut body).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+}
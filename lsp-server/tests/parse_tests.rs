@@ -6,6 +6,7 @@
 mod helpers;
 
 use expect_test::expect;
+use lsp_server::syntax::FrontendPatterns;
 use std::path::Path;
 
 // ===========================================================================
@@ -80,12 +81,24 @@ pub fn multiple_extra_attrs(x: i32) -> i32 {
 fn simple_command() -> String {
     "simple".to_string()
 }
+
+#[tauri::command]
+#[doc = "Documented through an attribute."]
+#[allow(dead_code)]
+fn doc_attr_command() {}
+
+#[cfg(debug_assertions)]
+#[tauri::command]
+#[tracing::instrument]
+fn command_between_attrs() {}
 "#,
         expect![[r#"
             /multi.rs:
               Command Definition "single_extra_attr" 2:3..2:20
               Command Definition "multiple_extra_attrs" 9:7..9:27
-              Command Definition "simple_command" 14:3..14:17"#]],
+              Command Definition "simple_command" 14:3..14:17
+              Command Definition "doc_attr_command" 21:3..21:19
+              Command Definition "command_between_attrs" 26:3..26:24"#]],
     );
 }
 
@@ -116,6 +129,108 @@ fn handle_event(app: &AppHandle) {
     );
 }
 
+#[test]
+fn parse_ranges_count_utf16_columns_after_multibyte_text() {
+    // Columns are UTF-16 code units: `é`, `ï` and `😀` are wider in bytes
+    helpers::check_parse(
+        r#"
+//- /events.rs
+fn notify(app: &AppHandle) {
+    let greeting = "héllo 😀"; app.emit("saved", ());
+}
+
+//- /main.ts
+import { invoke } from "@tauri-apps/api/core";
+const café = "naïve 😀"; invoke("greet");
+"#,
+        expect![[r#"
+            /events.rs:
+              Event Emit "saved" 1:41..1:46
+            /main.ts:
+              Command Call "greet" 1:33..1:38"#]],
+    );
+}
+
+#[test]
+fn parse_rust_specta_typed_events() {
+    helpers::check_parse(
+        r#"
+//- /events.rs
+#[derive(Clone, Serialize, Type, tauri_specta::Event)]
+pub struct UserUpdated {
+    id: u32,
+}
+
+#[derive(Clone, Serialize, Type, Event)]
+pub struct AppReady;
+
+fn notify(app: &AppHandle) {
+    UserUpdated { id: 1 }.emit(&app).unwrap();
+    events::UserUpdated { id: 2 }.emit_to(&app, "main").unwrap();
+    AppReady.emit(&app).unwrap();
+    APP_HANDLE.emit("plain-event", ()).unwrap();
+}
+"#,
+        expect![[r#"
+            /events.rs:
+              Event Definition "user-updated" 1:11..1:22
+              Event Definition "app-ready" 6:11..6:19
+              Event Emit "user-updated" 9:4..9:15
+              Event Emit "user-updated" 10:12..10:23
+              Event Emit "app-ready" 11:4..11:12
+              Event Emit "plain-event" 12:21..12:32"#]],
+    );
+}
+
+#[test]
+fn parse_rust_v1_event_methods() {
+    helpers::check_parse(
+        r#"
+//- /legacy.rs
+fn notify(app: &AppHandle, window: &Window) {
+    app.emit_all("sync-done", 1).unwrap();
+    window.emit_to("main", "focus-changed", true).unwrap();
+    app.trigger_global("reload", None);
+    app.listen_global("sync-done", |event| {});
+    app.emit_all(format!("dynamic"), 1).unwrap();
+}
+"#,
+        expect![[r#"
+            /legacy.rs:
+              Event Emit "sync-done" 1:18..1:27
+              Event Emit "focus-changed" 2:28..2:41
+              Event Emit "reload" 3:24..3:30
+              Event Listen "sync-done" 4:23..4:32"#]],
+    );
+}
+
+#[test]
+fn parse_rust_listen_payload_type() {
+    helpers::check_parse(
+        r#"
+//- /events.rs
+fn setup(app: &AppHandle) {
+    app.listen("user-updated", |event| {
+        let user = event.payload::<UserProfile>();
+    });
+
+    app.once("progress", |event| {
+        let value = serde_json::from_str::<Vec<u32>>(event.payload()).unwrap();
+    });
+
+    app.listen("untyped", |event| {
+        println!("{:?}", event.payload());
+    });
+}
+"#,
+        expect![[r#"
+            /events.rs:
+              Event Listen "user-updated" 1:16..1:28 payload=UserProfile
+              Event Listen "progress" 5:14..5:22 payload=number[]
+              Event Listen "untyped" 9:16..9:23"#]],
+    );
+}
+
 // ===========================================================================
 // TypeScript
 // ===========================================================================
@@ -139,8 +254,8 @@ async function fetchData() {
 "#,
         expect![[r#"
             /app.ts:
-              Command Call "greet" 3:33..3:38
-              Command Call "get_user" 8:31..8:39"#]],
+              Command Call "greet" 3:33..3:38 params=[name]
+              Command Call "get_user" 8:31..8:39 params=[id]"#]],
     );
 }
 
@@ -195,7 +310,7 @@ async function test() {
 "#,
         expect![[r#"
             /app.ts:
-              Command Call "greet" 4:18..4:23
+              Command Call "greet" 4:18..4:23 params=[name]
               Event Emit "status-changed" 5:10..5:24
               Event Listen "user-notification" 6:12..6:29
               Event Listen "single-event" 7:10..7:22
@@ -203,6 +318,28 @@ async function test() {
     );
 }
 
+#[test]
+fn parse_ts_colliding_aliases_last_import_wins() {
+    helpers::check_parse(
+        r#"
+//- /core_last.ts
+import { listen as invoke } from "@tauri-apps/api/event";
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+
+//- /event_last.ts
+import { invoke } from "@tauri-apps/api/core";
+import { listen as invoke } from "@tauri-apps/api/event";
+invoke("greet");
+"#,
+        expect![[r#"
+            /core_last.ts:
+              Command Call "greet" 2:8..2:13
+            /event_last.ts:
+              Event Listen "greet" 2:8..2:13"#]],
+    );
+}
+
 #[test]
 fn parse_ts_generic_invoke() {
     helpers::check_parse(
@@ -226,8 +363,8 @@ async function saveData<T>(data: T): Promise<void> {
 "#,
         expect![[r#"
             /generic.tsx:
-              Command Call "get_user" 8:37..8:45 return_type=User
-              Command Call "save_data" 13:24..13:33 return_type=void"#]],
+              Command Call "get_user" 8:37..8:45 params=[id] return_type=User
+              Command Call "save_data" 13:24..13:33 params=[data] return_type=void"#]],
     );
 }
 
@@ -255,6 +392,27 @@ function setupListener() {
     );
 }
 
+#[test]
+fn parse_ts_template_literal_names() {
+    // Only template literals without substitutions name an event
+    helpers::check_parse(
+        r#"
+//- /events.ts
+import { emit, emitTo, listen } from "@tauri-apps/api/event";
+
+emit(`file-saved`);
+listen(`file-saved`, () => {});
+emit(`file-${id}`);
+emitTo("main", `window-ready`);
+"#,
+        expect![[r#"
+            /events.ts:
+              Event Emit "file-saved" 2:6..2:16
+              Event Listen "file-saved" 3:8..3:18
+              Event Emit "window-ready" 5:16..5:28"#]],
+    );
+}
+
 #[test]
 fn parse_ts_specta_calls() {
     helpers::check_parse(
@@ -274,7 +432,7 @@ const result = await invoke('get_user', { id: 1 });
               Command SpectaCall "get_user_profile" 2:28..2:42 args=1
               Command SpectaCall "create_user" 3:26..3:36 args=3
               Command SpectaCall "ping" 4:15..4:19 args=0
-              Command Call "get_user" 7:29..7:37"#]],
+              Command Call "get_user" 7:29..7:37 params=[id]"#]],
     );
 }
 
@@ -328,8 +486,8 @@ function processData() {
 "#,
         expect![[r#"
             /app.js:
-              Command Call "greet" 3:12..3:17
-              Command Call "process_item" 9:12..9:24"#]],
+              Command Call "greet" 3:12..3:17 params=[name]
+              Command Call "process_item" 9:12..9:24 params=[item]"#]],
     );
 }
 
@@ -373,6 +531,107 @@ events.myCustomEvent(appWindow).listen((e) => console.log(e));
     );
 }
 
+#[test]
+fn parse_custom_frontend_patterns() {
+    helpers::check_parse(
+        r#"
+$FRONTEND_PATTERNS [{"function": "trackEvent", "argIndex": 2, "kind": "emit"}, {"function": "call", "argIndex": 1, "kind": "invoke"}]
+//- /analytics.ts
+import { emit } from "@tauri-apps/api/event";
+
+trackEvent(analytics, "ignored", "page-viewed");
+await call(client, "get_user", { id: 1 });
+trackEvent(analytics, "ignored", dynamicName);
+emit("tauri-event");
+"#,
+        expect![[r#"
+            /analytics.ts:
+              Event Emit "page-viewed" 2:34..2:45
+              Command Call "get_user" 3:20..3:28
+              Event Emit "tauri-event" 5:6..5:17"#]],
+    );
+}
+
+#[test]
+fn parse_object_form_invoke() {
+    helpers::check_parse(
+        r#"
+$FRONTEND_PATTERNS [{"function": "track", "property": "event", "kind": "emit"}]
+//- /legacy.ts
+import { invoke as call } from "@tauri-apps/api/core";
+import { emit } from "@tauri-apps/api/event";
+
+await call({ cmd: "get_user", args: { id: 1 } });
+await call({ "command": "save_user" });
+await call({ cmd: dynamicName });
+emit({ cmd: "not_an_event" });
+track({ event: "page-viewed", page });
+"#,
+        expect![[r#"
+            /legacy.ts:
+              Command Call "get_user" 3:19..3:27
+              Command Call "save_user" 4:25..4:34
+              Event Emit "page-viewed" 7:16..7:27"#]],
+    );
+}
+
+#[test]
+fn parse_invoke_with_local_string_binding() {
+    helpers::check_parse(
+        r#"
+//- /bound.ts
+import { invoke } from "@tauri-apps/api/core";
+import { emit, listen } from "@tauri-apps/api/event";
+
+async function load() {
+    const cmd = "get_user";
+    await invoke(cmd);
+    await invoke(cmd, { id: 1 });
+}
+
+async function save() {
+    let cmd = "save_user";
+    cmd = "save_admin";
+    await invoke(cmd);
+}
+
+const event = "user-saved";
+emit(event);
+
+function subscribe() {
+    listen(event, () => {});
+    const inner = "inner-event";
+    const handler = () => listen(inner, () => {});
+    let stable = "stable-event";
+    listen(stable, () => {});
+}
+"#,
+        expect![[r#"
+            /bound.ts:
+              Command Call "get_user" 4:17..4:25
+              Event Emit "user-saved" 15:15..15:25
+              Event Listen "stable-event" 22:18..22:30"#]],
+    );
+}
+
+#[test]
+fn frontend_patterns_skip_invalid_entries() {
+    let setting = serde_json::json!([
+        { "function": "track", "argIndex": 1, "kind": "emit" },
+        { "function": "noKind", "argIndex": 0 },
+        { "argIndex": 0, "kind": "invoke" },
+        { "function": "badKind", "kind": "subscribe" },
+    ]);
+    let patterns = FrontendPatterns::from_setting(&setting);
+
+    let custom: Vec<_> = patterns
+        .custom()
+        .map(|p| (p.function.as_str(), p.arg_index))
+        .collect();
+    assert_eq!(custom, vec![("track", 1)]);
+    assert!(patterns.tauri_api("emitTo", 1).is_some());
+}
+
 // ===========================================================================
 // Vue (uses fixture files — SFC needs <script> tags)
 // ===========================================================================
@@ -384,7 +643,10 @@ fn parse_vue_single_script() {
     )
     .unwrap();
     let path = std::path::PathBuf::from("/test/component.vue");
-    let result = lsp_server::tree_parser::parse(&path, &content).unwrap();
+    let result =
+        lsp_server::tree_parser::parse(&path, &content, None, &FrontendPatterns::default())
+            .unwrap()
+            .0;
 
     let mut out = String::new();
     let mut findings = result.findings;
@@ -425,13 +687,86 @@ fn parse_vue_multiple_scripts() {
     )
     .unwrap();
     let path = std::path::PathBuf::from("/test/multi.vue");
-    let result = lsp_server::tree_parser::parse(&path, &content).unwrap();
+    let result =
+        lsp_server::tree_parser::parse(&path, &content, None, &FrontendPatterns::default())
+            .unwrap()
+            .0;
     assert!(
         !result.findings.is_empty(),
         "Expected findings in Vue multi-script"
     );
 }
 
+#[test]
+fn parse_vue_setup_and_options_scripts() {
+    let content = std::fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/vue/setup_and_options.vue"),
+    )
+    .unwrap();
+    let path = std::path::PathBuf::from("/test/Settings.vue");
+    let result =
+        lsp_server::tree_parser::parse(&path, &content, None, &FrontendPatterns::default())
+            .unwrap()
+            .0;
+
+    // Each block is parsed on its own; positions are absolute within the .vue file
+    let mut ranges: Vec<_> = result
+        .findings
+        .iter()
+        .map(|f| {
+            (
+                f.key.as_str(),
+                f.range.start.line,
+                f.range.start.character,
+                f.range.end.character,
+            )
+        })
+        .collect();
+    ranges.sort_by_key(|&(_, line, character, _)| (line, character));
+    assert_eq!(
+        ranges,
+        vec![
+            ("load_settings", 10, 18, 31),
+            ("save_settings", 18, 27, 40),
+            ("reset_settings", 19, 28, 42),
+        ]
+    );
+    assert_eq!(
+        result.syntax_error, None,
+        "`>` in the generic attribute does not end the opening tag"
+    );
+}
+
+#[test]
+fn parse_vue_crlf_line_endings() {
+    let content = std::fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/crlf/Settings.vue"),
+    )
+    .unwrap();
+    assert!(
+        content.contains("\r\n"),
+        "Fixture must keep CRLF line endings"
+    );
+
+    let path = std::path::PathBuf::from("/test/Settings.vue");
+    let result =
+        lsp_server::tree_parser::parse(&path, &content, None, &FrontendPatterns::default())
+            .unwrap()
+            .0;
+
+    assert_eq!(result.findings.len(), 1);
+    let range = result.findings[0].range;
+    assert_eq!(
+        (
+            range.start.line,
+            range.start.character,
+            range.end.line,
+            range.end.character
+        ),
+        (8, 16, 8, 29)
+    );
+}
+
 // ===========================================================================
 // Svelte (fixture file — SFC)
 // ===========================================================================
@@ -443,13 +778,94 @@ fn parse_svelte_component() {
     )
     .unwrap();
     let path = std::path::PathBuf::from("/test/component.svelte");
-    let result = lsp_server::tree_parser::parse(&path, &content).unwrap();
+    let result =
+        lsp_server::tree_parser::parse(&path, &content, None, &FrontendPatterns::default())
+            .unwrap()
+            .0;
     assert!(
         !result.findings.is_empty(),
         "Expected findings in Svelte component"
     );
 }
 
+// ===========================================================================
+// Astro (fixture files)
+// ===========================================================================
+
+#[test]
+fn parse_astro_script_tag() {
+    let content = std::fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/astro/page.astro"),
+    )
+    .unwrap();
+    let path = std::path::PathBuf::from("/test/page.astro");
+    let index = lsp_server::indexer::ProjectIndex::new();
+
+    // Parsed with the TypeScript grammar, no text scan needed
+    assert!(lsp_server::file_processor::process_file_content(
+        &path, &content, &index
+    ));
+
+    let locations = index.get_locations(lsp_server::syntax::EntityType::Command, "greet");
+    assert_eq!(locations.len(), 1);
+    assert_eq!(locations[0].behavior, lsp_server::syntax::Behavior::Call);
+
+    let range = locations[0].range;
+    assert_eq!(
+        (
+            range.start.line,
+            range.start.character,
+            range.end.line,
+            range.end.character
+        ),
+        (13, 42, 13, 47)
+    );
+}
+
+#[test]
+fn parse_astro_frontmatter() {
+    let content = std::fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/astro/frontmatter.astro"),
+    )
+    .unwrap();
+    let path = std::path::PathBuf::from("/test/frontmatter.astro");
+    let result =
+        lsp_server::tree_parser::parse(&path, &content, None, &FrontendPatterns::default())
+            .unwrap()
+            .0;
+
+    let ranges: Vec<_> = result
+        .findings
+        .iter()
+        .map(|f| (f.key.as_str(), f.range.start.line, f.range.start.character))
+        .collect();
+    assert_eq!(ranges, vec![("load_settings", 4, 39)]);
+}
+
+#[test]
+fn parse_astro_without_frontmatter() {
+    helpers::check_parse(
+        r#"
+//- /plain.astro
+<h1>No frontmatter</h1>
+<script>
+  import { emit } from "@tauri-apps/api/event";
+  emit("page-loaded");
+</script>
+
+//- /unclosed.astro
+---
+import { invoke } from "@tauri-apps/api/core";
+invoke("greet");
+
+//- /empty.astro
+"#,
+        expect![[r#"
+            /plain.astro:
+              Event Emit "page-loaded" 3:8..3:19"#]],
+    );
+}
+
 // ===========================================================================
 // Angular
 // ===========================================================================
@@ -476,7 +892,182 @@ export class UserComponent {
 "#,
         expect![[r#"
             /user.component.ts:
-              Command Call "get_user" 10:31..10:39
+              Command Call "get_user" 10:31..10:39 params=[id]
               Event Emit "user-loaded" 11:10..11:21"#]],
     );
 }
+
+#[test]
+fn parse_angular_inline_template_and_class_invoke() {
+    // The template text mentions invoke(...) but is a string, not a call
+    helpers::check_parse(
+        r#"
+//- /settings.component.ts
+import { Component } from '@angular/core';
+import { invoke } from "@tauri-apps/api/core";
+
+@Component({
+  selector: 'app-settings',
+  template: `
+    <h1>{{ title }}</h1>
+    <button (click)="save()">invoke("not_a_command")</button>
+    <p *ngIf="saved">Saved via invoke('save_settings')</p>
+  `,
+  styles: [`button { margin: 0 }`]
+})
+export class SettingsComponent {
+  title = 'Settings';
+  saved = false;
+
+  async save() {
+    await invoke("save_settings", { theme: 'dark' });
+    this.saved = true;
+  }
+}
+
+@Component({ selector: 'app-about', templateUrl: './about.component.html' })
+export class AboutComponent {
+  version = invoke<string>("app_version");
+}
+"#,
+        expect![[r#"
+            /settings.component.ts:
+              Command Call "save_settings" 17:18..17:31 params=[theme]
+              Command Call "app_version" 24:28..24:39 return_type=string"#]],
+    );
+}
+
+// ===========================================================================
+// Incremental re-parse (didChange with ranged edits)
+// ===========================================================================
+
+/// Apply ranged edits (`[start line, start char, end line, end char]`) one by one,
+/// re-parsing incrementally after each, and check the findings match a full parse
+/// of the same text. Returns the final text.
+fn check_incremental_matches_full(
+    path: &str,
+    original: &str,
+    edits: &[([u32; 4], &str)],
+) -> String {
+    use lsp_server::file_processor::apply_content_change;
+    use tower_lsp_server::lsp_types::{Position, Range, TextDocumentContentChangeEvent};
+
+    let path = std::path::PathBuf::from(path);
+    let render = |index: &lsp_server::indexer::FileIndex| format!("{:?}", index.findings);
+
+    let mut text = original.to_string();
+    let (_, mut tree) =
+        lsp_server::tree_parser::parse(&path, &text, None, &FrontendPatterns::default()).unwrap();
+    assert!(
+        tree.is_some(),
+        "Single-tree languages return their syntax tree"
+    );
+
+    for &([start_line, start_char, end_line, end_char], new_text) in edits {
+        let change = TextDocumentContentChangeEvent {
+            range: Some(Range {
+                start: Position::new(start_line, start_char),
+                end: Position::new(end_line, end_char),
+            }),
+            range_length: None,
+            text: new_text.to_string(),
+        };
+        apply_content_change(&mut text, &mut tree, &change);
+
+        let (incremental, new_tree) = lsp_server::tree_parser::parse(
+            &path,
+            &text,
+            tree.as_ref(),
+            &FrontendPatterns::default(),
+        )
+        .unwrap();
+        let (full, _) =
+            lsp_server::tree_parser::parse(&path, &text, None, &FrontendPatterns::default())
+                .unwrap();
+        assert_eq!(
+            render(&incremental),
+            render(&full),
+            "After edit {new_text:?}:\n{text}"
+        );
+        tree = new_tree;
+    }
+
+    text
+}
+
+#[test]
+fn parse_incremental_typescript_matches_full_parse() {
+    let text = check_incremental_matches_full(
+        "/test/app.ts",
+        "import { invoke } from \"@tauri-apps/api/core\";\n// héllo → wörld\nawait invoke(\"greet\", { name });\n",
+        &[
+            // Rename the command (after a line with multi-byte characters)
+            ([2, 14, 2, 19], "say_hello"),
+            // Insert a new call on a new line
+            ([3, 0, 3, 0], "invoke(\"ping\");\n"),
+            // Delete across lines, leaving only the import and the new call
+            ([1, 0, 3, 0], ""),
+            // Type inside the string of the remaining call, with a 🦀 (surrogate pair)
+            ([1, 8, 1, 12], "p🦀ng"),
+        ],
+    );
+    assert_eq!(
+        text,
+        "import { invoke } from \"@tauri-apps/api/core\";\ninvoke(\"p🦀ng\");\n"
+    );
+}
+
+#[test]
+fn parse_incremental_rust_matches_full_parse() {
+    let text = check_incremental_matches_full(
+        "/test/lib.rs",
+        "#[tauri::command]\nfn greet() {}\n\nfn setup(app: AppHandle) {\n    app.emit(\"ready\", ()).unwrap();\n}\n",
+        &[
+            ([1, 3, 1, 8], "welcome"),
+            ([2, 0, 2, 0], "#[tauri::command]\nfn ping() {}\n"),
+            // Transient syntax error while typing
+            ([6, 14, 6, 19], "rea"),
+            ([6, 14, 6, 17], "started\", ());\n    app.emit(\"done"),
+        ],
+    );
+    assert!(text.contains("fn welcome() {}\n#[tauri::command]\nfn ping() {}\n"));
+    assert!(text.contains("app.emit(\"started\", ());\n    app.emit(\"done\", ()).unwrap();"));
+}
+
+#[test]
+fn parse_ts_promise_all_invokes() {
+    helpers::check_parse(
+        r#"
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+const [user, settings] = await Promise.all([
+    invoke<User>("get_user", { id }),
+    invoke("get_settings"),
+]);
+await Promise.all([invoke("a"), invoke("b")]);
+await Promise.allSettled(ids.map((id) => invoke("delete_item", { id })));
+"#,
+        expect![[r#"
+            /frontend.ts:
+              Command Call "get_user" 2:18..2:26 params=[id] return_type=User
+              Command Call "get_settings" 3:12..3:24
+              Command Call "a" 5:27..5:28
+              Command Call "b" 5:40..5:41
+              Command Call "delete_item" 6:49..6:60 params=[id]"#]],
+    );
+}
+
+#[test]
+fn parse_incremental_crlf_edit_past_line_end() {
+    // Clients may send a column past the end of the line; the edit must land before
+    // the `\r\n` terminator rather than between `\r` and `\n`
+    let text = check_incremental_matches_full(
+        "/test/app.ts",
+        "import { invoke } from \"@tauri-apps/api/core\";\r\ninvoke(\"greet\");\r\n",
+        &[([1, 100, 1, 100], " // done")],
+    );
+    assert_eq!(
+        text,
+        "import { invoke } from \"@tauri-apps/api/core\";\r\ninvoke(\"greet\"); // done\r\n"
+    );
+}
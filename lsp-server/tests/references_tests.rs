@@ -35,6 +35,36 @@ invoke("greet", { name: "Bob" });
     );
 }
 
+#[test]
+fn references_exclude_definition_without_include_declaration() {
+    let fixture = r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+invoke("gre$0et");
+invoke("greet");
+"#;
+
+    helpers::check_references_with_context(
+        fixture,
+        true,
+        expect![[r#"
+            /backend.rs 1:3..1:8
+            /frontend.ts 1:8..1:13
+            /frontend.ts 2:8..2:13"#]],
+    );
+    helpers::check_references_with_context(
+        fixture,
+        false,
+        expect![[r#"
+            /frontend.ts 1:8..1:13
+            /frontend.ts 2:8..2:13"#]],
+    );
+}
+
 #[test]
 fn references_from_ts_call_site() {
     helpers::check_references(
@@ -53,6 +83,120 @@ invoke("gre$0et");
     );
 }
 
+#[test]
+fn references_include_local_wrapper_call_sites() {
+    helpers::check_references(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn get_u$0ser() {}
+
+#[tauri::command]
+fn ping() {}
+
+//- /api.ts
+import { invoke as call } from "@tauri-apps/api/core";
+const api = {
+    getUser: () => call<User>("get_user"),
+    async load() { return await call("get_user"); },
+};
+function ping() {
+    return call("ping");
+}
+
+api.getUser();
+const user = await api.load();
+ping();
+other.getUser();
+"#,
+        expect![[r#"
+            /api.ts 10:23..10:27
+            /api.ts 2:31..2:39
+            /api.ts 3:38..3:46
+            /api.ts 9:4..9:11
+            /backend.rs 1:3..1:11"#]],
+    );
+}
+
+#[test]
+fn references_include_forwarding_invoke_wrapper_calls() {
+    helpers::check_references(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn get_u$0ser() {}
+
+//- /api.ts
+import * as core from "@tauri-apps/api/core";
+const invoke = <T>(cmd: string, args?: object) => core.invoke<T>(cmd, args);
+async function call(command: string) {
+    return await invoke(command);
+}
+
+const user = await invoke<User>("get_user", { id: 1 });
+await call("get_user");
+"#,
+        expect![[r#"
+            /api.ts 6:33..6:41
+            /api.ts 7:12..7:20
+            /backend.rs 1:3..1:11"#]],
+    );
+}
+
+#[test]
+fn references_include_imported_wrapper_call_sites() {
+    helpers::check_references(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn get_u$0ser() {}
+
+//- /api.ts
+import { invoke } from "@tauri-apps/api/core";
+export const getUser = () => invoke("get_user");
+export const api = { load: () => invoke("get_user") };
+
+//- /Component.tsx
+import { getUser as fetchUser, api } from "./api";
+import * as client from "./api";
+const user = await fetchUser();
+api.load();
+client.getUser();
+client.unknown();
+"#,
+        expect![[r#"
+            /Component.tsx 2:19..2:28
+            /Component.tsx 3:4..3:8
+            /Component.tsx 4:7..4:14
+            /api.ts 1:37..1:45
+            /api.ts 2:41..2:49
+            /backend.rs 1:3..1:11"#]],
+    );
+}
+
+#[test]
+fn references_from_imported_wrapper_call_site() {
+    helpers::check_references(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn get_user() {}
+
+//- /api.ts
+import { invoke } from "@tauri-apps/api/core";
+export const getUser = () => invoke("get_user");
+
+//- /Component.tsx
+import { getUser } from "./api";
+getU$0ser();
+"#,
+        expect![[r#"
+            /Component.tsx 1:0..1:7
+            /api.ts 1:37..1:45
+            /backend.rs 1:3..1:11"#]],
+    );
+}
+
 // ===========================================================================
 // Event references
 // ===========================================================================
@@ -78,6 +222,31 @@ listen("user-notification", (e) => console.log(e));
     );
 }
 
+#[test]
+fn references_specta_typed_event_links_to_frontend_listen() {
+    helpers::check_references(
+        r#"
+//- /backend.rs
+#[derive(Clone, Serialize, Type, tauri_specta::Event)]
+pub struct UserUpdated {
+    id: u32,
+}
+
+fn notify(app: &AppHandle) {
+    User$0Updated { id: 1 }.emit(&app).unwrap();
+}
+
+//- /frontend.ts
+import { listen } from "@tauri-apps/api/event";
+listen("user-updated", (e) => console.log(e));
+"#,
+        expect![[r#"
+            /backend.rs 1:11..1:22
+            /backend.rs 6:4..6:15
+            /frontend.ts 1:8..1:20"#]],
+    );
+}
+
 // ===========================================================================
 // No match
 // ===========================================================================
@@ -107,3 +276,26 @@ $0
         expect!["(none)"],
     );
 }
+
+#[test]
+fn references_include_invoke_through_local_const() {
+    helpers::check_references(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn get_$0user(id: u32) -> String {
+    id.to_string()
+}
+
+//- /frontend.ts
+import { invoke } from "@tauri-apps/api/core";
+export async function load() {
+    const cmd = "get_user";
+    return invoke(cmd, { id: 1 });
+}
+"#,
+        expect![[r#"
+            /backend.rs 1:3..1:11
+            /frontend.ts 2:17..2:25"#]],
+    );
+}
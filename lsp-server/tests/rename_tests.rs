@@ -0,0 +1,127 @@
+//! Rename (F2) tests
+//!
+//! Tests renaming events across Rust and frontend emit/listen sites.
+
+mod helpers;
+
+use expect_test::expect;
+
+#[test]
+fn rename_event_across_rust_and_frontend() {
+    helpers::check_rename(
+        r#"
+//- /backend.rs
+fn notify(app: tauri::AppHandle) {
+    app.emit("file-sa$0ved", ()).unwrap();
+}
+
+//- /frontend.ts
+listen("file-saved", () => {});
+emit("file-saved");
+"#,
+        "disk-saved",
+        expect![[r#"
+            prepare 1:14..1:24 "file-saved"
+            /backend.rs 1:14..1:24 "disk-saved"
+            /frontend.ts 0:8..0:18 "disk-saved"
+            /frontend.ts 1:6..1:16 "disk-saved""#]],
+    );
+}
+
+#[test]
+fn rename_event_from_frontend_listener() {
+    helpers::check_rename(
+        r#"
+//- /backend.rs
+fn notify(app: tauri::AppHandle) {
+    app.emit("progress", 42).unwrap();
+}
+
+//- /frontend.ts
+listen("prog$0ress", () => {});
+"#,
+        "download-progress",
+        expect![[r#"
+            prepare 0:8..0:16 "progress"
+            /backend.rs 1:14..1:22 "download-progress"
+            /frontend.ts 0:8..0:16 "download-progress""#]],
+    );
+}
+
+#[test]
+fn rename_event_leaves_differently_cased_event_alone() {
+    // `file-saved` and `file_saved` are distinct event names at runtime
+    helpers::check_rename(
+        r#"
+//- /backend.rs
+fn notify(app: tauri::AppHandle) {
+    app.emit("file-saved", ()).unwrap();
+    app.emit("file_saved", ()).unwrap();
+}
+
+//- /frontend.ts
+listen("file-sa$0ved", () => {});
+listen("file_saved", () => {});
+"#,
+        "disk-saved",
+        expect![[r#"
+            prepare 0:8..0:18 "file-saved"
+            /backend.rs 1:14..1:24 "disk-saved"
+            /frontend.ts 0:8..0:18 "disk-saved""#]],
+    );
+}
+
+#[test]
+fn rename_snake_case_event_keeps_other_convention_sites() {
+    helpers::check_rename(
+        r#"
+//- /backend.rs
+fn notify(app: tauri::AppHandle) {
+    app.emit("file-saved", ()).unwrap();
+    app.emit("file_s$0aved", ()).unwrap();
+}
+
+//- /frontend.ts
+listen("file-saved", () => {});
+listen("file_saved", () => {});
+"#,
+        "disk_saved",
+        expect![[r#"
+            prepare 2:14..2:24 "file_saved"
+            /backend.rs 2:14..2:24 "disk_saved"
+            /frontend.ts 1:8..1:18 "disk_saved""#]],
+    );
+}
+
+#[test]
+fn rename_command_is_not_supported() {
+    helpers::check_rename(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+
+//- /frontend.ts
+invoke("gre$0et");
+"#,
+        "welcome",
+        expect![[r#"
+            prepare (none)
+            (no edits)"#]],
+    );
+}
+
+#[test]
+fn rename_event_rejects_name_with_quote() {
+    helpers::check_rename(
+        r#"
+//- /frontend.ts
+emit("sav$0ed");
+listen("saved", () => {});
+"#,
+        "sa\"ved",
+        expect![[r#"
+            prepare 0:6..0:11 "saved"
+            (no edits)"#]],
+    );
+}
@@ -5,10 +5,12 @@ mod common_paths;
 
 use common_fixtures::load_fixture;
 use common_paths::test_path;
-use lsp_server::indexer::types::{CommandSchema, EventSchema};
+use lsp_server::indexer::types::{CommandSchema, EventSchema, RustTypeAlias};
 use lsp_server::rust_type_extractor::{
-    extract_command_schemas_from_tree, extract_event_schemas_from_tree, rust_type_to_ts,
+    expand_type_alias, extract_command_schemas_from_tree, extract_event_schemas_from_tree,
+    extract_type_aliases_from_tree, result_error_type, rust_type_to_ts,
 };
+use lsp_server::syntax::{is_optional_rust_type, FrontendPatterns};
 use std::path::Path;
 use tree_sitter::Parser;
 
@@ -29,6 +31,11 @@ fn extract_event_schemas(content: &str, source_path: &Path) -> Vec<EventSchema>
     extract_event_schemas_from_tree(tree.root_node(), content, source_path)
 }
 
+fn extract_type_aliases(content: &str, source_path: &Path) -> Vec<RustTypeAlias> {
+    let tree = parse_rust(content);
+    extract_type_aliases_from_tree(tree.root_node(), content, source_path)
+}
+
 // ============================================================
 // rust_type_to_ts primitive mappings
 // ============================================================
@@ -58,6 +65,19 @@ fn test_rust_type_to_ts_result() {
     );
 }
 
+#[test]
+fn test_rust_type_to_ts_qualified_result() {
+    assert_eq!(rust_type_to_ts("tauri::Result<User>"), "User");
+    assert_eq!(
+        rust_type_to_ts("std::result::Result<Vec<u8>, String>"),
+        "number[]"
+    );
+    assert_eq!(
+        rust_type_to_ts("std::option::Option<bool>"),
+        "boolean | null"
+    );
+}
+
 #[test]
 fn test_rust_type_to_ts_option() {
     assert_eq!(rust_type_to_ts("Option<String>"), "string | null");
@@ -76,6 +96,50 @@ fn test_rust_type_to_ts_unknown_passthrough() {
     assert_eq!(rust_type_to_ts("MyStruct"), "MyStruct");
 }
 
+#[test]
+fn test_result_error_type() {
+    assert_eq!(
+        result_error_type("Result<User, AppError>").as_deref(),
+        Some("AppError")
+    );
+    assert_eq!(
+        result_error_type("std::result::Result<(), String>").as_deref(),
+        Some("string")
+    );
+    assert_eq!(result_error_type("tauri::Result<User>"), None);
+    assert_eq!(result_error_type("Option<User>"), None);
+}
+
+#[test]
+fn test_is_optional_rust_type() {
+    assert!(is_optional_rust_type("Option<String>"));
+    assert!(is_optional_rust_type("std::option::Option<Vec<u8>>"));
+    assert!(is_optional_rust_type("&Option<String>"));
+    assert!(!is_optional_rust_type("MyOptionalThing"));
+    assert!(!is_optional_rust_type("MyOption<String>"));
+    assert!(!is_optional_rust_type("OptionalThing<String>"));
+    assert!(!is_optional_rust_type("Vec<Option<String>>"));
+}
+
+#[test]
+fn test_extract_optional_params() {
+    let content = r#"
+#[tauri::command]
+fn greet(name: String, nickname: Option<String>, mode: MyOptionalThing) {}
+"#;
+    let schemas = extract_command_schemas(content, &test_path("lib.rs"));
+
+    let optional: Vec<(&str, bool)> = schemas[0]
+        .params
+        .iter()
+        .map(|p| (p.name.as_str(), p.optional))
+        .collect();
+    assert_eq!(
+        optional,
+        [("name", false), ("nickname", true), ("mode", false)]
+    );
+}
+
 // ============================================================
 // extract_command_schemas from fixture
 // ============================================================
@@ -142,6 +206,26 @@ fn test_extract_option_return() {
     assert_eq!(find_user.unwrap().return_type, "string | null");
 }
 
+#[test]
+fn test_extract_command_with_interleaved_attrs() {
+    let content = load_fixture("rust/typed_commands.rs");
+    let schemas = extract_command_schemas(&content, &test_path("lib.rs"));
+
+    // #[tauri::command] followed by #[doc = "..."] and #[allow(...)]
+    let rename = schemas.iter().find(|s| s.command_name == "rename_user");
+    let s = rename.expect("Should find rename_user");
+    let names: Vec<_> = s.params.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["id", "new_name"]);
+    assert_eq!(s.return_type, "string");
+
+    // #[tauri::command] between #[allow(...)] and #[tracing::instrument(...)]
+    let delete = schemas.iter().find(|s| s.command_name == "delete_user");
+    let s = delete.expect("Should find delete_user");
+    let names: Vec<_> = s.params.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["id", "token"]);
+    assert_eq!(s.return_type, "boolean");
+}
+
 #[test]
 fn test_only_tauri_command_extracted() {
     let content = load_fixture("rust/typed_commands.rs");
@@ -155,6 +239,75 @@ fn test_only_tauri_command_extracted() {
     );
 }
 
+#[test]
+fn test_extract_state_wrapped_and_destructured_params() {
+    let content = r#"
+#[tauri::command]
+fn save(
+    State(db): State<'_, Database>,
+    state: tauri::State<'_, AppState>,
+    app: &tauri::AppHandle,
+    mut label: String,
+    (x, y): (i32, bool),
+    Json(payload): Json<Payload>,
+) {}
+"#;
+    let schemas = extract_command_schemas(content, &test_path("lib.rs"));
+    let params: Vec<_> = schemas[0]
+        .params
+        .iter()
+        .map(|p| format!("{}: {}", p.name, p.ts_type))
+        .collect();
+
+    assert_eq!(
+        params,
+        [
+            "label: string",
+            "x: number",
+            "y: boolean",
+            "payload: Payload"
+        ],
+        "Injected State/AppHandle params are skipped; patterns are flattened"
+    );
+}
+
+#[test]
+fn test_extract_argumented_command_attr() {
+    let content = r#"
+#[tauri::command(async, rename_all = "snake_case")]
+async fn save_user(user_name: String) {}
+
+#[tauri::command(async)]
+async fn load_user(user_id: u32) {}
+"#;
+    let path = test_path("lib.rs");
+    let schemas = extract_command_schemas(content, &path);
+    let frontend_params: Vec<_> = schemas
+        .iter()
+        .map(|s| {
+            let names: Vec<_> = s.params.iter().map(|p| s.frontend_param_name(p)).collect();
+            (s.command_name.as_str(), s.rename_all.as_deref(), names)
+        })
+        .collect();
+
+    assert_eq!(
+        frontend_params,
+        [
+            (
+                "save_user",
+                Some("snake_case"),
+                vec!["user_name".to_string()]
+            ),
+            ("load_user", None, vec!["userId".to_string()]),
+        ]
+    );
+
+    let (file_index, _) =
+        lsp_server::tree_parser::parse(&path, content, None, &FrontendPatterns::default()).unwrap();
+    let definitions: Vec<_> = file_index.findings.iter().map(|f| f.key.as_str()).collect();
+    assert_eq!(definitions, ["save_user", "load_user"]);
+}
+
 // ─── Event schema extraction ──────────────────────────────────────────────────
 
 #[test]
@@ -215,3 +368,83 @@ fn setup(app: AppHandle) {
     assert_eq!(schemas[0].event_name, "data-event");
     assert_eq!(schemas[0].payload_type, "MyData");
 }
+
+#[test]
+fn test_event_schema_emit_all_and_emit_to() {
+    let rust_code = r#"
+use tauri::AppHandle;
+
+fn setup(app: AppHandle) {
+    app.emit_all("count-changed", 42).unwrap();
+    app.emit_to("main", "label-changed", "hi").unwrap();
+}
+"#;
+
+    let schemas = extract_event_schemas(rust_code, &test_path("lib.rs"));
+    let found: Vec<_> = schemas
+        .iter()
+        .map(|s| (s.event_name.as_str(), s.payload_type.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![("count-changed", "number"), ("label-changed", "string")]
+    );
+}
+
+// ============================================================
+// Type aliases
+// ============================================================
+
+#[test]
+fn test_extract_type_aliases_skips_associated_types() {
+    let rust_code = r#"
+pub type ApiResult<T> = Result<T, AppError>;
+
+mod api {
+    type Users<'a, T: Clone> = Vec<&'a T>;
+}
+
+impl Iterator for Counter {
+    type Item = u32;
+}
+"#;
+
+    let aliases = extract_type_aliases(rust_code, &test_path("lib.rs"));
+    let found: Vec<_> = aliases
+        .iter()
+        .map(|a| (a.name.as_str(), a.generics.join(","), a.target.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            ("ApiResult", "T".to_string(), "Result<T, AppError>"),
+            ("Users", "'a,T".to_string(), "Vec<&'a T>"),
+        ]
+    );
+}
+
+#[test]
+fn test_expand_type_alias_one_level() {
+    let rust_code = r#"
+type ApiResult<T> = Result<T, AppError>;
+type Page<T> = ApiResult<Vec<T>>;
+type Loop<T> = Loop<T>;
+"#;
+    let aliases = extract_type_aliases(rust_code, &test_path("lib.rs"));
+
+    assert_eq!(
+        expand_type_alias("ApiResult<User>", &aliases[0]).as_deref(),
+        Some("Result<User, AppError>")
+    );
+    assert_eq!(
+        expand_type_alias("crate::ApiResult<Vec<u8>>", &aliases[0]).as_deref(),
+        Some("Result<Vec<u8>, AppError>")
+    );
+    // Only the outer alias is expanded
+    assert_eq!(
+        expand_type_alias("Page<User>", &aliases[1]).as_deref(),
+        Some("ApiResult<Vec<User>>")
+    );
+    assert_eq!(expand_type_alias("Loop<u32>", &aliases[2]), None);
+    assert_eq!(expand_type_alias("Result<User, E>", &aliases[0]), None);
+}
@@ -32,7 +32,7 @@ invoke("greet", { name: "World" });
 "#,
         expect![[r#"
             /frontend.ts:
-              Command Call "greet" 1:8..1:13"#]],
+              Command Call "greet" 1:8..1:13 params=[name]"#]],
     );
 }
 
@@ -115,7 +115,7 @@ invoke("gre$0et");
             - ⚡ 1 call(s)
 
             **Sample References:**
-            - ⚡️ `[CALL] frontend.ts:2`"#]],
+            - ⚡️ `[CALL] frontend.ts:2` TypeScript"#]],
     );
 }
 
@@ -143,7 +143,9 @@ fn smoke_diagnostics_undefined() {
 import { invoke } from "@tauri-apps/api/core";
 invoke("$0nonexistent");
 "#,
-        expect![[r#"WARNING 1:8..1:19 "Command 'nonexistent' is not defined in Rust backend""#]],
+        expect![[
+            r#"WARNING 1:8..1:19 "Command 'nonexistent' is not defined in Rust backend" [tarus/command-undefined]"#
+        ]],
     );
 }
 
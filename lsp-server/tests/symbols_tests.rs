@@ -3,6 +3,7 @@
 mod helpers;
 
 use expect_test::expect;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 // ===========================================================================
 // Document symbols
@@ -54,6 +55,29 @@ fn handle(app: &AppHandle) {
     );
 }
 
+#[test]
+fn document_symbols_hierarchical_struct_fields_are_children() {
+    helpers::check_document_symbols_hierarchical(
+        r#"
+//- /backend.rs
+$0
+#[derive(serde::Serialize)]
+struct User {
+    id: u32,
+    tags: Vec<String>,
+}
+
+#[tauri::command]
+fn get_user(id: u32) -> User { todo!() }
+"#,
+        expect![[r#"
+            Struct "User" 2:0..5:1
+              Field "id" 3:4..3:11 : number
+              Field "tags" 4:4..4:21 : string[]
+            Function "get_user (command)" 8:3..8:11 : Command"#]],
+    );
+}
+
 #[test]
 fn document_symbols_empty_file_returns_none() {
     helpers::check_document_symbols(
@@ -106,6 +130,67 @@ fn save_data() {}
     );
 }
 
+#[test]
+fn workspace_symbol_fuzzy_match_ranks_word_starts_first() {
+    let data = helpers::parse_fixture(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn arguments_user() {}
+
+#[tauri::command]
+fn get_user() {}
+
+#[tauri::command]
+fn save_data() {}
+"#,
+    );
+
+    let names: Vec<String> = data
+        .index
+        .search_workspace_symbols("gu", &AtomicBool::new(false))
+        .unwrap()
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+
+    assert_eq!(names, ["get_user (command)", "arguments_user (command)"]);
+}
+
+#[test]
+fn workspace_symbol_exact_and_prefix_matches_rank_highest() {
+    let data = helpers::parse_fixture(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn sync_user_greeting() {}
+
+#[tauri::command]
+fn greeting_sent() {}
+
+#[tauri::command]
+fn greeting() {}
+"#,
+    );
+
+    let names: Vec<String> = data
+        .index
+        .search_workspace_symbols("greeting", &AtomicBool::new(false))
+        .unwrap()
+        .into_iter()
+        .map(|s| s.name)
+        .collect();
+
+    assert_eq!(
+        names,
+        [
+            "greeting (command)",
+            "greeting_sent (command)",
+            "sync_user_greeting (command)"
+        ]
+    );
+}
+
 #[test]
 fn workspace_symbol_empty_query_returns_all() {
     helpers::check_workspace_symbols(
@@ -140,3 +225,26 @@ fn greet() {}
         expect!["(none)"],
     );
 }
+
+#[test]
+fn workspace_symbol_search_stops_when_cancelled() {
+    let data = helpers::parse_fixture(
+        r#"
+//- /backend.rs
+#[tauri::command]
+fn greet() {}
+"#,
+    );
+    let cancelled = AtomicBool::new(false);
+
+    assert!(data
+        .index
+        .search_workspace_symbols("greet", &cancelled)
+        .is_some_and(|syms| syms.len() == 1));
+
+    cancelled.store(true, Ordering::Relaxed);
+    assert!(data
+        .index
+        .search_workspace_symbols("greet", &cancelled)
+        .is_none());
+}
@@ -1,6 +1,11 @@
 //! UTF-16 character index conversion tests
 
-use lsp_server::utils::lsp_character_to_byte_index;
+use lsp_server::position::{
+    byte_offset_to_point, byte_offset_to_position, lsp_character_to_byte_index,
+    position_to_byte_offset,
+};
+use tower_lsp_server::lsp_types::Position;
+use tree_sitter::Point;
 
 #[test]
 fn test_lsp_character_to_byte_index_basic() {
@@ -21,6 +26,23 @@ fn test_lsp_character_to_byte_index_supplemental() {
     assert_eq!(lsp_character_to_byte_index(s2, 4), 6);
 }
 
+#[test]
+fn test_lsp_character_to_byte_index_inside_surrogate_pair() {
+    // 😀 is U+1F600: 2 UTF-16 units and 4 UTF-8 bytes, right before the cursor
+    let line = "invoke(\"😀|";
+    assert_eq!(lsp_character_to_byte_index(line, 8), 8); // before 😀
+    assert_eq!(lsp_character_to_byte_index(line, 9), 8); // inside 😀: start of the emoji
+    assert_eq!(lsp_character_to_byte_index(line, 10), 12); // after 😀
+    assert_eq!(lsp_character_to_byte_index(line, 11), 13); // end
+    assert_eq!(lsp_character_to_byte_index(line, 50), 13); // past the end
+
+    assert_eq!(
+        position_to_byte_offset("x\n😀b", Position::new(1, 1)),
+        2,
+        "a position inside the emoji falls back to its start"
+    );
+}
+
 #[test]
 fn test_lsp_character_to_byte_index_chinese() {
     // Chinese characters are usually 3 bytes in UTF-8 and 1 unit in UTF-16 (BMP).
@@ -51,3 +73,59 @@ fn test_lsp_character_to_byte_index_arabic() {
     assert_eq!(lsp_character_to_byte_index(s, 1), 2);
     assert_eq!(lsp_character_to_byte_index(s, 5), 10);
 }
+
+#[test]
+fn test_byte_offset_to_position_multibyte() {
+    // 😀 is 4 bytes in UTF-8 and 2 units in UTF-16; é is 2 bytes and 1 unit
+    let text = "a😀b\né!";
+
+    assert_eq!(byte_offset_to_position(text, 5), Position::new(0, 3)); // before 'b'
+    assert_eq!(byte_offset_to_position(text, 7), Position::new(1, 0)); // before 'é'
+    assert_eq!(byte_offset_to_position(text, 9), Position::new(1, 1)); // before '!'
+    assert_eq!(byte_offset_to_position(text, 3), Position::new(0, 1)); // inside 😀: clamped
+    assert_eq!(byte_offset_to_position(text, 100), Position::new(1, 2)); // past the end
+}
+
+#[test]
+fn test_position_round_trip() {
+    let text = "let a = \"你好\";\nconst 𐐀 = invoke(\"greet\");";
+
+    for (offset, _) in text.char_indices() {
+        let position = byte_offset_to_position(text, offset);
+        assert_eq!(
+            position_to_byte_offset(text, position),
+            offset,
+            "{position:?}"
+        );
+    }
+}
+
+#[test]
+fn test_positions_with_crlf_line_endings() {
+    let text = "// header\r\ninvoke(\"greet\");\r\n\r\nemit(\"done\");";
+    let invoke = text.find("invoke").unwrap();
+    let emit = text.find("emit").unwrap();
+
+    assert_eq!(byte_offset_to_position(text, invoke), Position::new(1, 0));
+    assert_eq!(byte_offset_to_position(text, emit), Position::new(3, 0));
+    assert_eq!(
+        byte_offset_to_point(text, emit),
+        Point { row: 3, column: 0 }
+    );
+    assert_eq!(
+        position_to_byte_offset(text, Position::new(1, 8)),
+        invoke + 8
+    );
+    assert_eq!(position_to_byte_offset(text, Position::new(3, 0)), emit);
+
+    // The line terminator is `\r\n`: columns never include the `\r`
+    let header_end = text.find('\r').unwrap();
+    assert_eq!(
+        byte_offset_to_position(text, header_end + 1),
+        Position::new(0, 9)
+    );
+    assert_eq!(
+        position_to_byte_offset(text, Position::new(0, 50)),
+        header_end
+    );
+}